@@ -7,6 +7,7 @@ pub struct DisputeMetricsAccumulator {
     pub disputes_status_rate: RateAccumulator,
     pub disputed_amount: DisputedAmountAccumulator,
     pub dispute_lost_amount: DisputedAmountAccumulator,
+    pub deadline_missed: CountAccumulator,
 }
 #[derive(Debug, Default)]
 pub struct RateAccumulator {
@@ -20,6 +21,11 @@ pub struct RateAccumulator {
 pub struct DisputedAmountAccumulator {
     pub total: Option<i64>,
 }
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
 
 pub trait DisputeMetricAccumulator {
     type MetricOutput;
@@ -51,6 +57,22 @@ impl DisputeMetricAccumulator for DisputedAmountAccumulator {
     }
 }
 
+impl DisputeMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
 impl DisputeMetricAccumulator for RateAccumulator {
     type MetricOutput = Option<(Option<u64>, Option<u64>, Option<u64>, Option<u64>)>;
 
@@ -94,6 +116,7 @@ impl DisputeMetricsAccumulator {
             disputes_lost: lost_rate,
             disputed_amount: self.disputed_amount.collect(),
             dispute_lost_amount: self.dispute_lost_amount.collect(),
+            disputes_deadline_missed: self.deadline_missed.collect(),
             total_dispute,
         }
     }
@@ -98,6 +98,9 @@ pub async fn get_metrics(
                 | DisputeMetrics::SessionizedTotalDisputeLostAmount => metrics_builder
                     .dispute_lost_amount
                     .add_metrics_bucket(&value),
+                DisputeMetrics::DisputesDeadlineMissed => {
+                    metrics_builder.deadline_missed.add_metrics_bucket(&value)
+                }
             }
         }
 
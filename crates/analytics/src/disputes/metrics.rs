@@ -1,4 +1,5 @@
 mod dispute_status_metric;
+mod disputes_deadline_missed;
 mod sessionized_metrics;
 mod total_amount_disputed;
 mod total_dispute_lost_amount;
@@ -14,8 +15,8 @@ use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
 
 use self::{
-    dispute_status_metric::DisputeStatusMetric, total_amount_disputed::TotalAmountDisputed,
-    total_dispute_lost_amount::TotalDisputeLostAmount,
+    dispute_status_metric::DisputeStatusMetric, disputes_deadline_missed::DisputesDeadlineMissed,
+    total_amount_disputed::TotalAmountDisputed, total_dispute_lost_amount::TotalDisputeLostAmount,
 };
 use crate::{
     enums::AuthInfo,
@@ -94,6 +95,11 @@ where
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
             }
+            Self::DisputesDeadlineMissed => {
+                DisputesDeadlineMissed::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
             Self::SessionizedTotalAmountDisputed => {
                 sessionized_metrics::TotalAmountDisputed::default()
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
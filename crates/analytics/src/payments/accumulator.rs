@@ -18,6 +18,7 @@ pub struct PaymentMetricsAccumulator {
     pub connector_success_rate: SuccessRateAccumulator,
     pub payments_distribution: PaymentsDistributionAccumulator,
     pub failure_reasons_distribution: FailureReasonsDistributionAccumulator,
+    pub estimated_connector_cost: EstimatedConnectorCostAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -70,6 +71,12 @@ pub struct RetriesAmountAccumulator {
     pub total: Option<i64>,
 }
 
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct EstimatedConnectorCostAccumulator {
+    pub total: Option<i64>,
+}
+
 #[derive(Debug, Default)]
 pub struct PaymentsDistributionAccumulator {
     pub success: u32,
@@ -393,6 +400,24 @@ impl PaymentMetricAccumulator for RetriesAmountAccumulator {
     }
 }
 
+impl PaymentMetricAccumulator for EstimatedConnectorCostAccumulator {
+    type MetricOutput = Option<u64>;
+    fn add_metrics_bucket(&mut self, metrics: &PaymentMetricRow) {
+        self.total = match (
+            self.total,
+            metrics.total.as_ref().and_then(ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        };
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.total.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
 impl PaymentMetricAccumulator for AverageAccumulator {
     type MetricOutput = Option<f64>;
 
@@ -463,6 +488,8 @@ impl PaymentMetricsAccumulator {
             failure_reason_count_without_smart_retries,
             payment_processed_amount_in_usd,
             payment_processed_amount_without_smart_retries_usd,
+            estimated_connector_cost: self.estimated_connector_cost.collect(),
+            estimated_connector_cost_in_usd: None,
         }
     }
 }
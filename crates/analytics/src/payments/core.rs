@@ -181,6 +181,11 @@ pub async fn get_metrics(
                                 .failure_reasons_distribution
                                 .add_metrics_bucket(&value);
                         }
+                        PaymentMetrics::EstimatedConnectorCost => {
+                            metrics_builder
+                                .estimated_connector_cost
+                                .add_metrics_bucket(&value);
+                        }
                     }
                 }
 
@@ -285,6 +290,28 @@ pub async fn get_metrics(
                 total_payment_processed_amount_without_smart_retries_usd +=
                     amount_in_usd.unwrap_or(0);
             }
+            if let Some(cost) = collected_values.estimated_connector_cost {
+                collected_values.estimated_connector_cost_in_usd =
+                    ex_rates.as_ref().and_then(|ex_rates| {
+                        id.currency
+                            .and_then(|currency| {
+                                i64::try_from(cost)
+                                    .inspect_err(|e| {
+                                        logger::error!("Amount conversion error: {:?}", e)
+                                    })
+                                    .ok()
+                                    .and_then(|cost_i64| {
+                                        convert(ex_rates, currency, Currency::USD, cost_i64)
+                                            .inspect_err(|e| {
+                                                logger::error!("Currency conversion error: {:?}", e)
+                                            })
+                                            .ok()
+                                    })
+                            })
+                            .map(|cost| (cost * rust_decimal::Decimal::new(100, 0)).to_u64())
+                            .unwrap_or_default()
+                    });
+            }
             if let Some(count) = collected_values.payment_processed_count_without_smart_retries {
                 total_payment_processed_count_without_smart_retries += count;
             }
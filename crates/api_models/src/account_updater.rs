@@ -0,0 +1,68 @@
+use common_utils::{events::ApiEventMetric, id_type};
+use utoipa::ToSchema;
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountUpdaterScheduleResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether batches of cards are periodically sent for updates
+    pub is_scheduled: bool,
+    /// Time at which the next batch is exported
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_batch_at: Option<time::PrimitiveDateTime>,
+    pub batch_interval_in_hours: u32,
+}
+
+impl ApiEventMetric for AccountUpdaterScheduleResponse {}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountUpdaterBatchResponse {
+    /// Identifier of the batch, used to upload the response file of the batch
+    pub batch_id: String,
+    /// Number of cards in the request file of the batch
+    pub record_count: u32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+impl ApiEventMetric for AccountUpdaterBatchResponse {}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AccountUpdaterBatchId {
+    pub batch_id: String,
+}
+
+impl ApiEventMetric for AccountUpdaterBatchId {}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountUpdaterRequestFileResponse {
+    pub batch_id: String,
+    /// Identifier of the key of the card network updater the request file is encrypted with
+    pub key_id: String,
+    /// Request file of the batch as a JWE, which can only be decrypted by the card network updater
+    pub encrypted_file: String,
+}
+
+impl ApiEventMetric for AccountUpdaterRequestFileResponse {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AccountUpdaterResponseFileRequest {
+    pub batch_id: String,
+    /// Response file received from the card networks, it contains card numbers
+    #[serde(skip)]
+    pub file: masking::Secret<Vec<u8>>,
+}
+
+impl ApiEventMetric for AccountUpdaterResponseFileRequest {}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct AccountUpdaterResponseFileResponse {
+    pub batch_id: String,
+    /// Number of card updates in the response file
+    pub record_count: u32,
+    /// Time at which the updates in the response file are applied to the payment methods
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub ingest_scheduled_at: time::PrimitiveDateTime,
+}
+
+impl ApiEventMetric for AccountUpdaterResponseFileResponse {}
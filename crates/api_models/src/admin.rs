@@ -943,6 +943,10 @@ pub struct MerchantConnectorCreate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees charged by the connector, used to rank the connectors of a cost based routing algorithm by the expected cost of a payment
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1289,6 +1293,10 @@ pub struct MerchantConnectorResponse {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees charged by the connector, used to rank the connectors of a cost based routing algorithm by the expected cost of a payment
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -1390,6 +1398,49 @@ pub struct MerchantConnectorListResponse {
     pub status: api_enums::ConnectorStatus,
 }
 
+/// State of the circuit breaker of a merchant connector account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorCircuitState {
+    /// Payments are routed to the connector account
+    Closed,
+    /// The connector account is skipped during connector selection
+    Open,
+    /// Payments are routed to the connector account again, to find out whether it has recovered
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorCircuitBreakerResponse {
+    /// Unique ID of the merchant connector account
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// Name of the Connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: String,
+
+    /// Identifier of the profile the connector account belongs to
+    #[schema(max_length = 64, value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// State of the circuit breaker of the connector account
+    pub state: ConnectorCircuitState,
+
+    /// Time at which the circuit was last opened, if it is open or half open
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<time::PrimitiveDateTime>,
+
+    /// Number of calls made to the connector account in the current window
+    pub calls_in_window: u64,
+
+    /// Number of the calls made to the connector account in the current window which failed or
+    /// were slow
+    pub failed_calls_in_window: u64,
+}
+
 #[cfg(feature = "v1")]
 impl MerchantConnectorListResponse {
     pub fn to_merchant_connector_info(&self, connector_label: &String) -> MerchantConnectorInfo {
@@ -1549,6 +1600,10 @@ pub struct MerchantConnectorUpdate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Fees charged by the connector, used to rank the connectors of a cost based routing algorithm by the expected cost of a payment
+    #[schema(value_type = Option<ConnectorFeeSchedule>)]
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -2019,6 +2074,14 @@ pub struct ProfileCreate {
 
     /// Indicates if pre network tokenization is enabled or not
     pub is_pre_network_tokenization_enabled: Option<bool>,
+
+    /// Policy applied to the disputes of the profile while their evidence deadline approaches
+    #[schema(value_type = Option<DisputePolicy>)]
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+
+    /// Policy applied to the payments of the profile when a connector response does not match the request sent to the connector
+    #[schema(value_type = Option<IntegrityCheckPolicy>)]
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[nutype::nutype(
@@ -2323,6 +2386,14 @@ pub struct ProfileResponse {
     /// Indicates if pre network tokenization is enabled or not
     #[schema(default = false, example = false)]
     pub is_pre_network_tokenization_enabled: bool,
+
+    /// Policy applied to the disputes of the profile while their evidence deadline approaches
+    #[schema(value_type = Option<DisputePolicy>)]
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+
+    /// Policy applied to the payments of the profile when a connector response does not match the request sent to the connector
+    #[schema(value_type = Option<IntegrityCheckPolicy>)]
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v2")]
@@ -2627,6 +2698,14 @@ pub struct ProfileUpdate {
     /// Indicates if pre network tokenization is enabled or not
     #[schema(default = false, example = false)]
     pub is_pre_network_tokenization_enabled: Option<bool>,
+
+    /// Policy applied to the disputes of the profile while their evidence deadline approaches
+    #[schema(value_type = Option<DisputePolicy>)]
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+
+    /// Policy applied to the payments of the profile when a connector response does not match the request sent to the connector
+    #[schema(value_type = Option<IntegrityCheckPolicy>)]
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v2")]
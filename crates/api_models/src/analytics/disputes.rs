@@ -24,6 +24,7 @@ pub enum DisputeMetrics {
     DisputeStatusMetric,
     TotalAmountDisputed,
     TotalDisputeLostAmount,
+    DisputesDeadlineMissed,
     SessionizedDisputeStatusMetric,
     SessionizedTotalAmountDisputed,
     SessionizedTotalDisputeLostAmount,
@@ -143,6 +144,7 @@ pub struct DisputeMetricsBucketValue {
     pub disputes_lost: Option<u64>,
     pub disputed_amount: Option<u64>,
     pub dispute_lost_amount: Option<u64>,
+    pub disputes_deadline_missed: Option<u64>,
     pub total_dispute: Option<u64>,
 }
 #[derive(Debug, serde::Serialize)]
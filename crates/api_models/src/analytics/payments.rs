@@ -117,6 +117,7 @@ pub enum PaymentMetrics {
     SessionizedConnectorSuccessRate,
     PaymentsDistribution,
     FailureReasons,
+    EstimatedConnectorCost,
 }
 
 impl ForexMetric for PaymentMetrics {
@@ -127,6 +128,7 @@ impl ForexMetric for PaymentMetrics {
                 | Self::AvgTicketSize
                 | Self::SessionizedPaymentProcessedAmount
                 | Self::SessionizedAvgTicketSize
+                | Self::EstimatedConnectorCost
         )
     }
 }
@@ -302,6 +304,8 @@ pub struct PaymentMetricsBucketValue {
     pub payments_failure_rate_distribution_with_only_retries: Option<f64>,
     pub failure_reason_count: Option<u64>,
     pub failure_reason_count_without_smart_retries: Option<u64>,
+    pub estimated_connector_cost: Option<u64>,
+    pub estimated_connector_cost_in_usd: Option<u64>,
 }
 
 #[derive(Debug, serde::Serialize)]
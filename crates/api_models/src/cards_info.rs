@@ -126,3 +126,113 @@ impl From<CardInfoMigrationResponseType> for CardInfoMigrationResponse {
         }
     }
 }
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardBinImportFileFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardBinRangeRecord {
+    /// Lower bound of the BIN range, between 6 and 19 digits
+    #[schema(example = "41111100")]
+    pub range_start: String,
+    /// Upper bound of the BIN range, between 6 and 19 digits. The range covers only `range_start` when not provided
+    #[schema(example = "41111199")]
+    pub range_end: Option<String>,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bank_code_id: Option<String>,
+    pub bank_code: Option<String>,
+    pub country_code: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct CardBinImportRequest {
+    pub source: String,
+    pub file_format: CardBinImportFileFormat,
+    pub ranges: Vec<CardBinRangeRecord>,
+}
+
+impl ApiEventMetric for CardBinImportRequest {}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CardBinImportResponse {
+    /// Version assigned to the import
+    pub version: i32,
+    /// Provider of the imported BIN data
+    pub source: String,
+    pub file_format: CardBinImportFileFormat,
+    /// Number of BIN ranges in the import
+    pub total_ranges: i32,
+    /// Number of BIN ranges not present in the previously active import
+    pub ranges_added: i32,
+    /// Number of BIN ranges of the previously active import not present in this import
+    pub ranges_removed: i32,
+    /// Number of BIN ranges whose details differ from the previously active import
+    pub ranges_modified: i32,
+    /// Whether this import is used for BIN lookups
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+impl ApiEventMetric for CardBinImportResponse {}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardBinImportListConstraints {
+    /// Limit on the number of imports to return
+    pub limit: Option<i64>,
+    /// The starting point within the list of imports
+    pub offset: Option<i64>,
+}
+
+impl ApiEventMetric for CardBinImportListConstraints {}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardBinImportRollbackRequest {
+    /// Version of the import to be restored. Defaults to the version preceding the active import
+    pub version: Option<i32>,
+}
+
+impl ApiEventMetric for CardBinImportRollbackRequest {}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardBinImportDiffRequest {
+    /// Version of the import whose changes are requested
+    #[serde(skip_deserializing)]
+    pub version: i32,
+    /// Version to compare against. Defaults to the version preceding `version`
+    pub compare_with: Option<i32>,
+}
+
+impl ApiEventMetric for CardBinImportDiffRequest {}
+
+#[derive(Debug, Default, serde::Serialize, ToSchema)]
+pub struct CardBinImportDiffResponse {
+    pub version: i32,
+    pub compared_with: Option<i32>,
+    /// BIN ranges present only in `version`
+    pub added: Vec<CardBinRangeRecord>,
+    /// BIN ranges present only in `compared_with`
+    pub removed: Vec<CardBinRangeRecord>,
+    /// BIN ranges present in both versions with different details, as present in `version`
+    pub modified: Vec<CardBinRangeRecord>,
+}
+
+impl ApiEventMetric for CardBinImportDiffResponse {}
@@ -375,3 +375,116 @@ pub struct CustomerUpdateRequestInternal {
     pub id: id_type::GlobalCustomerId,
     pub request: CustomerUpdateRequest,
 }
+
+/// Request to schedule the erasure of all the data linked to a customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerErasureRequest {
+    /// Time at which the data of the customer is erased. Defaults to the end of the configured
+    /// grace period, during which the erasure can still be cancelled.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub scheduled_at: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerErasureRequestInternal {
+    pub customer_id: id_type::CustomerId,
+    pub request: CustomerErasureRequest,
+}
+
+/// Status of the erasure of the data of a customer
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerErasureStatus {
+    /// The erasure is waiting for its scheduled time
+    Scheduled,
+    /// The data of the customer has been erased
+    Completed,
+    /// The erasure was cancelled before it was carried out
+    Cancelled,
+    /// The erasure could not be carried out, it has to be scheduled again
+    Failed,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerErasureResponse {
+    /// Identifier of the erasure request
+    #[schema(example = "CUSTOMER_ERASURE_merchant_1668273825_cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub erasure_id: String,
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// Status of the erasure
+    pub status: CustomerErasureStatus,
+    /// Time at which the erasure is carried out
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub scheduled_at: time::PrimitiveDateTime,
+    /// Time at which the erasure request was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerDataExportResponse {
+    /// Identifier of the export, used to retrieve the archive
+    #[schema(example = "dsr_export_3Ddbb3tsyEkUqJXqZMpv")]
+    pub export_id: String,
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// Hex encoded AES-256-GCM key of the archive. The archive is the 12 byte nonce followed by
+    /// the encrypted JSON document. The key is not stored, it is only returned in this response.
+    #[schema(value_type = String)]
+    pub archive_key: Secret<String>,
+    /// Time at which the archive was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerDataExportRetrieveRequest {
+    pub customer_id: id_type::CustomerId,
+    pub export_id: String,
+}
+
+/// All the data linked to a customer, as contained in the archive of a data export
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchive {
+    pub customer: CustomerResponse,
+    pub addresses: Vec<payments::Address>,
+    pub payment_methods: Vec<CustomerDataArchivePaymentMethod>,
+    pub mandates: Vec<crate::mandates::MandateResponse>,
+    pub payments: Vec<payments::PaymentsResponse>,
+    pub refunds: Vec<crate::refunds::RefundResponse>,
+    pub disputes: Vec<crate::disputes::DisputeResponse>,
+    pub events: Vec<crate::webhook_events::EventListItemResponse>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub exported_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize)]
+pub struct CustomerDataArchivePaymentMethod {
+    pub payment_method_id: String,
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub status: common_enums::PaymentMethodStatus,
+    /// Stored details of the payment method, card numbers are never part of the archive
+    pub payment_method_data: Option<pii::SecretSerdeValue>,
+    pub payment_method_billing_address: Option<pii::SecretSerdeValue>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub last_used_at: time::PrimitiveDateTime,
+}
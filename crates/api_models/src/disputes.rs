@@ -216,6 +216,14 @@ pub struct SubmitEvidenceRequest {
     pub uncategorized_text: Option<String>,
 }
 
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeEvidenceDraftResponse {
+    /// Evidence pre-filled from the data available for the disputed payment, to be reviewed before submission
+    pub evidence: SubmitEvidenceRequest,
+    /// File Id of the generated evidence summary document
+    pub summary_file_id: String,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct DeleteEvidenceRequest {
     /// Id of the dispute
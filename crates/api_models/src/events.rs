@@ -75,6 +75,7 @@ impl_api_event_type!(
         CreateFileResponse,
         MerchantConnectorResponse,
         MerchantConnectorId,
+        ConnectorCircuitBreakerResponse,
         MandateResponse,
         MandateRevokedResponse,
         RetrievePaymentLinkRequest,
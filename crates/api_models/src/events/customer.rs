@@ -1,5 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
+#[cfg(feature = "v1")]
+use crate::customers::{
+    CustomerDataExportResponse, CustomerDataExportRetrieveRequest, CustomerErasureRequestInternal,
+    CustomerErasureResponse,
+};
 use crate::customers::{
     CustomerDeleteResponse, CustomerRequest, CustomerResponse, CustomerUpdateRequestInternal,
 };
@@ -73,3 +78,39 @@ impl ApiEventMetric for CustomerUpdateRequestInternal {
         })
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerErasureRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerErasureResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataExportRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
@@ -1,8 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputesAggregateResponse, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeEvidenceDraftResponse, DisputeResponse,
+    DisputeResponsePaymentsRetrieve, DisputesAggregateResponse, SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -34,6 +34,14 @@ impl ApiEventMetric for DeleteEvidenceRequest {
     }
 }
 
+impl ApiEventMetric for DisputeEvidenceDraftResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.evidence.dispute_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for DisputesAggregateResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -31,6 +31,8 @@ pub struct GsmCreateRequest {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
+    /// delay in seconds before a payment is retried when the decision is `requeue`, doubled for every attempt already made on the payment
+    pub requeue_delay_in_seconds: Option<i32>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -97,6 +99,24 @@ pub struct GsmUpdateRequest {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: Option<bool>,
+    /// delay in seconds before a payment is retried when the decision is `requeue`, doubled for every attempt already made on the payment. Set to `null` to clear the delay, the field is left as is when it is not passed
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<i32>)]
+    pub requeue_delay_in_seconds: Option<Option<i32>>,
+}
+
+/// Tells apart a field set to `null`, deserialized as `Some(None)`, from a field which is not
+/// passed, deserialized as `None` through `#[serde(default)]`
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    <Option<T> as serde::Deserialize>::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -154,4 +174,6 @@ pub struct GsmResponse {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
+    /// delay in seconds before a payment is retried when the decision is `requeue`, doubled for every attempt already made on the payment
+    pub requeue_delay_in_seconds: Option<i32>,
 }
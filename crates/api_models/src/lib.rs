@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
@@ -822,7 +822,7 @@ pub enum PaymentMethodResponseData {
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -1930,6 +1930,47 @@ pub struct CustomerPaymentMethodsListResponse {
     pub is_guest_customer: Option<bool>,
 }
 
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CustomerNetworkTokensListResponse {
+    /// The unique identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// List of network tokens provisioned for the payment methods of the customer
+    pub network_tokens: Vec<CustomerNetworkToken>,
+}
+
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CustomerNetworkToken {
+    /// The unique identifier of the payment method the network token belongs to
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+    /// The status of the network token
+    #[schema(value_type = NetworkTokenStatus, example = "active")]
+    pub status: api_enums::NetworkTokenStatus,
+    /// The card network of the network token
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// The last four digits of the network token
+    #[schema(example = "4242")]
+    pub token_last4: Option<String>,
+    /// The expiry month of the network token
+    #[schema(value_type = Option<String>, example = "10")]
+    pub token_expiry_month: Option<masking::Secret<String>>,
+    /// The expiry year of the network token
+    #[schema(value_type = Option<String>, example = "2030")]
+    pub token_expiry_year: Option<masking::Secret<String>>,
+    /// The url of the card art provided by the card network
+    pub card_art_url: Option<String>,
+}
+
 // OLAP PML Response
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 #[derive(Debug, serde::Serialize, ToSchema)]
@@ -1136,6 +1136,12 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<bool>, default = false)]
     pub request_extended_authorization: Option<RequestExtendedAuthorizationBool>,
 
+    /// Optional boolean value to accept a partial approval of the amount by the connector.
+    /// The remaining balance can then be paid with another payment method by confirming the payment again,
+    /// for example when paying with a gift card and a card
+    #[schema(value_type = Option<bool>, default = false)]
+    pub enable_partial_authorization: Option<bool>,
+
     /// Merchant's identifier for the payment/invoice. This will be sent to the connector
     /// if the connector provides support to accept multiple reference ids.
     /// In case the connector supports only one reference id, Hyperswitch's Payment ID will be sent as reference.
@@ -5153,6 +5159,9 @@ pub struct PaymentsResponse {
     /// Indicates if the redirection has to open in the iframe
     pub is_iframe_redirection_enabled: Option<bool>,
 
+    /// Indicates if the payment accepts partial approvals and can be paid with several payment methods
+    pub enable_partial_authorization: Option<bool>,
+
     /// Contains whole connector response
     pub whole_connector_response: Option<String>,
 }
@@ -7561,6 +7570,89 @@ pub struct PaymentsManualUpdateResponse {
     pub connector_transaction_id: Option<String>,
 }
 
+#[derive(Debug, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsEligibilityExplanationRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The payment method to explain the eligibility of the merchant's connectors for
+    #[schema(value_type = PaymentMethod, example = "wallet")]
+    pub payment_method: api_enums::PaymentMethod,
+    /// The payment method type to explain the eligibility of the merchant's connectors for
+    #[schema(value_type = Option<PaymentMethodType>, example = "apple_pay")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsEligibilityExplanationResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The payment method the eligibility was explained for
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: api_enums::PaymentMethod,
+    /// The payment method type the eligibility was explained for
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The eligibility of each of the merchant connector accounts of the profile of the payment
+    pub connectors: Vec<ConnectorEligibilityExplanation>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct ConnectorEligibilityExplanation {
+    /// The connector the eligibility was explained for
+    #[schema(value_type = Connector)]
+    pub connector: api_enums::RoutableConnectors,
+    /// The identifier for the merchant connector account
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// Whether the connector is eligible for the payment
+    pub eligible: bool,
+    /// The constraints the payment does not satisfy, empty if the connector is eligible
+    pub reasons: Vec<IneligibilityReason>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct IneligibilityReason {
+    /// The constraints through which the failing constraint was reached, starting from the
+    /// connector
+    #[schema(example = json!(["Connector: connector stripe", "Config for respective PaymentMethodType for the connector"]))]
+    pub path: Vec<String>,
+    /// The failing constraint
+    #[schema(example = "payment_currency EUR not in [GBP, USD] (Configs for Currency)")]
+    pub message: String,
+}
+
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct PaymentsRoutingTraceRequest {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsRoutingTraceResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The routing traces of the payment attempts, oldest first
+    pub traces: Vec<PaymentAttemptRoutingTrace>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentAttemptRoutingTrace {
+    /// The identifier for the payment attempt that was routed
+    pub attempt_id: String,
+    /// The connector the payment attempt was routed to first
+    pub routed_connector: Option<String>,
+    /// The time at which the payment attempt was routed
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// How the connectors for the payment attempt were chosen
+    pub trace: crate::routing::RoutingTrace,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub enum ThreeDsCompletionIndicator {
     /// 3DS method successfully completed
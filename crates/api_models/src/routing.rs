@@ -155,6 +155,8 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::PayoutRecipientCountry,
+        DirKeyKind::PayoutPriority,
     ];
 }
 
@@ -305,6 +307,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    CostBased,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -342,6 +345,10 @@ pub enum StaticRoutingAlgorithm {
     Advanced(Program<ConnectorSelection>),
     #[schema(value_type=ProgramThreeDsDecisionRule)]
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    /// Connectors ranked by the expected cost of the payment, estimated from the fee schedules of
+    /// their merchant connector accounts. The order of the list breaks ties, and the connectors
+    /// without a fee applying to the payment follow the others in that order.
+    CostBased(Vec<RoutableConnectorChoice>),
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -366,7 +373,11 @@ pub struct RuleThreeDsDecisionRule {
 impl StaticRoutingAlgorithm {
     pub fn should_validate_connectors_in_routing_config(&self) -> bool {
         match self {
-            Self::Single(_) | Self::Priority(_) | Self::VolumeSplit(_) | Self::Advanced(_) => true,
+            Self::Single(_)
+            | Self::Priority(_)
+            | Self::VolumeSplit(_)
+            | Self::Advanced(_)
+            | Self::CostBased(_) => true,
             Self::ThreeDsDecisionRule(_) => false,
         }
     }
@@ -380,6 +391,7 @@ pub enum RoutingAlgorithmSerde {
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(Program<ConnectorSelection>),
     ThreeDsDecisionRule(Program<ThreeDSDecisionRule>),
+    CostBased(Vec<RoutableConnectorChoice>),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
@@ -397,6 +409,11 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            RoutingAlgorithmSerde::CostBased(i) if i.is_empty() => {
+                Err(ParsingError::StructParseFailure(
+                    "Connectors list can't be empty for Cost based Algorithm",
+                ))?
+            }
             _ => {}
         };
         Ok(match value {
@@ -405,6 +422,7 @@ impl TryFrom<RoutingAlgorithmSerde> for StaticRoutingAlgorithm {
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
             RoutingAlgorithmSerde::ThreeDsDecisionRule(i) => Self::ThreeDsDecisionRule(i),
+            RoutingAlgorithmSerde::CostBased(i) => Self::CostBased(i),
         })
     }
 }
@@ -508,6 +526,7 @@ impl StaticRoutingAlgorithm {
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
             Self::ThreeDsDecisionRule(_) => RoutingAlgorithmKind::ThreeDsDecisionRule,
+            Self::CostBased(_) => RoutingAlgorithmKind::CostBased,
         }
     }
 }
@@ -1348,7 +1367,7 @@ pub struct CalSuccessRateEventResponse {
     pub routing_approach: RoutingApproach,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RoutingApproach {
     Exploitation,
@@ -1533,3 +1552,136 @@ pub enum ContractUpdationStatusEventResponse {
     ContractUpdationSucceeded,
     ContractUpdationFailed,
 }
+
+/// Record of how the connectors for a payment attempt were chosen
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct RoutingTrace {
+    /// The routing algorithm that was active on the profile, absent when the default fallback
+    /// configuration of the profile was used
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The version of the routing algorithm, which is the unix timestamp at which the algorithm
+    /// was activated on the profile
+    pub algorithm_version: Option<i64>,
+    /// The kind of the routing algorithm that was evaluated
+    pub algorithm_kind: Option<RoutingAlgorithmKind>,
+    /// The rule of the advanced routing algorithm that matched the payment, absent when the
+    /// default selection of the algorithm was used
+    pub matched_rule: Option<String>,
+    /// The connectors chosen by the routing algorithm
+    pub algorithm_connectors: Vec<RoutableConnectorChoice>,
+    /// The connectors chosen by the routing algorithm that were removed by the constraint graph
+    pub ineligible_connectors: Vec<RoutableConnectorChoice>,
+    /// The connectors added from the default fallback configuration of the profile
+    pub fallback_connectors: Vec<RoutableConnectorChoice>,
+    /// The connectors that were eligible for the payment after constraint graph filtering,
+    /// including the fallback connectors
+    pub eligible_connectors: Vec<RoutableConnectorChoice>,
+    /// The connectors that were skipped as their circuit breaker was open
+    pub open_circuit_connectors: Vec<RoutableConnectorChoice>,
+    /// The outcome of each of the dynamic routing steps that were performed
+    pub dynamic_routing: Vec<DynamicRoutingTraceStep>,
+    /// The fallbacks taken while routing the payment
+    pub fallbacks: Vec<RoutingTraceFallback>,
+    /// The final order in which the connectors will be tried
+    pub final_connectors: Vec<RoutableConnectorChoice>,
+    /// The attempt that was retried by this attempt, when the payment was automatically retried
+    /// after the previous attempt failed. The connectors of a retried attempt are not routed
+    /// again, they are the connectors left over from the previous attempt.
+    pub retried_attempt_id: Option<String>,
+}
+
+impl RoutingTrace {
+    /// Starts the trace of a payment routed with the algorithm passed in the payment request
+    pub fn for_straight_through_algorithm(algorithm: &StraightThroughAlgorithm) -> Self {
+        Self {
+            algorithm_kind: Some(match algorithm {
+                StraightThroughAlgorithm::Single(_) => RoutingAlgorithmKind::Single,
+                StraightThroughAlgorithm::Priority(_) => RoutingAlgorithmKind::Priority,
+                StraightThroughAlgorithm::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Records the connectors left after eligibility analysis, along with the connectors of the
+    /// routing algorithm that were removed and the fallback connectors that were added
+    pub fn set_eligible_connectors(&mut self, eligible_connectors: &[RoutableConnectorChoice]) {
+        self.ineligible_connectors = self
+            .algorithm_connectors
+            .iter()
+            .filter(|connector| !eligible_connectors.contains(connector))
+            .cloned()
+            .collect();
+        self.fallback_connectors = eligible_connectors
+            .iter()
+            .filter(|connector| !self.algorithm_connectors.contains(connector))
+            .cloned()
+            .collect();
+        self.eligible_connectors = eligible_connectors.to_vec();
+    }
+
+    /// Records the eligible connectors that were skipped by the circuit breaker
+    pub fn set_closed_circuit_connectors(&mut self, connectors: &[RoutableConnectorChoice]) {
+        self.open_circuit_connectors = self
+            .eligible_connectors
+            .iter()
+            .filter(|connector| !connectors.contains(connector))
+            .cloned()
+            .collect();
+    }
+
+    /// Records a fallback taken while routing the payment
+    pub fn add_fallback(&mut self, stage: RoutingTraceStage, reason: String) {
+        self.fallbacks.push(RoutingTraceFallback { stage, reason });
+    }
+}
+
+/// The stages a payment goes through while being routed
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, strum::Display, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingTraceStage {
+    StaticRouting,
+    EligibilityAnalysis,
+    CircuitBreaker,
+    SuccessBasedRouting,
+    ContractBasedRouting,
+    EliminationRouting,
+    OpenRouter,
+}
+
+/// The outcome of a dynamic routing step
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct DynamicRoutingTraceStep {
+    /// The dynamic routing step that was performed
+    pub stage: RoutingTraceStage,
+    /// The approach taken by the dynamic routing step
+    pub routing_approach: Option<RoutingApproach>,
+    /// The score of each of the connectors, empty when the step does not score the connectors
+    pub scores: Vec<RoutingTraceConnectorScore>,
+    /// The connectors that were eliminated by elimination routing
+    pub eliminated_connectors: Vec<RoutableConnectorChoice>,
+    /// The order of the connectors after the step
+    pub connectors: Vec<RoutableConnectorChoice>,
+}
+
+/// The score assigned to a connector by dynamic routing
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct RoutingTraceConnectorScore {
+    /// The connector label, in the form `connector:merchant_connector_id`
+    #[schema(example = "stripe:mca_123")]
+    pub label: String,
+    /// The score of the connector
+    pub score: f64,
+}
+
+/// A fallback taken while routing a payment
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct RoutingTraceFallback {
+    /// The stage at which the fallback was taken
+    pub stage: RoutingTraceStage,
+    /// Why the fallback was taken
+    #[schema(example = "success_rate gRPC client not found")]
+    pub reason: String,
+}
@@ -79,3 +79,77 @@ pub struct MerchantSurchargeConfigs {
 }
 
 pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SurchargeSimulationPayment {
+    /// Amount of the hypothetical payment, in the lowest denomination of the currency
+    pub amount: MinorUnit,
+    pub currency: common_enums::Currency,
+    pub payment_method: common_enums::PaymentMethod,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub billing_country: Option<common_enums::Country>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeSimulationRequest {
+    /// Surcharge rules to simulate, the rules configured for the merchant are used when not provided
+    pub algorithm: Option<Program<SurchargeDecisionConfigs>>,
+    pub payment: SurchargeSimulationPayment,
+}
+
+impl events::ApiEventMetric for SurchargeSimulationRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SurchargeSimulationBatchRequest {
+    /// Surcharge rules to simulate, the rules configured for the merchant are used when not provided
+    pub algorithm: Option<Program<SurchargeDecisionConfigs>>,
+    pub payments: Vec<SurchargeSimulationBatchPayment>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SurchargeSimulationBatchPayment {
+    /// Line number of the payment in the uploaded file, the header row being the first line
+    pub line_number: u64,
+    pub payment: SurchargeSimulationPayment,
+}
+
+impl events::ApiEventMetric for SurchargeSimulationBatchRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SurchargeSimulationResponse {
+    pub payment: SurchargeSimulationPayment,
+    /// Name of the rule that matched the payment, absent when the default selection was applied
+    pub matched_rule: Option<String>,
+    pub surcharge: Option<SurchargeOutput>,
+    pub tax_on_surcharge: Option<Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>>,
+    pub surcharge_amount: MinorUnit,
+    pub tax_on_surcharge_amount: MinorUnit,
+    /// Amount to be charged to the customer, inclusive of surcharge and tax on surcharge
+    pub total_amount: MinorUnit,
+    /// Steps followed while evaluating the surcharge rules for the payment
+    pub trace: Vec<String>,
+}
+
+impl events::ApiEventMetric for SurchargeSimulationResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SurchargeSimulationBatchResponse {
+    /// Line number of the payment in the uploaded file
+    pub line_number: u64,
+    pub simulation: Option<SurchargeSimulationResponse>,
+    pub error: Option<String>,
+}
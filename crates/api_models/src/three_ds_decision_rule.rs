@@ -70,6 +70,10 @@ pub struct ThreeDsDecisionRuleExecuteRequest {
     pub issuer: Option<IssuerData>,
     /// Optional data about the acquirer.
     pub acquirer: Option<AcquirerData>,
+    /// The payment for which the rule is executed. Used to assign the payment to a rule version
+    /// when a rollout is configured on the rule.
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
 }
 
 /// Represents the response from executing a 3DS decision rule.
@@ -78,6 +82,74 @@ pub struct ThreeDsDecisionRuleExecuteResponse {
     /// The decision made by the 3DS decision rule engine.
     #[schema(value_type = ThreeDSDecision)]
     pub decision: common_types::three_ds_decision_rule_engine::ThreeDSDecision,
+    /// The ID of the rule version that was executed.
+    #[schema(value_type = String)]
+    pub routing_id: common_utils::id_type::RoutingId,
+    /// The rollout variant the payment was assigned to, if a rollout is configured on the rule.
+    pub rollout_variant: Option<ThreeDsDecisionRuleRolloutVariant>,
+}
+
+/// Represents the variants of a 3DS decision rule rollout.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ThreeDsDecisionRuleRolloutVariant {
+    /// The rule version requested for execution.
+    Baseline,
+    /// The rule version being rolled out.
+    Candidate,
+}
+
+/// Represents a rollout of a candidate 3DS decision rule version over a baseline version.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ThreeDsDecisionRuleRollout {
+    /// The ID of the rule version currently in use.
+    #[schema(value_type = String)]
+    pub baseline_routing_id: common_utils::id_type::RoutingId,
+    /// The ID of the rule version being rolled out.
+    #[schema(value_type = String)]
+    pub candidate_routing_id: common_utils::id_type::RoutingId,
+    /// The percentage of payments, between 0 and 100, to be evaluated with the candidate version.
+    pub candidate_percentage: u8,
+    /// Time at which the rollout was last modified, as a unix timestamp.
+    pub modified_at: i64,
+}
+
+/// Represents the request to create or update a 3DS decision rule rollout.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ThreeDsDecisionRuleRolloutRequest {
+    /// The ID of the rule version currently in use.
+    #[schema(value_type = String)]
+    pub baseline_routing_id: common_utils::id_type::RoutingId,
+    /// The ID of the rule version being rolled out.
+    #[schema(value_type = String)]
+    pub candidate_routing_id: common_utils::id_type::RoutingId,
+    /// The percentage of payments, between 0 and 100, to be evaluated with the candidate version.
+    pub candidate_percentage: u8,
+}
+
+/// Represents the outcome of a payment evaluated with a 3DS decision rule.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ThreeDsDecisionRuleOutcomeRequest {
+    /// The ID of the rule version that was executed for the payment.
+    #[schema(value_type = String)]
+    pub routing_id: common_utils::id_type::RoutingId,
+    /// The rollout variant the payment was assigned to.
+    pub rollout_variant: Option<ThreeDsDecisionRuleRolloutVariant>,
+    /// Whether the payment was authorized.
+    pub authorization_successful: bool,
 }
 
 impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleExecuteRequest {
@@ -91,3 +163,21 @@ impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleExecuteResponse
         Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
     }
 }
+
+impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleRollout {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
+
+impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleRolloutRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
+
+impl common_utils::events::ApiEventMetric for ThreeDsDecisionRuleOutcomeRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ThreeDsDecisionRule)
+    }
+}
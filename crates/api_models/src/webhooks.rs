@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+#[cfg(feature = "v1")]
+use crate::payment_methods;
 #[cfg(feature = "payouts")]
 use crate::payouts;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
@@ -346,6 +348,8 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -322,6 +322,20 @@ pub enum Connector {
 impl Connector {
     #[cfg(feature = "payouts")]
     pub fn supports_instant_payout(self, payout_method: Option<PayoutType>) -> bool {
+        // The dummy connectors create and process a payout in a single call
+        #[cfg(feature = "dummy_connector")]
+        if matches!(
+            self,
+            Self::DummyConnector1
+                | Self::DummyConnector2
+                | Self::DummyConnector3
+                | Self::DummyConnector4
+                | Self::DummyConnector5
+                | Self::DummyConnector6
+                | Self::DummyConnector7
+        ) {
+            return true;
+        }
         matches!(
             (self, payout_method),
             (Self::Paypal, Some(PayoutType::Wallet))
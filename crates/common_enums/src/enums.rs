@@ -1413,6 +1413,7 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    PaymentMethods,
     #[cfg(feature = "payouts")]
     Payouts,
 }
@@ -1439,8 +1440,10 @@ impl EventClass {
                 EventType::DisputeChallenged,
                 EventType::DisputeWon,
                 EventType::DisputeLost,
+                EventType::DisputeDeadlineApproaching,
             ]),
             Self::Mandates => HashSet::from([EventType::MandateActive, EventType::MandateRevoked]),
+            Self::PaymentMethods => HashSet::from([EventType::PaymentMethodUpdated]),
             #[cfg(feature = "payouts")]
             Self::Payouts => HashSet::from([
                 EventType::PayoutSuccess,
@@ -1491,8 +1494,12 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// Evidence submission deadline of an open dispute is approaching
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
+    /// Card details of a stored payment method were updated by the card account updater
+    PaymentMethodUpdated,
     #[cfg(feature = "payouts")]
     PayoutSuccess,
     #[cfg(feature = "payouts")]
@@ -1599,6 +1606,9 @@ pub enum IntentStatus {
     PartiallyCaptured,
     /// The payment has been captured partially and the remaining amount is capturable
     PartiallyCapturedAndCapturable,
+    /// A part of the amount has been authorized with one or more payment methods, and the
+    /// remaining balance is to be paid with another payment method.
+    PartiallyAuthorizedAndRequiresPaymentMethod,
 }
 
 impl IntentStatus {
@@ -1612,7 +1622,8 @@ impl IntentStatus {
             | Self::RequiresPaymentMethod
             | Self::RequiresConfirmation
             | Self::RequiresCapture
-            | Self::PartiallyCapturedAndCapturable => false,
+            | Self::PartiallyCapturedAndCapturable
+            | Self::PartiallyAuthorizedAndRequiresPaymentMethod => false,
         }
     }
 
@@ -1627,7 +1638,8 @@ impl IntentStatus {
             | Self::Failed
             | Self::Cancelled
             |  Self::PartiallyCaptured
-            |  Self::RequiresCapture => false,
+            |  Self::RequiresCapture
+            |  Self::PartiallyAuthorizedAndRequiresPaymentMethod => false,
             Self::Processing
             | Self::RequiresCustomerAction
             | Self::RequiresMerchantAction
@@ -1723,6 +1735,32 @@ pub enum PaymentMethodStatus {
     AwaitingData,
 }
 
+/// Status of the network token of a payment method, as reported by the network tokenization service
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    /// The network token can be used for payments.
+    Active,
+    /// The network token was suspended by the card network or the issuer, it can be resumed later.
+    Suspended,
+    /// The network token was deleted and cannot be used for payments anymore.
+    Deleted,
+}
+
 impl From<AttemptStatus> for PaymentMethodStatus {
     fn from(attempt_status: AttemptStatus) -> Self {
         match attempt_status {
@@ -6932,6 +6970,8 @@ pub enum PayoutEntityType {
     serde::Serialize,
     strum::Display,
     strum::EnumString,
+    strum::VariantNames,
+    strum::EnumIter,
     ToSchema,
     Hash,
 )]
@@ -6972,6 +7012,7 @@ pub enum PaymentSource {
     Sdk,
     Webhook,
     ExternalAuthenticator,
+    Scheduler,
 }
 
 #[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
@@ -6997,7 +7038,7 @@ impl PaymentSource {
     pub fn is_for_internal_use_only(self) -> bool {
         match self {
             Self::Dashboard | Self::Sdk | Self::MerchantServer | Self::Postman => false,
-            Self::Webhook | Self::ExternalAuthenticator => true,
+            Self::Webhook | Self::ExternalAuthenticator | Self::Scheduler => true,
         }
     }
 }
@@ -8323,6 +8364,10 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    DisputeDeadlineWorkflow,
+    PaymentRequeueWorkflow,
+    AccountUpdaterWorkflow,
+    CustomerErasureWorkflow,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,295 @@
+//! Types for the fees charged by connectors for processing payments
+
+use common_enums::enums;
+use common_utils::{
+    consts::FEE_PERCENTAGE_PRECISION_LENGTH,
+    errors::{CustomResult, PercentageError},
+    impl_to_sql_from_sql_json,
+    types::{MinorUnit, Percentage},
+};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, FromSqlRow, AsExpression, ToSchema)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Fees charged by the connector of a merchant connector account, used to rank connectors by the expected cost of a payment in cost based routing
+pub struct ConnectorFeeSchedule {
+    /// Fees of the schedule. A payment is charged the first fee whose criteria it matches, so more specific fees should be listed before the more general ones
+    pub fees: Vec<ConnectorFee>,
+}
+impl_to_sql_from_sql_json!(ConnectorFeeSchedule);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A fee charged for the payments matching all of its criteria. Criteria which are not set match every payment.
+pub struct ConnectorFee {
+    /// Card networks of the payments the fee applies to
+    #[schema(value_type = Option<Vec<CardNetwork>>, example = json!(["Visa"]))]
+    pub card_networks: Option<Vec<enums::CardNetwork>>,
+
+    /// Card types of the payments the fee applies to
+    #[schema(value_type = Option<Vec<CardType>>, example = json!(["debit"]))]
+    pub card_types: Option<Vec<enums::CardType>>,
+
+    /// Billing countries of the payments the fee applies to
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["DE", "FR"]))]
+    pub countries: Option<Vec<enums::CountryAlpha2>>,
+
+    /// Currencies of the payments the fee applies to
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR"]))]
+    pub currencies: Option<Vec<enums::Currency>>,
+
+    /// Currency of the fixed fees of the components. When not set, the fixed fees are in the currency of the payment if the fee is restricted to currencies. A fee with fixed fees does not apply to payments in another currency than its fixed fees, nor to any payment when the currency of its fixed fees is unknown.
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub fixed_fee_currency: Option<enums::Currency>,
+
+    /// Components the fee is made of, charged on top of each other. Interchange++ pricing is described with an interchange, a scheme fee and a markup component.
+    pub components: Vec<FeeComponent>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// A part of a connector fee
+pub struct FeeComponent {
+    /// The tier of the fee the component is charged for
+    pub tier: FeeTier,
+
+    /// Percentage of the payment amount charged, precise up to 4 decimal digits
+    #[schema(value_type = Option<Object>, example = json!({"percentage": 0.2}))]
+    pub percentage: Option<Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>>,
+
+    /// Fixed fee charged per payment, in the minor unit of the currency of the fixed fees of the fee
+    #[schema(value_type = Option<i64>, example = 10)]
+    pub fixed_fee: Option<MinorUnit>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// Tiers of a connector fee
+pub enum FeeTier {
+    /// Fee paid to the issuer of the card
+    Interchange,
+    /// Fee paid to the card network
+    SchemeFee,
+    /// Fee kept by the connector
+    Markup,
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The details of a payment the fee of a connector depends on
+pub struct FeeEstimationInput {
+    /// Amount of the payment
+    pub amount: MinorUnit,
+    /// Currency of the payment
+    pub currency: enums::Currency,
+    /// Card network of the payment, if paid by card
+    pub card_network: Option<enums::CardNetwork>,
+    /// Card type of the payment, if paid by card
+    pub card_type: Option<enums::CardType>,
+    /// Country of the billing address of the payment
+    pub billing_country: Option<enums::CountryAlpha2>,
+}
+
+impl ConnectorFee {
+    /// Whether the payment matches all the criteria of the fee. Fixed fees are never converted, so
+    /// a fee whose fixed fees cannot be charged in the currency of the payment does not apply to it.
+    pub fn is_applicable(&self, input: &FeeEstimationInput) -> bool {
+        fn matches<T: PartialEq>(criteria: Option<&Vec<T>>, value: Option<&T>) -> bool {
+            criteria.map_or(true, |criteria| {
+                value.is_some_and(|value| criteria.contains(value))
+            })
+        }
+
+        matches(self.card_networks.as_ref(), input.card_network.as_ref())
+            && matches(self.card_types.as_ref(), input.card_type.as_ref())
+            && matches(self.countries.as_ref(), input.billing_country.as_ref())
+            && matches(self.currencies.as_ref(), Some(&input.currency))
+            && self.is_fixed_fee_in_currency(input.currency)
+    }
+
+    /// Whether the fixed fees of the components, if any, are in the given currency
+    fn is_fixed_fee_in_currency(&self, currency: enums::Currency) -> bool {
+        let has_fixed_fee = self
+            .components
+            .iter()
+            .any(|component| component.fixed_fee.is_some());
+
+        !has_fixed_fee
+            || match self.fixed_fee_currency {
+                Some(fixed_fee_currency) => fixed_fee_currency == currency,
+                None => self.currencies.is_some(),
+            }
+    }
+
+    /// The amount charged by the fee for a payment of the given amount
+    pub fn calculate(&self, amount: MinorUnit) -> CustomResult<MinorUnit, PercentageError> {
+        self.components
+            .iter()
+            .try_fold(MinorUnit::zero(), |total, component| {
+                let percentage_fee = component
+                    .percentage
+                    .as_ref()
+                    .map(|percentage| percentage.apply_and_ceil_result(amount))
+                    .transpose()?
+                    .unwrap_or_default();
+                Ok(total + percentage_fee + component.fixed_fee.unwrap_or_default())
+            })
+    }
+}
+
+impl ConnectorFeeSchedule {
+    /// The fee charged by the connector for the payment, `None` when no fee of the schedule applies to it
+    pub fn estimate_cost(
+        &self,
+        input: &FeeEstimationInput,
+    ) -> CustomResult<Option<MinorUnit>, PercentageError> {
+        self.fees
+            .iter()
+            .find(|fee| fee.is_applicable(input))
+            .map(|fee| fee.calculate(input.amount))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_input(currency: enums::Currency) -> FeeEstimationInput {
+        FeeEstimationInput {
+            amount: MinorUnit::new(10000),
+            currency,
+            card_network: Some(enums::CardNetwork::Visa),
+            card_type: Some(enums::CardType::Debit),
+            billing_country: Some(enums::CountryAlpha2::DE),
+        }
+    }
+
+    fn get_fee(value: serde_json::Value) -> ConnectorFee {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_adds_up_the_components() {
+        let fee = get_fee(serde_json::json!({
+            "currencies": ["EUR"],
+            "components": [
+                {"tier": "interchange", "percentage": {"percentage": 0.25}},
+                {"tier": "scheme_fee", "percentage": {"percentage": 0.5}, "fixed_fee": 2},
+                {"tier": "markup", "fixed_fee": 10}
+            ]
+        }));
+
+        assert_eq!(
+            fee.calculate(MinorUnit::new(10000)).unwrap(),
+            MinorUnit::new(87)
+        );
+        assert_eq!(
+            fee.calculate(MinorUnit::zero()).unwrap(),
+            MinorUnit::new(12)
+        );
+    }
+
+    #[test]
+    fn test_is_applicable_matches_all_the_criteria() {
+        let fee = get_fee(serde_json::json!({
+            "card_networks": ["Visa"],
+            "card_types": ["debit"],
+            "countries": ["DE", "FR"],
+            "components": [{"tier": "markup", "percentage": {"percentage": 1.5}}]
+        }));
+
+        assert!(fee.is_applicable(&get_input(enums::Currency::EUR)));
+        assert!(fee.is_applicable(&get_input(enums::Currency::USD)));
+        assert!(!fee.is_applicable(&FeeEstimationInput {
+            card_network: Some(enums::CardNetwork::Mastercard),
+            ..get_input(enums::Currency::EUR)
+        }));
+        assert!(!fee.is_applicable(&FeeEstimationInput {
+            card_type: None,
+            ..get_input(enums::Currency::EUR)
+        }));
+        assert!(!fee.is_applicable(&FeeEstimationInput {
+            billing_country: Some(enums::CountryAlpha2::US),
+            ..get_input(enums::Currency::EUR)
+        }));
+    }
+
+    #[test]
+    fn test_is_applicable_with_fixed_fees_in_another_currency() {
+        // The currency of the fixed fees is unknown
+        let fee = get_fee(serde_json::json!({
+            "components": [{"tier": "markup", "fixed_fee": 25}]
+        }));
+        assert!(!fee.is_applicable(&get_input(enums::Currency::EUR)));
+
+        // The fixed fees are in the currency of the payment
+        let fee = get_fee(serde_json::json!({
+            "currencies": ["EUR", "USD"],
+            "components": [{"tier": "markup", "fixed_fee": 25}]
+        }));
+        assert!(fee.is_applicable(&get_input(enums::Currency::USD)));
+        assert!(!fee.is_applicable(&get_input(enums::Currency::GBP)));
+
+        let fee = get_fee(serde_json::json!({
+            "fixed_fee_currency": "EUR",
+            "components": [{"tier": "markup", "fixed_fee": 25}]
+        }));
+        assert!(fee.is_applicable(&get_input(enums::Currency::EUR)));
+        assert!(!fee.is_applicable(&get_input(enums::Currency::USD)));
+    }
+
+    #[test]
+    fn test_estimate_cost_charges_the_first_applicable_fee() {
+        let schedule: ConnectorFeeSchedule = serde_json::from_value(serde_json::json!({
+            "fees": [
+                {
+                    "card_types": ["credit"],
+                    "components": [{"tier": "markup", "percentage": {"percentage": 1.5}}]
+                },
+                {
+                    "fixed_fee_currency": "EUR",
+                    "components": [
+                        {"tier": "markup", "percentage": {"percentage": 0.5}, "fixed_fee": 10}
+                    ]
+                },
+                {
+                    "components": [{"tier": "markup", "percentage": {"percentage": 0.25}}]
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            schedule
+                .estimate_cost(&get_input(enums::Currency::EUR))
+                .unwrap(),
+            Some(MinorUnit::new(60))
+        );
+        assert_eq!(
+            schedule
+                .estimate_cost(&FeeEstimationInput {
+                    card_type: Some(enums::CardType::Credit),
+                    ..get_input(enums::Currency::EUR)
+                })
+                .unwrap(),
+            Some(MinorUnit::new(150))
+        );
+        // The fixed fee in euros is skipped for the payments in dollars
+        assert_eq!(
+            schedule
+                .estimate_cost(&get_input(enums::Currency::USD))
+                .unwrap(),
+            Some(MinorUnit::new(25))
+        );
+        assert_eq!(
+            ConnectorFeeSchedule { fees: Vec::new() }
+                .estimate_cost(&get_input(enums::Currency::EUR))
+                .unwrap(),
+            None
+        );
+    }
+}
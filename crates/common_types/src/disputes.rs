@@ -0,0 +1,45 @@
+//! Dispute related types
+
+use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Policy applied to the disputes of a profile while their evidence deadline approaches
+pub struct DisputePolicy {
+    /// Disputes with an amount lower than this threshold are accepted automatically instead of being challenged
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub auto_accept_below_amount: Option<MinorUnit>,
+
+    /// Hours before the evidence deadline at which the merchant is reminded about an open dispute
+    #[serde(default = "default_reminder_hours_before_deadline")]
+    #[schema(value_type = Vec<u16>, example = json!([72, 24]))]
+    pub reminder_hours_before_deadline: Vec<u16>,
+}
+impl_to_sql_from_sql_json!(DisputePolicy);
+
+impl Default for DisputePolicy {
+    fn default() -> Self {
+        Self {
+            auto_accept_below_amount: None,
+            reminder_hours_before_deadline: default_reminder_hours_before_deadline(),
+        }
+    }
+}
+
+impl DisputePolicy {
+    /// Checks whether a dispute of the given amount is to be accepted without challenging it
+    pub fn should_auto_accept(&self, dispute_amount: MinorUnit) -> bool {
+        self.auto_accept_below_amount
+            .is_some_and(|threshold| dispute_amount < threshold)
+    }
+}
+
+fn default_reminder_hours_before_deadline() -> Vec<u16> {
+    vec![72, 24]
+}
@@ -2,8 +2,11 @@
 
 #![warn(missing_docs, missing_debug_implementations)]
 
+/// types for the fees charged by connectors
+pub mod connector_fees;
 pub mod consts;
 pub mod customers;
+pub mod disputes;
 pub mod domain;
 pub mod payment_methods;
 pub mod payments;
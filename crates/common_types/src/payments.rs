@@ -254,3 +254,16 @@ pub struct XenditMultipleSplitResponse {
     pub routes: Vec<XenditSplitRoute>,
 }
 impl_to_sql_from_sql_json!(XenditMultipleSplitResponse);
+
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Policy applied to the payments of a profile when the amount or currency in a connector response does not match the one sent to the connector
+pub struct IntegrityCheckPolicy {
+    /// Hold the payment for manual review, in the `requires_merchant_action` status, instead of only recording the mismatch. Mismatched refunds are always held for manual review and mismatched disputes are only recorded.
+    #[serde(default)]
+    pub quarantine_on_mismatch: bool,
+}
+impl_to_sql_from_sql_json!(IntegrityCheckPolicy);
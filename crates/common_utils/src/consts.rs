@@ -46,6 +46,9 @@ pub fn default_payouts_list_limit() -> u32 {
 /// surcharge percentage maximum precision length
 pub const SURCHARGE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
 
+/// connector fee percentage maximum precision length
+pub const FEE_PERCENTAGE_PRECISION_LENGTH: u8 = 4;
+
 /// Header Key for application overhead of a request
 pub const X_HS_LATENCY: &str = "x-hs-latency";
 
@@ -219,6 +219,18 @@ impl MerchantId {
             ),
         }
     }
+    /// Get the key of the 3DS decision rule rollout configured over a baseline rule version
+    pub fn get_three_ds_decision_rule_rollout_key(
+        &self,
+        baseline_routing_id: &crate::id_type::RoutingId,
+    ) -> String {
+        format!(
+            "three_ds_decision_rule_rollout_{}_{}",
+            self.get_string_repr(),
+            baseline_routing_id.get_string_repr()
+        )
+    }
+
     /// allow payment update via client auth default should be false
     pub fn get_payment_update_enabled_for_client_auth_key(&self) -> String {
         format!(
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[cfg(feature = "payouts")]
 use api_models::enums::PayoutConnectors;
@@ -62,6 +62,20 @@ pub enum ConnectorAuthType {
     NoKey,
 }
 
+impl ConnectorAuthType {
+    pub fn get_auth_type_name(&self) -> &'static str {
+        match self {
+            Self::HeaderKey { .. } => "HeaderKey",
+            Self::BodyKey { .. } => "BodyKey",
+            Self::SignatureKey { .. } => "SignatureKey",
+            Self::MultiAuthKey { .. } => "MultiAuthKey",
+            Self::CurrencyAuthKey { .. } => "CurrencyAuthKey",
+            Self::CertificateAuth { .. } => "CertificateAuth",
+            Self::NoKey => "NoKey",
+        }
+    }
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Deserialize, serde::Serialize, Clone)]
 #[serde(untagged)]
@@ -281,15 +295,60 @@ pub struct ConnectorConfig {
     pub unified_authentication_service: Option<ConnectorTomlConfig>,
 }
 
-impl ConnectorConfig {
-    fn new() -> Result<Self, String> {
-        let config_str = if cfg!(feature = "production") {
-            include_str!("../toml/production.toml")
+/// The environments for which connector configs are maintained, one TOML file each
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConfigEnvironment {
+    Development,
+    Sandbox,
+    Production,
+}
+
+impl ConfigEnvironment {
+    /// The environment whose configs this build serves, as selected by the crate features
+    pub fn current() -> Self {
+        if cfg!(feature = "production") {
+            Self::Production
         } else if cfg!(feature = "sandbox") {
-            include_str!("../toml/sandbox.toml")
+            Self::Sandbox
         } else {
-            include_str!("../toml/development.toml")
-        };
+            Self::Development
+        }
+    }
+
+    fn get_config_str(self) -> &'static str {
+        match self {
+            Self::Development => include_str!("../toml/development.toml"),
+            Self::Sandbox => include_str!("../toml/sandbox.toml"),
+            Self::Production => include_str!("../toml/production.toml"),
+        }
+    }
+
+    /// Loads every connector config of the environment, keyed by the name of its TOML table.
+    /// Unlike [`ConnectorConfig`], this includes the connectors disabled by the crate features.
+    pub fn get_connector_configs(self) -> Result<ConnectorTomlConfigs, String> {
+        toml::from_str::<ConnectorTomlConfigs>(self.get_config_str()).map_err(|err| err.to_string())
+    }
+}
+
+/// The connector configs of an environment, keyed by the name of their TOML table
+pub type ConnectorTomlConfigs = BTreeMap<String, ConnectorTomlConfig>;
+
+impl ConnectorConfig {
+    fn new() -> Result<Self, String> {
+        let config_str = ConfigEnvironment::current().get_config_str();
         let config = toml::from_str::<Self>(config_str);
         match config {
             Ok(data) => Ok(data),
@@ -303,6 +362,20 @@ impl ConnectorConfig {
     ) -> Result<Option<ConnectorTomlConfig>, String> {
         let connector_data = Self::new()?;
         match connector {
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector1 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector2 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector3 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector4 => Ok(connector_data.stripe_test),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector5 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector6 => Ok(connector_data.dummy_connector),
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector7 => Ok(connector_data.paypal_test),
             PayoutConnectors::Adyen => Ok(connector_data.adyen_payout),
             PayoutConnectors::Adyenplatform => Ok(connector_data.adyenplatform_payout),
             PayoutConnectors::Cybersource => Ok(connector_data.cybersource_payout),
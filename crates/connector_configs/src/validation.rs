@@ -0,0 +1,661 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt,
+};
+
+use api_models::enums::{CardNetwork, PaymentMethod, PaymentMethodType};
+
+use crate::connector::{ConfigEnvironment, ConnectorTomlConfig, ConnectorTomlConfigs};
+
+/// What a connector integration supports, as advertised by its `ConnectorSpecifications`
+#[derive(Debug, Clone, Default)]
+pub struct ConnectorCapabilities {
+    pub payment_methods: HashMap<PaymentMethod, HashSet<PaymentMethodType>>,
+    /// Card networks supported for credit and debit cards. Left empty when the connector does not
+    /// advertise them, in which case the card networks listed in the config are not checked
+    pub card_networks: HashSet<CardNetwork>,
+}
+
+impl ConnectorCapabilities {
+    fn get_unsupported(&self, listed: &ListedPaymentMethod) -> Option<ConfigIssueKind> {
+        let is_payment_method_type_supported = self
+            .payment_methods
+            .get(&listed.payment_method)
+            .is_some_and(|payment_method_types| {
+                payment_method_types.contains(&listed.payment_method_type)
+            });
+        if !is_payment_method_type_supported {
+            return Some(ConfigIssueKind::UnsupportedPaymentMethodType {
+                payment_method: listed.payment_method,
+                payment_method_type: listed.payment_method_type,
+            });
+        }
+
+        match &listed.card_network {
+            Some(card_network)
+                if !self.card_networks.is_empty() && !self.card_networks.contains(card_network) =>
+            {
+                Some(ConfigIssueKind::UnsupportedCardNetwork {
+                    payment_method_type: listed.payment_method_type,
+                    card_network: card_network.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A payment method listed in a connector config
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize)]
+pub struct ListedPaymentMethod {
+    pub payment_method: PaymentMethod,
+    pub payment_method_type: PaymentMethodType,
+    /// Only set for the credit and debit sections, which list card networks
+    pub card_network: Option<CardNetwork>,
+}
+
+impl fmt::Display for ListedPaymentMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.payment_method, self.payment_method_type)?;
+        match &self.card_network {
+            Some(card_network) => write!(f, " ({card_network})"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ConnectorTomlConfig {
+    pub fn get_listed_payment_methods(&self) -> Vec<ListedPaymentMethod> {
+        let cards = [
+            (PaymentMethodType::Credit, &self.credit),
+            (PaymentMethodType::Debit, &self.debit),
+        ]
+        .into_iter()
+        .flat_map(|(payment_method_type, providers)| {
+            providers
+                .iter()
+                .flatten()
+                .map(move |provider| ListedPaymentMethod {
+                    payment_method: PaymentMethod::Card,
+                    payment_method_type,
+                    card_network: Some(provider.payment_method_type.clone()),
+                })
+        });
+
+        let others = [
+            (PaymentMethod::BankTransfer, &self.bank_transfer),
+            (PaymentMethod::BankRedirect, &self.bank_redirect),
+            (PaymentMethod::BankDebit, &self.bank_debit),
+            (PaymentMethod::OpenBanking, &self.open_banking),
+            (PaymentMethod::PayLater, &self.pay_later),
+            (PaymentMethod::Wallet, &self.wallet),
+            (PaymentMethod::Crypto, &self.crypto),
+            (PaymentMethod::Reward, &self.reward),
+            (PaymentMethod::Upi, &self.upi),
+            (PaymentMethod::Voucher, &self.voucher),
+            (PaymentMethod::GiftCard, &self.gift_card),
+            (PaymentMethod::CardRedirect, &self.card_redirect),
+            (PaymentMethod::RealTimePayment, &self.real_time_payment),
+        ]
+        .into_iter()
+        .flat_map(|(payment_method, providers)| {
+            providers
+                .iter()
+                .flatten()
+                .map(move |provider| ListedPaymentMethod {
+                    payment_method,
+                    payment_method_type: provider.payment_method_type,
+                    card_network: None,
+                })
+        });
+
+        cards.chain(others).collect()
+    }
+
+    /// The names of the metadata fields the dashboard collects for the connector, prefixed with
+    /// the section they belong to, e.g. `metadata.merchant_id`
+    pub fn get_metadata_field_names(&self) -> Vec<String> {
+        let mut field_names = self.get_metadata_and_wallet_field_names();
+        field_names.extend(get_field_names(
+            "additional_merchant_data",
+            self.additional_merchant_data.as_deref(),
+        ));
+        field_names
+    }
+
+    /// The fields collected for the connector in `metadata` and `connector_wallets_details`, which
+    /// include the wallet details, e.g. `connector_wallets_details.samsung_pay`
+    fn get_metadata_and_wallet_field_names(&self) -> Vec<String> {
+        let mut field_names = get_field_names("metadata", self.metadata.as_deref());
+        field_names.extend(get_field_names(
+            "connector_wallets_details",
+            self.connector_wallets_details.as_deref(),
+        ));
+        field_names
+    }
+
+    /// Returns the fields one of which has to be configured for the wallet, when none of them is
+    fn get_missing_wallet_metadata(
+        &self,
+        payment_method_type: PaymentMethodType,
+        wallet_metadata_fields: &HashMap<PaymentMethodType, BTreeSet<String>>,
+    ) -> Option<Vec<String>> {
+        let fields = wallet_metadata_fields.get(&payment_method_type)?;
+        let configured_fields = self.get_metadata_and_wallet_field_names();
+        (!fields.iter().any(|field| configured_fields.contains(field)))
+            .then(|| fields.iter().cloned().collect())
+    }
+}
+
+/// The fields collected for each wallet across the configs, named after the wallet in either the
+/// `metadata` or the `connector_wallets_details` section. Once a connector collects details for a
+/// wallet, every connector listing the wallet is expected to collect them as well.
+fn get_wallet_metadata_fields(
+    configs: &ConnectorTomlConfigs,
+) -> HashMap<PaymentMethodType, BTreeSet<String>> {
+    let wallets = configs
+        .values()
+        .flat_map(|config| config.get_listed_payment_methods())
+        .filter(|listed| listed.payment_method == PaymentMethod::Wallet)
+        .map(|listed| listed.payment_method_type)
+        .collect::<HashSet<_>>();
+
+    let mut wallet_metadata_fields = HashMap::<PaymentMethodType, BTreeSet<String>>::new();
+    for field in configs
+        .values()
+        .flat_map(|config| config.get_metadata_and_wallet_field_names())
+    {
+        let wallet = field
+            .split_once('.')
+            .and_then(|(_, key)| wallets.iter().find(|wallet| wallet.to_string() == key))
+            .copied();
+        if let Some(wallet) = wallet {
+            wallet_metadata_fields
+                .entry(wallet)
+                .or_default()
+                .insert(field);
+        }
+    }
+    wallet_metadata_fields
+}
+
+fn get_field_names<T: serde::Serialize>(section: &str, value: Option<&T>) -> Vec<String> {
+    match value.and_then(|value| toml::Value::try_from(value).ok()) {
+        Some(toml::Value::Table(table)) => {
+            table.keys().map(|key| format!("{section}.{key}")).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConfigIssueKind {
+    /// The config lists a payment method type which the connector does not support
+    UnsupportedPaymentMethodType {
+        payment_method: PaymentMethod,
+        payment_method_type: PaymentMethodType,
+    },
+    /// The config lists a card network which the connector does not support
+    UnsupportedCardNetwork {
+        payment_method_type: PaymentMethodType,
+        card_network: CardNetwork,
+    },
+    /// The config lists a wallet without the metadata fields needed to process it
+    MissingWalletMetadata {
+        payment_method_type: PaymentMethodType,
+        fields: Vec<String>,
+    },
+    /// The connector is configured in another environment, but not in this one
+    ConnectorMissing { present_in: ConfigEnvironment },
+    /// The payment method is listed for the connector in another environment, but not in this one
+    PaymentMethodMissing {
+        payment_method: ListedPaymentMethod,
+        present_in: ConfigEnvironment,
+    },
+    /// The metadata field is collected for the connector in another environment, but not in
+    /// this one
+    MetadataFieldMissing {
+        field: String,
+        present_in: ConfigEnvironment,
+    },
+    /// The connector is authenticated differently in another environment
+    AuthTypeMismatch {
+        auth_type: &'static str,
+        other_environment: ConfigEnvironment,
+        other_auth_type: &'static str,
+    },
+}
+
+impl fmt::Display for ConfigIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedPaymentMethodType {
+                payment_method,
+                payment_method_type,
+            } => write!(
+                f,
+                "{payment_method} {payment_method_type} is listed but not supported by the connector"
+            ),
+            Self::UnsupportedCardNetwork {
+                payment_method_type,
+                card_network,
+            } => write!(
+                f,
+                "card network {card_network} is listed for {payment_method_type} but not supported by the connector"
+            ),
+            Self::MissingWalletMetadata {
+                payment_method_type,
+                fields,
+            } => write!(
+                f,
+                "{payment_method_type} is listed but `{}` is not configured",
+                fields.join("` or `")
+            ),
+            Self::ConnectorMissing { present_in } => {
+                write!(f, "connector is not configured, but is in {present_in}")
+            }
+            Self::PaymentMethodMissing {
+                payment_method,
+                present_in,
+            } => write!(f, "{payment_method} is not listed, but is in {present_in}"),
+            Self::MetadataFieldMissing { field, present_in } => {
+                write!(f, "`{field}` is not configured, but is in {present_in}")
+            }
+            Self::AuthTypeMismatch {
+                auth_type,
+                other_environment,
+                other_auth_type,
+            } => write!(
+                f,
+                "uses {auth_type} authentication, but {other_environment} uses {other_auth_type}"
+            ),
+        }
+    }
+}
+
+/// A problem found in the config of a connector for an environment
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ConfigIssue {
+    pub environment: ConfigEnvironment,
+    pub connector: String,
+    #[serde(flatten)]
+    pub kind: ConfigIssueKind,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}: {}",
+            self.environment, self.connector, self.kind
+        )
+    }
+}
+
+/// Checks the connector configs of an environment against what the connectors support.
+///
+/// `get_capabilities` is called with the name of the TOML table of each connector, and returns
+/// `None` for connectors whose capabilities are not known, whose payment methods are then not
+/// checked.
+pub fn validate_connector_configs<F>(
+    environment: ConfigEnvironment,
+    configs: &ConnectorTomlConfigs,
+    get_capabilities: F,
+) -> Vec<ConfigIssue>
+where
+    F: Fn(&str) -> Option<ConnectorCapabilities>,
+{
+    let mut issues = Vec::new();
+    let wallet_metadata_fields = get_wallet_metadata_fields(configs);
+
+    for (connector, config) in configs {
+        let capabilities = get_capabilities(connector);
+
+        for listed in config.get_listed_payment_methods() {
+            let unsupported = capabilities
+                .as_ref()
+                .and_then(|capabilities| capabilities.get_unsupported(&listed));
+            let missing_metadata = config
+                .get_missing_wallet_metadata(listed.payment_method_type, &wallet_metadata_fields)
+                .map(|fields| ConfigIssueKind::MissingWalletMetadata {
+                    payment_method_type: listed.payment_method_type,
+                    fields,
+                });
+
+            for kind in unsupported.into_iter().chain(missing_metadata) {
+                let issue = ConfigIssue {
+                    environment,
+                    connector: connector.clone(),
+                    kind,
+                };
+                // Each card network of the credit and debit sections is listed on its own, which
+                // would otherwise report an unsupported card payment method type once per network
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Reports the connectors, payment methods, metadata fields and authentication types which differ
+/// between the connector configs of two environments
+pub fn diff_connector_configs(
+    environment: ConfigEnvironment,
+    configs: &ConnectorTomlConfigs,
+    other_environment: ConfigEnvironment,
+    other_configs: &ConnectorTomlConfigs,
+) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+    collect_missing(
+        environment,
+        configs,
+        other_environment,
+        other_configs,
+        &mut issues,
+    );
+    collect_missing(
+        other_environment,
+        other_configs,
+        environment,
+        configs,
+        &mut issues,
+    );
+
+    for (connector, config) in configs {
+        let auth_type = config
+            .connector_auth
+            .as_ref()
+            .map(|auth| auth.get_auth_type_name());
+        let other_auth_type = other_configs
+            .get(connector)
+            .and_then(|other_config| other_config.connector_auth.as_ref())
+            .map(|auth| auth.get_auth_type_name());
+
+        if let (Some(auth_type), Some(other_auth_type)) = (auth_type, other_auth_type) {
+            if auth_type != other_auth_type {
+                issues.push(ConfigIssue {
+                    environment,
+                    connector: connector.clone(),
+                    kind: ConfigIssueKind::AuthTypeMismatch {
+                        auth_type,
+                        other_environment,
+                        other_auth_type,
+                    },
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Reports what is configured in `source_configs` but missing from `target_configs`
+fn collect_missing(
+    source_environment: ConfigEnvironment,
+    source_configs: &ConnectorTomlConfigs,
+    target_environment: ConfigEnvironment,
+    target_configs: &ConnectorTomlConfigs,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    for (connector, source_config) in source_configs {
+        let Some(target_config) = target_configs.get(connector) else {
+            issues.push(ConfigIssue {
+                environment: target_environment,
+                connector: connector.clone(),
+                kind: ConfigIssueKind::ConnectorMissing {
+                    present_in: source_environment,
+                },
+            });
+            continue;
+        };
+
+        let target_payment_methods = target_config
+            .get_listed_payment_methods()
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let missing_payment_methods = source_config
+            .get_listed_payment_methods()
+            .into_iter()
+            .filter(|payment_method| !target_payment_methods.contains(payment_method))
+            .map(|payment_method| ConfigIssueKind::PaymentMethodMissing {
+                payment_method,
+                present_in: source_environment,
+            });
+
+        let target_fields = target_config
+            .get_metadata_field_names()
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let missing_fields = source_config
+            .get_metadata_field_names()
+            .into_iter()
+            .filter(|field| !target_fields.contains(field))
+            .map(|field| ConfigIssueKind::MetadataFieldMissing {
+                field,
+                present_in: source_environment,
+            });
+
+        issues.extend(
+            missing_payment_methods
+                .chain(missing_fields)
+                .map(|kind| ConfigIssue {
+                    environment: target_environment,
+                    connector: connector.clone(),
+                    kind,
+                }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const DEVELOPMENT_CONFIGS: &str = r#"
+[stripe]
+[[stripe.credit]]
+  payment_method_type = "Visa"
+[[stripe.credit]]
+  payment_method_type = "Mastercard"
+[[stripe.wallet]]
+  payment_method_type = "apple_pay"
+[[stripe.wallet]]
+  payment_method_type = "google_pay"
+[stripe.connector_auth.HeaderKey]
+api_key = "Secret Key"
+[[stripe.metadata.apple_pay]]
+name = "certificate"
+label = "Merchant Certificate"
+placeholder = "Enter Merchant Certificate"
+required = true
+type = "Text"
+
+[adyen]
+[[adyen.wallet]]
+  payment_method_type = "apple_pay"
+[[adyen.wallet]]
+  payment_method_type = "paypal"
+[[adyen.wallet]]
+  payment_method_type = "samsung_pay"
+[adyen.connector_auth.BodyKey]
+api_key = "Adyen API Key"
+key1 = "Adyen Account Id"
+[[adyen.connector_wallets_details.samsung_pay]]
+name = "service_id"
+label = "Samsung Pay Service Id"
+placeholder = "Enter Samsung Pay Service Id"
+required = true
+type = "Text"
+"#;
+
+    const SANDBOX_CONFIGS: &str = r#"
+[stripe]
+[[stripe.credit]]
+  payment_method_type = "Visa"
+[[stripe.wallet]]
+  payment_method_type = "apple_pay"
+[[stripe.wallet]]
+  payment_method_type = "google_pay"
+[stripe.connector_auth.BodyKey]
+api_key = "Secret Key"
+key1 = "Publishable Key"
+[[stripe.metadata.apple_pay]]
+name = "certificate"
+label = "Merchant Certificate"
+placeholder = "Enter Merchant Certificate"
+required = true
+type = "Text"
+
+[paypal]
+[[paypal.wallet]]
+  payment_method_type = "paypal"
+[paypal.connector_auth.HeaderKey]
+api_key = "Client Secret"
+"#;
+
+    fn get_configs(configs: &str) -> ConnectorTomlConfigs {
+        toml::from_str(configs).unwrap()
+    }
+
+    fn get_stripe_capabilities() -> ConnectorCapabilities {
+        ConnectorCapabilities {
+            payment_methods: HashMap::from([
+                (
+                    PaymentMethod::Card,
+                    HashSet::from([PaymentMethodType::Credit, PaymentMethodType::Debit]),
+                ),
+                (
+                    PaymentMethod::Wallet,
+                    HashSet::from([PaymentMethodType::ApplePay]),
+                ),
+            ]),
+            card_networks: HashSet::from([CardNetwork::Visa]),
+        }
+    }
+
+    #[test]
+    fn test_validate_connector_configs() {
+        let issues = validate_connector_configs(
+            ConfigEnvironment::Development,
+            &get_configs(DEVELOPMENT_CONFIGS),
+            |connector| (connector == "stripe").then(get_stripe_capabilities),
+        );
+        let issues = issues
+            .into_iter()
+            .map(|issue| (issue.connector, issue.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            issues,
+            vec![
+                // Apple Pay details are collected for stripe, so they are expected of adyen too
+                (
+                    "adyen".to_string(),
+                    ConfigIssueKind::MissingWalletMetadata {
+                        payment_method_type: PaymentMethodType::ApplePay,
+                        fields: vec!["metadata.apple_pay".to_string()],
+                    }
+                ),
+                (
+                    "stripe".to_string(),
+                    ConfigIssueKind::UnsupportedCardNetwork {
+                        payment_method_type: PaymentMethodType::Credit,
+                        card_network: CardNetwork::Mastercard,
+                    }
+                ),
+                // No connector collects Google Pay details, so only the support is checked
+                (
+                    "stripe".to_string(),
+                    ConfigIssueKind::UnsupportedPaymentMethodType {
+                        payment_method: PaymentMethod::Wallet,
+                        payment_method_type: PaymentMethodType::GooglePay,
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wallet_metadata_fields_are_derived_from_the_configs() {
+        let wallet_metadata_fields = get_wallet_metadata_fields(&get_configs(DEVELOPMENT_CONFIGS));
+
+        assert_eq!(
+            wallet_metadata_fields,
+            HashMap::from([
+                (
+                    PaymentMethodType::ApplePay,
+                    BTreeSet::from(["metadata.apple_pay".to_string()])
+                ),
+                (
+                    PaymentMethodType::SamsungPay,
+                    BTreeSet::from(["connector_wallets_details.samsung_pay".to_string()])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_diff_connector_configs() {
+        let issues = diff_connector_configs(
+            ConfigEnvironment::Development,
+            &get_configs(DEVELOPMENT_CONFIGS),
+            ConfigEnvironment::Sandbox,
+            &get_configs(SANDBOX_CONFIGS),
+        );
+
+        assert!(issues.contains(&ConfigIssue {
+            environment: ConfigEnvironment::Sandbox,
+            connector: "adyen".to_string(),
+            kind: ConfigIssueKind::ConnectorMissing {
+                present_in: ConfigEnvironment::Development,
+            },
+        }));
+        assert!(issues.contains(&ConfigIssue {
+            environment: ConfigEnvironment::Development,
+            connector: "paypal".to_string(),
+            kind: ConfigIssueKind::ConnectorMissing {
+                present_in: ConfigEnvironment::Sandbox,
+            },
+        }));
+        assert!(issues.contains(&ConfigIssue {
+            environment: ConfigEnvironment::Sandbox,
+            connector: "stripe".to_string(),
+            kind: ConfigIssueKind::PaymentMethodMissing {
+                payment_method: ListedPaymentMethod {
+                    payment_method: PaymentMethod::Card,
+                    payment_method_type: PaymentMethodType::Credit,
+                    card_network: Some(CardNetwork::Mastercard),
+                },
+                present_in: ConfigEnvironment::Development,
+            },
+        }));
+        assert!(issues.contains(&ConfigIssue {
+            environment: ConfigEnvironment::Development,
+            connector: "stripe".to_string(),
+            kind: ConfigIssueKind::AuthTypeMismatch {
+                auth_type: "HeaderKey",
+                other_environment: ConfigEnvironment::Sandbox,
+                other_auth_type: "BodyKey",
+            },
+        }));
+        // The Apple Pay details of stripe are configured in both environments
+        assert_eq!(issues.len(), 4);
+    }
+
+    #[test]
+    fn test_diff_of_identical_configs_is_empty() {
+        let configs = get_configs(DEVELOPMENT_CONFIGS);
+        assert!(diff_connector_configs(
+            ConfigEnvironment::Development,
+            &configs,
+            ConfigEnvironment::Production,
+            &configs,
+        )
+        .is_empty());
+    }
+}
@@ -74,6 +74,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -129,6 +131,8 @@ pub struct ProfileNew {
     pub id: Option<common_utils::id_type::ProfileId>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -183,6 +187,8 @@ pub struct ProfileUpdateInternal {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -234,6 +240,8 @@ impl ProfileUpdateInternal {
             is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled,
             three_ds_decision_rule_algorithm,
+            dispute_policy,
+            integrity_check_policy,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -316,6 +324,8 @@ impl ProfileUpdateInternal {
                 .or(source.is_pre_network_tokenization_enabled),
             three_ds_decision_rule_algorithm: three_ds_decision_rule_algorithm
                 .or(source.three_ds_decision_rule_algorithm),
+            dispute_policy: dispute_policy.or(source.dispute_policy),
+            integrity_check_policy: integrity_check_policy.or(source.integrity_check_policy),
         }
     }
 }
@@ -0,0 +1,114 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    cards_info::CardInfo,
+    enums as storage_enums,
+    schema::{card_bin_import, card_bin_range},
+};
+
+/// Length to which the bounds of BIN ranges are padded, so that ranges of different account
+/// number lengths can be compared lexicographically.
+pub const NORMALIZED_BIN_RANGE_LENGTH: usize = 19;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = card_bin_import)]
+pub struct CardBinImportNew {
+    pub version: i32,
+    pub source: String,
+    pub file_format: String,
+    pub total_ranges: i32,
+    pub ranges_added: i32,
+    pub ranges_removed: i32,
+    pub ranges_modified: i32,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Identifiable, Queryable, Selectable, serde::Deserialize, serde::Serialize,
+)]
+#[diesel(table_name = card_bin_import, primary_key(version), check_for_backend(diesel::pg::Pg))]
+pub struct CardBinImport {
+    pub version: i32,
+    pub source: String,
+    pub file_format: String,
+    pub total_ranges: i32,
+    pub ranges_added: i32,
+    pub ranges_removed: i32,
+    pub ranges_modified: i32,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Identifiable,
+    Queryable,
+    Selectable,
+    Insertable,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[diesel(
+    table_name = card_bin_range,
+    primary_key(import_version, range_start, range_end),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct CardBinRange {
+    pub import_version: i32,
+    pub range_start: String,
+    pub range_end: String,
+    pub card_issuer: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    pub card_type: Option<String>,
+    pub card_subtype: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bank_code_id: Option<String>,
+    pub bank_code: Option<String>,
+    pub country_code: Option<String>,
+}
+
+impl CardBinRange {
+    /// Pads the lower bound of a BIN range with zeroes to the normalized length.
+    pub fn normalize_range_start(range_start: &str) -> String {
+        format!("{range_start:0<NORMALIZED_BIN_RANGE_LENGTH$}")
+    }
+
+    /// Pads the upper bound of a BIN range with nines to the normalized length.
+    pub fn normalize_range_end(range_end: &str) -> String {
+        format!("{range_end:9<NORMALIZED_BIN_RANGE_LENGTH$}")
+    }
+
+    /// Checks whether the attributes of both ranges are the same, irrespective of their versions.
+    pub fn has_same_details(&self, other: &Self) -> bool {
+        self.card_issuer == other.card_issuer
+            && self.card_network == other.card_network
+            && self.card_type == other.card_type
+            && self.card_subtype == other.card_subtype
+            && self.card_issuing_country == other.card_issuing_country
+            && self.bank_code_id == other.bank_code_id
+            && self.bank_code == other.bank_code
+            && self.country_code == other.country_code
+    }
+
+    pub fn into_card_info(self, card_iin: &str, date_created: PrimitiveDateTime) -> CardInfo {
+        CardInfo {
+            card_iin: card_iin.to_owned(),
+            card_issuer: self.card_issuer,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_subtype: self.card_subtype,
+            card_issuing_country: self.card_issuing_country,
+            bank_code_id: self.bank_code_id,
+            bank_code: self.bank_code,
+            country_code: self.country_code,
+            date_created,
+            last_updated: None,
+            last_updated_provider: None,
+        }
+    }
+}
@@ -152,3 +152,11 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Debug, serde::Deserialize, Serialize, Clone)]
+pub struct DisputeDeadlineTrackingData {
+    pub dispute_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+}
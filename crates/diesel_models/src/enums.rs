@@ -58,6 +58,7 @@ pub enum RoutingAlgorithmKind {
     Advanced,
     Dynamic,
     ThreeDsDecisionRule,
+    CostBased,
 }
 
 #[derive(
@@ -80,6 +81,7 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PaymentMethodDetails,
 }
 
 // Refund
@@ -33,6 +33,7 @@ pub struct EventNew {
 #[diesel(table_name = events)]
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
+    pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub is_overall_delivery_successful: Option<bool>,
 }
@@ -102,6 +103,9 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    PaymentMethod {
+        payment_method_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -42,6 +42,7 @@ pub struct GatewayStatusMap {
     pub unified_message: Option<String>,
     pub error_category: Option<ErrorCategory>,
     pub clear_pan_possible: bool,
+    pub requeue_delay_in_seconds: Option<i32>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
@@ -60,6 +61,7 @@ pub struct GatewayStatusMappingNew {
     pub unified_message: Option<String>,
     pub error_category: Option<ErrorCategory>,
     pub clear_pan_possible: bool,
+    pub requeue_delay_in_seconds: Option<i32>,
 }
 
 #[derive(
@@ -81,6 +83,7 @@ pub struct GatewayStatusMapperUpdateInternal {
     pub error_category: Option<ErrorCategory>,
     pub last_modified: PrimitiveDateTime,
     pub clear_pan_possible: Option<bool>,
+    pub requeue_delay_in_seconds: Option<Option<i32>>,
 }
 
 #[derive(Debug)]
@@ -93,6 +96,7 @@ pub struct GatewayStatusMappingUpdate {
     pub unified_message: Option<String>,
     pub error_category: Option<ErrorCategory>,
     pub clear_pan_possible: Option<bool>,
+    pub requeue_delay_in_seconds: Option<Option<i32>>,
 }
 
 impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
@@ -106,6 +110,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             unified_message,
             error_category,
             clear_pan_possible,
+            requeue_delay_in_seconds,
         } = value;
         Self {
             status,
@@ -122,6 +127,7 @@ impl From<GatewayStatusMappingUpdate> for GatewayStatusMapperUpdateInternal {
             code: None,
             message: None,
             clear_pan_possible,
+            requeue_delay_in_seconds,
         }
     }
 }
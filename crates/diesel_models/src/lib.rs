@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_bin;
 pub mod cards_info;
 pub mod configs;
 
@@ -25,6 +26,7 @@ pub mod generic_link;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod local_vault;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -44,6 +46,7 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_trace;
 pub mod types;
 pub mod unified_translations;
 
@@ -65,11 +68,12 @@ use diesel_impl::{DieselArray, OptionalDieselArray};
 pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
-    address::*, api_keys::*, callback_mapper::*, cards_info::*, configs::*, customers::*,
-    dispute::*, ephemeral_key::*, events::*, file::*, generic_link::*, locker_mock_up::*,
-    mandate::*, merchant_account::*, merchant_connector_account::*, payment_attempt::*,
-    payment_intent::*, payment_method::*, payout_attempt::*, payouts::*, process_tracker::*,
-    refund::*, reverse_lookup::*, user_authentication_method::*,
+    address::*, api_keys::*, callback_mapper::*, card_bin::*, cards_info::*, configs::*,
+    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, generic_link::*,
+    local_vault::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
+    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
+    user_authentication_method::*,
 };
 
 /// The types and implementations provided by this module are required for the schema generated by
@@ -0,0 +1,36 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::local_vault;
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, Selectable, PartialEq)]
+#[diesel(table_name = local_vault, primary_key(merchant_id, vault_id), check_for_backend(diesel::pg::Pg))]
+pub struct LocalVault {
+    pub vault_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub fingerprint_id: String,
+    pub data_encryption_key: Vec<u8>,
+    pub encrypted_data: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = local_vault)]
+pub struct LocalVaultNew {
+    pub vault_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub fingerprint_id: String,
+    pub data_encryption_key: Vec<u8>,
+    pub encrypted_data: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = local_vault)]
+pub struct LocalVaultUpdate {
+    pub fingerprint_id: String,
+    pub data_encryption_key: Vec<u8>,
+    pub encrypted_data: Vec<u8>,
+}
@@ -55,6 +55,7 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
     pub id: Option<id_type::MerchantConnectorAccountId>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v1")]
@@ -142,6 +143,7 @@ pub struct MerchantConnectorAccountNew {
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
     pub id: Option<id_type::MerchantConnectorAccountId>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -198,6 +200,7 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
+    pub fee_schedule: Option<common_types::connector_fees::ConnectorFeeSchedule>,
 }
 
 #[cfg(feature = "v2")]
@@ -246,6 +249,7 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            fee_schedule: self.fee_schedule.or(source.fee_schedule),
 
             ..source
         }
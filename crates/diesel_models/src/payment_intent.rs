@@ -155,6 +155,7 @@ pub struct PaymentIntent {
     pub created_by: Option<String>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub extended_return_url: Option<String>,
+    pub enable_partial_authorization: Option<bool>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression, PartialEq)]
@@ -425,6 +426,7 @@ pub struct PaymentIntentNew {
     pub created_by: Option<String>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub extended_return_url: Option<String>,
+    pub enable_partial_authorization: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -523,6 +525,13 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryption>,
     },
+    CustomerDetailsRedactionUpdate {
+        description: Option<String>,
+        customer_details: Option<Encryption>,
+        billing_details: Option<Encryption>,
+        shipping_details: Option<Encryption>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -1495,6 +1504,53 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 extended_return_url: None,
             },
+            PaymentIntentUpdate::CustomerDetailsRedactionUpdate {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+                modified_at: common_utils::date_time::now(),
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                merchant_order_reference_id: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                force_3ds_challenge: None,
+                is_iframe_redirection_enabled: None,
+                extended_return_url: None,
+            },
         }
     }
 }
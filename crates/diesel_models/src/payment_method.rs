@@ -69,6 +69,7 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -158,6 +159,7 @@ pub struct PaymentMethodNew {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -259,6 +261,14 @@ pub enum PaymentMethodUpdate {
         network_token_requestor_reference_id: Option<String>,
         network_token_locker_id: Option<String>,
         network_token_payment_method_data: Option<Encryption>,
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    },
+    NetworkTokenStatusUpdate {
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    },
+    /// Removes the network token of the payment method
+    NetworkTokenDataClear {
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
     },
     ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
         connector_mandate_details: Option<pii::SecretSerdeValue>,
@@ -402,16 +412,17 @@ pub struct PaymentMethodUpdateInternal {
     network_transaction_id: Option<String>,
     status: Option<storage_enums::PaymentMethodStatus>,
     locker_id: Option<String>,
-    network_token_requestor_reference_id: Option<String>,
+    network_token_requestor_reference_id: Option<Option<String>>,
     payment_method: Option<storage_enums::PaymentMethod>,
     connector_mandate_details: Option<serde_json::Value>,
     updated_by: Option<String>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
     last_modified: PrimitiveDateTime,
-    network_token_locker_id: Option<String>,
-    network_token_payment_method_data: Option<Encryption>,
+    network_token_locker_id: Option<Option<String>>,
+    network_token_payment_method_data: Option<Option<Encryption>>,
     scheme: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
 }
 
 #[cfg(all(
@@ -437,6 +448,7 @@ impl PaymentMethodUpdateInternal {
             network_token_locker_id,
             network_token_payment_method_data,
             scheme,
+            network_token_status,
         } = self;
 
         PaymentMethod {
@@ -473,10 +485,12 @@ impl PaymentMethodUpdateInternal {
             updated_by: updated_by.or(source.updated_by),
             version: source.version,
             network_token_requestor_reference_id: network_token_requestor_reference_id
-                .or(source.network_token_requestor_reference_id),
-            network_token_locker_id: network_token_locker_id.or(source.network_token_locker_id),
+                .unwrap_or(source.network_token_requestor_reference_id),
+            network_token_locker_id: network_token_locker_id
+                .unwrap_or(source.network_token_locker_id),
             network_token_payment_method_data: network_token_payment_method_data
-                .or(source.network_token_payment_method_data),
+                .unwrap_or(source.network_token_payment_method_data),
+            network_token_status: network_token_status.or(source.network_token_status),
         }
     }
 }
@@ -508,6 +522,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -528,6 +543,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -546,6 +562,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
@@ -568,6 +585,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme,
+                network_token_status: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -589,6 +607,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -607,6 +626,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -625,16 +645,18 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_transaction_id: None,
                 status,
                 locker_id,
-                network_token_requestor_reference_id,
+                network_token_requestor_reference_id: network_token_requestor_reference_id
+                    .map(Some),
                 payment_method,
                 connector_mandate_details: None,
                 updated_by: None,
                 payment_method_issuer,
                 payment_method_type,
                 last_modified: common_utils::date_time::now(),
-                network_token_locker_id,
-                network_token_payment_method_data,
+                network_token_locker_id: network_token_locker_id.map(Some),
+                network_token_payment_method_data: network_token_payment_method_data.map(Some),
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -655,11 +677,13 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
             PaymentMethodUpdate::NetworkTokenDataUpdate {
                 network_token_requestor_reference_id,
                 network_token_locker_id,
                 network_token_payment_method_data,
+                network_token_status,
             } => Self {
                 metadata: None,
                 payment_method_data: None,
@@ -673,10 +697,54 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_type: None,
                 last_modified: common_utils::date_time::now(),
                 network_transaction_id: None,
-                network_token_requestor_reference_id,
-                network_token_locker_id,
-                network_token_payment_method_data,
+                network_token_requestor_reference_id: network_token_requestor_reference_id
+                    .map(Some),
+                network_token_locker_id: network_token_locker_id.map(Some),
+                network_token_payment_method_data: network_token_payment_method_data.map(Some),
+                scheme: None,
+                network_token_status,
+            },
+            PaymentMethodUpdate::NetworkTokenDataClear {
+                network_token_status,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_transaction_id: None,
+                network_token_requestor_reference_id: Some(None),
+                network_token_locker_id: Some(None),
+                network_token_payment_method_data: Some(None),
+                scheme: None,
+                network_token_status,
+            },
+            PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_transaction_id: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status,
             },
             PaymentMethodUpdate::ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
                 connector_mandate_details,
@@ -699,6 +767,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
             },
         }
     }
@@ -901,6 +970,7 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             network_token_payment_method_data: payment_method_new
                 .network_token_payment_method_data
                 .clone(),
+            network_token_status: payment_method_new.network_token_status,
         }
     }
 }
@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
+pub mod card_bin;
 pub mod cards_info;
 pub mod configs;
 
@@ -21,6 +22,7 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod local_vault;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -39,6 +41,7 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_trace;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
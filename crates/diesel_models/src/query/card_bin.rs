@@ -0,0 +1,121 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    card_bin::{CardBinImport, CardBinImportNew, CardBinRange},
+    errors,
+    query::generics,
+    schema::{card_bin_import::dsl as import_dsl, card_bin_range::dsl as range_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl CardBinImportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CardBinImport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CardBinImport {
+    pub async fn find_by_version(conn: &PgPooledConn, version: i32) -> StorageResult<Self> {
+        generics::generic_find_by_id::<<Self as HasTable>::Table, _, _>(conn, version).await
+    }
+
+    pub async fn find_active(conn: &PgPooledConn) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            import_dsl::is_active.eq(true),
+        )
+        .await
+    }
+
+    pub async fn list(
+        conn: &PgPooledConn,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            import_dsl::version.is_not_null(),
+            limit,
+            offset,
+            Some(import_dsl::version.desc()),
+        )
+        .await
+    }
+
+    /// Marks the given version as the only active import in a single statement, so that
+    /// lookups never observe zero or multiple active imports.
+    pub async fn activate(conn: &PgPooledConn, version: i32) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            import_dsl::is_active
+                .eq(true)
+                .or(import_dsl::version.eq(version)),
+            import_dsl::is_active.eq(import_dsl::version.eq(version)),
+        )
+        .await
+    }
+}
+
+impl CardBinRange {
+    pub async fn insert_batch(conn: &PgPooledConn, batch: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self>::table()).values(batch);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        query
+            .execute_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while inserting card bin ranges")
+    }
+
+    pub async fn find_by_import_version(
+        conn: &PgPooledConn,
+        import_version: i32,
+    ) -> StorageResult<Vec<Self>> {
+        let query = <Self>::table()
+            .filter(range_dsl::import_version.eq(import_version))
+            .order(range_dsl::range_start.asc());
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while fetching card bin ranges")
+    }
+
+    /// Finds the most specific range of the active import which contains the given card number
+    /// prefix. Nested ranges are resolved in favour of the one with the highest lower bound.
+    pub async fn find_active_by_card_number_prefix(
+        conn: &PgPooledConn,
+        card_number_prefix: &str,
+    ) -> StorageResult<Option<Self>> {
+        let normalized_card_number = Self::normalize_range_start(card_number_prefix);
+        let active_import_versions = import_dsl::card_bin_import
+            .filter(import_dsl::is_active.eq(true))
+            .select(import_dsl::version);
+
+        let query = <Self>::table()
+            .filter(range_dsl::import_version.eq_any(active_import_versions))
+            .filter(range_dsl::range_start.le(normalized_card_number.clone()))
+            .filter(range_dsl::range_end.ge(normalized_card_number))
+            .order(range_dsl::range_start.desc())
+            .limit(1);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        query
+            .get_results_async::<Self>(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while finding card bin range")
+            .map(|ranges| ranges.into_iter().next())
+    }
+}
@@ -0,0 +1,81 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    local_vault::{LocalVault, LocalVaultNew, LocalVaultUpdate},
+    schema::local_vault::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl LocalVaultNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LocalVault> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LocalVault {
+    pub async fn find_by_merchant_id_vault_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::vault_id.eq(vault_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_merchant_id_customer_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_vault_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+        local_vault_update: LocalVaultUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::vault_id.eq(vault_id.to_owned())),
+            local_vault_update,
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_vault_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::vault_id.eq(vault_id.to_owned())),
+        )
+        .await
+    }
+}
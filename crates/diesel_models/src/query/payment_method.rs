@@ -78,6 +78,21 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_network_token_requestor_reference_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::network_token_requestor_reference_id
+                    .eq(network_token_requestor_reference_id.to_owned()),
+            ),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -184,6 +199,25 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: storage_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::status.eq(status)),
+            limit,
+            offset,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -0,0 +1,33 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    routing_trace::{RoutingTrace, RoutingTraceNew},
+    schema::routing_trace::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingTraceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingTrace> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingTrace {
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,32 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::routing_trace;
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = routing_trace)]
+pub struct RoutingTraceNew {
+    pub id: String,
+    pub payment_id: id_type::PaymentId,
+    pub attempt_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub algorithm_id: Option<id_type::RoutingId>,
+    pub routed_connector: Option<String>,
+    pub trace: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = routing_trace, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct RoutingTrace {
+    pub id: String,
+    pub payment_id: id_type::PaymentId,
+    pub attempt_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub algorithm_id: Option<id_type::RoutingId>,
+    pub routed_connector: Option<String>,
+    pub trace: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
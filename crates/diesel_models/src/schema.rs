@@ -228,6 +228,8 @@ diesel::table! {
         is_iframe_redirection_enabled -> Nullable<Bool>,
         is_pre_network_tokenization_enabled -> Nullable<Bool>,
         three_ds_decision_rule_algorithm -> Nullable<Jsonb>,
+        dispute_policy -> Nullable<Jsonb>,
+        integrity_check_policy -> Nullable<Jsonb>,
     }
 }
 
@@ -285,6 +287,49 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_bin_import (version) {
+        version -> Int4,
+        #[max_length = 255]
+        source -> Varchar,
+        #[max_length = 16]
+        file_format -> Varchar,
+        total_ranges -> Int4,
+        ranges_added -> Int4,
+        ranges_removed -> Int4,
+        ranges_modified -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_bin_range (import_version, range_start, range_end) {
+        import_version -> Int4,
+        #[max_length = 19]
+        range_start -> Varchar,
+        #[max_length = 19]
+        range_end -> Varchar,
+        card_issuer -> Nullable<Text>,
+        card_network -> Nullable<Text>,
+        card_type -> Nullable<Text>,
+        card_subtype -> Nullable<Text>,
+        card_issuing_country -> Nullable<Text>,
+        #[max_length = 32]
+        bank_code_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        bank_code -> Nullable<Varchar>,
+        #[max_length = 32]
+        country_code -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -576,6 +621,7 @@ diesel::table! {
         #[max_length = 64]
         error_category -> Nullable<Varchar>,
         clear_pan_possible -> Bool,
+        requeue_delay_in_seconds -> Nullable<Int4>,
     }
 }
 
@@ -626,6 +672,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    local_vault (merchant_id, vault_id) {
+        #[max_length = 64]
+        vault_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 128]
+        fingerprint_id -> Varchar,
+        data_encryption_key -> Bytea,
+        encrypted_data -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -797,6 +862,7 @@ diesel::table! {
         version -> ApiVersion,
         #[max_length = 64]
         id -> Nullable<Varchar>,
+        fee_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -947,6 +1013,7 @@ diesel::table! {
         #[max_length = 255]
         created_by -> Nullable<Varchar>,
         setup_future_usage_applied -> Nullable<FutureUsage>,
+        estimated_connector_cost -> Nullable<Int8>,
     }
 }
 
@@ -1041,6 +1108,7 @@ diesel::table! {
         is_iframe_redirection_enabled -> Nullable<Bool>,
         #[max_length = 2048]
         extended_return_url -> Nullable<Varchar>,
+        enable_partial_authorization -> Nullable<Bool>,
     }
 }
 
@@ -1132,6 +1200,8 @@ diesel::table! {
         #[max_length = 64]
         network_token_locker_id -> Nullable<Varchar>,
         network_token_payment_method_data -> Nullable<Bytea>,
+        #[max_length = 32]
+        network_token_status -> Nullable<Varchar>,
     }
 }
 
@@ -1418,6 +1488,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_trace (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        routed_connector -> Nullable<Varchar>,
+        trace -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1573,6 +1667,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     business_profile,
     callback_mapper,
     captures,
+    card_bin_import,
+    card_bin_range,
     cards_info,
     configs,
     customers,
@@ -1585,6 +1681,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    local_vault,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1603,6 +1700,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_trace,
     themes,
     unified_translations,
     user_authentication_methods,
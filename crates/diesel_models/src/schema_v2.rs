@@ -293,6 +293,49 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_bin_import (version) {
+        version -> Int4,
+        #[max_length = 255]
+        source -> Varchar,
+        #[max_length = 16]
+        file_format -> Varchar,
+        total_ranges -> Int4,
+        ranges_added -> Int4,
+        ranges_removed -> Int4,
+        ranges_modified -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    card_bin_range (import_version, range_start, range_end) {
+        import_version -> Int4,
+        #[max_length = 19]
+        range_start -> Varchar,
+        #[max_length = 19]
+        range_end -> Varchar,
+        card_issuer -> Nullable<Text>,
+        card_network -> Nullable<Text>,
+        card_type -> Nullable<Text>,
+        card_subtype -> Nullable<Text>,
+        card_issuing_country -> Nullable<Text>,
+        #[max_length = 32]
+        bank_code_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        bank_code -> Nullable<Varchar>,
+        #[max_length = 32]
+        country_code -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -588,6 +631,7 @@ diesel::table! {
         #[max_length = 64]
         error_category -> Nullable<Varchar>,
         clear_pan_possible -> Bool,
+        requeue_delay_in_seconds -> Nullable<Int4>,
     }
 }
 
@@ -638,6 +682,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    local_vault (merchant_id, vault_id) {
+        #[max_length = 64]
+        vault_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 128]
+        fingerprint_id -> Varchar,
+        data_encryption_key -> Bytea,
+        encrypted_data -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1349,6 +1412,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_trace (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        routed_connector -> Nullable<Varchar>,
+        trace -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1524,6 +1611,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     business_profile,
     callback_mapper,
     captures,
+    card_bin_import,
+    card_bin_range,
     cards_info,
     configs,
     customers,
@@ -1536,6 +1625,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    local_vault,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1554,6 +1644,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_trace,
     themes,
     tokenization,
     unified_translations,
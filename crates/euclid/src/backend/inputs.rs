@@ -52,6 +52,12 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutInput {
+    pub recipient_country: Option<enums::Country>,
+    pub priority: Option<enums::PayoutSendPriority>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -61,4 +67,6 @@ pub struct BackendInput {
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub payout: Option<PayoutInput>,
 }
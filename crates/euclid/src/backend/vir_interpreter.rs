@@ -156,6 +156,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -199,6 +200,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -243,6 +245,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -287,6 +290,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -331,6 +335,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -375,6 +380,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -419,6 +425,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -463,6 +470,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -507,6 +515,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -553,6 +562,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -597,6 +607,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -650,6 +661,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            payout: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -665,4 +677,108 @@ mod test {
             "rule_1"
         );
     }
+
+    fn get_payout_input(payout: inputs::PayoutInput) -> inputs::BackendInput {
+        inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(1000),
+                currency: enums::Currency::EUR,
+                card_bin: None,
+                authentication_type: None,
+                capture_method: None,
+                business_country: Some(enums::Country::Germany),
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::BankTransfer),
+                payment_method_type: Some(enums::PaymentMethodType::Sepa),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            payout: Some(payout),
+        }
+    }
+
+    #[test]
+    fn test_payout_recipient_country() {
+        let program_str = r#"
+        default: ["adyenplatform"]
+        rule_1: ["wise"]
+        {
+            payout_recipient_country = (Germany, France)
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let result = backend
+            .execute(get_payout_input(inputs::PayoutInput {
+                recipient_country: Some(enums::Country::France),
+                priority: None,
+            }))
+            .expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+
+        let result = backend
+            .execute(get_payout_input(inputs::PayoutInput {
+                recipient_country: Some(enums::Country::Spain),
+                priority: None,
+            }))
+            .expect("Execution");
+        assert!(result.rule_name.is_none());
+
+        let result = backend
+            .execute(get_payout_input(inputs::PayoutInput {
+                recipient_country: None,
+                priority: None,
+            }))
+            .expect("Execution");
+        assert!(result.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_payout_priority() {
+        let program_str = r#"
+        default: ["adyenplatform"]
+        rule_1: ["wise"]
+        {
+            payout_priority = instant
+        }
+        rule_2: ["adyenplatform"]
+        {
+            payout_recipient_country = Germany
+            payout_priority /= instant
+        }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let result = backend
+            .execute(get_payout_input(inputs::PayoutInput {
+                recipient_country: Some(enums::Country::Germany),
+                priority: Some(enums::PayoutSendPriority::Instant),
+            }))
+            .expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+
+        let result = backend
+            .execute(get_payout_input(inputs::PayoutInput {
+                recipient_country: Some(enums::Country::Germany),
+                priority: Some(enums::PayoutSendPriority::CrossBorder),
+            }))
+            .expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_2");
+    }
 }
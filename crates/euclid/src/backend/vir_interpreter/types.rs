@@ -58,6 +58,7 @@ impl Context {
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
         let payment_mandate = input.mandate;
+        let payout = input.payout;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -143,6 +144,16 @@ impl Context {
             }
         }
 
+        // Handle payout data
+        if let Some(payout) = payout {
+            if let Some(country) = payout.recipient_country {
+                enum_values.insert(EuclidValue::PayoutRecipientCountry(country));
+            }
+            if let Some(priority) = payout.priority {
+                enum_values.insert(EuclidValue::PayoutPriority(priority));
+            }
+        }
+
         let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
@@ -82,6 +82,8 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            Self::PayoutRecipientCountry(country) => country.to_string(),
+            Self::PayoutPriority(priority) => priority.to_string(),
         }
     }
 }
@@ -1,6 +1,7 @@
 pub use common_enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Country, CountryAlpha2, Currency,
-    FutureUsage as SetupFutureUsage, PaymentMethod, PaymentMethodType, RoutableConnectors,
+    FutureUsage as SetupFutureUsage, PaymentMethod, PaymentMethodType, PayoutSendPriority,
+    RoutableConnectors,
 };
 use strum::VariantNames;
 
@@ -34,6 +35,7 @@ collect_variants!(CaptureMethod);
 collect_variants!(Currency);
 collect_variants!(Country);
 collect_variants!(SetupFutureUsage);
+collect_variants!(PayoutSendPriority);
 #[cfg(feature = "payouts")]
 collect_variants!(PayoutType);
 #[cfg(feature = "payouts")]
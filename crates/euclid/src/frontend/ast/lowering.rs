@@ -275,6 +275,8 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::PayoutRecipientCountry => lower_enum!(PayoutRecipientCountry, value),
+        dir::DirKeyKind::PayoutPriority => lower_enum!(PayoutPriority, value),
     }
 }
 
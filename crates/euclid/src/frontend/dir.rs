@@ -334,6 +334,20 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "payout_recipient_country",
+        detailed_message = "Country of the recipient of the payout",
+        props(Category = "Payout")
+    )]
+    #[serde(rename = "payout_recipient_country")]
+    PayoutRecipientCountry,
+    #[strum(
+        serialize = "payout_priority",
+        detailed_message = "Priority with which the payout is to be sent (Instant, Fast, Regular, Wire, Cross Border, Internal)",
+        props(Category = "Payout")
+    )]
+    #[serde(rename = "payout_priority")]
+    PayoutPriority,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -391,6 +405,8 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::PayoutRecipientCountry => types::DataType::EnumVariant,
+            Self::PayoutPriority => types::DataType::EnumVariant,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -555,6 +571,16 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::PayoutRecipientCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::PayoutRecipientCountry)
+                    .collect(),
+            ),
+            Self::PayoutPriority => Some(
+                enums::PayoutPriority::iter()
+                    .map(DirValue::PayoutPriority)
+                    .collect(),
+            ),
         }
     }
 }
@@ -640,6 +666,10 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "payout_recipient_country")]
+    PayoutRecipientCountry(enums::Country),
+    #[serde(rename = "payout_priority")]
+    PayoutPriority(enums::PayoutPriority),
 }
 
 impl DirValue {
@@ -683,6 +713,8 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::PayoutRecipientCountry(_) => (DirKeyKind::PayoutRecipientCountry, None),
+            Self::PayoutPriority(_) => (DirKeyKind::PayoutPriority, None),
         };
 
         DirKey::new(kind, data)
@@ -727,6 +759,8 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::PayoutRecipientCountry(_) => None,
+            Self::PayoutPriority(_) => None,
         }
     }
 
@@ -782,6 +816,8 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::PayoutRecipientCountry(c1), Self::PayoutRecipientCountry(c2)) => c1 == c2,
+            (Self::PayoutPriority(p1), Self::PayoutPriority(p2)) => p1 == p2,
             _ => false,
         }
     }
@@ -836,6 +872,30 @@ pub enum PayoutDirKeyKind {
     #[serde(rename = "amount")]
     PayoutAmount,
 
+    #[strum(
+        serialize = "currency",
+        detailed_message = "Currency used for the payout",
+        props(Category = "Order details")
+    )]
+    #[serde(rename = "currency")]
+    PayoutCurrency,
+
+    #[strum(
+        serialize = "payout_recipient_country",
+        detailed_message = "Country of the recipient of the payout",
+        props(Category = "Payout")
+    )]
+    #[serde(rename = "payout_recipient_country")]
+    PayoutRecipientCountry,
+
+    #[strum(
+        serialize = "payout_priority",
+        detailed_message = "Priority with which the payout is to be sent",
+        props(Category = "Payout")
+    )]
+    #[serde(rename = "payout_priority")]
+    PayoutPriority,
+
     #[strum(
         serialize = "payment_method",
         detailed_message = "Different modes of payout - eg. cards, wallets, banks",
@@ -874,6 +934,12 @@ pub enum PayoutDirValue {
     BusinessLabel(types::StrValue),
     #[serde(rename = "amount")]
     PayoutAmount(types::NumValue),
+    #[serde(rename = "currency")]
+    PayoutCurrency(enums::PaymentCurrency),
+    #[serde(rename = "payout_recipient_country")]
+    PayoutRecipientCountry(enums::Country),
+    #[serde(rename = "payout_priority")]
+    PayoutPriority(enums::PayoutPriority),
     #[serde(rename = "payment_method")]
     PayoutType(common_enums::PayoutType),
     #[serde(rename = "wallet")]
@@ -4,9 +4,10 @@ use utoipa::ToSchema;
 use crate::enums::collect_variants;
 pub use crate::enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
-    Country as BillingCountry, Country as IssuerCountry, Country as AcquirerCountry, CountryAlpha2,
-    Currency as PaymentCurrency, MandateAcceptanceType, MandateType, PaymentMethod, PaymentType,
-    RoutableConnectors, SetupFutureUsage,
+    Country as BillingCountry, Country as IssuerCountry, Country as AcquirerCountry,
+    Country as PayoutRecipientCountry, CountryAlpha2, Currency as PaymentCurrency,
+    MandateAcceptanceType, MandateType, PaymentMethod, PaymentType,
+    PayoutSendPriority as PayoutPriority, RoutableConnectors, SetupFutureUsage,
 };
 #[cfg(feature = "payouts")]
 pub use crate::enums::{PayoutBankTransferType, PayoutType, PayoutWalletType};
@@ -276,6 +276,10 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        dir::DirValue::PayoutRecipientCountry(country) => {
+            EuclidValue::PayoutRecipientCountry(country)
+        }
+        dir::DirValue::PayoutPriority(priority) => EuclidValue::PayoutPriority(priority),
     })
 }
 
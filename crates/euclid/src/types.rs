@@ -77,6 +77,10 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "payout_recipient_country")]
+    PayoutRecipientCountry,
+    #[strum(serialize = "payout_priority")]
+    PayoutPriority,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -95,6 +99,8 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::PayoutRecipientCountry,
+        DirKeyKind::PayoutPriority,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -163,6 +169,8 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::PayoutRecipientCountry => DataType::EnumVariant,
+            Self::PayoutPriority => DataType::EnumVariant,
         }
     }
 }
@@ -285,6 +293,8 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    PayoutRecipientCountry(enums::Country),
+    PayoutPriority(enums::PayoutSendPriority),
 }
 
 impl EuclidValue {
@@ -320,6 +330,8 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::PayoutRecipientCountry(_) => EuclidKey::PayoutRecipientCountry,
+            Self::PayoutPriority(_) => EuclidKey::PayoutPriority,
         }
     }
 }
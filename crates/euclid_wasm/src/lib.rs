@@ -28,6 +28,7 @@ use euclid::{
         dir::{self, enums as dir_enums, EuclidDirFilter},
     },
 };
+use kgraph_utils::transformers::IntoContext;
 use strum::{EnumMessage, EnumProperty, VariantNames};
 use wasm_bindgen::prelude::*;
 
@@ -195,6 +196,30 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
     Ok(serde_wasm_bindgen::to_value(&valid_connectors)?)
 }
 
+/// This function allows the frontend to find out why one of the merchant's configured connectors
+/// is not eligible for a payment. The input arguments are the connector and the same payment
+/// input that `runProgram` accepts. The failing constraints of the knowledge graph are returned
+/// along with the path through which each of them was reached.
+#[wasm_bindgen(js_name = explainConnectorEligibility)]
+pub fn explain_connector_eligibility(connector: JsValue, input: JsValue) -> JsResult {
+    let seed_data = SEED_DATA.get().ok_or("Data not seeded").err_to_js()?;
+
+    let connector: RoutableConnectors = serde_wasm_bindgen::from_value(connector)?;
+    let input: inputs::BackendInput = serde_wasm_bindgen::from_value(input)?;
+
+    let context = dssa::graph::AnalysisContext::from_dir_values(input.into_context().err_to_js()?);
+
+    let explanation = kgraph_utils::explanation::explain_value_eligibility(
+        &seed_data.cgraph,
+        dir::DirValue::Connector(Box::new(ast::ConnectorChoice { connector })),
+        &context,
+        None,
+    )
+    .err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&explanation)?)
+}
+
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
@@ -232,6 +257,9 @@ pub fn get_all_keys() -> JsResult {
         "customer_device_display_size",
         "acquirer_country",
         "acquirer_fraud_rate",
+        // Payout Keys are only exposed through the payout routing keys
+        "payout_recipient_country",
+        "payout_priority",
     ];
 
     let keys: Vec<&'static str> = dir::DirKeyKind::VARIANTS
@@ -305,6 +333,8 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::MobilePaymentType => dir_enums::MobilePaymentType::VARIANTS,
         dir::DirKeyKind::IssuerCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::AcquirerCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::PayoutRecipientCountry => dir_enums::PayoutRecipientCountry::VARIANTS,
+        dir::DirKeyKind::PayoutPriority => dir_enums::PayoutPriority::VARIANTS,
         dir::DirKeyKind::CustomerDeviceType => dir_enums::CustomerDeviceType::VARIANTS,
         dir::DirKeyKind::CustomerDevicePlatform => dir_enums::CustomerDevicePlatform::VARIANTS,
         dir::DirKeyKind::CustomerDeviceDisplaySize => {
@@ -427,6 +457,11 @@ pub fn get_payout_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::PayoutDirKeyKind::PayoutType => dir_enums::PayoutType::VARIANTS,
         dir::PayoutDirKeyKind::WalletType => dir_enums::PayoutWalletType::VARIANTS,
         dir::PayoutDirKeyKind::BankTransferType => dir_enums::PayoutBankTransferType::VARIANTS,
+        dir::PayoutDirKeyKind::PayoutCurrency => dir_enums::PaymentCurrency::VARIANTS,
+        dir::PayoutDirKeyKind::PayoutRecipientCountry => {
+            dir_enums::PayoutRecipientCountry::VARIANTS
+        }
+        dir::PayoutDirKeyKind::PayoutPriority => dir_enums::PayoutPriority::VARIANTS,
 
         dir::PayoutDirKeyKind::PayoutAmount | dir::PayoutDirKeyKind::BusinessLabel => {
             Err("Key does not have variants".to_string())?
@@ -80,7 +80,7 @@ use router_env::{instrument, tracing};
 use transformers as adyen;
 
 #[cfg(feature = "payouts")]
-use crate::utils::PayoutsData as UtilsPayoutData;
+use crate::utils::{get_payout_integrity_object, PayoutsData as UtilsPayoutData};
 use crate::{
     capture_method_not_supported,
     constants::{self, headers},
@@ -1342,11 +1342,29 @@ impl ConnectorIntegration<PoCreate, PayoutsData, PayoutsResponseData> for Adyen
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
+
+        // The amount is not returned when the payout is only submitted for review
+        let response_integrity_object = response
+            .amount
+            .as_ref()
+            .map(|amount| {
+                get_payout_integrity_object(
+                    self.amount_converter,
+                    amount.value,
+                    amount.currency.to_string(),
+                )
+            })
+            .transpose()?;
+
         RouterData::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|mut router_data| {
+            router_data.request.integrity_object = response_integrity_object;
+            router_data
+        })
     }
 
     fn get_error_response(
@@ -1568,11 +1586,29 @@ impl ConnectorIntegration<PoFulfill, PayoutsData, PayoutsResponseData> for Adyen
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
+
+        // The amount is not returned when the payout is only submitted for review
+        let response_integrity_object = response
+            .amount
+            .as_ref()
+            .map(|amount| {
+                get_payout_integrity_object(
+                    self.amount_converter,
+                    amount.value,
+                    amount.currency.to_string(),
+                )
+            })
+            .transpose()?;
+
         RouterData::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|mut router_data| {
+            router_data.request.integrity_object = response_integrity_object;
+            router_data
+        })
     }
 
     fn get_error_response(
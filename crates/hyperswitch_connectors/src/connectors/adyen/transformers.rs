@@ -5085,7 +5085,7 @@ pub struct AdyenPayoutResponse {
     psp_reference: String,
     result_code: Option<AdyenStatus>,
     response: Option<AdyenStatus>,
-    amount: Option<Amount>,
+    pub amount: Option<Amount>,
     merchant_reference: Option<String>,
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
@@ -7,7 +7,7 @@ use common_utils::{
     consts,
     ext_traits::{OptionExt, ValueExt},
     pii,
-    types::{SemanticVersion, StringMajorUnit},
+    types::{SemanticVersion, StringMajorUnit, StringMajorUnitForConnector},
 };
 use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
@@ -157,6 +157,7 @@ impl TryFrom<&SetupMandateRouterData> for CybersourceZeroMandateRequest {
                 merchant_intitiated_transaction: None,
                 ignore_avs_result: connector_merchant_config.disable_avs,
                 ignore_cv_result: connector_merchant_config.disable_cvn,
+                partial_auth_indicator: None,
             }),
         );
 
@@ -414,6 +415,7 @@ pub struct CybersourceAuthorizationOptions {
     merchant_intitiated_transaction: Option<MerchantInitiatedTransaction>,
     ignore_avs_result: Option<bool>,
     ignore_cv_result: Option<bool>,
+    partial_auth_indicator: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -736,6 +738,7 @@ impl
                     }),
                     ignore_avs_result: connector_merchant_config.disable_avs,
                     ignore_cv_result: connector_merchant_config.disable_cvn,
+                    partial_auth_indicator: item.router_data.request.enable_partial_authorization,
                     merchant_intitiated_transaction: None,
                 }),
             )
@@ -786,6 +789,10 @@ impl
                             }),
                             ignore_avs_result: connector_merchant_config.disable_avs,
                             ignore_cv_result: connector_merchant_config.disable_cvn,
+                            partial_auth_indicator: item
+                                .router_data
+                                .request
+                                .enable_partial_authorization,
                         }),
                     )
                 }
@@ -854,6 +861,10 @@ impl
                             }),
                             ignore_avs_result: connector_merchant_config.disable_avs,
                             ignore_cv_result: connector_merchant_config.disable_cvn,
+                            partial_auth_indicator: item
+                                .router_data
+                                .request
+                                .enable_partial_authorization,
                         }),
                     )
                 }
@@ -924,6 +935,10 @@ impl
                             }),
                             ignore_avs_result: connector_merchant_config.disable_avs,
                             ignore_cv_result: connector_merchant_config.disable_cvn,
+                            partial_auth_indicator: item
+                                .router_data
+                                .request
+                                .enable_partial_authorization,
                         }),
                     )
                 }
@@ -938,6 +953,7 @@ impl
                     merchant_intitiated_transaction: None,
                     ignore_avs_result: connector_merchant_config.disable_avs,
                     ignore_cv_result: connector_merchant_config.disable_cvn,
+                    partial_auth_indicator: item.router_data.request.enable_partial_authorization,
                 }),
             )
         };
@@ -1057,6 +1073,7 @@ impl
                         merchant_intitiated_transaction: None,
                         ignore_avs_result: connector_merchant_config.disable_avs,
                         ignore_cv_result: connector_merchant_config.disable_cvn,
+                        partial_auth_indicator: None,
                     }),
                 )
             } else {
@@ -2451,6 +2468,7 @@ impl TryFrom<&CybersourceRouterData<&PaymentsIncrementalAuthorizationRouterData>
                     }),
                     ignore_avs_result: connector_merchant_config.disable_avs,
                     ignore_cv_result: connector_merchant_config.disable_cvn,
+                    partial_auth_indicator: None,
                 }),
                 commerce_indicator: String::from("internet"),
                 capture: None,
@@ -2571,7 +2589,7 @@ pub enum CybersourcePaymentStatus {
     Accepted,
     Cancelled,
     StatusNotReceived,
-    //PartialAuthorized, not being consumed yet.
+    PartialAuthorized,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -2587,7 +2605,7 @@ pub fn map_cybersource_attempt_status(
     capture: bool,
 ) -> enums::AttemptStatus {
     match status {
-        CybersourcePaymentStatus::Authorized => {
+        CybersourcePaymentStatus::Authorized | CybersourcePaymentStatus::PartialAuthorized => {
             if capture {
                 // Because Cybersource will return Payment Status as Authorized even in AutoCapture Payment
                 enums::AttemptStatus::Charged
@@ -2638,6 +2656,19 @@ pub struct CybersourcePaymentsResponse {
     risk_information: Option<ClientRiskInformation>,
     token_information: Option<CybersourceTokenInformation>,
     error_information: Option<CybersourceErrorInformation>,
+    order_information: Option<CybersourceOrderInformationResponse>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceOrderInformationResponse {
+    amount_details: Option<CybersourceAmountDetailsResponse>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceAmountDetailsResponse {
+    authorized_amount: Option<StringMajorUnit>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -2834,6 +2865,35 @@ impl
             .map(AdditionalPaymentMethodConnectorResponse::from)
             .map(ConnectorResponseData::with_additional_payment_method_data);
 
+        // Cybersource approves a part of the amount when partial authorization is requested and the
+        // balance available on the card is insufficient
+        let authorized_amount = match item.response.status {
+            Some(CybersourcePaymentStatus::PartialAuthorized) => item
+                .response
+                .order_information
+                .as_ref()
+                .and_then(|order_information| order_information.amount_details.as_ref())
+                .and_then(|amount_details| amount_details.authorized_amount.clone())
+                .map(|authorized_amount| {
+                    utils::convert_back_amount_to_minor_units(
+                        &StringMajorUnitForConnector,
+                        authorized_amount,
+                        item.data.request.currency,
+                    )
+                })
+                .transpose()?,
+            _ => None,
+        };
+        let connector_response = match (connector_response, authorized_amount) {
+            (Some(connector_response), Some(authorized_amount)) => {
+                Some(connector_response.set_authorized_amount(authorized_amount))
+            }
+            (None, Some(authorized_amount)) => Some(ConnectorResponseData::with_authorized_amount(
+                authorized_amount,
+            )),
+            (connector_response, None) => connector_response,
+        };
+
         Ok(Self {
             status,
             response,
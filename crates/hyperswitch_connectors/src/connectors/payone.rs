@@ -67,7 +67,10 @@ use crate::{
     },
 };
 #[cfg(feature = "payouts")]
-use crate::{types::ResponseRouterData, utils::convert_amount};
+use crate::{
+    types::ResponseRouterData,
+    utils::{convert_amount, get_payout_integrity_object},
+};
 #[derive(Clone)]
 pub struct Payone {
     #[cfg(feature = "payouts")]
@@ -346,11 +349,21 @@ impl ConnectorIntegration<PoFulfill, PayoutsData, PayoutsResponseData> for Payon
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
 
+        let response_integrity_object = get_payout_integrity_object(
+            self.amount_converter,
+            response.payout_output.amount_of_money.amount,
+            response.payout_output.amount_of_money.currency_code.clone(),
+        )?;
+
         RouterData::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|mut router_data| {
+            router_data.request.integrity_object = Some(response_integrity_object);
+            router_data
+        })
     }
 
     fn get_error_response(
@@ -104,8 +104,8 @@ pub struct PayonePayoutFulfillRequest {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AmountOfMoney {
-    amount: MinorUnit,
-    currency_code: String,
+    pub amount: MinorUnit,
+    pub currency_code: String,
 }
 
 #[cfg(feature = "payouts")]
@@ -225,7 +225,7 @@ pub enum PayoneStatus {
 #[serde(rename_all = "camelCase")]
 pub struct PayonePayoutFulfillResponse {
     id: String,
-    payout_output: PayoutOutput,
+    pub payout_output: PayoutOutput,
     status: PayoneStatus,
 }
 
@@ -233,7 +233,7 @@ pub struct PayonePayoutFulfillResponse {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PayoutOutput {
-    amount_of_money: AmountOfMoney,
+    pub amount_of_money: AmountOfMoney,
 }
 
 #[cfg(feature = "payouts")]
@@ -78,7 +78,9 @@ use stripe::auth_headers;
 
 use self::transformers as stripe;
 #[cfg(feature = "payouts")]
-use crate::utils::{PayoutsData as OtherPayoutsData, RouterData as OtherRouterData};
+use crate::utils::{
+    get_payout_integrity_object, PayoutsData as OtherPayoutsData, RouterData as OtherRouterData,
+};
 use crate::{
     constants::headers::{AUTHORIZATION, CONTENT_TYPE, STRIPE_COMPATIBLE_CONNECT_ACCOUNT},
     types::{
@@ -2494,12 +2496,23 @@ impl ConnectorIntegration<PoCreate, PayoutsData, PayoutsResponseData> for Stripe
             .response
             .parse_struct("StripeConnectPayoutCreateResponse")
             .change_context(ConnectorError::ResponseDeserializationFailed)?;
+
+        let response_integrity_object = get_payout_integrity_object(
+            self.amount_converter,
+            response.amount,
+            response.currency.clone(),
+        )?;
+
         event_builder.map(|i| i.set_error_response_body(&response));
         RouterData::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|mut router_data| {
+            router_data.request.integrity_object = Some(response_integrity_object);
+            router_data
+        })
     }
 
     fn get_error_response(
@@ -2575,12 +2588,23 @@ impl ConnectorIntegration<PoFulfill, PayoutsData, PayoutsResponseData> for Strip
             .response
             .parse_struct("StripeConnectPayoutFulfillResponse")
             .change_context(ConnectorError::ResponseDeserializationFailed)?;
+
+        let response_integrity_object = get_payout_integrity_object(
+            self.amount_converter,
+            response.amount,
+            response.currency.clone(),
+        )?;
+
         event_builder.map(|i| i.set_error_response_body(&response));
         RouterData::try_from(ResponseRouterData {
             response,
             data: data.clone(),
             http_code: res.status_code,
         })
+        .map(|mut router_data| {
+            router_data.request.integrity_object = Some(response_integrity_object);
+            router_data
+        })
     }
 
     fn get_error_response(
@@ -1,6 +1,6 @@
 use api_models;
 use common_enums::{enums, Currency};
-use common_utils::{ext_traits::OptionExt as _, pii::Email};
+use common_utils::{ext_traits::OptionExt as _, pii::Email, types::MinorUnit};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::types::{PayoutsResponseData, PayoutsRouterData};
 use hyperswitch_interfaces::errors;
@@ -41,6 +41,8 @@ pub struct StripeConnectPayoutCreateRequest {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StripeConnectPayoutCreateResponse {
     id: String,
+    pub amount: MinorUnit,
+    pub currency: String,
     description: Option<String>,
     source_transaction: Option<String>,
 }
@@ -62,7 +64,8 @@ pub struct StripeConnectPayoutFulfillRequest {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StripeConnectPayoutFulfillResponse {
     id: String,
-    currency: String,
+    pub amount: MinorUnit,
+    pub currency: String,
     description: Option<String>,
     failure_balance_transaction: Option<String>,
     failure_code: Option<String>,
@@ -6064,6 +6064,7 @@ pub(crate) fn convert_setup_mandate_router_data_to_authorize_router_data(
         customer_id: None,
         surcharge_details: None,
         request_extended_authorization: None,
+        enable_partial_authorization: None,
         request_incremental_authorization: data.request.request_incremental_authorization,
         metadata: None,
         authentication_data: None,
@@ -6374,6 +6375,29 @@ pub fn get_refund_integrity_object<T>(
     })
 }
 
+#[cfg(feature = "payouts")]
+pub fn get_payout_integrity_object<T>(
+    amount_convertor: &dyn AmountConvertor<Output = T>,
+    amount: T,
+    currency: String,
+) -> Result<
+    hyperswitch_domain_models::router_request_types::PayoutIntegrityObject,
+    error_stack::Report<errors::ConnectorError>,
+> {
+    let currency_enum = enums::Currency::from_str(currency.to_uppercase().as_str())
+        .change_context(errors::ConnectorError::ParsingFailed)?;
+
+    let amount_in_minor_unit =
+        convert_back_amount_to_minor_units(amount_convertor, amount, currency_enum)?;
+
+    Ok(
+        hyperswitch_domain_models::router_request_types::PayoutIntegrityObject {
+            amount: amount_in_minor_unit,
+            destination_currency: currency_enum,
+        },
+    )
+}
+
 #[cfg(feature = "frm")]
 pub trait FraudCheckSaleRequest {
     fn get_order_details(&self) -> Result<Vec<OrderDetailsWithAmount>, Error>;
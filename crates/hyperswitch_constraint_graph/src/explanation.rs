@@ -0,0 +1,151 @@
+use std::sync::Weak;
+
+use crate::{
+    error::{AnalysisTrace, ValueTracePredecessor},
+    types::{NodeValue, Relation, RelationResolution, ValueNode},
+};
+
+/// A node crossed on the way from the checked node to a failing constraint
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConstraintPathSegment<V: ValueNode> {
+    pub info: Option<&'static str>,
+    pub value: Option<NodeValue<V>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "type", content = "details", rename_all = "snake_case")]
+pub enum FailedConstraintKind<V: ValueNode> {
+    /// The value was expected to be present in (positive relation) or absent from (negative
+    /// relation) the checking context, and it wasn't
+    Value {
+        value: NodeValue<V>,
+        relation: Relation,
+    },
+    /// The value found in the checking context for a key was expected to be one of (positive
+    /// relation) or none of (negative relation) the listed values. `found` is `None` when the
+    /// context had no value for the key at all
+    In {
+        expected: Vec<V>,
+        found: Option<V>,
+        relation: Relation,
+    },
+    /// Two edges leading to the same node required opposite relations
+    Contradiction { relation: RelationResolution },
+}
+
+/// A single unsatisfied constraint, along with the path of nodes through which it was reached
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FailedConstraint<V: ValueNode> {
+    pub path: Vec<ConstraintPathSegment<V>>,
+    pub info: Option<&'static str>,
+    pub kind: FailedConstraintKind<V>,
+}
+
+impl<V: ValueNode> AnalysisTrace<V> {
+    /// Flattens the trace into the list of leaf constraints that were not satisfied.
+    ///
+    /// The trace only holds weak references to its predecessors, so this must be called while the
+    /// memoization cache the analysis was run with is still alive. Predecessors that have already
+    /// been dropped are skipped.
+    pub fn failed_constraints(&self) -> Vec<FailedConstraint<V>> {
+        let mut failures = Vec::new();
+        self.collect_failed_constraints(&mut Vec::new(), &mut failures);
+        failures
+    }
+
+    fn collect_failed_constraints(
+        &self,
+        path: &mut Vec<ConstraintPathSegment<V>>,
+        failures: &mut Vec<FailedConstraint<V>>,
+    ) {
+        match self {
+            Self::Value {
+                value,
+                relation,
+                predecessors: None,
+                info,
+                ..
+            } => failures.push(FailedConstraint {
+                path: path.clone(),
+                info: *info,
+                kind: FailedConstraintKind::Value {
+                    value: value.clone(),
+                    relation: *relation,
+                },
+            }),
+
+            Self::Value {
+                value,
+                predecessors: Some(predecessors),
+                info,
+                ..
+            } => {
+                path.push(ConstraintPathSegment {
+                    info: *info,
+                    value: Some(value.clone()),
+                });
+                match predecessors {
+                    ValueTracePredecessor::Mandatory(predecessor) => {
+                        Self::collect_from_weak(predecessor, path, failures)
+                    }
+                    ValueTracePredecessor::OneOf(predecessors) => {
+                        predecessors.iter().for_each(|predecessor| {
+                            Self::collect_from_weak(predecessor, path, failures)
+                        })
+                    }
+                }
+                path.pop();
+            }
+
+            Self::AllAggregation {
+                unsatisfied, info, ..
+            }
+            | Self::AnyAggregation {
+                unsatisfied, info, ..
+            } => {
+                path.push(ConstraintPathSegment {
+                    info: *info,
+                    value: None,
+                });
+                unsatisfied
+                    .iter()
+                    .for_each(|predecessor| Self::collect_from_weak(predecessor, path, failures));
+                path.pop();
+            }
+
+            Self::InAggregation {
+                expected,
+                found,
+                relation,
+                info,
+                ..
+            } => failures.push(FailedConstraint {
+                path: path.clone(),
+                info: *info,
+                kind: FailedConstraintKind::In {
+                    expected: expected.clone(),
+                    found: found.clone(),
+                    relation: *relation,
+                },
+            }),
+
+            Self::Contradiction { relation } => failures.push(FailedConstraint {
+                path: path.clone(),
+                info: None,
+                kind: FailedConstraintKind::Contradiction {
+                    relation: *relation,
+                },
+            }),
+        }
+    }
+
+    fn collect_from_weak(
+        trace: &Weak<Self>,
+        path: &mut Vec<ConstraintPathSegment<V>>,
+        failures: &mut Vec<FailedConstraint<V>>,
+    ) {
+        if let Some(trace) = trace.upgrade() {
+            trace.collect_failed_constraints(path, failures);
+        }
+    }
+}
@@ -76,6 +76,8 @@ pub struct Profile {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -129,6 +131,8 @@ pub struct ProfileSetter {
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -188,6 +192,8 @@ impl From<ProfileSetter> for Profile {
             is_iframe_redirection_enabled: value.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: value.is_pre_network_tokenization_enabled,
             three_ds_decision_rule_algorithm: None, // three_ds_decision_rule_algorithm is not yet created during profile creation
+            dispute_policy: value.dispute_policy,
+            integrity_check_policy: value.integrity_check_policy,
         }
     }
 }
@@ -248,6 +254,8 @@ pub struct ProfileGeneralUpdate {
     pub merchant_business_country: Option<api_enums::CountryAlpha2>,
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
+    pub dispute_policy: Option<common_types::disputes::DisputePolicy>,
+    pub integrity_check_policy: Option<common_types::payments::IntegrityCheckPolicy>,
 }
 
 #[cfg(feature = "v1")]
@@ -324,6 +332,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     merchant_business_country,
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
+                    dispute_policy,
+                    integrity_check_policy,
                 } = *update;
 
                 Self {
@@ -373,6 +383,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
                     three_ds_decision_rule_algorithm: None,
+                    dispute_policy,
+                    integrity_check_policy,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -425,6 +437,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm,
+                dispute_policy: None,
+                integrity_check_policy: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -474,6 +488,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                dispute_policy: None,
+                integrity_check_policy: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -523,6 +539,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                dispute_policy: None,
+                integrity_check_policy: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -572,6 +590,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                dispute_policy: None,
+                integrity_check_policy: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -621,6 +641,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                dispute_policy: None,
+                integrity_check_policy: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -670,6 +692,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 is_pre_network_tokenization_enabled: None,
                 three_ds_decision_rule_algorithm: None,
+                dispute_policy: None,
+                integrity_check_policy: None,
             },
         }
     }
@@ -739,6 +763,8 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
             three_ds_decision_rule_algorithm: self.three_ds_decision_rule_algorithm,
+            dispute_policy: self.dispute_policy,
+            integrity_check_policy: self.integrity_check_policy,
         })
     }
 
@@ -834,6 +860,8 @@ impl super::behaviour::Conversion for Profile {
                     .is_pre_network_tokenization_enabled
                     .unwrap_or(false),
                 three_ds_decision_rule_algorithm: item.three_ds_decision_rule_algorithm,
+                dispute_policy: item.dispute_policy,
+                integrity_check_policy: item.integrity_check_policy,
             })
         }
         .await
@@ -897,6 +925,8 @@ impl super::behaviour::Conversion for Profile {
             merchant_business_country: self.merchant_business_country,
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
+            dispute_policy: self.dispute_policy,
+            integrity_check_policy: self.integrity_check_policy,
         })
     }
 }
@@ -1,5 +1,5 @@
 use common_utils::errors;
-use diesel_models::cards_info;
+use diesel_models::{card_bin, cards_info};
 
 #[async_trait::async_trait]
 pub trait CardsInfoInterface {
@@ -8,6 +8,11 @@ pub trait CardsInfoInterface {
         &self,
         _card_iin: &str,
     ) -> errors::CustomResult<Option<cards_info::CardInfo>, Self::Error>;
+    /// Falls back to the ranges of the active BIN import when there is no exact match on the IIN
+    async fn get_card_info_with_bin_range_fallback(
+        &self,
+        card_iin: &str,
+    ) -> errors::CustomResult<Option<cards_info::CardInfo>, Self::Error>;
     async fn add_card_info(
         &self,
         data: cards_info::CardInfo,
@@ -17,4 +22,27 @@ pub trait CardsInfoInterface {
         card_iin: String,
         data: cards_info::UpdateCardInfo,
     ) -> errors::CustomResult<cards_info::CardInfo, Self::Error>;
+    async fn insert_card_bin_import(
+        &self,
+        card_bin_import: card_bin::CardBinImportNew,
+        ranges: Vec<card_bin::CardBinRange>,
+    ) -> errors::CustomResult<card_bin::CardBinImport, Self::Error>;
+    async fn find_card_bin_import_by_version(
+        &self,
+        version: i32,
+    ) -> errors::CustomResult<card_bin::CardBinImport, Self::Error>;
+    async fn find_active_card_bin_import(
+        &self,
+    ) -> errors::CustomResult<Option<card_bin::CardBinImport>, Self::Error>;
+    async fn list_card_bin_imports(
+        &self,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> errors::CustomResult<Vec<card_bin::CardBinImport>, Self::Error>;
+    async fn activate_card_bin_import(&self, version: i32)
+        -> errors::CustomResult<(), Self::Error>;
+    async fn list_card_bin_ranges_by_import_version(
+        &self,
+        version: i32,
+    ) -> errors::CustomResult<Vec<card_bin::CardBinRange>, Self::Error>;
 }
@@ -285,6 +285,12 @@ pub enum ApiErrorResponse {
     InvalidPlatformOperation,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "External vault failed during processing with connector")]
     ExternalVaultFailed,
+    #[error(error_type = ErrorType::DuplicateRequest, code = "IR_46", message = "A request with the same idempotency key is currently being processed")]
+    IdempotencyKeyInUse,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_47", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyMismatch,
+    #[error(error_type = ErrorType::ProcessingError, code = "IR_48", message = "The retry limit of the card network {card_network} has been reached for this card")]
+    NetworkRetryLimitExceeded { card_network: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -643,6 +649,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExternalVaultFailed => {
                 AER::BadRequest(ApiError::new("IR", 45, "External Vault failed while processing with connector.", None))
             },
+            Self::IdempotencyKeyInUse => {
+                AER::Conflict(ApiError::new("IR", 46, "A request with the same idempotency key is currently being processed", None))
+            },
+            Self::IdempotencyKeyMismatch => {
+                AER::Unprocessable(ApiError::new("IR", 47, "Keys for idempotent requests can only be used with the same parameters they were first used with", None))
+            },
+            Self::NetworkRetryLimitExceeded { card_network } => {
+                AER::BadRequest(ApiError::new("IR", 48, format!("The retry limit of the card network {card_network} has been reached for this card"), None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
@@ -892,6 +892,8 @@ pub struct PaymentAttempt {
     /// merchantwho invoked the resource based api (identifier) and through what source (Api, Jwt(Dashboard))
     pub created_by: Option<CreatedBy>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    /// fee the connector is estimated to charge for the payment, from the fee schedule of its merchant connector account
+    pub estimated_connector_cost: Option<MinorUnit>,
 }
 
 #[cfg(feature = "v1")]
@@ -1146,6 +1148,8 @@ pub struct PaymentAttemptNew {
     /// merchantwho invoked the resource based api (identifier) and through what source (Api, Jwt(Dashboard))
     pub created_by: Option<CreatedBy>,
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
+    /// fee the connector is estimated to charge for the payment, from the fee schedule of its merchant connector account
+    pub estimated_connector_cost: Option<MinorUnit>,
 }
 
 #[cfg(feature = "v1")]
@@ -1213,6 +1217,7 @@ pub enum PaymentAttemptUpdate {
         customer_acceptance: Option<pii::SecretSerdeValue>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
         card_discovery: Option<common_enums::CardDiscovery>,
+        estimated_connector_cost: Option<MinorUnit>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1348,6 +1353,10 @@ pub enum PaymentAttemptUpdate {
         updated_by: String,
         connector_metadata: Option<serde_json::Value>,
     },
+    CustomerDetailsRedactionUpdate {
+        browser_info: Option<serde_json::Value>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -1471,6 +1480,7 @@ impl PaymentAttemptUpdate {
                 customer_acceptance,
                 connector_mandate_detail,
                 card_discovery,
+                estimated_connector_cost,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1506,6 +1516,7 @@ impl PaymentAttemptUpdate {
                 order_tax_amount: net_amount.get_order_tax_amount(),
                 connector_mandate_detail,
                 card_discovery,
+                estimated_connector_cost,
             },
             Self::VoidUpdate {
                 status,
@@ -1729,6 +1740,13 @@ impl PaymentAttemptUpdate {
                 updated_by,
                 connector_metadata,
             },
+            Self::CustomerDetailsRedactionUpdate {
+                browser_info,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::CustomerDetailsRedactionUpdate {
+                browser_info,
+                updated_by,
+            },
         }
     }
 }
@@ -1902,6 +1920,7 @@ impl behaviour::Conversion for PaymentAttempt {
             issuer_error_code: self.issuer_error_code,
             issuer_error_message: self.issuer_error_message,
             setup_future_usage_applied: self.setup_future_usage_applied,
+            estimated_connector_cost: self.estimated_connector_cost,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_transaction_data: None,
             processor_merchant_id: Some(self.processor_merchant_id),
@@ -2001,6 +2020,7 @@ impl behaviour::Conversion for PaymentAttempt {
                     .created_by
                     .and_then(|created_by| created_by.parse::<CreatedBy>().ok()),
                 setup_future_usage_applied: storage_model.setup_future_usage_applied,
+                estimated_connector_cost: storage_model.estimated_connector_cost,
             })
         }
         .await
@@ -2089,6 +2109,7 @@ impl behaviour::Conversion for PaymentAttempt {
             processor_merchant_id: Some(self.processor_merchant_id),
             created_by: self.created_by.map(|cb| cb.to_string()),
             setup_future_usage_applied: self.setup_future_usage_applied,
+            estimated_connector_cost: self.estimated_connector_cost,
         })
     }
 }
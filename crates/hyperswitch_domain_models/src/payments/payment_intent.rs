@@ -325,6 +325,13 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     },
+    CustomerDetailsRedactionUpdate {
+        description: Option<String>,
+        customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        billing_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -963,6 +970,21 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 ..Default::default()
             },
+            PaymentIntentUpdate::CustomerDetailsRedactionUpdate {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -1155,6 +1177,19 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 updated_by,
                 shipping_details: shipping_details.map(Encryption::from),
             },
+            PaymentIntentUpdate::CustomerDetailsRedactionUpdate {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self::CustomerDetailsRedactionUpdate {
+                description,
+                customer_details: customer_details.map(Encryption::from),
+                billing_details: billing_details.map(Encryption::from),
+                shipping_details: shipping_details.map(Encryption::from),
+                updated_by,
+            },
         }
     }
 }
@@ -2012,6 +2047,7 @@ impl behaviour::Conversion for PaymentIntent {
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             extended_return_url: self.return_url,
+            enable_partial_authorization: self.enable_partial_authorization,
         })
     }
 
@@ -2112,6 +2148,7 @@ impl behaviour::Conversion for PaymentIntent {
                 force_3ds_challenge: storage_model.force_3ds_challenge,
                 force_3ds_challenge_trigger: storage_model.force_3ds_challenge_trigger,
                 is_iframe_redirection_enabled: storage_model.is_iframe_redirection_enabled,
+                enable_partial_authorization: storage_model.enable_partial_authorization,
             })
         }
         .await
@@ -2184,6 +2221,7 @@ impl behaviour::Conversion for PaymentIntent {
             force_3ds_challenge_trigger: self.force_3ds_challenge_trigger,
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             extended_return_url: self.return_url,
+            enable_partial_authorization: self.enable_partial_authorization,
         })
     }
 }
@@ -366,6 +366,8 @@ pub struct PaymentMethodBalance {
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
     extended_authorization_response_data: Option<ExtendedAuthorizationResponseData>,
+    /// Amount approved by the connector, when only a part of the requested amount is approved
+    authorized_amount: Option<MinorUnit>,
 }
 
 impl ConnectorResponseData {
@@ -375,6 +377,20 @@ impl ConnectorResponseData {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
             extended_authorization_response_data: None,
+            authorized_amount: None,
+        }
+    }
+    pub fn with_authorized_amount(authorized_amount: MinorUnit) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            extended_authorization_response_data: None,
+            authorized_amount: Some(authorized_amount),
+        }
+    }
+    pub fn set_authorized_amount(self, authorized_amount: MinorUnit) -> Self {
+        Self {
+            authorized_amount: Some(authorized_amount),
+            ..self
         }
     }
     pub fn get_extended_authorization_response_data(
@@ -382,6 +398,9 @@ impl ConnectorResponseData {
     ) -> Option<&ExtendedAuthorizationResponseData> {
         self.extended_authorization_response_data.as_ref()
     }
+    pub fn get_authorized_amount(&self) -> Option<MinorUnit> {
+        self.authorized_amount
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -713,7 +732,8 @@ impl
             }
             // Invalid statues for this flow, after doing authorization this state is invalid
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 
@@ -745,7 +765,8 @@ impl
             common_enums::IntentStatus::RequiresCapture => Some(MinorUnit::zero()),
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 }
@@ -926,7 +947,8 @@ impl
             }
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 
@@ -966,7 +988,8 @@ impl
             | common_enums::IntentStatus::RequiresConfirmation => None,
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => {
                 todo!()
             }
         }
@@ -1167,7 +1190,8 @@ impl
             }
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 
@@ -1206,7 +1230,8 @@ impl
             }
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 }
@@ -1402,7 +1427,8 @@ impl
             }
             // Invalid statues for this flow, after doing authorization this state is invalid
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 
@@ -1434,7 +1460,8 @@ impl
             common_enums::IntentStatus::RequiresCapture => Some(MinorUnit::zero()),
             // Invalid statues for this flow
             common_enums::IntentStatus::PartiallyCaptured
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 }
@@ -63,6 +63,8 @@ pub struct PaymentsAuthorizeData {
     pub authentication_data: Option<AuthenticationData>,
     pub request_extended_authorization:
         Option<common_types::primitive_wrappers::RequestExtendedAuthorizationBool>,
+    /// Whether the connector may approve only a part of the amount
+    pub enable_partial_authorization: Option<bool>,
     pub split_payments: Option<common_types::payments::SplitPaymentsRequest>,
 
     // New amount for amount frame work
@@ -153,6 +155,15 @@ pub struct PaymentsIncrementalAuthorizationData {
     pub currency: storage_enums::Currency,
     pub reason: Option<String>,
     pub connector_transaction_id: String,
+    pub integrity_object: Option<IncrementalAuthorizationIntegrityObject>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementalAuthorizationIntegrityObject {
+    /// amount by which the authorization is incremented
+    pub additional_amount: MinorUnit,
+    /// authorization currency
+    pub currency: storage_enums::Currency,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -703,6 +714,16 @@ pub struct RefundIntegrityObject {
     pub refund_amount: MinorUnit,
 }
 
+/// Amount and currency of a dispute raised by the connector, which are checked against the
+/// payment being disputed
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisputeIntegrityObject {
+    /// dispute amount
+    pub dispute_amount: MinorUnit,
+    /// dispute currency
+    pub currency: storage_enums::Currency,
+}
+
 #[derive(Debug, serde::Deserialize, Clone)]
 pub enum SplitRefundsRequest {
     StripeSplitRefund(StripeSplitRefund),
@@ -885,6 +906,16 @@ pub struct PayoutsData {
     pub minor_amount: MinorUnit,
     pub priority: Option<storage_enums::PayoutSendPriority>,
     pub connector_transfer_method_id: Option<String>,
+    pub integrity_object: Option<PayoutIntegrityObject>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayoutIntegrityObject {
+    /// payout amount
+    pub amount: MinorUnit,
+    /// payout currency
+    pub destination_currency: storage_enums::Currency,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -1,8 +1,12 @@
-use common_utils::errors::IntegrityCheckError;
+use common_utils::{errors::IntegrityCheckError, types::MinorUnit};
 use hyperswitch_domain_models::router_request_types::{
-    AuthoriseIntegrityObject, CaptureIntegrityObject, PaymentsAuthorizeData, PaymentsCaptureData,
-    PaymentsSyncData, RefundIntegrityObject, RefundsData, SyncIntegrityObject,
+    AuthoriseIntegrityObject, CaptureIntegrityObject, DisputeIntegrityObject,
+    IncrementalAuthorizationIntegrityObject, PaymentsAuthorizeData, PaymentsCaptureData,
+    PaymentsIncrementalAuthorizationData, PaymentsSyncData, RefundIntegrityObject, RefundsData,
+    SyncIntegrityObject,
 };
+#[cfg(feature = "payouts")]
+use hyperswitch_domain_models::router_request_types::{PayoutIntegrityObject, PayoutsData};
 
 /// Connector Integrity trait to check connector data integrity
 pub trait FlowIntegrity {
@@ -130,6 +134,55 @@ where
     }
 }
 
+impl<T, Request> CheckIntegrity<Request, T> for PaymentsIncrementalAuthorizationData
+where
+    T: FlowIntegrity,
+    Request: GetIntegrityObject<T>,
+{
+    fn check_integrity(
+        &self,
+        request: &Request,
+        connector_transaction_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        match request.get_response_integrity_object() {
+            Some(res_integrity_object) => {
+                let req_integrity_object = request.get_request_integrity_object();
+                T::compare(
+                    req_integrity_object,
+                    res_integrity_object,
+                    connector_transaction_id,
+                )
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<T, Request> CheckIntegrity<Request, T> for PayoutsData
+where
+    T: FlowIntegrity,
+    Request: GetIntegrityObject<T>,
+{
+    fn check_integrity(
+        &self,
+        request: &Request,
+        connector_payout_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        match request.get_response_integrity_object() {
+            Some(res_integrity_object) => {
+                let req_integrity_object = request.get_request_integrity_object();
+                T::compare(
+                    req_integrity_object,
+                    res_integrity_object,
+                    connector_payout_id,
+                )
+            }
+            None => Ok(()),
+        }
+    }
+}
+
 impl FlowIntegrity for RefundIntegrityObject {
     type IntegrityObject = Self;
     fn compare(
@@ -297,6 +350,123 @@ impl FlowIntegrity for CaptureIntegrityObject {
     }
 }
 
+impl FlowIntegrity for IncrementalAuthorizationIntegrityObject {
+    type IntegrityObject = Self;
+    fn compare(
+        req_integrity_object: Self,
+        res_integrity_object: Self,
+        connector_transaction_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        let mut mismatched_fields = Vec::new();
+
+        if req_integrity_object.additional_amount != res_integrity_object.additional_amount {
+            mismatched_fields.push(format_mismatch(
+                "additional_amount",
+                &req_integrity_object.additional_amount.to_string(),
+                &res_integrity_object.additional_amount.to_string(),
+            ));
+        }
+
+        if req_integrity_object.currency != res_integrity_object.currency {
+            mismatched_fields.push(format_mismatch(
+                "currency",
+                &req_integrity_object.currency.to_string(),
+                &res_integrity_object.currency.to_string(),
+            ));
+        }
+
+        if mismatched_fields.is_empty() {
+            Ok(())
+        } else {
+            let field_names = mismatched_fields.join(", ");
+
+            Err(IntegrityCheckError {
+                field_names,
+                connector_transaction_id,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl FlowIntegrity for PayoutIntegrityObject {
+    type IntegrityObject = Self;
+    fn compare(
+        req_integrity_object: Self,
+        res_integrity_object: Self,
+        connector_transaction_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        let mut mismatched_fields = Vec::new();
+
+        if req_integrity_object.amount != res_integrity_object.amount {
+            mismatched_fields.push(format_mismatch(
+                "amount",
+                &req_integrity_object.amount.to_string(),
+                &res_integrity_object.amount.to_string(),
+            ));
+        }
+
+        if req_integrity_object.destination_currency != res_integrity_object.destination_currency {
+            mismatched_fields.push(format_mismatch(
+                "destination_currency",
+                &req_integrity_object.destination_currency.to_string(),
+                &res_integrity_object.destination_currency.to_string(),
+            ));
+        }
+
+        if mismatched_fields.is_empty() {
+            Ok(())
+        } else {
+            let field_names = mismatched_fields.join(", ");
+
+            Err(IntegrityCheckError {
+                field_names,
+                connector_transaction_id,
+            })
+        }
+    }
+}
+
+/// Here the request integrity object holds the amount and currency of the disputed payment, a
+/// dispute may be raised for part of the payment but never for more than it
+impl FlowIntegrity for DisputeIntegrityObject {
+    type IntegrityObject = Self;
+    fn compare(
+        req_integrity_object: Self,
+        res_integrity_object: Self,
+        connector_transaction_id: Option<String>,
+    ) -> Result<(), IntegrityCheckError> {
+        let mut mismatched_fields = Vec::new();
+
+        if res_integrity_object.dispute_amount > req_integrity_object.dispute_amount {
+            mismatched_fields.push(format_mismatch(
+                "dispute_amount",
+                &format!("at most {}", req_integrity_object.dispute_amount),
+                &res_integrity_object.dispute_amount.to_string(),
+            ));
+        }
+
+        if req_integrity_object.currency != res_integrity_object.currency {
+            mismatched_fields.push(format_mismatch(
+                "currency",
+                &req_integrity_object.currency.to_string(),
+                &res_integrity_object.currency.to_string(),
+            ));
+        }
+
+        if mismatched_fields.is_empty() {
+            Ok(())
+        } else {
+            let field_names = mismatched_fields.join(", ");
+
+            Err(IntegrityCheckError {
+                field_names,
+                connector_transaction_id,
+            })
+        }
+    }
+}
+
 impl GetIntegrityObject<CaptureIntegrityObject> for PaymentsCaptureData {
     fn get_response_integrity_object(&self) -> Option<CaptureIntegrityObject> {
         self.integrity_object.clone()
@@ -349,7 +519,155 @@ impl GetIntegrityObject<SyncIntegrityObject> for PaymentsSyncData {
     }
 }
 
+impl GetIntegrityObject<IncrementalAuthorizationIntegrityObject>
+    for PaymentsIncrementalAuthorizationData
+{
+    fn get_response_integrity_object(&self) -> Option<IncrementalAuthorizationIntegrityObject> {
+        self.integrity_object.clone()
+    }
+
+    fn get_request_integrity_object(&self) -> IncrementalAuthorizationIntegrityObject {
+        IncrementalAuthorizationIntegrityObject {
+            additional_amount: MinorUnit::new(self.additional_amount),
+            currency: self.currency,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl GetIntegrityObject<PayoutIntegrityObject> for PayoutsData {
+    fn get_response_integrity_object(&self) -> Option<PayoutIntegrityObject> {
+        self.integrity_object.clone()
+    }
+
+    fn get_request_integrity_object(&self) -> PayoutIntegrityObject {
+        PayoutIntegrityObject {
+            amount: self.minor_amount,
+            destination_currency: self.destination_currency,
+        }
+    }
+}
+
 #[inline]
 fn format_mismatch(field: &str, expected: &str, found: &str) -> String {
     format!("{} expected {} but found {}", field, expected, found)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_enums::Currency;
+
+    use super::*;
+
+    #[test]
+    fn test_incremental_authorization_integrity() {
+        let request = IncrementalAuthorizationIntegrityObject {
+            additional_amount: MinorUnit::new(500),
+            currency: Currency::USD,
+        };
+
+        assert!(IncrementalAuthorizationIntegrityObject::compare(
+            request.clone(),
+            request.clone(),
+            None
+        )
+        .is_ok());
+
+        let error = IncrementalAuthorizationIntegrityObject::compare(
+            request,
+            IncrementalAuthorizationIntegrityObject {
+                additional_amount: MinorUnit::new(600),
+                currency: Currency::EUR,
+            },
+            Some("auth_1".to_string()),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.field_names,
+            "additional_amount expected 500 but found 600, currency expected USD but found EUR"
+        );
+        assert_eq!(error.connector_transaction_id, Some("auth_1".to_string()));
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_integrity() {
+        let request = PayoutIntegrityObject {
+            amount: MinorUnit::new(1000),
+            destination_currency: Currency::GBP,
+        };
+
+        assert!(PayoutIntegrityObject::compare(request.clone(), request.clone(), None).is_ok());
+
+        let error = PayoutIntegrityObject::compare(
+            request.clone(),
+            PayoutIntegrityObject {
+                amount: MinorUnit::new(999),
+                destination_currency: Currency::GBP,
+            },
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(error.field_names, "amount expected 1000 but found 999");
+
+        let error = PayoutIntegrityObject::compare(
+            request,
+            PayoutIntegrityObject {
+                amount: MinorUnit::new(1000),
+                destination_currency: Currency::USD,
+            },
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.field_names,
+            "destination_currency expected GBP but found USD"
+        );
+    }
+
+    #[test]
+    fn test_dispute_integrity() {
+        let payment = DisputeIntegrityObject {
+            dispute_amount: MinorUnit::new(1000),
+            currency: Currency::USD,
+        };
+
+        // A dispute may be raised for the whole payment or for a part of it
+        assert!(DisputeIntegrityObject::compare(payment.clone(), payment.clone(), None).is_ok());
+        assert!(DisputeIntegrityObject::compare(
+            payment.clone(),
+            DisputeIntegrityObject {
+                dispute_amount: MinorUnit::new(400),
+                currency: Currency::USD,
+            },
+            None
+        )
+        .is_ok());
+
+        let error = DisputeIntegrityObject::compare(
+            payment.clone(),
+            DisputeIntegrityObject {
+                dispute_amount: MinorUnit::new(1001),
+                currency: Currency::USD,
+            },
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.field_names,
+            "dispute_amount expected at most 1000 but found 1001"
+        );
+
+        let error = DisputeIntegrityObject::compare(
+            payment,
+            DisputeIntegrityObject {
+                dispute_amount: MinorUnit::new(1000),
+                currency: Currency::INR,
+            },
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(error.field_names, "currency expected USD but found INR");
+    }
+}
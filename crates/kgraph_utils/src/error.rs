@@ -13,6 +13,8 @@ pub enum KgraphError {
     DomainCreationError,
     #[error("There was an error constructing the graph: {0}")]
     GraphConstructionError(hyperswitch_constraint_graph::GraphError<dir::DirValue>),
+    #[error("There was an error analysing the graph: {0}")]
+    GraphAnalysisError(hyperswitch_constraint_graph::GraphError<dir::DirValue>),
     #[error("There was an error constructing the context")]
     ContextConstructionError(Box<AnalysisErrorType>),
     #[error("there was an unprecedented indexing error")]
@@ -0,0 +1,146 @@
+use euclid::{dssa::graph::AnalysisContext, frontend::dir};
+use hyperswitch_constraint_graph::{self as cgraph, NodeViz};
+
+use crate::error::KgraphError;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct IneligibilityReason {
+    /// The nodes of the graph through which the failing constraint was reached, starting from
+    /// the checked value
+    pub path: Vec<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EligibilityExplanation {
+    pub eligible: bool,
+    pub reasons: Vec<IneligibilityReason>,
+}
+
+fn describe_node_value(value: &cgraph::NodeValue<dir::DirValue>) -> String {
+    match value {
+        cgraph::NodeValue::Key(key) => key.viz(),
+        cgraph::NodeValue::Value(value) => format!("{} {}", value.get_key().viz(), value.viz()),
+    }
+}
+
+fn describe_path_segment(segment: &cgraph::ConstraintPathSegment<dir::DirValue>) -> String {
+    match (segment.info, segment.value.as_ref()) {
+        (Some(info), Some(value)) => format!("{info}: {}", describe_node_value(value)),
+        (None, Some(value)) => describe_node_value(value),
+        (Some(info), None) => info.to_string(),
+        (None, None) => "aggregation".to_string(),
+    }
+}
+
+fn describe_failed_constraint(constraint: &cgraph::FailedConstraint<dir::DirValue>) -> String {
+    let message = match &constraint.kind {
+        cgraph::FailedConstraintKind::Value {
+            value,
+            relation: cgraph::Relation::Positive,
+        } => format!("{} is required", describe_node_value(value)),
+
+        cgraph::FailedConstraintKind::Value {
+            value,
+            relation: cgraph::Relation::Negative,
+        } => format!("{} is not allowed", describe_node_value(value)),
+
+        cgraph::FailedConstraintKind::In {
+            expected,
+            found,
+            relation,
+        } => {
+            let key = expected
+                .first()
+                .map(|value| value.get_key().viz())
+                .unwrap_or_default();
+            let mut expected = expected.iter().map(NodeViz::viz).collect::<Vec<_>>();
+            expected.sort();
+            let expected = expected.join(", ");
+
+            match (found, relation) {
+                (Some(found), cgraph::Relation::Positive) => {
+                    format!("{key} {} not in [{expected}]", found.viz())
+                }
+                (Some(found), cgraph::Relation::Negative) => {
+                    format!(
+                        "{key} {} is one of the disallowed [{expected}]",
+                        found.viz()
+                    )
+                }
+                (None, _) => format!("{key} is missing, expected one of [{expected}]"),
+            }
+        }
+
+        cgraph::FailedConstraintKind::Contradiction { .. } => {
+            "contradicting constraints were found".to_string()
+        }
+    };
+
+    match constraint.info {
+        Some(info) => format!("{message} ({info})"),
+        None => message,
+    }
+}
+
+/// Checks whether `value` is eligible in the given context the same way
+/// [`euclid::dssa::graph::CgraphExt::check_value_validity`] does and, if it is not, explains
+/// which constraints of the graph ruled it out
+pub fn explain_value_eligibility(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    value: dir::DirValue,
+    ctx: &AnalysisContext,
+    domains: Option<&[String]>,
+) -> Result<EligibilityExplanation, KgraphError> {
+    let node_value = cgraph::NodeValue::Value(value);
+    let Some(node_id) = graph.value_map.get(&node_value) else {
+        return Ok(EligibilityExplanation {
+            eligible: false,
+            reasons: vec![IneligibilityReason {
+                path: Vec::new(),
+                message: format!("{} is not configured", describe_node_value(&node_value)),
+            }],
+        });
+    };
+
+    // The analysis trace holds weak references into the memoization cache, which therefore has
+    // to outlive the explanation below
+    let mut memo = cgraph::Memoization::new();
+    let result = graph.check_node(
+        ctx,
+        *node_id,
+        cgraph::Relation::Positive,
+        cgraph::Strength::Weak,
+        &mut memo,
+        &mut cgraph::CycleCheck::new(),
+        domains,
+    );
+
+    let trace = match result {
+        Ok(()) => {
+            return Ok(EligibilityExplanation {
+                eligible: true,
+                reasons: Vec::new(),
+            })
+        }
+        Err(error) => error
+            .get_analysis_trace()
+            .map_err(KgraphError::GraphAnalysisError)?,
+    };
+
+    let reasons = trace
+        .upgrade()
+        .map(|trace| trace.failed_constraints())
+        .unwrap_or_default()
+        .iter()
+        .map(|constraint| IneligibilityReason {
+            path: constraint.path.iter().map(describe_path_segment).collect(),
+            message: describe_failed_constraint(constraint),
+        })
+        .collect();
+
+    Ok(EligibilityExplanation {
+        eligible: false,
+        reasons,
+    })
+}
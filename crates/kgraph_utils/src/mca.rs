@@ -1175,6 +1175,7 @@ mod tests {
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
             connector_wallets_details: None,
+            fee_schedule: None,
         };
 
         let config_map = kgraph_types::CountryCurrencyFilter {
@@ -1307,6 +1308,29 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_single_mismatch_failure_explanation() {
+        let graph = build_test_data();
+
+        let explanation = crate::explanation::explain_value_eligibility(
+            &graph,
+            dirval!(Connector = Stripe),
+            &AnalysisContext::from_dir_values([
+                dirval!(Connector = Stripe),
+                dirval!(PaymentMethod = Card),
+                dirval!(CardType = Debit),
+                dirval!(CardNetwork = Maestro),
+                dirval!(PaymentCurrency = PHP),
+                dirval!(PaymentAmount = 100),
+            ]),
+            None,
+        )
+        .expect("explanation");
+
+        assert!(!explanation.eligible);
+        assert!(!explanation.reasons.is_empty());
+    }
+
     #[test]
     fn test_incomplete_data_failure_case() {
         let graph = build_test_data();
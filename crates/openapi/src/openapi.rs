@@ -84,6 +84,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
         routes::payments::payments_update_metadata,
+        routes::payments::payments_routing_trace,
 
         // Routes for relay
         routes::relay::relay,
@@ -113,6 +114,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::connector_list,
         routes::merchant_connector_account::connector_update,
         routes::merchant_connector_account::connector_delete,
+        routes::merchant_connector_account::connector_circuit_breaker_list,
+        routes::merchant_connector_account::connector_circuit_breaker_reset,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -223,6 +226,8 @@ Never share your secret api keys. Keep them guarded and secure.
         common_utils::payout_method_utils::PaypalAdditionalData,
         common_utils::payout_method_utils::VenmoAdditionalData,
         common_types::payments::SplitPaymentsRequest,
+        common_types::disputes::DisputePolicy,
+        common_types::payments::IntegrityCheckPolicy,
         common_types::payments::StripeSplitPaymentRequest,
         common_types::domain::AdyenSplitData,
         common_types::domain::AdyenSplitItem,
@@ -239,8 +244,13 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::StripeChargeResponseData,
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
+        common_types::connector_fees::ConnectorFeeSchedule,
+        common_types::connector_fees::ConnectorFee,
+        common_types::connector_fees::FeeComponent,
+        common_types::connector_fees::FeeTier,
         api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
         api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteResponse,
+        api_models::three_ds_decision_rule::ThreeDsDecisionRuleRolloutVariant,
         api_models::three_ds_decision_rule::PaymentData,
         api_models::three_ds_decision_rule::PaymentMethodMetaData,
         api_models::three_ds_decision_rule::CustomerDeviceData,
@@ -260,6 +270,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
+        api_models::admin::ConnectorCircuitState,
+        api_models::admin::ConnectorCircuitBreakerResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
@@ -675,6 +687,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::ProfileDefaultRoutingConfig,
         api_models::routing::MerchantRoutingAlgorithm,
         api_models::routing::RoutingAlgorithmKind,
+        api_models::routing::RoutingTrace,
+        api_models::routing::RoutingTraceStage,
+        api_models::routing::DynamicRoutingTraceStep,
+        api_models::routing::RoutingTraceConnectorScore,
+        api_models::routing::RoutingTraceFallback,
+        api_models::routing::RoutingApproach,
         api_models::routing::RoutingDictionary,
         api_models::routing::RoutingAlgorithmWrapper,
         api_models::routing::EliminationRoutingConfig,
@@ -760,6 +778,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsPostSessionTokensResponse,
         api_models::payments::PaymentsUpdateMetadataRequest,
         api_models::payments::PaymentsUpdateMetadataResponse,
+        api_models::payments::PaymentsRoutingTraceResponse,
+        api_models::payments::PaymentAttemptRoutingTrace,
         api_models::payments::CtpServiceDetails,
         api_models::payments::AdyenConnectorMetadata,
         api_models::payments::AdyenTestingData,
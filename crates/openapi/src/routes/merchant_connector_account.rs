@@ -327,3 +327,45 @@ pub async fn connector_delete() {}
     security(("admin_api_key" = []))
 )]
 pub async fn connector_delete() {}
+
+/// Merchant Connector - List Circuit Breakers
+///
+/// List the circuit breaker state of each Merchant Connector of the merchant. Connectors whose circuit is open are skipped during connector selection.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/circuit_breakers",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Circuit breakers retrieved successfully", body = Vec<ConnectorCircuitBreakerResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "List the circuit breakers of the Merchant Connectors",
+    security(("api_key" = []))
+)]
+pub async fn connector_circuit_breaker_list() {}
+
+/// Merchant Connector - Reset Circuit Breaker
+///
+/// Close the circuit of a Merchant Connector, so that it is considered during connector selection again
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/circuit_breaker/reset",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Circuit breaker reset", body = ConnectorCircuitBreakerResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Reset the circuit breaker of a Merchant Connector",
+    security(("api_key" = []))
+)]
+pub async fn connector_circuit_breaker_reset() {}
@@ -623,6 +623,26 @@ pub fn payments_post_session_tokens() {}
 )]
 pub fn payments_update_metadata() {}
 
+/// Payments - Routing Trace
+///
+/// To retrieve how the connectors of each of the attempts of a payment were chosen
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/routing_trace",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Retrieved the routing traces of the payment", body = PaymentsRoutingTraceResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Routing Trace of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_routing_trace() {}
+
 /// Payments - Create Intent
 ///
 /// **Creates a payment intent object when amount_details are passed.**
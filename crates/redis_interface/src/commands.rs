@@ -195,6 +195,28 @@ impl super::RedisConnectionPool {
         }
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_multiple_keys<V>(
+        &self,
+        keys: &[RedisKey],
+    ) -> CustomResult<Vec<Option<V>>, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.pool
+            .mget(
+                keys.iter()
+                    .map(|key| key.tenant_aware_key(self))
+                    .collect::<Vec<_>>(),
+            )
+            .await
+            .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn exists<V>(&self, key: &RedisKey) -> CustomResult<bool, errors::RedisError>
     where
@@ -282,6 +304,28 @@ impl super::RedisConnectionPool {
         Ok(del_result)
     }
 
+    /// Deletes the keys matching the pattern, returning the number of keys deleted
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_keys_matching_pattern(
+        &self,
+        pattern: &RedisKey,
+    ) -> CustomResult<usize, errors::RedisError> {
+        // The keys returned by the scan already carry the tenant prefix
+        let keys = self.scan(pattern, None, None).await?;
+
+        let del_result = futures::future::try_join_all(
+            keys.into_iter()
+                .map(|key| async move { self.pool.del::<DelReply, _>(key).await }),
+        )
+        .await
+        .change_context(errors::RedisError::DeleteFailed)?;
+
+        Ok(del_result
+            .into_iter()
+            .filter(|reply| matches!(reply, DelReply::KeyDeleted))
+            .count())
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key_with_expiry<V>(
         &self,
@@ -466,6 +510,40 @@ impl super::RedisConnectionPool {
         Ok(values_after_increment)
     }
 
+    /// Increments the fields of a hash and sets its expiry, sending the commands in a single
+    /// pipeline
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_fields_in_hash_with_expiry<T>(
+        &self,
+        key: &RedisKey,
+        fields_to_increment: &[(T, i64)],
+        seconds: i64,
+    ) -> CustomResult<Vec<usize>, errors::RedisError>
+    where
+        T: Debug + ToString,
+    {
+        let pipeline = self.pool.next().pipeline();
+        for (field, increment) in fields_to_increment.iter() {
+            pipeline
+                .hincrby::<(), _, _>(key.tenant_aware_key(self), field.to_string(), *increment)
+                .await
+                .change_context(errors::RedisError::IncrementHashFieldFailed)?;
+        }
+        pipeline
+            .expire::<(), _>(key.tenant_aware_key(self), seconds)
+            .await
+            .change_context(errors::RedisError::SetExpiryFailed)?;
+
+        // The reply of the expiry follows the values of the incremented fields
+        let mut values_after_increment = pipeline
+            .all::<Vec<usize>>()
+            .await
+            .change_context(errors::RedisError::IncrementHashFieldFailed)?;
+        values_after_increment.truncate(fields_to_increment.len());
+
+        Ok(values_after_increment)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan(
         &self,
@@ -610,6 +688,34 @@ impl super::RedisConnectionPool {
         }
     }
 
+    /// Fetches all the fields of several hashes, sending the commands in a single pipeline. The
+    /// fields are returned in the order of the keys.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_fields_of_multiple_keys<V>(
+        &self,
+        keys: &[RedisKey],
+    ) -> CustomResult<Vec<V>, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let pipeline = self.pool.next().pipeline();
+        for key in keys.iter() {
+            pipeline
+                .hgetall::<(), _>(key.tenant_aware_key(self))
+                .await
+                .change_context(errors::RedisError::GetHashFieldFailed)?;
+        }
+
+        pipeline
+            .all::<Vec<V>>()
+            .await
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
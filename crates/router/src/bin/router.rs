@@ -1,18 +1,60 @@
 use error_stack::ResultExt;
 use router::{
-    configs::settings::{CmdLineConf, Settings},
+    configs::{
+        connector_config_validation::{self, ConnectorConfigsCommand},
+        settings::{CmdLineConf, Settings},
+    },
     core::errors::{ApplicationError, ApplicationResult},
     logger,
     routes::metrics,
 };
 
+#[derive(clap::Parser)]
+#[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
+struct RouterCmdLine {
+    #[command(flatten)]
+    conf: CmdLineConf,
+    #[command(subcommand)]
+    command: Option<RouterCommand>,
+}
+
+#[derive(clap::Subcommand)]
+enum RouterCommand {
+    /// Checks the connector configs served to the dashboard
+    #[command(subcommand)]
+    ConnectorConfigs(ConnectorConfigsCommand),
+}
+
+#[allow(clippy::print_stdout)] // The issues are the output of the command
+fn run_command(command: RouterCommand) -> ApplicationResult<()> {
+    match command {
+        RouterCommand::ConnectorConfigs(command) => {
+            let issues = connector_config_validation::run_connector_configs_command(command)?;
+            for issue in &issues {
+                println!("{issue}");
+            }
+
+            if issues.is_empty() {
+                Ok(())
+            } else {
+                Err(error_stack::report!(ApplicationError::ConfigurationError)
+                    .attach_printable(format!("Found {} connector config issues", issues.len())))
+            }
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> ApplicationResult<()> {
     // get commandline config before initializing config
-    let cmd_line = <CmdLineConf as clap::Parser>::parse();
+    let cmd_line = <RouterCmdLine as clap::Parser>::parse();
+
+    if let Some(command) = cmd_line.command {
+        return run_command(command);
+    }
 
     #[allow(clippy::expect_used)]
-    let conf = Settings::with_config_path(cmd_line.config_path)
+    let conf = Settings::with_config_path(cmd_line.conf.config_path)
         .expect("Unable to construct application configuration");
     #[allow(clippy::expect_used)]
     conf.validate()
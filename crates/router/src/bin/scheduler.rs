@@ -324,6 +324,18 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::DisputeDeadlineWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline::DisputeDeadlineWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentRequeueWorkflow => {
+                    Ok(Box::new(workflows::payment_requeue::PaymentRequeueWorkflow))
+                }
+                storage::ProcessTrackerRunner::AccountUpdaterWorkflow => {
+                    Ok(Box::new(workflows::account_updater::AccountUpdaterWorkflow))
+                }
+                storage::ProcessTrackerRunner::CustomerErasureWorkflow => Ok(Box::new(
+                    workflows::customer_erasure::CustomerErasureWorkflow,
+                )),
             }
         };
 
@@ -66,6 +66,15 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::ApiError, code = "external_vault_failed", message = "external vault has failed")]
     ExternalVaultFailed,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "A request with the same idempotency key is currently being processed")]
+    IdempotencyKeyInUse,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyMismatch,
+
+    #[error(error_type = StripeErrorType::CardError, code = "card_declined", message = "The retry limit of the card network {card_network} has been reached for this card")]
+    NetworkRetryLimitExceeded { card_network: String },
+
     #[error(error_type = StripeErrorType::ApiError, code = "internal_server_error", message = "Server is down")]
     InternalServerError,
 
@@ -509,6 +518,11 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::RefundFailed { data: _ } => Self::RefundFailed, // Nothing at stripe to map
             errors::ApiErrorResponse::PayoutFailed { data: _ } => Self::PayoutFailed,
             errors::ApiErrorResponse::ExternalVaultFailed => Self::ExternalVaultFailed,
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::IdempotencyKeyMismatch => Self::IdempotencyKeyMismatch,
+            errors::ApiErrorResponse::NetworkRetryLimitExceeded { card_network } => {
+                Self::NetworkRetryLimitExceeded { card_network }
+            }
 
             errors::ApiErrorResponse::MandateUpdateFailed
             | errors::ApiErrorResponse::MandateSerializationFailed
@@ -782,6 +796,9 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,
+            Self::IdempotencyKeyMismatch => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::NetworkRetryLimitExceeded { .. } => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -424,7 +424,10 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
             api_enums::IntentStatus::Processing => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction
             | api_enums::IntentStatus::RequiresMerchantAction => Self::RequiresAction,
-            api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
+            api_enums::IntentStatus::RequiresPaymentMethod
+            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => {
+                Self::RequiresPaymentMethod
+            }
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
             api_enums::IntentStatus::RequiresCapture
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => Self::RequiresCapture,
@@ -87,6 +87,8 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    #[cfg(feature = "v1")]
+    PaymentMethod(StripePaymentMethodResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +109,25 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub customer: Option<common_utils::id_type::CustomerId>,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<common_enums::PaymentMethod>,
+    pub card: Option<StripePaymentMethodCard>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodCard {
+    pub brand: Option<common_enums::CardNetwork>,
+    pub last4: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -238,6 +259,23 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+#[cfg(feature = "v1")]
+impl From<api_models::payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: api_models::payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            customer: res.customer_id,
+            payment_method_type: res.payment_method,
+            card: res.card.map(|card| StripePaymentMethodCard {
+                brand: card.card_network,
+                last4: card.last4_digits,
+                exp_month: card.expiry_month,
+                exp_year: card.expiry_year,
+            }),
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -280,8 +318,10 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -338,6 +378,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
         }
     }
 }
@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use crate::{
-    core::{api_locking, errors},
+    core::{api_locking, errors, idempotency},
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
@@ -44,6 +44,7 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest, payload = ?payload);
 
+    let mut idempotency_context = idempotency::IdempotencyContext::default();
     let server_wrap_util_res = api::server_wrap_util(
         &flow,
         state.clone().into(),
@@ -53,6 +54,7 @@ where
         func,
         api_authentication,
         lock_action,
+        &mut idempotency_context,
     )
     .await
     .map(|response| {
@@ -178,6 +180,7 @@ where
         }
         Err(error) => api::log_and_return_error_response(error),
     };
+    let res = idempotency_context.release(res).await;
 
     let response_code = res.status().as_u16();
     let end_instant = Instant::now();
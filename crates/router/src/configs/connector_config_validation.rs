@@ -0,0 +1,106 @@
+use api_models::feature_matrix::PaymentMethodSpecificFeatures;
+use connector_configs::{
+    connector::{ConfigEnvironment, ConnectorTomlConfigs},
+    validation::{self, ConfigIssue, ConnectorCapabilities},
+};
+use error_stack::report;
+use hyperswitch_interfaces::api::ConnectorSpecifications;
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::errors::{ApplicationError, ApplicationResult},
+    types::api,
+};
+
+#[derive(Debug, Clone, clap::Subcommand)]
+pub enum ConnectorConfigsCommand {
+    /// Checks the connector configs against the payment methods each connector integration
+    /// supports and the metadata its wallets need
+    Validate {
+        /// Environment whose configs are checked, all of them if not specified
+        #[arg(short, long, value_name = "ENVIRONMENT")]
+        environment: Option<ConfigEnvironment>,
+    },
+    /// Reports the connectors, payment methods, metadata fields and authentication types which
+    /// differ between the connector configs of two environments
+    Diff {
+        #[arg(value_name = "ENVIRONMENT")]
+        environment: ConfigEnvironment,
+        #[arg(value_name = "OTHER_ENVIRONMENT")]
+        other_environment: ConfigEnvironment,
+    },
+}
+
+/// The capabilities advertised by the `ConnectorSpecifications` of the connector named after the
+/// TOML table of its config. Connectors which are not payment connectors, such as the payout
+/// specific tables, are not known and yield `None`.
+pub fn get_connector_capabilities(connector_name: &str) -> Option<ConnectorCapabilities> {
+    let connector = api::ConnectorData::convert_connector(connector_name).ok()?;
+    let supported_payment_methods = connector.get_supported_payment_methods()?;
+
+    let mut capabilities = ConnectorCapabilities::default();
+    for (payment_method, payment_method_types) in supported_payment_methods {
+        capabilities
+            .payment_methods
+            .entry(*payment_method)
+            .or_default()
+            .extend(payment_method_types.keys().copied());
+
+        capabilities.card_networks.extend(
+            payment_method_types
+                .values()
+                .filter_map(|details| match &details.specific_features {
+                    Some(PaymentMethodSpecificFeatures::Card(card_features)) => {
+                        Some(card_features.supported_card_networks.iter().cloned())
+                    }
+                    None => None,
+                })
+                .flatten(),
+        );
+    }
+
+    Some(capabilities)
+}
+
+fn get_connector_configs(
+    environment: ConfigEnvironment,
+) -> ApplicationResult<ConnectorTomlConfigs> {
+    environment.get_connector_configs().map_err(|error| {
+        report!(ApplicationError::ConfigurationError).attach_printable(format!(
+            "Failed to parse the {environment} connector configs: {error}"
+        ))
+    })
+}
+
+/// Runs the command, returning the issues found in the connector configs
+pub fn run_connector_configs_command(
+    command: ConnectorConfigsCommand,
+) -> ApplicationResult<Vec<ConfigIssue>> {
+    match command {
+        ConnectorConfigsCommand::Validate { environment } => {
+            let environments = environment
+                .map(|environment| vec![environment])
+                .unwrap_or_else(|| ConfigEnvironment::iter().collect());
+
+            let mut issues = Vec::new();
+            for environment in environments {
+                let configs = get_connector_configs(environment)?;
+                issues.extend(validation::validate_connector_configs(
+                    environment,
+                    &configs,
+                    get_connector_capabilities,
+                ));
+            }
+            Ok(issues)
+        }
+        ConnectorConfigsCommand::Diff {
+            environment,
+            other_environment,
+        } => Ok(validation::diff_connector_configs(
+            environment,
+            &get_connector_configs(environment)?,
+            other_environment,
+            &get_connector_configs(other_environment)?,
+        )),
+    }
+}
@@ -55,6 +55,7 @@ impl Default for super::settings::Locker {
             host: "localhost".into(),
             host_rs: "localhost".into(),
             mock_locker: true,
+            local_vault: false,
             basilisk_host: "localhost".into(),
             locker_signing_key_id: "1".into(),
             //true or false
@@ -66,6 +67,50 @@ impl Default for super::settings::Locker {
     }
 }
 
+impl Default for super::settings::AccountUpdaterConfig {
+    fn default() -> Self {
+        Self {
+            expiry_window_in_months: 2,
+            // Card networks process updater batches about once a month
+            batch_interval_in_hours: 720,
+            page_size: 1000,
+            network_updater: None,
+        }
+    }
+}
+
+impl Default for super::settings::DataSubjectRequestsConfig {
+    fn default() -> Self {
+        Self {
+            erasure_grace_period_in_hours: 72,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_seconds: 60,
+            minimum_calls: 20,
+            failure_rate_threshold: 50,
+            slow_call_threshold_in_milliseconds: 10000,
+            open_duration_in_seconds: 30,
+            half_open_success_threshold: 5,
+            half_open_max_probe_calls: 10,
+        }
+    }
+}
+
+impl Default for super::settings::SuccessRateEstimatorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            default_block_duration_in_mins: 5,
+        }
+    }
+}
+
 impl Default for super::settings::SupportedConnectors {
     fn default() -> Self {
         Self {
@@ -101,6 +146,26 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::IncomingWebhookQueueSettings {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_delay_in_milliseconds: 500,
+            // 7 days
+            ttl_in_seconds: 604800,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            // 24 hours
+            ttl_in_seconds: 86400,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -37,17 +37,20 @@ impl SecretsHandler for settings::Jwekey {
             rust_locker_encryption_key,
             vault_private_key,
             tunnel_private_key,
+            local_vault_private_key,
         ) = tokio::try_join!(
             secret_management_client.get_secret(jwekey.vault_encryption_key.clone()),
             secret_management_client.get_secret(jwekey.rust_locker_encryption_key.clone()),
             secret_management_client.get_secret(jwekey.vault_private_key.clone()),
-            secret_management_client.get_secret(jwekey.tunnel_private_key.clone())
+            secret_management_client.get_secret(jwekey.tunnel_private_key.clone()),
+            secret_management_client.get_secret(jwekey.local_vault_private_key.clone())
         )?;
         Ok(value.transition_state(|_| Self {
             vault_encryption_key,
             rust_locker_encryption_key,
             vault_private_key,
             tunnel_private_key,
+            local_vault_private_key,
         }))
     }
 }
@@ -314,11 +317,15 @@ impl SecretsHandler for settings::NetworkTokenizationService {
         let private_key = secret_management_client
             .get_secret(network_tokenization.private_key.clone())
             .await?;
+        let webhook_secret = secret_management_client
+            .get_secret(network_tokenization.webhook_secret.clone())
+            .await?;
 
         Ok(value.transition_state(|network_tokenization| Self {
             public_key,
             private_key,
             token_service_api_key,
+            webhook_secret,
             ..network_tokenization
         }))
     }
@@ -501,6 +508,12 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        idempotency: conf.idempotency,
+        network_retry_limits: conf.network_retry_limits,
+        account_updater: conf.account_updater,
+        data_subject_requests: conf.data_subject_requests,
+        connector_circuit_breaker: conf.connector_circuit_breaker,
+        success_rate_estimator: conf.success_rate_estimator,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
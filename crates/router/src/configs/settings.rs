@@ -123,6 +123,12 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub idempotency: IdempotencyConfig,
+    pub network_retry_limits: NetworkRetryLimitConfig,
+    pub account_updater: AccountUpdaterConfig,
+    pub data_subject_requests: DataSubjectRequestsConfig,
+    pub connector_circuit_breaker: ConnectorCircuitBreakerConfig,
+    pub success_rate_estimator: SuccessRateEstimatorConfig,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -541,6 +547,9 @@ pub struct DummyConnector {
     pub default_return_url: String,
     pub slack_invite_url: String,
     pub discord_invite_url: String,
+    pub timeout_duration: u64,
+    pub webhook_delay: u64,
+    pub webhook_secret: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -601,6 +610,8 @@ pub struct NetworkTokenizationService {
     pub key_id: String,
     pub delete_token_url: url::Url,
     pub check_token_status_url: url::Url,
+    #[serde(default)]
+    pub webhook_secret: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -720,6 +731,7 @@ pub struct Locker {
     pub host: String,
     pub host_rs: String,
     pub mock_locker: bool,
+    pub local_vault: bool,
     pub basilisk_host: String,
     pub locker_signing_key_id: String,
     pub locker_enabled: bool,
@@ -756,6 +768,7 @@ pub struct Jwekey {
     pub rust_locker_encryption_key: Secret<String>,
     pub vault_private_key: Secret<String>,
     pub tunnel_private_key: Secret<String>,
+    pub local_vault_private_key: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -831,6 +844,19 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    pub queue: IncomingWebhookQueueSettings,
+}
+
+/// Incoming webhooks received while their resource is locked are queued and processed in order
+/// by the lock holder
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookQueueSettings {
+    /// Number of times a queued webhook is retried before it is moved to the dead letter queue
+    pub max_retries: u8,
+    pub retry_delay_in_milliseconds: u32,
+    /// Expiry of the queue and the dead letter queue of a resource
+    pub ttl_in_seconds: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -1034,6 +1060,10 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.network_retry_limits.validate()?;
+        self.account_updater.validate()?;
+        self.connector_circuit_breaker.validate()?;
+        self.success_rate_estimator.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -1103,6 +1133,97 @@ pub struct Payouts {
     pub required_fields: PayoutRequiredFields,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct IdempotencyConfig {
+    /// Time in seconds for which the response of a request is replayed when its idempotency key is reused
+    pub ttl_in_seconds: u32,
+}
+
+/// Limits set by card networks on the number of times a declined card may be retried
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkRetryLimitConfig {
+    pub enabled: bool,
+    /// A card network may have more than one limit, each over a different rolling window
+    pub limits: Vec<NetworkRetryLimit>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NetworkRetryLimit {
+    pub card_network: enums::CardNetwork,
+    /// Number of declined attempts allowed with a card within the window
+    pub max_retries: u32,
+    pub window_in_seconds: u32,
+}
+
+/// Batches of stored cards sent to the card networks for updates of their details
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccountUpdaterConfig {
+    /// Cards expiring within these many months are included in a batch
+    pub expiry_window_in_months: u8,
+    /// Interval between the batches of a merchant
+    pub batch_interval_in_hours: u32,
+    /// Number of payment methods read from the database at a time while exporting a batch
+    pub page_size: u32,
+    /// Card network updater the request files of the batches are encrypted for
+    pub network_updater: Option<NetworkUpdaterConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NetworkUpdaterConfig {
+    /// Public key of the card network updater, used to encrypt the request files
+    pub public_key: Secret<String>,
+    pub key_id: String,
+    /// Request files are delivered to this URL once exported, they can only be retrieved by the
+    /// merchant otherwise
+    pub delivery_url: Option<url::Url>,
+    #[serde(default)]
+    pub api_key: Secret<String>,
+}
+
+/// Data export and erasure requests of customers
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DataSubjectRequestsConfig {
+    /// Delay between an erasure request and the erasure, during which it can still be cancelled
+    pub erasure_grace_period_in_hours: u32,
+}
+
+/// Circuit breakers of the merchant connector accounts, which take a connector account out of
+/// connector selection while its calls keep failing or timing out
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCircuitBreakerConfig {
+    pub enabled: bool,
+    /// Length of the windows over which the calls to a connector account are counted
+    pub window_in_seconds: u32,
+    /// Number of calls a window needs before its failure rate can open the circuit
+    pub minimum_calls: u32,
+    /// Percentage of failed calls in a window at which the circuit opens
+    pub failure_rate_threshold: u8,
+    /// Calls taking longer than this are counted as failed
+    pub slow_call_threshold_in_milliseconds: u64,
+    /// Time for which an open circuit skips the connector account, before it is half open
+    pub open_duration_in_seconds: u32,
+    /// Number of successful calls which close a half open circuit
+    pub half_open_success_threshold: u32,
+    /// Number of payments routed to a half open circuit, until enough of their calls succeed or
+    /// one of them fails
+    pub half_open_max_probe_calls: u32,
+}
+
+/// Success rate estimator backing success based routing when the dynamic routing service is not
+/// deployed or cannot be reached
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SuccessRateEstimatorConfig {
+    pub enabled: bool,
+    /// Length of the blocks the outcomes of payments are counted in, for the success based
+    /// routing configs which do not set `current_block_threshold.duration_in_mins`
+    pub default_block_duration_in_mins: u64,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LockSettings {
     pub redis_lock_expiry_seconds: u32,
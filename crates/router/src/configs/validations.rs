@@ -30,14 +30,25 @@ impl super::settings::Locker {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(!self.mock_locker && self.host.is_default_or_empty(), || {
+        when(self.mock_locker && self.local_vault, || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "locker host must not be empty when mock locker is disabled".into(),
+                "mock locker and local vault must not be enabled together".into(),
             ))
         })?;
 
+        let is_external_locker = !self.mock_locker && !self.local_vault;
+
         when(
-            !self.mock_locker && self.basilisk_host.is_default_or_empty(),
+            is_external_locker && self.host.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "locker host must not be empty when mock locker is disabled".into(),
+                ))
+            },
+        )?;
+
+        when(
+            is_external_locker && self.basilisk_host.is_default_or_empty(),
             || {
                 Err(ApplicationError::InvalidConfigurationValueError(
                     "basilisk host must not be empty when mock locker is disabled".into(),
@@ -181,6 +192,99 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::AccountUpdaterConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_interval_in_hours.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater batch_interval_in_hours must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.page_size.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater page_size must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::NetworkRetryLimitConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.limits.iter().try_for_each(|limit| {
+            when(
+                limit.max_retries.is_default_or_empty()
+                    || limit.window_in_seconds.is_default_or_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "network retry limit of {} must have a non-zero max_retries and window_in_seconds",
+                        limit.card_network
+                    )))
+                },
+            )
+        })
+    }
+}
+
+impl super::settings::ConnectorCircuitBreakerConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.failure_rate_threshold.is_default_or_empty() || self.failure_rate_threshold > 100,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector circuit breaker failure_rate_threshold must be between 1 and 100"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.window_in_seconds.is_default_or_empty()
+                || self.minimum_calls.is_default_or_empty()
+                || self
+                    .slow_call_threshold_in_milliseconds
+                    .is_default_or_empty()
+                || self.open_duration_in_seconds.is_default_or_empty()
+                || self.half_open_success_threshold.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector circuit breaker window_in_seconds, minimum_calls, slow_call_threshold_in_milliseconds, open_duration_in_seconds and half_open_success_threshold must not be empty or 0".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.half_open_max_probe_calls < self.half_open_success_threshold,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector circuit breaker half_open_max_probe_calls must not be less than half_open_success_threshold".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::SuccessRateEstimatorConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.default_block_duration_in_mins.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "success rate estimator default_block_duration_in_mins must not be empty or 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::WebhooksSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -189,6 +293,12 @@ impl super::settings::WebhooksSettings {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "redis_lock_expiry_seconds must not be empty or 0".into(),
             ))
+        })?;
+
+        when(self.queue.ttl_in_seconds.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks queue ttl_in_seconds must not be empty or 0".into(),
+            ))
         })
     }
 }
@@ -2,10 +2,11 @@ use common_utils::pii;
 use diesel_models::enums::Currency;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
-    connector::utils::RouterData,
+    connector::utils::{PaymentsAuthorizeRequestData, RouterData},
     core::errors,
     services,
     types::{self, api, domain, storage::enums},
@@ -66,6 +67,33 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: DummyConnectorCaptureMethod,
+    setup_mandate: bool,
+    webhook_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorCaptureMethod {
+    Automatic,
+    Manual,
+    ManualMultiple,
+}
+
+impl TryFrom<enums::CaptureMethod> for DummyConnectorCaptureMethod {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(value: enums::CaptureMethod) -> Result<Self, Self::Error> {
+        match value {
+            enums::CaptureMethod::Automatic | enums::CaptureMethod::SequentialAutomatic => {
+                Ok(Self::Automatic)
+            }
+            enums::CaptureMethod::Manual => Ok(Self::Manual),
+            enums::CaptureMethod::ManualMultiple => Ok(Self::ManualMultiple),
+            enums::CaptureMethod::Scheduled => {
+                Err(errors::ConnectorError::CaptureMethodNotSupported.into())
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -171,44 +199,127 @@ impl TryFrom<domain::payments::PayLaterData> for DummyConnectorPayLater {
     }
 }
 
-impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
-    for DummyConnectorPaymentsRequest<T>
-{
+impl TryFrom<(&domain::PaymentMethodData, Option<Secret<String>>)> for PaymentMethodData {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let payment_method_data: Result<PaymentMethodData, Self::Error> = match item
-            .request
-            .payment_method_data
-        {
-            domain::PaymentMethodData::Card(ref req_card) => {
-                let card_holder_name = item.get_optional_billing_full_name();
-                Ok(PaymentMethodData::Card(DummyConnectorCard::try_from((
-                    req_card.clone(),
-                    card_holder_name,
-                ))?))
-            }
-            domain::PaymentMethodData::Upi(ref req_upi_data) => match req_upi_data {
-                domain::UpiData::UpiCollect(data) => Ok(PaymentMethodData::Upi(
-                    DummyConnectorUpi::try_from(data.clone())?,
-                )),
+    fn try_from(
+        (payment_method_data, card_holder_name): (
+            &domain::PaymentMethodData,
+            Option<Secret<String>>,
+        ),
+    ) -> Result<Self, Self::Error> {
+        match payment_method_data {
+            domain::PaymentMethodData::Card(req_card) => Ok(Self::Card(
+                DummyConnectorCard::try_from((req_card.clone(), card_holder_name))?,
+            )),
+            domain::PaymentMethodData::Upi(req_upi_data) => match req_upi_data {
+                domain::UpiData::UpiCollect(data) => {
+                    Ok(Self::Upi(DummyConnectorUpi::try_from(data.clone())?))
+                }
                 domain::UpiData::UpiIntent(_) => {
                     Err(errors::ConnectorError::NotImplemented("UPI Intent".to_string()).into())
                 }
             },
-            domain::PaymentMethodData::Wallet(ref wallet_data) => {
-                Ok(PaymentMethodData::Wallet(wallet_data.clone().try_into()?))
+            domain::PaymentMethodData::Wallet(wallet_data) => {
+                Ok(Self::Wallet(wallet_data.clone().try_into()?))
+            }
+            domain::PaymentMethodData::PayLater(pay_later_data) => {
+                Ok(Self::PayLater(pay_later_data.clone().try_into()?))
             }
-            domain::PaymentMethodData::PayLater(ref pay_later_data) => Ok(
-                PaymentMethodData::PayLater(pay_later_data.clone().try_into()?),
-            ),
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
-        };
+        }
+    }
+}
+
+impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
+    for DummyConnectorPaymentsRequest<T>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             amount: item.request.amount,
             currency: item.request.currency,
-            payment_method_data: payment_method_data?,
+            payment_method_data: PaymentMethodData::try_from((
+                &item.request.payment_method_data,
+                item.get_optional_billing_full_name(),
+            ))?,
+            return_url: item.request.router_return_url.clone(),
+            connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method.unwrap_or_default().try_into()?,
+            setup_mandate: item.request.is_customer_initiated_mandate_payment(),
+            webhook_url: item.request.webhook_url.clone(),
+        })
+    }
+}
+
+impl<const T: u8> TryFrom<&types::SetupMandateRouterData> for DummyConnectorPaymentsRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SetupMandateRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount.unwrap_or(0),
+            currency: item.request.currency,
+            payment_method_data: PaymentMethodData::try_from((
+                &item.request.payment_method_data,
+                item.get_optional_billing_full_name(),
+            ))?,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method: item.request.capture_method.unwrap_or_default().try_into()?,
+            setup_mandate: true,
+            webhook_url: item.request.webhook_url.clone(),
+        })
+    }
+}
+
+/// Merchant initiated payment charging the payment method stored by the mandate, whose id is
+/// part of the url
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorMandatePaymentRequest {
+    amount: i64,
+    currency: Currency,
+    capture_method: DummyConnectorCaptureMethod,
+    webhook_url: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for DummyConnectorMandatePaymentRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency,
+            capture_method: item.request.capture_method.unwrap_or_default().try_into()?,
+            webhook_url: item.request.webhook_url.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorIncrementalAuthorizationRequest {
+    additional_amount: i64,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for DummyConnectorIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            additional_amount: item.request.additional_amount,
         })
     }
 }
@@ -238,6 +349,10 @@ pub enum DummyConnectorPaymentStatus {
     Failed,
     #[default]
     Processing,
+    Authorized,
+    #[serde(rename = "partially_captured")]
+    PartiallyCaptured,
+    Cancelled,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
@@ -246,6 +361,9 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
+            DummyConnectorPaymentStatus::PartiallyCaptured => Self::PartialCharged,
+            DummyConnectorPaymentStatus::Cancelled => Self::Voided,
         }
     }
 }
@@ -253,12 +371,15 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PaymentsResponse {
     status: DummyConnectorPaymentStatus,
-    id: String,
+    pub(super) id: String,
     amount: i64,
     currency: Currency,
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    /// Set when the payment set up a mandate, or was charged through one
+    #[serde(default)]
+    mandate_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -289,16 +410,27 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
             .map(|redirection_url| {
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
+        let mandate_reference =
+            item.response
+                .mandate_id
+                .map(|mandate_id| types::MandateReference {
+                    connector_mandate_id: Some(mandate_id),
+                    payment_method_id: None,
+                    mandate_metadata: None,
+                    connector_mandate_request_reference_id: None,
+                });
+        let status = enums::AttemptStatus::from(item.response.status);
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data: Box::new(redirection_data),
-                mandate_reference: Box::new(None),
+                mandate_reference: Box::new(mandate_reference),
                 connector_metadata: None,
                 network_txn_id: None,
                 connector_response_reference_id: None,
-                incremental_authorization_allowed: None,
+                // The simulator authorizes additional amounts until the payment is captured
+                incremental_authorization_allowed: Some(status == enums::AttemptStatus::Authorized),
                 charges: None,
             }),
             ..item.data
@@ -306,6 +438,60 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
     }
 }
 
+/// The simulator answers an incremental authorization with the payment, whose amount now
+/// includes the additional amount
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IncrementalAuthorizationResponse {
+    status: DummyConnectorPaymentStatus,
+    id: String,
+    amount: i64,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            IncrementalAuthorizationResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            IncrementalAuthorizationResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status {
+            DummyConnectorPaymentStatus::Authorized
+            | DummyConnectorPaymentStatus::PartiallyCaptured => {
+                common_enums::AuthorizationStatus::Success
+            }
+            DummyConnectorPaymentStatus::Processing => {
+                common_enums::AuthorizationStatus::Processing
+            }
+            DummyConnectorPaymentStatus::Succeeded
+            | DummyConnectorPaymentStatus::Failed
+            | DummyConnectorPaymentStatus::Cancelled => common_enums::AuthorizationStatus::Failure,
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    connector_authorization_id: None,
+                    error_code: None,
+                    error_message: None,
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum DummyConnectorNextAction {
@@ -325,6 +511,7 @@ impl DummyConnectorNextAction {
 #[derive(Default, Debug, Serialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
+    pub webhook_url: Option<String>,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
@@ -332,6 +519,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
             amount: item.request.refund_amount,
+            webhook_url: item.request.webhook_url.clone(),
         })
     }
 }
@@ -361,7 +549,7 @@ impl From<RefundStatus> for enums::RefundStatus {
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct RefundResponse {
-    id: String,
+    pub(super) id: String,
     status: RefundStatus,
     currency: Currency,
     created: String,
@@ -403,6 +591,117 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+// PAYOUTS :
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutMethodData {
+    Card(DummyConnectorCard),
+    Bank(DummyConnectorBankTransfer),
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorBankTransfer {
+    iban: Secret<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorPayoutRequest<const T: u8> {
+    amount: i64,
+    currency: Currency,
+    payout_method_data: DummyConnectorPayoutMethodData,
+    connector: DummyConnectors,
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8, F> TryFrom<&types::PayoutsRouterData<F>> for DummyConnectorPayoutRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let payout_method_data = match item.get_payout_method_data()? {
+            api::PayoutMethodData::Card(card) => {
+                DummyConnectorPayoutMethodData::Card(DummyConnectorCard {
+                    name: card.card_holder_name.unwrap_or(Secret::new("".to_string())),
+                    number: card.card_number,
+                    expiry_month: card.expiry_month,
+                    expiry_year: card.expiry_year,
+                    cvc: Secret::new("".to_string()),
+                })
+            }
+            api::PayoutMethodData::Bank(api::BankPayout::Sepa(bank)) => {
+                DummyConnectorPayoutMethodData::Bank(DummyConnectorBankTransfer { iban: bank.iban })
+            }
+            api::PayoutMethodData::Bank(_) | api::PayoutMethodData::Wallet(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    "Payout methods other than cards and SEPA bank transfers".to_string(),
+                ))?
+            }
+        };
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.destination_currency,
+            payout_method_data,
+            connector: Into::<DummyConnectors>::into(T),
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutStatus {
+    Succeeded,
+    Failed,
+    Processing,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+impl From<DummyConnectorPayoutStatus> for enums::PayoutStatus {
+    fn from(item: DummyConnectorPayoutStatus) -> Self {
+        match item {
+            DummyConnectorPayoutStatus::Succeeded => Self::Success,
+            DummyConnectorPayoutStatus::Failed => Self::Failed,
+            DummyConnectorPayoutStatus::Processing | DummyConnectorPayoutStatus::Unknown => {
+                Self::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorPayoutResponse {
+    pub(super) id: String,
+    status: DummyConnectorPayoutStatus,
+    amount: i64,
+    currency: Currency,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::from(item.response.status)),
+                connector_payout_id: Some(item.response.id),
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+                error_code: None,
+                error_message: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
@@ -414,3 +713,70 @@ pub struct ErrorData {
     pub message: String,
     pub reason: Option<String>,
 }
+
+// WEBHOOKS :
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentAuthorized,
+    PaymentCaptured,
+    PaymentCancelled,
+    RefundSucceeded,
+    DisputeOpened,
+    DisputeWon,
+    DisputeLost,
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(value: DummyConnectorWebhookEventType) -> Self {
+        match value {
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEventType::PaymentAuthorized => {
+                Self::PaymentIntentAuthorizationSuccess
+            }
+            DummyConnectorWebhookEventType::PaymentCaptured => Self::PaymentIntentCaptureSuccess,
+            DummyConnectorWebhookEventType::PaymentCancelled => Self::PaymentIntentCancelled,
+            DummyConnectorWebhookEventType::RefundSucceeded => Self::RefundSuccess,
+            DummyConnectorWebhookEventType::DisputeOpened => Self::DisputeOpened,
+            DummyConnectorWebhookEventType::DisputeWon => Self::DisputeWon,
+            DummyConnectorWebhookEventType::DisputeLost => Self::DisputeLost,
+            DummyConnectorWebhookEventType::Unknown => Self::EventNotSupported,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookData {
+    Payment(PaymentsResponse),
+    Refund(RefundResponse),
+    Dispute(DisputeResponse),
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub id: String,
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: DummyConnectorWebhookData,
+}
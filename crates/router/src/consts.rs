@@ -30,10 +30,15 @@ pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in sp
 pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not found";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
+pub const REQUEUE_FAILED_ERROR_MESSAGE: &str =
+    "This Payment has been moved to failed as it could not be retried after it was requeued";
 
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 
+/// Delay before a requeued payment is retried, when it is not configured in GSM
+pub const DEFAULT_REQUEUE_DELAY_IN_SECONDS: i64 = 60;
+
 /// Payment intent default client secret expiry (in seconds)
 pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 
@@ -79,6 +84,7 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_DISPUTE_DEADLINE_REMINDER: &str = "Dispute Evidence Deadline Reminder";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -244,6 +250,9 @@ pub const IRRELEVANT_PAYMENT_ATTEMPT_ID: &str = "irrelevant_payment_attempt_id";
 // Default payment method storing TTL in redis in seconds
 pub const DEFAULT_PAYMENT_METHOD_STORE_TTL: i64 = 86400; // 1 day
 
+// Network token metadata caching TTL in redis in seconds
+pub const NETWORK_TOKEN_METADATA_CACHE_TTL: i64 = 86400; // 1 day
+
 // List of countries that are part of the PSD2 region
 pub const PSD2_COUNTRIES: [Country; 27] = [
     Country::Austria,
@@ -24,6 +24,10 @@ use pm_auth::{connector::plaid::transformers::PlaidAuthType, types as pm_auth_ty
 use regex::Regex;
 use uuid::Uuid;
 
+#[cfg(feature = "dummy_connector")]
+use crate::configs::settings;
+#[cfg(feature = "v1")]
+use crate::core::payments::routing::circuit_breaker;
 #[cfg(any(feature = "v1", feature = "v2"))]
 use crate::types::transformers::ForeignFrom;
 use crate::{
@@ -2637,6 +2641,7 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             status: Some(connector_status),
             additional_merchant_data: Box::new(encrypted_data.additional_merchant_data),
             connector_wallets_details: Box::new(encrypted_data.connector_wallets_details),
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -2997,6 +3002,7 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             business_sub_label: self.business_sub_label.clone(),
             additional_merchant_data: encrypted_data.additional_merchant_data,
             version: common_types::consts::API_VERSION,
+            fee_schedule: self.fee_schedule,
         })
     }
 
@@ -3057,6 +3063,33 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
     }
 }
 
+/// The webhooks of the dummy connector are signed with `dummy_connector.webhook_secret`, which
+/// is stored as the webhook secret of its connector accounts when the merchant does not provide
+/// one, so that their webhooks are verified
+#[cfg(feature = "dummy_connector")]
+fn get_dummy_connector_webhook_details(
+    connector_name: api_enums::Connector,
+    connector_webhook_details: Option<api_models::admin::MerchantConnectorWebhookDetails>,
+    dummy_connector_conf: &settings::DummyConnector,
+) -> Option<api_models::admin::MerchantConnectorWebhookDetails> {
+    let is_dummy_connector = matches!(
+        connector_name,
+        api_enums::Connector::DummyConnector1
+            | api_enums::Connector::DummyConnector2
+            | api_enums::Connector::DummyConnector3
+            | api_enums::Connector::DummyConnector4
+            | api_enums::Connector::DummyConnector5
+            | api_enums::Connector::DummyConnector6
+            | api_enums::Connector::DummyConnector7
+    );
+    connector_webhook_details.or_else(|| {
+        is_dummy_connector.then(|| api_models::admin::MerchantConnectorWebhookDetails {
+            merchant_secret: dummy_connector_conf.webhook_secret.clone(),
+            additional_secret: None,
+        })
+    })
+}
+
 pub async fn create_connector(
     state: SessionState,
     req: api::MerchantConnectorCreate,
@@ -3076,6 +3109,16 @@ pub async fn create_connector(
         },
     )?;
 
+    #[cfg(feature = "dummy_connector")]
+    let req = api::MerchantConnectorCreate {
+        connector_webhook_details: get_dummy_connector_webhook_details(
+            req.connector_name,
+            req.connector_webhook_details,
+            &state.conf.dummy_connector,
+        ),
+        ..req
+    };
+
     let connector_metadata = ConnectorMetadata {
         connector_metadata: &req.metadata,
     };
@@ -3405,6 +3448,105 @@ pub async fn list_payment_connectors(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(feature = "v1")]
+fn get_connector_circuit_breaker_response(
+    mca: &domain::MerchantConnectorAccount,
+    status: circuit_breaker::CircuitStatus,
+) -> admin_types::ConnectorCircuitBreakerResponse {
+    admin_types::ConnectorCircuitBreakerResponse {
+        merchant_connector_id: mca.merchant_connector_id.clone(),
+        connector_name: mca.connector_name.clone(),
+        profile_id: mca.profile_id.clone(),
+        state: status.state,
+        opened_at: status.opened_at,
+        calls_in_window: status.calls_in_window,
+        failed_calls_in_window: status.failed_calls_in_window,
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn list_connector_circuit_breakers(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<Vec<admin_types::ConnectorCircuitBreakerResponse>> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &merchant_id,
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    let circuit_breakers = merchant_connector_accounts
+        .iter()
+        .map(|mca| circuit_breaker::CircuitBreaker::new(mca.merchant_connector_id.clone()))
+        .collect::<Vec<_>>();
+    let statuses = circuit_breaker::CircuitBreaker::get_statuses(&state, &circuit_breakers).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        merchant_connector_accounts
+            .iter()
+            .zip(statuses)
+            .map(|(mca, status)| get_connector_circuit_breaker_response(mca, status))
+            .collect(),
+    ))
+}
+
+/// Closes the circuit of the connector account, so that it is no longer skipped during connector
+/// selection
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn reset_connector_circuit_breaker(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<admin_types::ConnectorCircuitBreakerResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    let circuit_breaker = circuit_breaker::CircuitBreaker::new(merchant_connector_id);
+    circuit_breaker.close(&state).await?;
+    let status = circuit_breaker.get_status(&state).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        get_connector_circuit_breaker_response(&mca, status),
+    ))
+}
+
 pub async fn update_connector(
     state: SessionState,
     merchant_id: &id_type::MerchantId,
@@ -3440,6 +3582,23 @@ pub async fn update_connector(
         .await?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
 
+    // Accounts created before the webhook secret of the dummy connector was stored get it on
+    // their next update, unless the merchant has configured webhook details of their own
+    #[cfg(feature = "dummy_connector")]
+    let req = match api_enums::Connector::from_str(&mca.get_connector_name_as_string()) {
+        Ok(connector_name) if mca.connector_webhook_details.is_none() => {
+            api_models::admin::MerchantConnectorUpdate {
+                connector_webhook_details: get_dummy_connector_webhook_details(
+                    connector_name,
+                    req.connector_webhook_details,
+                    &state.conf.dummy_connector,
+                ),
+                ..req
+            }
+        }
+        _ => req,
+    };
+
     let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
         merchant_account.clone(),
         key_store.clone(),
@@ -4053,6 +4212,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             is_pre_network_tokenization_enabled: self
                 .is_pre_network_tokenization_enabled
                 .unwrap_or_default(),
+            dispute_policy: self.dispute_policy,
+            integrity_check_policy: self.integrity_check_policy,
         }))
     }
 
@@ -4534,6 +4695,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 merchant_business_country: self.merchant_business_country,
                 is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
                 is_pre_network_tokenization_enabled: self.is_pre_network_tokenization_enabled,
+                dispute_policy: self.dispute_policy,
+                integrity_check_policy: self.integrity_check_policy,
             },
         )))
     }
@@ -23,7 +23,8 @@ pub enum LockAction {
     // Sleep until the lock is acquired
     Hold { input: LockingInput },
     // Queue it but return response as 2xx, could be used for webhooks
-    QueueWithOk,
+    // The lock is tried only once, the caller queues the request if the lock is busy
+    QueueWithOk { input: LockingInput },
     // Return Error
     Drop,
     // Locking Not applicable
@@ -38,6 +39,12 @@ pub struct LockingInput {
 }
 
 impl LockingInput {
+    /// Whether both the inputs lock the same resource
+    pub fn locks_same_resource(&self, other: &Self) -> bool {
+        self.api_identifier == other.api_identifier
+            && self.unique_locking_key == other.unique_locking_key
+    }
+
     fn get_redis_locking_key(&self, merchant_id: common_utils::id_type::MerchantId) -> String {
         format!(
             "{}_{}_{}_{}",
@@ -47,6 +54,46 @@ impl LockingInput {
             self.unique_locking_key
         )
     }
+
+    async fn try_acquire_lock<A>(
+        &self,
+        state: &A,
+        merchant_id: common_utils::id_type::MerchantId,
+    ) -> RouterResult<LockStatus>
+    where
+        A: SessionStateInfo,
+    {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let redis_locking_key = self.get_redis_locking_key(merchant_id);
+        let redis_lock_expiry_seconds = state.conf().lock_settings.redis_lock_expiry_seconds;
+
+        match redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &redis_locking_key.as_str().into(),
+                state.get_request_id(),
+                Some(i64::from(redis_lock_expiry_seconds)),
+            )
+            .await
+        {
+            Ok(redis::SetnxReply::KeySet) => {
+                logger::info!("Lock acquired for locking input {:?}", self);
+                tracing::Span::current().record("redis_lock_acquired", redis_locking_key);
+                Ok(LockStatus::Acquired)
+            }
+            Ok(redis::SetnxReply::KeyNotSet) => {
+                logger::info!(
+                    "Lock busy by other request when tried for locking input {:?}",
+                    self
+                );
+                Ok(LockStatus::Busy)
+            }
+            Err(err) => Err(err).change_context(errors::ApiErrorResponse::InternalServerError),
+        }
+    }
 }
 
 impl LockAction {
@@ -61,57 +108,34 @@ impl LockAction {
     {
         match self {
             Self::Hold { input } => {
-                let redis_conn = state
-                    .store()
-                    .get_redis_conn()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
-
-                let redis_locking_key = input.get_redis_locking_key(merchant_id);
                 let delay_between_retries_in_milliseconds = state
                     .conf()
                     .lock_settings
                     .delay_between_retries_in_milliseconds;
-                let redis_lock_expiry_seconds =
-                    state.conf().lock_settings.redis_lock_expiry_seconds;
                 let lock_retries = input
                     .override_lock_retries
                     .unwrap_or(state.conf().lock_settings.lock_retries);
                 for _retry in 0..lock_retries {
-                    let redis_lock_result = redis_conn
-                        .set_key_if_not_exists_with_expiry(
-                            &redis_locking_key.as_str().into(),
-                            state.get_request_id(),
-                            Some(i64::from(redis_lock_expiry_seconds)),
-                        )
-                        .await;
-
-                    match redis_lock_result {
-                        Ok(redis::SetnxReply::KeySet) => {
-                            logger::info!("Lock acquired for locking input {:?}", input);
-                            tracing::Span::current()
-                                .record("redis_lock_acquired", redis_locking_key);
-                            return Ok(());
-                        }
-                        Ok(redis::SetnxReply::KeyNotSet) => {
-                            logger::info!(
-                                "Lock busy by other request when tried for locking input {:?}",
-                                input
-                            );
+                    match input.try_acquire_lock(state, merchant_id.clone()).await? {
+                        LockStatus::Acquired => return Ok(()),
+                        LockStatus::Busy => {
                             actix_time::sleep(tokio::time::Duration::from_millis(u64::from(
                                 delay_between_retries_in_milliseconds,
                             )))
                             .await;
                         }
-                        Err(err) => {
-                            return Err(err)
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                        }
                     }
                 }
 
                 Err(report!(errors::ApiErrorResponse::ResourceBusy))
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::QueueWithOk { input } => {
+                match input.try_acquire_lock(state, merchant_id).await? {
+                    LockStatus::Acquired => Ok(()),
+                    LockStatus::Busy => Err(report!(errors::ApiErrorResponse::ResourceBusy)),
+                }
+            }
+            Self::Drop | Self::NotApplicable => Ok(()),
         }
     }
 
@@ -125,7 +149,7 @@ impl LockAction {
         A: SessionStateInfo,
     {
         match self {
-            Self::Hold { input } => {
+            Self::Hold { input } | Self::QueueWithOk { input } => {
                 let redis_conn = state
                     .store()
                     .get_redis_conn()
@@ -168,7 +192,7 @@ impl LockAction {
                     }
                 }
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::Drop | Self::NotApplicable => Ok(()),
         }
     }
 }
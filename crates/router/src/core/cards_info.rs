@@ -8,6 +8,8 @@ use hyperswitch_domain_models::cards_info;
 use rdkafka::message::ToBytes;
 use router_env::{instrument, tracing};
 
+pub mod bin_import;
+
 use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
@@ -45,7 +47,7 @@ pub async fn retrieve_card_info(
     .await?;
 
     let card_info = db
-        .get_card_info(&request.card_iin)
+        .get_card_info_with_bin_range_fallback(&request.card_iin)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to retrieve card information")?
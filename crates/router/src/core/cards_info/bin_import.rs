@@ -0,0 +1,499 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::cards_info as cards_info_api_types;
+use common_utils::fp_utils::when;
+use csv::Reader;
+use diesel_models::card_bin;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::cards_info::CardsInfoInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes,
+    services::ApplicationResponse,
+    types::transformers::{ForeignFrom, ForeignTryFrom},
+};
+
+const MIN_BIN_RANGE_LENGTH: usize = 6;
+
+#[derive(Debug, MultipartForm)]
+pub struct CardBinImportForm {
+    #[multipart(limit = "50MB")]
+    pub file: Bytes,
+    pub file_format: Text<cards_info_api_types::CardBinImportFileFormat>,
+    pub source: Text<String>,
+}
+
+fn parse_card_bin_ranges_csv(
+    data: &[u8],
+) -> csv::Result<Vec<cards_info_api_types::CardBinRangeRecord>> {
+    let mut csv_reader = Reader::from_reader(data);
+    csv_reader.deserialize().collect()
+}
+
+pub fn get_card_bin_import_request(
+    form: CardBinImportForm,
+) -> Result<cards_info_api_types::CardBinImportRequest, errors::ApiErrorResponse> {
+    let file_format = form.file_format.into_inner();
+    let ranges = match file_format {
+        cards_info_api_types::CardBinImportFileFormat::Csv => {
+            parse_card_bin_ranges_csv(&form.file.data).map_err(|error| error.to_string())
+        }
+        cards_info_api_types::CardBinImportFileFormat::Json => {
+            serde_json::from_slice(&form.file.data).map_err(|error| error.to_string())
+        }
+    }
+    .map_err(|message| errors::ApiErrorResponse::PreconditionFailed { message })?;
+
+    Ok(cards_info_api_types::CardBinImportRequest {
+        source: form.source.into_inner(),
+        file_format,
+        ranges,
+    })
+}
+
+fn validate_bin_range_bound(bound: &str) -> Result<(), errors::ApiErrorResponse> {
+    let is_valid_bound = (MIN_BIN_RANGE_LENGTH..=card_bin::NORMALIZED_BIN_RANGE_LENGTH)
+        .contains(&bound.len())
+        && bound.chars().all(|digit| digit.is_ascii_digit());
+    when(!is_valid_bound, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Invalid BIN range bound {bound}, expected between {MIN_BIN_RANGE_LENGTH} and {} digits",
+                card_bin::NORMALIZED_BIN_RANGE_LENGTH
+            ),
+        })
+    })
+}
+
+fn to_card_bin_range(
+    import_version: i32,
+    record: cards_info_api_types::CardBinRangeRecord,
+) -> Result<card_bin::CardBinRange, errors::ApiErrorResponse> {
+    let range_end = record
+        .range_end
+        .unwrap_or_else(|| record.range_start.clone());
+    validate_bin_range_bound(&record.range_start)?;
+    validate_bin_range_bound(&range_end)?;
+
+    let normalized_range_start = card_bin::CardBinRange::normalize_range_start(&record.range_start);
+    let normalized_range_end = card_bin::CardBinRange::normalize_range_end(&range_end);
+    when(normalized_range_start > normalized_range_end, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "BIN range start {} is greater than the range end {range_end}",
+                record.range_start
+            ),
+        })
+    })?;
+
+    Ok(card_bin::CardBinRange {
+        import_version,
+        range_start: normalized_range_start,
+        range_end: normalized_range_end,
+        card_issuer: record.card_issuer,
+        card_network: record.card_network,
+        card_type: record.card_type,
+        card_subtype: record.card_subtype,
+        card_issuing_country: record.card_issuing_country,
+        bank_code_id: record.bank_code_id,
+        bank_code: record.bank_code,
+        country_code: record.country_code,
+    })
+}
+
+fn to_card_bin_ranges(
+    import_version: i32,
+    records: Vec<cards_info_api_types::CardBinRangeRecord>,
+) -> Result<Vec<card_bin::CardBinRange>, errors::ApiErrorResponse> {
+    let mut seen_ranges = HashSet::with_capacity(records.len());
+    records
+        .into_iter()
+        .map(|record| {
+            let range = to_card_bin_range(import_version, record)?;
+            let is_duplicate =
+                !seen_ranges.insert((range.range_start.clone(), range.range_end.clone()));
+            when(is_duplicate, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Duplicate BIN range {} - {} in the import",
+                        range.range_start, range.range_end
+                    ),
+                })
+            })?;
+            Ok(range)
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+pub struct CardBinRangeDiff {
+    pub added: Vec<card_bin::CardBinRange>,
+    pub removed: Vec<card_bin::CardBinRange>,
+    pub modified: Vec<card_bin::CardBinRange>,
+}
+
+/// Compares two sets of BIN ranges by their bounds. Modified ranges are returned as present in
+/// `new_ranges`.
+pub fn diff_card_bin_ranges(
+    old_ranges: &[card_bin::CardBinRange],
+    new_ranges: &[card_bin::CardBinRange],
+) -> CardBinRangeDiff {
+    let old_ranges_by_bounds = old_ranges
+        .iter()
+        .map(|range| {
+            (
+                (range.range_start.as_str(), range.range_end.as_str()),
+                range,
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let new_range_bounds = new_ranges
+        .iter()
+        .map(|range| (range.range_start.as_str(), range.range_end.as_str()))
+        .collect::<HashSet<_>>();
+
+    let mut diff = CardBinRangeDiff::default();
+    for range in new_ranges {
+        match old_ranges_by_bounds.get(&(range.range_start.as_str(), range.range_end.as_str())) {
+            None => diff.added.push(range.clone()),
+            Some(old_range) if !old_range.has_same_details(range) => {
+                diff.modified.push(range.clone())
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed = old_ranges
+        .iter()
+        .filter(|range| {
+            !new_range_bounds.contains(&(range.range_start.as_str(), range.range_end.as_str()))
+        })
+        .cloned()
+        .collect();
+    diff
+}
+
+fn count_as_i32(count: usize) -> RouterResult<i32> {
+    i32::try_from(count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Number of BIN ranges exceeds the supported limit")
+}
+
+async fn find_preceding_card_bin_import_version(
+    db: &dyn crate::db::StorageInterface,
+    version: i32,
+) -> RouterResult<Option<i32>> {
+    let imports = db
+        .list_card_bin_imports(None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list card BIN imports")?;
+    Ok(imports
+        .into_iter()
+        .map(|import| import.version)
+        .find(|import_version| *import_version < version))
+}
+
+#[instrument(skip_all)]
+pub async fn import_card_bins(
+    state: routes::SessionState,
+    request: cards_info_api_types::CardBinImportRequest,
+) -> RouterResponse<cards_info_api_types::CardBinImportResponse> {
+    let db = state.store.as_ref();
+
+    let version = db
+        .list_card_bin_imports(Some(1), None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the latest card BIN import")?
+        .first()
+        .map_or(1, |latest_import| latest_import.version + 1);
+
+    let ranges = to_card_bin_ranges(version, request.ranges)?;
+
+    let active_ranges = match db
+        .find_active_card_bin_import()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the active card BIN import")?
+    {
+        Some(active_import) => db
+            .list_card_bin_ranges_by_import_version(active_import.version)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch BIN ranges of the active import")?,
+        None => Vec::new(),
+    };
+    let diff = diff_card_bin_ranges(&active_ranges, &ranges);
+
+    let card_bin_import = card_bin::CardBinImportNew {
+        version,
+        source: request.source,
+        file_format: request.file_format.to_string(),
+        total_ranges: count_as_i32(ranges.len())?,
+        ranges_added: count_as_i32(diff.added.len())?,
+        ranges_removed: count_as_i32(diff.removed.len())?,
+        ranges_modified: count_as_i32(diff.modified.len())?,
+        is_active: false,
+        created_at: common_utils::date_time::now(),
+    };
+
+    let card_bin_import = db
+        .insert_card_bin_import(card_bin_import, ranges)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Another card BIN import is in progress, please retry".to_string(),
+        })?;
+
+    // The import is made active only once all of its ranges are stored, so that lookups never
+    // observe a partially loaded version
+    db.activate_card_bin_import(version)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to activate the card BIN import")?;
+
+    logger::info!(
+        version,
+        ranges_added = card_bin_import.ranges_added,
+        ranges_removed = card_bin_import.ranges_removed,
+        ranges_modified = card_bin_import.ranges_modified,
+        "Card BIN import activated"
+    );
+
+    let response =
+        cards_info_api_types::CardBinImportResponse::foreign_try_from(card_bin::CardBinImport {
+            is_active: true,
+            ..card_bin_import
+        })?;
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn list_card_bin_imports(
+    state: routes::SessionState,
+    constraints: cards_info_api_types::CardBinImportListConstraints,
+) -> RouterResponse<Vec<cards_info_api_types::CardBinImportResponse>> {
+    let db = state.store.as_ref();
+    let imports = db
+        .list_card_bin_imports(constraints.limit, constraints.offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list card BIN imports")?;
+
+    imports
+        .into_iter()
+        .map(cards_info_api_types::CardBinImportResponse::foreign_try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn rollback_card_bin_import(
+    state: routes::SessionState,
+    request: cards_info_api_types::CardBinImportRollbackRequest,
+) -> RouterResponse<cards_info_api_types::CardBinImportResponse> {
+    let db = state.store.as_ref();
+
+    let version = match request.version {
+        Some(version) => version,
+        None => {
+            let active_import = db
+                .find_active_card_bin_import()
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the active card BIN import")?
+                .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "No card BIN import is active".to_string(),
+                }))?;
+            find_preceding_card_bin_import_version(db, active_import.version)
+                .await?
+                .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "No card BIN import precedes the active import".to_string(),
+                }))?
+        }
+    };
+
+    let card_bin_import = db
+        .find_card_bin_import_by_version(version)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Card BIN import with version {version} does not exist"),
+        })?;
+
+    // Imports which failed midway are never activated, and must not be restored either
+    let stored_ranges = db
+        .list_card_bin_ranges_by_import_version(version)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch BIN ranges of the card BIN import")?;
+    when(
+        count_as_i32(stored_ranges.len())? != card_bin_import.total_ranges,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Card BIN import with version {version} is incomplete"),
+            }))
+        },
+    )?;
+
+    db.activate_card_bin_import(version)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to activate the card BIN import")?;
+
+    logger::info!(version, "Card BIN import rolled back");
+
+    let response =
+        cards_info_api_types::CardBinImportResponse::foreign_try_from(card_bin::CardBinImport {
+            is_active: true,
+            ..card_bin_import
+        })?;
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn get_card_bin_import_diff(
+    state: routes::SessionState,
+    request: cards_info_api_types::CardBinImportDiffRequest,
+) -> RouterResponse<cards_info_api_types::CardBinImportDiffResponse> {
+    let db = state.store.as_ref();
+
+    let card_bin_import = db
+        .find_card_bin_import_by_version(request.version)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "Card BIN import with version {} does not exist",
+                request.version
+            ),
+        })?;
+
+    let compared_with = match request.compare_with {
+        Some(compare_with) => Some(
+            db.find_card_bin_import_by_version(compare_with)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!("Card BIN import with version {compare_with} does not exist"),
+                })?
+                .version,
+        ),
+        None => find_preceding_card_bin_import_version(db, card_bin_import.version).await?,
+    };
+
+    let ranges = db
+        .list_card_bin_ranges_by_import_version(card_bin_import.version)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch BIN ranges of the card BIN import")?;
+    let compared_ranges = match compared_with {
+        Some(compared_with) => db
+            .list_card_bin_ranges_by_import_version(compared_with)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch BIN ranges of the compared card BIN import")?,
+        None => Vec::new(),
+    };
+
+    let diff = diff_card_bin_ranges(&compared_ranges, &ranges);
+    let to_records = |ranges: Vec<card_bin::CardBinRange>| -> Vec<_> {
+        ranges
+            .into_iter()
+            .map(cards_info_api_types::CardBinRangeRecord::foreign_from)
+            .collect()
+    };
+
+    Ok(ApplicationResponse::Json(
+        cards_info_api_types::CardBinImportDiffResponse {
+            version: card_bin_import.version,
+            compared_with,
+            added: to_records(diff.added),
+            removed: to_records(diff.removed),
+            modified: to_records(diff.modified),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn record(
+        range_start: &str,
+        range_end: Option<&str>,
+        card_issuer: &str,
+    ) -> cards_info_api_types::CardBinRangeRecord {
+        cards_info_api_types::CardBinRangeRecord {
+            range_start: range_start.to_string(),
+            range_end: range_end.map(str::to_string),
+            card_issuer: Some(card_issuer.to_string()),
+            card_network: None,
+            card_type: None,
+            card_subtype: None,
+            card_issuing_country: None,
+            bank_code_id: None,
+            bank_code: None,
+            country_code: None,
+        }
+    }
+
+    #[test]
+    fn test_bin_range_bounds_are_normalized() {
+        let range = to_card_bin_range(1, record("411111", Some("41111199"), "issuer")).unwrap();
+        assert_eq!(range.range_start, "4111110000000000000");
+        assert_eq!(range.range_end, "4111119999999999999");
+
+        let single_bin_range = to_card_bin_range(1, record("411111", None, "issuer")).unwrap();
+        assert_eq!(single_bin_range.range_start, "4111110000000000000");
+        assert_eq!(single_bin_range.range_end, "4111119999999999999");
+    }
+
+    #[test]
+    fn test_invalid_bin_ranges_are_rejected() {
+        assert!(to_card_bin_range(1, record("41111", None, "issuer")).is_err());
+        assert!(to_card_bin_range(1, record("41111a", None, "issuer")).is_err());
+        assert!(to_card_bin_range(1, record("422222", Some("411111"), "issuer")).is_err());
+        assert!(to_card_bin_ranges(
+            1,
+            vec![
+                record("411111", None, "issuer"),
+                record("411111", None, "issuer")
+            ]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_diff_card_bin_ranges() {
+        let old_ranges = to_card_bin_ranges(
+            1,
+            vec![
+                record("411111", None, "issuer"),
+                record("422222", None, "issuer"),
+                record("433333", None, "issuer"),
+            ],
+        )
+        .unwrap();
+        let new_ranges = to_card_bin_ranges(
+            2,
+            vec![
+                record("411111", None, "issuer"),
+                record("422222", None, "another_issuer"),
+                record("444444", None, "issuer"),
+            ],
+        )
+        .unwrap();
+
+        let diff = diff_card_bin_ranges(&old_ranges, &new_ranges);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].range_start, "4444440000000000000");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].range_start, "4333330000000000000");
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(
+            diff.modified[0].card_issuer.as_deref(),
+            Some("another_issuer")
+        );
+    }
+}
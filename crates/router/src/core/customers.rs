@@ -1,3 +1,6 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod data_subject_requests;
+
 use common_utils::{
     crypto::Encryptable,
     errors::ReportSwitchExt,
@@ -0,0 +1,1088 @@
+//! Data subject requests of customers. All the data linked to a customer can be exported as an
+//! encrypted archive, and erased after a grace period. The erasure redacts the customer, their
+//! addresses, their payments and the webhooks sent for them, and deletes their payment methods
+//! from the vault.
+
+use api_models::{
+    customers::{
+        CustomerDataArchive, CustomerDataArchivePaymentMethod, CustomerDataExportResponse,
+        CustomerDataExportRetrieveRequest, CustomerErasureRequestInternal, CustomerErasureResponse,
+        CustomerErasureStatus,
+    },
+    webhook_events::{OutgoingWebhookRequestContent, OutgoingWebhookResponseContent},
+};
+use common_utils::{
+    crypto::{self, EncodeMessage, Encryptable, GcmAes256},
+    date_time,
+    ext_traits::{Encode, StringExt},
+    generate_id, id_type, type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::{
+    payments::payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+    type_encryption::{crypto_operation, CryptoOperation},
+};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        customers::{self as customers_core, REDACTED},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType, DataSubjectRequestOperation},
+    routes::{metrics as router_metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::{self, mandates::MandateResponseExt},
+        domain, storage,
+        transformers::ForeignFrom,
+    },
+};
+
+const CUSTOMER_ERASURE_TAG: &str = "CUSTOMER_ERASURE";
+const CUSTOMER_ERASURE_NAME: &str = "CUSTOMER_ERASURE";
+const CUSTOMER_ERASURE_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::CustomerErasureWorkflow;
+
+/// Number of payments of the customer read from the database at a time
+const PAYMENTS_PAGE_SIZE: u32 = common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1;
+
+fn get_erasure_task_id(
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> String {
+    format!(
+        "{CUSTOMER_ERASURE_RUNNER}_{CUSTOMER_ERASURE_NAME}_{}_{}",
+        merchant_id.get_string_repr(),
+        customer_id.get_string_repr()
+    )
+}
+
+fn get_export_file_key(
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    export_id: &str,
+) -> String {
+    format!(
+        "data_subject_requests/{}/{}/{export_id}",
+        merchant_id.get_string_repr(),
+        customer_id.get_string_repr()
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn export_customer_data(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerDataExportResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let archive = Box::pin(get_customer_data_archive(
+        &state,
+        &merchant_context,
+        &customer_id,
+    ))
+    .await?;
+
+    let archive = serde_json::to_vec(&archive)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize customer data archive")?;
+
+    // Every archive is encrypted with its own key, which is handed out once and never stored
+    let archive_key = crypto::generate_cryptographically_secure_random_bytes::<32>();
+    let encrypted_archive = GcmAes256
+        .encode_message(&archive_key, &archive)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt customer data archive")?;
+
+    let export_id = generate_id(consts::ID_LENGTH, "dsr");
+    state
+        .file_storage_client
+        .upload_file(
+            &get_export_file_key(merchant_id, &customer_id, &export_id),
+            encrypted_archive,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store customer data archive")?;
+
+    emit_audit_event(
+        &state,
+        DataSubjectRequestOperation::Export,
+        merchant_id,
+        &customer_id,
+        &export_id,
+    );
+
+    Ok(ApplicationResponse::Json(CustomerDataExportResponse {
+        export_id,
+        customer_id,
+        archive_key: Secret::new(hex::encode(archive_key)),
+        created_at: date_time::now(),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_customer_data_export(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: CustomerDataExportRetrieveRequest,
+) -> RouterResponse<serde_json::Value> {
+    let encrypted_archive = state
+        .file_storage_client
+        .retrieve_file(&get_export_file_key(
+            merchant_context.get_merchant_account().get_id(),
+            &req.customer_id,
+            &req.export_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Customer data export {} not found", req.export_id),
+        })?;
+
+    Ok(ApplicationResponse::FileData((
+        encrypted_archive,
+        mime::APPLICATION_OCTET_STREAM,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn schedule_customer_erasure(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: CustomerErasureRequestInternal,
+) -> RouterResponse<CustomerErasureResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let customer_id = req.customer_id;
+
+    // Customers that are already redacted are accepted, so that an erasure that failed after
+    // redacting the customer can be requested again to erase the rest of their data
+    find_customer_to_erase(&state, &merchant_context, &customer_id).await?;
+
+    validate_no_active_mandates(db, merchant_id, &customer_id).await?;
+
+    let schedule_time = req.request.scheduled_at.unwrap_or_else(|| {
+        date_time::now().saturating_add(time::Duration::hours(i64::from(
+            state
+                .conf
+                .data_subject_requests
+                .erasure_grace_period_in_hours,
+        )))
+    });
+    let task_id = get_erasure_task_id(merchant_id, &customer_id);
+
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve customer erasure task")?;
+
+    let process = match existing_task {
+        Some(process) => db
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage::enums::ProcessTrackerStatus::New),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule customer erasure task")?,
+        None => {
+            let tracking_data = storage::CustomerErasureTrackingData {
+                merchant_id: merchant_id.clone(),
+                customer_id: customer_id.clone(),
+            };
+            let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+                task_id,
+                CUSTOMER_ERASURE_NAME,
+                CUSTOMER_ERASURE_RUNNER,
+                [CUSTOMER_ERASURE_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct customer erasure process tracker task")?;
+
+            let process = db
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert customer erasure process tracker task")?;
+            router_metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "CustomerErasure")),
+            );
+            process
+        }
+    };
+
+    emit_audit_event(
+        &state,
+        DataSubjectRequestOperation::ErasureScheduled,
+        merchant_id,
+        &customer_id,
+        &process.id,
+    );
+
+    Ok(ApplicationResponse::Json(get_erasure_response(
+        customer_id,
+        process,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_customer_erasure(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerErasureResponse> {
+    let process = find_erasure_task(
+        &*state.store,
+        merchant_context.get_merchant_account().get_id(),
+        &customer_id,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(get_erasure_response(
+        customer_id,
+        process,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn cancel_customer_erasure(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerErasureResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let process = find_erasure_task(db, merchant_id, &customer_id).await?;
+
+    if process.status == storage::enums::ProcessTrackerStatus::Finish {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Customer erasure is not scheduled".to_string(),
+        }));
+    }
+
+    db.as_scheduler()
+        .finish_process_with_business_status(process.clone(), business_status::REVOKED)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revoke customer erasure task")?;
+
+    emit_audit_event(
+        &state,
+        DataSubjectRequestOperation::ErasureCancelled,
+        merchant_id,
+        &customer_id,
+        &process.id,
+    );
+
+    Ok(ApplicationResponse::Json(CustomerErasureResponse {
+        status: CustomerErasureStatus::Cancelled,
+        updated_at: date_time::now(),
+        ..get_erasure_response(customer_id, process)
+    }))
+}
+
+/// Erases all the data linked to a customer. The personal details of their payments and the
+/// payloads of the webhooks sent for their payments, refunds, disputes and mandates are redacted
+/// first, after which the customer, their addresses and their payment methods are redacted as when
+/// the customer is deleted. Every step can be run again, so an erasure that failed part way is
+/// resumed by requesting it again. Payments and events are updated through the store, so that with
+/// the Kafka store a redaction event is published to the analytics topics for every redacted record.
+#[instrument(skip_all)]
+pub async fn erase_customer_data(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    let customer = find_customer_to_erase(state, merchant_context, customer_id).await?;
+
+    // Mandates may have been created since the erasure was scheduled
+    validate_no_active_mandates(
+        &*state.store,
+        merchant_context.get_merchant_account().get_id(),
+        customer_id,
+    )
+    .await?;
+
+    let erasure_steps = ErasureStep::get_pending_steps(is_customer_redacted(&customer));
+
+    for erasure_step in erasure_steps {
+        match erasure_step {
+            ErasureStep::RedactPayments => {
+                redact_customer_payments(state, merchant_context, customer_id).await?
+            }
+            ErasureStep::RedactEvents => {
+                redact_customer_events(state, merchant_context, customer_id).await?
+            }
+            ErasureStep::RedactCustomer => {
+                Box::pin(customers_core::delete_customer(
+                    state.clone(),
+                    merchant_context.clone(),
+                    customer_id.clone(),
+                ))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact the customer")?;
+            }
+        }
+        logger::debug!(?erasure_step, "Customer erasure step completed");
+    }
+
+    logger::info!(customer_id = ?customer_id, "Erased customer data");
+    Ok(())
+}
+
+/// Steps of the erasure of the data of a customer, in the order in which they are carried out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErasureStep {
+    RedactPayments,
+    RedactEvents,
+    RedactCustomer,
+}
+
+impl ErasureStep {
+    /// The payments and events are redacted on every run, as a previous run may have failed after
+    /// redacting only some of them. The customer is redacted last, and only once.
+    fn get_pending_steps(is_customer_redacted: bool) -> Vec<Self> {
+        if is_customer_redacted {
+            vec![Self::RedactPayments, Self::RedactEvents]
+        } else {
+            vec![
+                Self::RedactPayments,
+                Self::RedactEvents,
+                Self::RedactCustomer,
+            ]
+        }
+    }
+}
+
+/// The data of customers with active mandates is still needed for their recurring payments
+async fn validate_no_active_mandates(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    let has_active_mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve mandates of the customer")?
+        .iter()
+        .any(|mandate| mandate.mandate_status == storage::enums::MandateStatus::Active);
+
+    if has_active_mandates {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Customer has active mandates, revoke them before requesting an erasure"
+                .to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn is_customer_redacted(customer: &domain::Customer) -> bool {
+    is_redacted_name(customer.name.as_ref().map(|name| name.peek().as_str()))
+}
+
+fn is_redacted_name(name: Option<&str>) -> bool {
+    name == Some(REDACTED)
+}
+
+/// Finds the customer whose data is erased, including customers that are already redacted
+async fn find_customer_to_erase(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<domain::Customer> {
+    state
+        .store
+        .find_customer_optional_with_redacted_customer_details_by_customer_id_merchant_id(
+            &state.into(),
+            customer_id,
+            merchant_context.get_merchant_account().get_id(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the customer")?
+        .ok_or_else(|| report!(errors::ApiErrorResponse::CustomerNotFound))
+}
+
+async fn redact_customer_payments(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let redacted_details = cards::create_encrypted_data(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        serde_json::json!({}),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt redacted payment details")?;
+
+    let mut offset = 0;
+    loop {
+        let payment_intents =
+            list_customer_payment_intents(state, merchant_context, customer_id, offset).await?;
+        let page_size = payment_intents.len();
+
+        for payment_intent in payment_intents {
+            let payment_intent_update =
+                storage::PaymentIntentUpdate::CustomerDetailsRedactionUpdate {
+                    description: payment_intent
+                        .description
+                        .as_ref()
+                        .map(|_| REDACTED.to_string()),
+                    customer_details: payment_intent
+                        .customer_details
+                        .as_ref()
+                        .map(|_| redacted_details.clone()),
+                    billing_details: payment_intent
+                        .billing_details
+                        .as_ref()
+                        .map(|_| redacted_details.clone()),
+                    shipping_details: payment_intent
+                        .shipping_details
+                        .as_ref()
+                        .map(|_| redacted_details.clone()),
+                    updated_by: storage_scheme.to_string(),
+                };
+
+            let attempts = db
+                .find_attempts_by_merchant_id_payment_id(
+                    merchant_id,
+                    &payment_intent.payment_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve payment attempts of the customer")?;
+
+            for attempt in attempts
+                .into_iter()
+                .filter(|attempt| attempt.browser_info.is_some())
+            {
+                db.update_payment_attempt_with_attempt_id(
+                    attempt,
+                    storage::PaymentAttemptUpdate::CustomerDetailsRedactionUpdate {
+                        browser_info: Some(serde_json::json!({})),
+                        updated_by: storage_scheme.to_string(),
+                    },
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact payment attempt of the customer")?;
+            }
+
+            db.update_payment_intent(
+                key_manager_state,
+                payment_intent,
+                payment_intent_update,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to redact payment of the customer")?;
+        }
+
+        if page_size < usize::try_from(PAYMENTS_PAGE_SIZE).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += PAYMENTS_PAGE_SIZE;
+    }
+
+    Ok(())
+}
+
+/// Redacts the stored request and response of every delivery attempt of the webhooks sent for
+/// the payments, refunds, disputes and mandates of a customer
+async fn redact_customer_events(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let redacted_request = encrypt_event_content(
+        state,
+        key_store,
+        &OutgoingWebhookRequestContent {
+            body: Secret::new(serde_json::json!({}).to_string()),
+            headers: Vec::new(),
+        },
+    )
+    .await?;
+
+    for primary_object_id in
+        get_customer_event_object_ids(state, merchant_context, customer_id).await?
+    {
+        let initial_events = db
+            .list_initial_events_by_merchant_id_primary_object_id(
+                key_manager_state,
+                merchant_id,
+                &primary_object_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve events of the customer")?;
+
+        for initial_event in initial_events {
+            let delivery_attempts = db
+                .list_events_by_merchant_id_initial_attempt_id(
+                    key_manager_state,
+                    merchant_id,
+                    &initial_event.event_id,
+                    key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve delivery attempts of the event")?;
+
+            for event in delivery_attempts {
+                // Only the status code and the error of the delivery are kept from the response
+                let redacted_response = match &event.response {
+                    Some(response) => {
+                        let response_content: Option<OutgoingWebhookResponseContent> = response
+                            .peek()
+                            .parse_struct("OutgoingWebhookResponseContent")
+                            .ok();
+                        let redacted_response_content = OutgoingWebhookResponseContent {
+                            body: None,
+                            headers: None,
+                            status_code: response_content
+                                .as_ref()
+                                .and_then(|content| content.status_code),
+                            error_message: response_content
+                                .and_then(|content| content.error_message),
+                        };
+                        Some(
+                            encrypt_event_content(state, key_store, &redacted_response_content)
+                                .await?,
+                        )
+                    }
+                    None => None,
+                };
+
+                db.update_event_by_merchant_id_event_id(
+                    key_manager_state,
+                    merchant_id,
+                    &event.event_id,
+                    domain::EventUpdate::RedactionUpdate {
+                        request: event.request.as_ref().map(|_| redacted_request.clone()),
+                        response: redacted_response,
+                    },
+                    key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact event of the customer")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the identifiers of the objects of a customer for which webhooks may have been sent
+async fn get_customer_event_object_ids(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<Vec<String>> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let mut event_object_ids = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve mandates of the customer")?
+        .into_iter()
+        .map(|mandate| mandate.mandate_id)
+        .collect::<Vec<_>>();
+
+    let mut offset = 0;
+    loop {
+        let payment_intents =
+            list_customer_payment_intents(state, merchant_context, customer_id, offset).await?;
+        let page_size = payment_intents.len();
+
+        for payment_intent in payment_intents {
+            event_object_ids.extend(
+                db.find_refund_by_payment_id_merchant_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve refunds of the payment")?
+                .into_iter()
+                .map(|refund| refund.refund_id),
+            );
+            event_object_ids.extend(
+                db.find_disputes_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to retrieve disputes of the payment")?
+                    .into_iter()
+                    .map(|dispute| dispute.dispute_id),
+            );
+            event_object_ids.push(payment_intent.payment_id.get_string_repr().to_owned());
+        }
+
+        if page_size < usize::try_from(PAYMENTS_PAGE_SIZE).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += PAYMENTS_PAGE_SIZE;
+    }
+
+    Ok(event_object_ids)
+}
+
+async fn encrypt_event_content<T: serde::Serialize>(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    content: &T,
+) -> RouterResult<Encryptable<Secret<String>>> {
+    let content = content
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode redacted event content")?;
+
+    crypto_operation(
+        &state.into(),
+        type_name!(domain::Event),
+        CryptoOperation::Encrypt(Secret::new(content)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|value| value.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt redacted event content")
+}
+
+/// Records the erasure of the data of a customer in the audit log, once it is carried out
+pub fn record_customer_erasure(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    erasure_id: &str,
+) {
+    emit_audit_event(
+        state,
+        DataSubjectRequestOperation::Erasure,
+        merchant_context.get_merchant_account().get_id(),
+        customer_id,
+        erasure_id,
+    );
+}
+
+async fn get_customer_data_archive(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<CustomerDataArchive> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mut addresses = Vec::new();
+    let customer_address = match &customer.address_id {
+        Some(address_id) => {
+            let address = db
+                .find_address_by_address_id(key_manager_state, address_id, key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve address of the customer")?;
+            addresses.push(api::Address::foreign_from(address.clone()));
+            Some(api::AddressDetails::from(address))
+        }
+        None => None,
+    };
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve payment methods of the customer")?,
+    }
+    .into_iter()
+    .map(|payment_method| CustomerDataArchivePaymentMethod {
+        payment_method_id: payment_method.payment_method_id,
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        status: payment_method.status,
+        payment_method_data: payment_method
+            .payment_method_data
+            .map(|data| data.into_inner()),
+        payment_method_billing_address: payment_method
+            .payment_method_billing_address
+            .map(|address| address.into_inner()),
+        created_at: payment_method.created_at,
+        last_used_at: payment_method.last_used_at,
+    })
+    .collect();
+
+    let mut mandates = Vec::new();
+    let mut event_object_ids = Vec::new();
+    for mandate in db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve mandates of the customer")?
+    {
+        event_object_ids.push(mandate.mandate_id.clone());
+        mandates.push(
+            api::mandates::MandateResponse::from_db_mandate(
+                state,
+                key_store.clone(),
+                mandate,
+                merchant_context.get_merchant_account(),
+            )
+            .await?,
+        );
+    }
+
+    let mut payments = Vec::new();
+    let mut refunds = Vec::new();
+    let mut disputes = Vec::new();
+    let mut offset = 0;
+    loop {
+        let payment_intents =
+            list_customer_payment_intents(state, merchant_context, customer_id, offset).await?;
+        let page_size = payment_intents.len();
+
+        for payment_intent in payment_intents {
+            for address_id in [
+                &payment_intent.billing_address_id,
+                &payment_intent.shipping_address_id,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                match db
+                    .find_address_by_merchant_id_payment_id_address_id(
+                        key_manager_state,
+                        merchant_id,
+                        &payment_intent.payment_id,
+                        address_id,
+                        key_store,
+                        storage_scheme,
+                    )
+                    .await
+                {
+                    Ok(payment_address) => {
+                        addresses.push(api::Address::foreign_from(payment_address.address))
+                    }
+                    Err(error) if error.current_context().is_db_not_found() => {}
+                    Err(error) => Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to retrieve address of the payment")?,
+                }
+            }
+
+            for refund in db
+                .find_refund_by_payment_id_merchant_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve refunds of the payment")?
+            {
+                event_object_ids.push(refund.refund_id.clone());
+                refunds.push(api::RefundResponse::foreign_from(refund));
+            }
+
+            for dispute in db
+                .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to retrieve disputes of the payment")?
+            {
+                event_object_ids.push(dispute.dispute_id.clone());
+                disputes.push(api_models::disputes::DisputeResponse::foreign_from(dispute));
+            }
+
+            event_object_ids.push(payment_intent.payment_id.get_string_repr().to_owned());
+            match db
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    &payment_intent.active_attempt.get_id(),
+                    // since OLAP doesn't have KV. Force to get the data from PSQL.
+                    storage::enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+            {
+                Ok(payment_attempt) => payments.push(api::PaymentsResponse::foreign_from((
+                    payment_intent,
+                    payment_attempt,
+                ))),
+                Err(error) if error.current_context().is_db_not_found() => {
+                    logger::warn!(
+                        ?error,
+                        "payment_attempts missing for payment_id : {:?}",
+                        payment_intent.payment_id,
+                    );
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to retrieve payment attempt of the customer")?,
+            }
+        }
+
+        if page_size < usize::try_from(PAYMENTS_PAGE_SIZE).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += PAYMENTS_PAGE_SIZE;
+    }
+
+    let mut events = Vec::new();
+    for primary_object_id in event_object_ids {
+        let object_events = db
+            .list_initial_events_by_merchant_id_primary_object_id(
+                key_manager_state,
+                merchant_id,
+                &primary_object_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve events of the customer")?;
+
+        events.extend(
+            object_events.into_iter().filter_map(|event| {
+                api::webhook_events::EventListItemResponse::try_from(event).ok()
+            }),
+        );
+    }
+
+    Ok(CustomerDataArchive {
+        customer: api::CustomerResponse::foreign_from((customer, customer_address)).into_inner(),
+        addresses,
+        payment_methods,
+        mandates,
+        payments,
+        refunds,
+        disputes,
+        events,
+        exported_at: date_time::now(),
+    })
+}
+
+async fn list_customer_payment_intents(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::CustomerId,
+    offset: u32,
+) -> RouterResult<Vec<storage::PaymentIntent>> {
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: Some(customer_id.clone()),
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(PAYMENTS_PAGE_SIZE),
+        order: Default::default(),
+        card_network: None,
+        card_discovery: None,
+        merchant_order_reference_id: None,
+    }));
+
+    state
+        .store
+        .filter_payment_intent_by_constraints(
+            &state.into(),
+            merchant_context.get_merchant_account().get_id(),
+            &constraints,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve payments of the customer")
+}
+
+async fn find_erasure_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<storage::ProcessTracker> {
+    db.find_process_by_id(&get_erasure_task_id(merchant_id, customer_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve customer erasure task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!(
+                    "No erasure requested for customer {}",
+                    customer_id.get_string_repr()
+                ),
+            })
+        })
+}
+
+fn get_erasure_response(
+    customer_id: id_type::CustomerId,
+    process: storage::ProcessTracker,
+) -> CustomerErasureResponse {
+    let status = match (process.status, process.business_status.as_str()) {
+        (storage::enums::ProcessTrackerStatus::Finish, business_status::COMPLETED_BY_PT) => {
+            CustomerErasureStatus::Completed
+        }
+        (storage::enums::ProcessTrackerStatus::Finish, business_status::REVOKED) => {
+            CustomerErasureStatus::Cancelled
+        }
+        (storage::enums::ProcessTrackerStatus::Finish, _) => CustomerErasureStatus::Failed,
+        _ => CustomerErasureStatus::Scheduled,
+    };
+
+    CustomerErasureResponse {
+        erasure_id: process.id,
+        customer_id,
+        status,
+        scheduled_at: process.schedule_time.unwrap_or(process.created_at),
+        updated_at: process.updated_at,
+    }
+}
+
+fn emit_audit_event(
+    state: &SessionState,
+    operation: DataSubjectRequestOperation,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    request_id: &str,
+) {
+    logger::info!(?operation, request_id, "Data subject request processed");
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::DataSubjectRequest {
+            operation,
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            request_id: request_id.to_owned(),
+        }))
+        .emit();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erasure_redacts_payments_and_events_before_the_customer() {
+        assert_eq!(
+            ErasureStep::get_pending_steps(false),
+            vec![
+                ErasureStep::RedactPayments,
+                ErasureStep::RedactEvents,
+                ErasureStep::RedactCustomer
+            ]
+        );
+    }
+
+    #[test]
+    fn test_erasure_resumes_after_the_customer_was_redacted() {
+        // An erasure that failed after the customer was redacted only redacts the payments and
+        // events again
+        assert_eq!(
+            ErasureStep::get_pending_steps(true),
+            vec![ErasureStep::RedactPayments, ErasureStep::RedactEvents]
+        );
+    }
+
+    #[test]
+    fn test_erasure_after_failure_while_redacting_payments() {
+        // A failure while redacting the payments leaves the customer unredacted, so that the
+        // erasure can be requested again and runs every step
+        let customer_name = Some("John Doe");
+        assert!(!is_redacted_name(customer_name));
+        assert_eq!(
+            ErasureStep::get_pending_steps(is_redacted_name(customer_name)),
+            vec![
+                ErasureStep::RedactPayments,
+                ErasureStep::RedactEvents,
+                ErasureStep::RedactCustomer
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_redacted_name() {
+        assert!(is_redacted_name(Some(REDACTED)));
+        assert!(!is_redacted_name(None));
+        assert!(!is_redacted_name(Some("redacted")));
+    }
+}
@@ -4,7 +4,7 @@ use api_models::{enums as api_enums, open_router};
 use common_enums::enums;
 use common_utils::id_type;
 use error_stack::ResultExt;
-use masking::Secret;
+use masking::{PeekInterface, Secret};
 
 use super::{
     payments::{OperationSessionGetters, OperationSessionSetters},
@@ -314,6 +314,17 @@ pub async fn get_debit_routing_output<
                 return None;
             }
 
+            if co_badged_card_data.is_none() {
+                if let Some(card_isin) = card_isin.as_ref() {
+                    if !is_debit_card_as_per_bin_data(state, card_isin).await {
+                        logger::debug!(
+                            "BIN data identifies the card as non debit; skipping routing"
+                        );
+                        return None;
+                    }
+                }
+            }
+
             let co_badged_card_request = open_router::CoBadgedCardRequest {
                 merchant_category_code: enums::MerchantCategoryCode::Mcc0001,
                 acquirer_country,
@@ -335,6 +346,19 @@ pub async fn get_debit_routing_output<
     }
 }
 
+/// Cards without a card type in the BIN data are trusted to be the debit cards they are declared as
+async fn is_debit_card_as_per_bin_data(state: &SessionState, card_isin: &Secret<String>) -> bool {
+    state
+        .store
+        .get_card_info_with_bin_range_fallback(card_isin.peek())
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to fetch the BIN data of the card"))
+        .ok()
+        .flatten()
+        .and_then(|card_info| card_info.card_type)
+        .map_or(true, |card_type| card_type.eq_ignore_ascii_case("debit"))
+}
+
 fn extract_saved_card_info<F, D>(
     payment_data: &D,
 ) -> (
@@ -7,6 +7,8 @@ use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod evidence_builder;
 pub mod transformers;
 
 use super::{
@@ -15,7 +17,8 @@ use super::{
 };
 use crate::{
     core::{files, payments, utils as core_utils},
-    routes::SessionState,
+    db::StorageInterface,
+    routes::{metrics as router_metrics, SessionState},
     services,
     types::{
         api::{self, disputes},
@@ -25,8 +28,14 @@ use crate::{
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
+    utils::OptionExt,
 };
 
+const DISPUTE_DEADLINE_TAG: &str = "DISPUTE";
+const DISPUTE_DEADLINE_NAME: &str = "DISPUTE_DEADLINE";
+const DISPUTE_DEADLINE_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::DisputeDeadlineWorkflow;
+
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
     state: SessionState,
@@ -292,6 +301,30 @@ pub async fn submit_evidence(
             })
         },
     )?;
+    // The text evidence is stored before it is sent to the connector, so that it is retained
+    // when the evidence is drafted again after a failed submission
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceUpdate {
+        evidence: transformers::update_dispute_text_evidence(dispute_evidence, &req)
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error while encoding dispute evidence")?
+            .into(),
+    };
+    let dispute = db
+        .update_dispute(dispute, update_dispute)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.to_owned(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
     let submit_evidence_request_data =
         transformers::get_evidence_request_data(&state, &merchant_context, req, &dispute).await?;
 
@@ -421,6 +454,63 @@ pub async fn submit_evidence(
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip(state))]
+pub async fn draft_evidence(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::DisputeEvidenceDraftResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_context.get_merchant_account().get_id(),
+            &req.dispute_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                "Evidence cannot be drafted because the dispute is in {} stage and has {} status",
+                dispute.dispute_stage, dispute.dispute_status
+            ),
+            })
+        },
+    )?;
+    let stored_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let evidence_sources =
+        evidence_builder::collect_evidence_sources(&state, &merchant_context, &dispute).await?;
+    let evidence =
+        evidence_builder::build_evidence_request(&dispute, stored_evidence, &evidence_sources);
+    let summary_document = evidence_builder::render_evidence_summary(&dispute, &evidence);
+    let summary_file_id = evidence_builder::store_evidence_summary(
+        &state,
+        &merchant_context,
+        &dispute,
+        summary_document,
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidenceDraftResponse {
+            evidence,
+            summary_file_id,
+        },
+    ))
+}
+
 pub async fn attach_evidence(
     state: SessionState,
     merchant_context: domain::MerchantContext,
@@ -600,3 +690,270 @@ pub async fn get_aggregates_for_disputes(
         },
     ))
 }
+
+// Schedule the dispute_deadline task in the process_tracker table.
+// Disputes which are to be accepted as per the profile dispute policy are picked up right away,
+// the rest are scheduled for the first reminder prior to the evidence deadline.
+// The task of a dispute is rescheduled when its evidence deadline changes, and reminders and the
+// deadline check are rescheduled in execute_workflow() based on the dispute state.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn schedule_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    dispute: &diesel_models::dispute::Dispute,
+    business_profile: &domain::Profile,
+) -> errors::CustomResult<(), errors::ApiErrorResponse> {
+    let Some(challenge_required_by) = dispute.challenge_required_by else {
+        return Ok(());
+    };
+
+    if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened {
+        return Ok(());
+    }
+
+    let schedule_time = get_dispute_deadline_task_schedule_time(
+        &business_profile.dispute_policy.clone().unwrap_or_default(),
+        dispute.dispute_amount,
+        challenge_required_by,
+        common_utils::date_time::now(),
+    );
+
+    let process_tracker_id = format!(
+        "{DISPUTE_DEADLINE_RUNNER}_{DISPUTE_DEADLINE_NAME}_{}",
+        dispute.dispute_id
+    );
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute deadline task")?;
+
+    if let Some(existing_process) = existing_process {
+        return db
+            .as_scheduler()
+            .reset_process(existing_process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while rescheduling dispute deadline task: {}",
+                    dispute.dispute_id
+                )
+            });
+    }
+
+    let dispute_deadline_tracker = diesel_models::DisputeDeadlineTrackingData {
+        dispute_id: dispute.dispute_id.clone(),
+        merchant_id: dispute.merchant_id.clone(),
+        profile_id: dispute
+            .profile_id
+            .clone()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not find profile_id in dispute")?,
+    };
+
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        process_tracker_id,
+        DISPUTE_DEADLINE_NAME,
+        DISPUTE_DEADLINE_RUNNER,
+        [DISPUTE_DEADLINE_TAG],
+        dispute_deadline_tracker,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct dispute deadline process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting dispute deadline task to process_tracker: {}",
+                dispute.dispute_id
+            )
+        })?;
+    router_metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "DisputeDeadline")),
+    );
+
+    Ok(())
+}
+
+/// Returns the time at which the dispute deadline task of an open dispute is to be run first
+pub fn get_dispute_deadline_task_schedule_time(
+    dispute_policy: &common_types::disputes::DisputePolicy,
+    dispute_amount: common_utils::types::MinorUnit,
+    challenge_required_by: time::PrimitiveDateTime,
+    current_time: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    if dispute_policy.should_auto_accept(dispute_amount) {
+        current_time
+    } else {
+        get_dispute_deadline_schedule_time(
+            challenge_required_by,
+            &dispute_policy.reminder_hours_before_deadline,
+            current_time,
+        )
+    }
+}
+
+/// Returns the latest reminder which is due, as the hours before the evidence deadline it was
+/// configured at
+pub fn get_due_dispute_deadline_reminder(
+    challenge_required_by: time::PrimitiveDateTime,
+    reminder_hours_before_deadline: &[u16],
+    current_time: time::PrimitiveDateTime,
+) -> Option<u16> {
+    reminder_hours_before_deadline
+        .iter()
+        .filter(|hours| {
+            challenge_required_by.saturating_sub(time::Duration::hours(i64::from(**hours)))
+                <= current_time
+        })
+        .min()
+        .copied()
+}
+
+/// Returns the earliest reminder time which is still in the future, or the evidence deadline
+/// itself once all the reminders are due.
+pub fn get_dispute_deadline_schedule_time(
+    challenge_required_by: time::PrimitiveDateTime,
+    reminder_hours_before_deadline: &[u16],
+    current_time: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    reminder_hours_before_deadline
+        .iter()
+        .map(|hours| challenge_required_by.saturating_sub(time::Duration::hours(i64::from(*hours))))
+        .filter(|reminder_time| *reminder_time > current_time)
+        .min()
+        .unwrap_or(challenge_required_by)
+}
+
+#[cfg(test)]
+mod tests {
+    use common_types::disputes::DisputePolicy;
+    use common_utils::types::MinorUnit;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_dispute_policy(auto_accept_below_amount: Option<i64>) -> DisputePolicy {
+        DisputePolicy {
+            auto_accept_below_amount: auto_accept_below_amount.map(MinorUnit::new),
+            reminder_hours_before_deadline: vec![24, 72],
+        }
+    }
+
+    #[test]
+    fn test_dispute_deadline_schedule_time() {
+        let challenge_required_by = datetime!(2025-06-10 12:00);
+        let reminder_hours = [24, 72];
+
+        // The earliest reminder which is still in the future
+        assert_eq!(
+            get_dispute_deadline_schedule_time(
+                challenge_required_by,
+                &reminder_hours,
+                datetime!(2025-06-01 00:00)
+            ),
+            datetime!(2025-06-07 12:00)
+        );
+        assert_eq!(
+            get_dispute_deadline_schedule_time(
+                challenge_required_by,
+                &reminder_hours,
+                datetime!(2025-06-08 00:00)
+            ),
+            datetime!(2025-06-09 12:00)
+        );
+
+        // The deadline check once all the reminders are due
+        assert_eq!(
+            get_dispute_deadline_schedule_time(
+                challenge_required_by,
+                &reminder_hours,
+                datetime!(2025-06-09 12:00)
+            ),
+            challenge_required_by
+        );
+        assert_eq!(
+            get_dispute_deadline_schedule_time(
+                challenge_required_by,
+                &[],
+                datetime!(2025-06-01 00:00)
+            ),
+            challenge_required_by
+        );
+    }
+
+    #[test]
+    fn test_dispute_deadline_task_schedule_time() {
+        let challenge_required_by = datetime!(2025-06-10 12:00);
+        let current_time = datetime!(2025-06-01 00:00);
+
+        // Disputes below the threshold are picked up right away to be accepted
+        assert_eq!(
+            get_dispute_deadline_task_schedule_time(
+                &get_dispute_policy(Some(1000)),
+                MinorUnit::new(999),
+                challenge_required_by,
+                current_time
+            ),
+            current_time
+        );
+        assert_eq!(
+            get_dispute_deadline_task_schedule_time(
+                &get_dispute_policy(Some(1000)),
+                MinorUnit::new(1000),
+                challenge_required_by,
+                current_time
+            ),
+            datetime!(2025-06-07 12:00)
+        );
+        assert_eq!(
+            get_dispute_deadline_task_schedule_time(
+                &get_dispute_policy(None),
+                MinorUnit::new(1),
+                challenge_required_by,
+                current_time
+            ),
+            datetime!(2025-06-07 12:00)
+        );
+    }
+
+    #[test]
+    fn test_due_dispute_deadline_reminder() {
+        let challenge_required_by = datetime!(2025-06-10 12:00);
+        let reminder_hours = [72, 24];
+
+        assert_eq!(
+            get_due_dispute_deadline_reminder(
+                challenge_required_by,
+                &reminder_hours,
+                datetime!(2025-06-07 11:59)
+            ),
+            None
+        );
+        assert_eq!(
+            get_due_dispute_deadline_reminder(
+                challenge_required_by,
+                &reminder_hours,
+                datetime!(2025-06-07 12:00)
+            ),
+            Some(72)
+        );
+        assert_eq!(
+            get_due_dispute_deadline_reminder(
+                challenge_required_by,
+                &reminder_hours,
+                datetime!(2025-06-09 13:00)
+            ),
+            Some(24)
+        );
+    }
+}
@@ -0,0 +1,756 @@
+use api_models::{disputes::SubmitEvidenceRequest, payments as payment_types};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use masking::PeekInterface;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        payments::helpers as payment_helpers,
+    },
+    routes::SessionState,
+    types::{
+        api::DisputeEvidence,
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Number of prior successful payments of the customer included in the evidence
+const PRIOR_PAYMENTS_LIMIT: u32 = 10;
+
+/// Maximum number of text lines rendered on a single page of the summary document
+const SUMMARY_LINES_PER_PAGE: usize = 50;
+
+/// Maximum number of characters rendered on a single line of the summary document
+const SUMMARY_LINE_WIDTH: usize = 95;
+
+/// Data already available for a disputed payment, from which evidence is assembled
+pub struct EvidenceSources {
+    pub payment_intent: storage::PaymentIntent,
+    pub payment_attempt: storage::PaymentAttempt,
+    pub billing_address: Option<payment_types::Address>,
+    pub shipping_address: Option<payment_types::Address>,
+    pub order_details: Vec<payment_types::OrderDetailsWithAmount>,
+    pub additional_card_info: Option<payment_types::AdditionalCardInfo>,
+    pub refunds: Vec<storage::Refund>,
+    pub prior_payments: Vec<storage::PaymentIntent>,
+}
+
+pub async fn collect_evidence_sources(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &diesel_models::dispute::Dispute,
+) -> RouterResult<EvidenceSources> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &dispute.payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let billing_address = payment_helpers::get_address_by_id(
+        state,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?
+    .map(payment_types::Address::foreign_from);
+
+    let shipping_address = payment_helpers::get_address_by_id(
+        state,
+        payment_intent.shipping_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?
+    .map(payment_types::Address::foreign_from);
+
+    let order_details = payment_intent
+        .order_details
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|order_detail| {
+            order_detail
+                .parse_value("OrderDetailsWithAmount")
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "OrderDetailsWithAmount",
+                })
+                .attach_printable("Unable to parse OrderDetailsWithAmount")
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The payment method data is a free form record, a failure in parsing it should not block
+    // the evidence assembly
+    let additional_card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| {
+            data.parse_value::<payment_types::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            payment_types::AdditionalPaymentData::Card(card_info) => Some(*card_info),
+            _ => None,
+        });
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(&dispute.payment_id, merchant_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refunds for the disputed payment")?;
+
+    let prior_payments = match payment_intent.customer_id.clone() {
+        Some(customer_id) => {
+            let constraints =
+                PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                    offset: 0,
+                    starting_at: None,
+                    ending_at: Some(payment_intent.created_at),
+                    amount_filter: None,
+                    connector: None,
+                    currency: None,
+                    status: Some(vec![storage_enums::IntentStatus::Succeeded]),
+                    payment_method: None,
+                    payment_method_type: None,
+                    authentication_type: None,
+                    merchant_connector_id: None,
+                    profile_id: None,
+                    customer_id: Some(customer_id),
+                    starting_after_id: None,
+                    ending_before_id: None,
+                    limit: Some(PRIOR_PAYMENTS_LIMIT),
+                    order: Default::default(),
+                    card_network: None,
+                    card_discovery: None,
+                    merchant_order_reference_id: None,
+                }));
+            db.filter_payment_intent_by_constraints(
+                &state.into(),
+                merchant_id,
+                &constraints,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch prior payments of the customer")?
+            .into_iter()
+            .filter(|prior_payment| prior_payment.payment_id != payment_intent.payment_id)
+            .collect()
+        }
+        None => vec![],
+    };
+
+    Ok(EvidenceSources {
+        payment_intent,
+        payment_attempt,
+        billing_address,
+        shipping_address,
+        order_details,
+        additional_card_info,
+        refunds,
+        prior_payments,
+    })
+}
+
+/// Pre-fills the evidence request with the data available for the payment. Evidence already
+/// attached or submitted for the dispute is retained, only empty text fields are filled and the
+/// generated statements are added to the text the merchant entered
+pub fn build_evidence_request(
+    dispute: &diesel_models::dispute::Dispute,
+    stored_evidence: DisputeEvidence,
+    sources: &EvidenceSources,
+) -> SubmitEvidenceRequest {
+    let product_description = if sources.order_details.is_empty() {
+        sources.payment_intent.description.clone()
+    } else {
+        Some(
+            sources
+                .order_details
+                .iter()
+                .map(|order_detail| {
+                    format!("{} x {}", order_detail.quantity, order_detail.product_name)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    };
+
+    let customer_purchase_ip = sources
+        .payment_attempt
+        .browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.get("ip_address"))
+        .and_then(|ip_address| ip_address.as_str())
+        .map(ToString::to_string);
+
+    let generated_evidence = SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        billing_address: sources.billing_address.as_ref().and_then(format_address),
+        customer_email_address: sources
+            .billing_address
+            .as_ref()
+            .and_then(|address| address.email.as_ref())
+            .map(|email| email.peek().to_string()),
+        customer_name: sources.billing_address.as_ref().and_then(get_customer_name),
+        customer_purchase_ip,
+        product_description,
+        shipping_address: sources.shipping_address.as_ref().and_then(format_address),
+        uncategorized_text: Some(get_evidence_statements(sources).join("\n")),
+        ..Default::default()
+    };
+    merge_with_stored_evidence(stored_evidence, generated_evidence)
+}
+
+/// Text the merchant entered takes precedence over the generated text
+fn fill_if_empty(stored: Option<String>, generated: Option<String>) -> Option<String> {
+    stored
+        .filter(|stored| !stored.trim().is_empty())
+        .or(generated)
+}
+
+fn merge_uncategorized_text(stored: Option<String>, generated: Option<String>) -> Option<String> {
+    match (stored.filter(|stored| !stored.trim().is_empty()), generated) {
+        (Some(stored), Some(generated)) if stored.contains(&generated) => Some(stored),
+        (Some(stored), Some(generated)) => Some(format!("{stored}\n\n{generated}")),
+        (stored, generated) => stored.or(generated),
+    }
+}
+
+fn merge_with_stored_evidence(
+    stored: DisputeEvidence,
+    generated: SubmitEvidenceRequest,
+) -> SubmitEvidenceRequest {
+    SubmitEvidenceRequest {
+        dispute_id: generated.dispute_id,
+        access_activity_log: fill_if_empty(
+            stored.access_activity_log,
+            generated.access_activity_log,
+        ),
+        billing_address: fill_if_empty(stored.billing_address, generated.billing_address),
+        cancellation_policy_disclosure: fill_if_empty(
+            stored.cancellation_policy_disclosure,
+            generated.cancellation_policy_disclosure,
+        ),
+        cancellation_rebuttal: fill_if_empty(
+            stored.cancellation_rebuttal,
+            generated.cancellation_rebuttal,
+        ),
+        customer_email_address: fill_if_empty(
+            stored.customer_email_address,
+            generated.customer_email_address,
+        ),
+        customer_name: fill_if_empty(stored.customer_name, generated.customer_name),
+        customer_purchase_ip: fill_if_empty(
+            stored.customer_purchase_ip,
+            generated.customer_purchase_ip,
+        ),
+        product_description: fill_if_empty(
+            stored.product_description,
+            generated.product_description,
+        ),
+        refund_policy_disclosure: fill_if_empty(
+            stored.refund_policy_disclosure,
+            generated.refund_policy_disclosure,
+        ),
+        refund_refusal_explanation: fill_if_empty(
+            stored.refund_refusal_explanation,
+            generated.refund_refusal_explanation,
+        ),
+        service_date: fill_if_empty(stored.service_date, generated.service_date),
+        shipping_address: fill_if_empty(stored.shipping_address, generated.shipping_address),
+        shipping_carrier: fill_if_empty(stored.shipping_carrier, generated.shipping_carrier),
+        shipping_date: fill_if_empty(stored.shipping_date, generated.shipping_date),
+        shipping_tracking_number: fill_if_empty(
+            stored.shipping_tracking_number,
+            generated.shipping_tracking_number,
+        ),
+        uncategorized_text: merge_uncategorized_text(
+            stored.uncategorized_text,
+            generated.uncategorized_text,
+        ),
+        cancellation_policy: stored.cancellation_policy,
+        customer_communication: stored.customer_communication,
+        customer_signature: stored.customer_signature,
+        receipt: stored.receipt,
+        refund_policy: stored.refund_policy,
+        service_documentation: stored.service_documentation,
+        shipping_documentation: stored.shipping_documentation,
+        invoice_showing_distinct_transactions: stored.invoice_showing_distinct_transactions,
+        recurring_transaction_agreement: stored.recurring_transaction_agreement,
+        uncategorized_file: stored.uncategorized_file,
+    }
+}
+
+fn format_address(address: &payment_types::Address) -> Option<String> {
+    address.address.as_ref().map(|details| {
+        [
+            details.line1.as_ref().map(|line| line.peek().to_owned()),
+            details.line2.as_ref().map(|line| line.peek().to_owned()),
+            details.line3.as_ref().map(|line| line.peek().to_owned()),
+            details.city.clone(),
+            details.state.as_ref().map(|state| state.peek().to_owned()),
+            details.zip.as_ref().map(|zip| zip.peek().to_owned()),
+            details.country.map(|country| country.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ")
+    })
+}
+
+fn get_customer_name(address: &payment_types::Address) -> Option<String> {
+    address.address.as_ref().and_then(|details| {
+        match (details.first_name.as_ref(), details.last_name.as_ref()) {
+            (Some(first_name), Some(last_name)) => {
+                Some(format!("{} {}", first_name.peek(), last_name.peek()))
+            }
+            (Some(name), None) | (None, Some(name)) => Some(name.peek().to_owned()),
+            (None, None) => None,
+        }
+    })
+}
+
+/// Statements describing the authentication, payment checks, refunds and customer history of
+/// the disputed payment
+fn get_evidence_statements(sources: &EvidenceSources) -> Vec<String> {
+    let mut statements = vec![];
+
+    let payment_attempt = &sources.payment_attempt;
+    statements.push(format!(
+        "Authentication type: {}",
+        payment_attempt
+            .authentication_type
+            .map(|authentication_type| authentication_type.to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    ));
+    if payment_attempt.external_three_ds_authentication_attempted == Some(true) {
+        statements.push(format!(
+            "External 3DS authentication attempted via {}",
+            payment_attempt
+                .authentication_connector
+                .as_deref()
+                .unwrap_or("unknown authentication connector")
+        ));
+    }
+    if let Some(card_info) = sources.additional_card_info.as_ref() {
+        if let Some(card_network) = card_info.card_network.as_ref() {
+            statements.push(format!("Card network: {card_network}"));
+        }
+        if let Some(last4) = card_info.last4.as_ref() {
+            statements.push(format!("Card last 4 digits: {last4}"));
+        }
+        if let Some(authentication_data) = card_info.authentication_data.as_ref() {
+            statements.push(format!("3DS authentication result: {authentication_data}"));
+        }
+        if let Some(payment_checks) = card_info.payment_checks.as_ref() {
+            statements.push(format!("AVS and CVV check results: {payment_checks}"));
+        }
+    }
+
+    if sources.refunds.is_empty() {
+        statements.push("No refunds were requested for this payment".to_string());
+    } else {
+        for refund in sources.refunds.iter() {
+            statements.push(format!(
+                "Refund {} of {} {} with status {} created at {}",
+                refund.refund_id,
+                refund.refund_amount,
+                refund.currency,
+                refund.refund_status,
+                refund.created_at
+            ));
+        }
+    }
+
+    if !sources.prior_payments.is_empty() {
+        statements.push(format!(
+            "The customer has {} prior successful payment(s) with the merchant",
+            sources.prior_payments.len()
+        ));
+        for prior_payment in sources.prior_payments.iter() {
+            statements.push(format!(
+                "Payment {} of {} {} created at {}",
+                prior_payment.payment_id.get_string_repr(),
+                prior_payment.amount,
+                prior_payment
+                    .currency
+                    .map(|currency| currency.to_string())
+                    .unwrap_or_default(),
+                prior_payment.created_at
+            ));
+        }
+    }
+
+    statements
+}
+
+/// Stores the evidence summary document through the file storage module and returns its file_id
+pub async fn store_evidence_summary(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &diesel_models::dispute::Dispute,
+    summary_document: Vec<u8>,
+) -> RouterResult<String> {
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!(
+        "{}/{}",
+        merchant_context
+            .get_merchant_account()
+            .get_id()
+            .get_string_repr(),
+        file_id
+    );
+    let file_size = i32::try_from(summary_document.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Evidence summary document is too large")?;
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+        file_name: Some(format!("{}_evidence_summary.pdf", dispute.dispute_id)),
+        file_size,
+        file_type: mime::APPLICATION_PDF.to_string(),
+        provider_file_id: None,
+        file_upload_provider: None,
+        available: false,
+        connector_label: None,
+        profile_id: dispute.profile_id.clone(),
+        merchant_connector_id: dispute.merchant_connector_id.clone(),
+    };
+    let file_metadata_object = state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata")?;
+    state
+        .file_storage_client
+        .upload_file(&file_key, summary_document)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the evidence summary document")?;
+    let update_file_metadata = diesel_models::file::FileMetadataUpdate::Update {
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(storage_enums::FileUploadProvider::Router),
+        available: true,
+        profile_id: dispute.profile_id.clone(),
+        merchant_connector_id: dispute.merchant_connector_id.clone(),
+    };
+    state
+        .store
+        .update_file_metadata(file_metadata_object, update_file_metadata)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update file_metadata with file_id: {file_id}")
+        })?;
+    Ok(file_id)
+}
+
+/// Renders the pre-filled evidence as a PDF document for the merchant to review
+pub fn render_evidence_summary(
+    dispute: &diesel_models::dispute::Dispute,
+    evidence: &SubmitEvidenceRequest,
+) -> Vec<u8> {
+    let mut lines = vec![
+        format!("Dispute evidence summary for {}", dispute.dispute_id),
+        String::new(),
+        format!("Payment: {}", dispute.payment_id.get_string_repr()),
+        format!("Connector: {}", dispute.connector),
+        format!("Disputed amount: {} {}", dispute.amount, dispute.currency),
+        format!(
+            "Reason: {}",
+            dispute
+                .connector_reason
+                .as_deref()
+                .unwrap_or("not provided")
+        ),
+        String::new(),
+    ];
+    let fields = [
+        ("Customer name", &evidence.customer_name),
+        ("Customer email", &evidence.customer_email_address),
+        ("Customer purchase IP", &evidence.customer_purchase_ip),
+        ("Billing address", &evidence.billing_address),
+        ("Shipping address", &evidence.shipping_address),
+        ("Product description", &evidence.product_description),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value {
+            lines.push(format!("{label}: {value}"));
+        }
+    }
+    if let Some(statements) = evidence.uncategorized_text.as_ref() {
+        lines.push(String::new());
+        lines.extend(statements.lines().map(ToString::to_string));
+    }
+    render_pdf(&lines)
+}
+
+/// Minimal single font PDF writer, long lines are wrapped and content is paginated
+fn render_pdf(lines: &[String]) -> Vec<u8> {
+    let wrapped_lines = lines
+        .iter()
+        .flat_map(|line| {
+            let characters = line
+                .chars()
+                .map(|character| {
+                    if character.is_ascii() && !character.is_ascii_control() {
+                        character
+                    } else {
+                        '?'
+                    }
+                })
+                .collect::<Vec<_>>();
+            if characters.is_empty() {
+                vec![String::new()]
+            } else {
+                characters
+                    .chunks(SUMMARY_LINE_WIDTH)
+                    .map(|chunk| chunk.iter().collect::<String>())
+                    .collect()
+            }
+        })
+        .collect::<Vec<_>>();
+    let pages = wrapped_lines
+        .chunks(SUMMARY_LINES_PER_PAGE)
+        .map(|page_lines| {
+            let text = page_lines
+                .iter()
+                .map(|line| {
+                    let escaped = line
+                        .replace('\\', "\\\\")
+                        .replace('(', "\\(")
+                        .replace(')', "\\)");
+                    format!("({escaped}) Tj T*")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("BT\n/F1 10 Tf\n14 TL\n50 800 Td\n{text}\nET")
+        })
+        .collect::<Vec<_>>();
+
+    // Object 1 is the catalog, 2 the page tree and 3 the font, every page takes two objects
+    let page_object_ids = (0..pages.len())
+        .map(|index| 4 + 2 * index)
+        .collect::<Vec<_>>();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_object_ids
+                .iter()
+                .map(|object_id| format!("{object_id} 0 R"))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+    for (page, page_object_id) in pages.iter().zip(page_object_ids.iter()) {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            page_object_id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{page}\nendstream",
+            page.len()
+        ));
+    }
+
+    let mut document = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+        document.push_str(&format!("{} 0 obj\n{object}\nendobj\n", index + 1));
+    }
+    let xref_offset = document.len();
+    document.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        document.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    document.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    ));
+    document.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_address() -> payment_types::Address {
+        payment_types::Address {
+            address: Some(payment_types::AddressDetails {
+                city: Some("San Fransico".to_string()),
+                country: Some(common_enums::CountryAlpha2::US),
+                line1: Some(Secret::new("1467".to_string())),
+                line2: None,
+                line3: None,
+                zip: Some(Secret::new("94122".to_string())),
+                state: Some(Secret::new("California".to_string())),
+                first_name: Some(Secret::new("John".to_string())),
+                last_name: None,
+            }),
+            phone: None,
+            email: None,
+        }
+    }
+
+    #[test]
+    fn test_address_and_customer_name_formatting() {
+        let address = get_address();
+        assert_eq!(
+            format_address(&address),
+            Some("1467, San Fransico, California, 94122, US".to_string())
+        );
+        assert_eq!(get_customer_name(&address), Some("John".to_string()));
+
+        let address = payment_types::Address {
+            address: None,
+            ..get_address()
+        };
+        assert_eq!(format_address(&address), None);
+        assert_eq!(get_customer_name(&address), None);
+    }
+
+    #[test]
+    fn test_stored_evidence_takes_precedence_over_generated_evidence() {
+        let stored = DisputeEvidence {
+            receipt: Some("file_receipt".to_string()),
+            customer_name: Some("Jane Doe".to_string()),
+            product_description: Some("  ".to_string()),
+            shipping_tracking_number: Some("1Z999".to_string()),
+            uncategorized_text: Some("Customer confirmed the delivery by email".to_string()),
+            ..Default::default()
+        };
+        let generated = SubmitEvidenceRequest {
+            dispute_id: "dp_1".to_string(),
+            customer_name: Some("John".to_string()),
+            customer_email_address: Some("john@example.com".to_string()),
+            product_description: Some("1 x Shoes".to_string()),
+            uncategorized_text: Some("Authentication type: three_ds".to_string()),
+            ..Default::default()
+        };
+        let evidence = merge_with_stored_evidence(stored, generated);
+
+        assert_eq!(evidence.dispute_id, "dp_1");
+        assert_eq!(evidence.receipt, Some("file_receipt".to_string()));
+        assert_eq!(evidence.customer_name, Some("Jane Doe".to_string()));
+        assert_eq!(
+            evidence.customer_email_address,
+            Some("john@example.com".to_string())
+        );
+        assert_eq!(evidence.product_description, Some("1 x Shoes".to_string()));
+        assert_eq!(evidence.shipping_tracking_number, Some("1Z999".to_string()));
+        assert_eq!(
+            evidence.uncategorized_text,
+            Some(
+                "Customer confirmed the delivery by email\n\nAuthentication type: three_ds"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated_statements_are_not_repeated() {
+        let generated = Some("Authentication type: three_ds".to_string());
+        let stored = Some("Delivered\n\nAuthentication type: three_ds".to_string());
+        assert_eq!(
+            merge_uncategorized_text(stored.clone(), generated.clone()),
+            stored
+        );
+        assert_eq!(merge_uncategorized_text(None, generated.clone()), generated);
+        assert_eq!(
+            merge_uncategorized_text(Some(String::new()), generated.clone()),
+            generated
+        );
+        assert_eq!(
+            merge_uncategorized_text(Some("Delivered".to_string()), None),
+            Some("Delivered".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_pdf() {
+        let document = String::from_utf8(render_pdf(&[
+            "Reason: fraud (card not present)".to_string(),
+            "Customer name: Zoë".to_string(),
+        ]))
+        .unwrap();
+
+        assert!(document.starts_with("%PDF-1.4\n"));
+        assert!(document.ends_with("%%EOF\n"));
+        assert!(document.contains("/Count 1"));
+        assert!(document.contains("(Reason: fraud \\(card not present\\)) Tj T*"));
+        assert!(document.contains("(Customer name: Zo?) Tj T*"));
+
+        // Every entry of the cross reference table points to the start of its object
+        let (body, xref) = document.split_once("xref\n").unwrap();
+        let offsets = xref
+            .lines()
+            .skip(2)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line.get(..10).unwrap().parse::<usize>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(offsets.len(), 5);
+        for (index, offset) in offsets.into_iter().enumerate() {
+            assert!(body
+                .get(offset..)
+                .unwrap()
+                .starts_with(&format!("{} 0 obj\n", index + 1)));
+        }
+    }
+
+    #[test]
+    fn test_render_pdf_wraps_and_paginates_lines() {
+        let long_line = "a".repeat(SUMMARY_LINE_WIDTH * 2 + 10);
+        let document = String::from_utf8(render_pdf(&[long_line])).unwrap();
+        assert_eq!(document.matches(" Tj T*").count(), 3);
+
+        let lines = (0..SUMMARY_LINES_PER_PAGE * 2 + 1)
+            .map(|index| format!("Line {index}"))
+            .collect::<Vec<_>>();
+        let document = String::from_utf8(render_pdf(&lines)).unwrap();
+        assert!(document.contains("/Count 3"));
+        assert!(document.contains("/Kids [4 0 R 6 0 R 8 0 R]"));
+    }
+}
@@ -197,6 +197,65 @@ pub fn update_dispute_evidence(
     }
 }
 
+/// Retains the text evidence of a submission, text left out of it keeps the value stored before
+pub fn update_dispute_text_evidence(
+    dispute_evidence: DisputeEvidence,
+    evidence_request: &api_models::disputes::SubmitEvidenceRequest,
+) -> DisputeEvidence {
+    let evidence_request = evidence_request.clone();
+    DisputeEvidence {
+        access_activity_log: evidence_request
+            .access_activity_log
+            .or(dispute_evidence.access_activity_log),
+        billing_address: evidence_request
+            .billing_address
+            .or(dispute_evidence.billing_address),
+        cancellation_policy_disclosure: evidence_request
+            .cancellation_policy_disclosure
+            .or(dispute_evidence.cancellation_policy_disclosure),
+        cancellation_rebuttal: evidence_request
+            .cancellation_rebuttal
+            .or(dispute_evidence.cancellation_rebuttal),
+        customer_email_address: evidence_request
+            .customer_email_address
+            .or(dispute_evidence.customer_email_address),
+        customer_name: evidence_request
+            .customer_name
+            .or(dispute_evidence.customer_name),
+        customer_purchase_ip: evidence_request
+            .customer_purchase_ip
+            .or(dispute_evidence.customer_purchase_ip),
+        product_description: evidence_request
+            .product_description
+            .or(dispute_evidence.product_description),
+        refund_policy_disclosure: evidence_request
+            .refund_policy_disclosure
+            .or(dispute_evidence.refund_policy_disclosure),
+        refund_refusal_explanation: evidence_request
+            .refund_refusal_explanation
+            .or(dispute_evidence.refund_refusal_explanation),
+        service_date: evidence_request
+            .service_date
+            .or(dispute_evidence.service_date),
+        shipping_address: evidence_request
+            .shipping_address
+            .or(dispute_evidence.shipping_address),
+        shipping_carrier: evidence_request
+            .shipping_carrier
+            .or(dispute_evidence.shipping_carrier),
+        shipping_date: evidence_request
+            .shipping_date
+            .or(dispute_evidence.shipping_date),
+        shipping_tracking_number: evidence_request
+            .shipping_tracking_number
+            .or(dispute_evidence.shipping_tracking_number),
+        uncategorized_text: evidence_request
+            .uncategorized_text
+            .or(dispute_evidence.uncategorized_text),
+        ..dispute_evidence
+    }
+}
+
 pub async fn get_dispute_evidence_block(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
@@ -348,6 +348,8 @@ pub enum RoutingError {
     SuccessRateCalculationError,
     #[error("Success rate client from dynamic routing gRPC service not initialized")]
     SuccessRateClientInitializationError,
+    #[error("Unable to estimate success rates from the payment outcomes counted in redis")]
+    SuccessRateEstimationError,
     #[error("Elimination client from dynamic routing gRPC service not initialized")]
     EliminationClientInitializationError,
     #[error("Unable to analyze elimination routing config from dynamic routing service")]
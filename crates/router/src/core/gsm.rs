@@ -6,7 +6,7 @@ use router_env::{instrument, tracing};
 use crate::{
     core::{
         errors,
-        errors::{RouterResponse, StorageErrorExt},
+        errors::{RouterResponse, RouterResult, StorageErrorExt},
     },
     db::gsm::GsmInterface,
     services,
@@ -19,6 +19,7 @@ pub async fn create_gsm_rule(
     state: SessionState,
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
+    validate_requeue_delay(gsm_rule.requeue_delay_in_seconds)?;
     let db = state.store.as_ref();
     GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
@@ -69,7 +70,9 @@ pub async fn update_gsm_rule(
         unified_message,
         error_category,
         clear_pan_possible,
+        requeue_delay_in_seconds,
     } = gsm_request;
+    validate_requeue_delay(requeue_delay_in_seconds.flatten())?;
     GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
@@ -86,6 +89,7 @@ pub async fn update_gsm_rule(
             unified_message,
             error_category,
             clear_pan_possible,
+            requeue_delay_in_seconds,
         },
     )
     .await
@@ -142,3 +146,15 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+fn validate_requeue_delay(requeue_delay_in_seconds: Option<i32>) -> RouterResult<()> {
+    common_utils::fp_utils::when(
+        requeue_delay_in_seconds.is_some_and(|delay| delay <= 0),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "requeue_delay_in_seconds must be greater than zero".to_string(),
+            })
+        },
+    )?;
+    Ok(())
+}
@@ -79,7 +79,7 @@ impl HealthCheckInterface for app::SessionState {
         &self,
     ) -> CustomResult<HealthState, errors::HealthCheckLockerError> {
         let locker = &self.conf.locker;
-        if !locker.mock_locker {
+        if !locker.mock_locker && !locker.local_vault {
             let mut url = locker.host_rs.to_owned();
             url.push_str(consts::LOCKER_HEALTH_CALL_PATH);
             let request = services::Request::new(services::Method::Get, &url);
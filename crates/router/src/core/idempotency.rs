@@ -0,0 +1,528 @@
+use actix_web::{
+    body::{self, BoxBody},
+    http::{
+        header::{self, HeaderMap},
+        StatusCode,
+    },
+    HttpResponse,
+};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, tracing, types::FlowMetric};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api_locking::{LockAction, LockingInput},
+    errors::{self, RouterResult},
+};
+use crate::{
+    headers,
+    routes::{app::SessionStateInfo, lock_utils, SessionState},
+};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Headers which are set again when the stored response is sent
+const UNSTORED_RESPONSE_HEADERS: [header::HeaderName; 3] = [
+    header::CONTENT_LENGTH,
+    header::DATE,
+    header::TRANSFER_ENCODING,
+];
+
+/// Idempotency key sent with a request, scoped to the endpoint the request was sent to and
+/// bound to the hash of the request body
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    idempotency_key: String,
+    endpoint: String,
+    request_hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotentResponseRecord {
+    request_hash: String,
+    response: StoredResponse,
+}
+
+/// Response sent for a request with an idempotency key, which is replayed as it was sent
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoredResponse {
+    status_code: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl StoredResponse {
+    /// Reads the response to be stored, and returns the response to be sent in its place since
+    /// reading the body consumes it. Responses with a body that is not UTF-8 are not stored.
+    async fn from_http_response(response: HttpResponse) -> (HttpResponse, Option<Self>) {
+        let (response, response_body) = response.into_parts();
+        let response_body = match body::to_bytes(response_body).await {
+            Ok(response_body) => response_body,
+            Err(error) => {
+                logger::error!(%error, "Failed to read response for idempotency record");
+                return (HttpResponse::InternalServerError().finish(), None);
+            }
+        };
+
+        let stored_response = String::from_utf8(response_body.to_vec())
+            .map(|body| Self {
+                status_code: response.status().as_u16(),
+                headers: get_stored_headers(response.headers()),
+                body,
+            })
+            .map_err(|_| logger::warn!("Response body is not UTF-8, it is not stored"))
+            .ok();
+
+        (
+            response.set_body(BoxBody::new(response_body)),
+            stored_response,
+        )
+    }
+
+    pub fn into_http_response(self) -> HttpResponse {
+        let mut response = HttpResponse::build(
+            StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        );
+        for (name, value) in self.headers {
+            response.append_header((name, value));
+        }
+        response.body(self.body)
+    }
+}
+
+fn get_stored_headers(response_headers: &HeaderMap) -> Vec<(String, String)> {
+    response_headers
+        .iter()
+        .filter(|(name, _)| !UNSTORED_RESPONSE_HEADERS.contains(name))
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (name.to_string(), value.to_owned()))
+        })
+        .collect()
+}
+
+/// State of the idempotency key of a request, shared by the wrappers of the API handlers. The
+/// response of a request holding the key is stored once it has been built, so that it can be
+/// replayed for later requests with the same key.
+#[derive(Default)]
+pub enum IdempotencyContext {
+    /// The request was not sent with an idempotency key, or the flow does not honour it
+    #[default]
+    NotApplicable,
+    /// The request holds the key and its response is stored when the key is released
+    Held(Box<HeldIdempotencyKey>),
+    /// The response stored for an earlier request with the same key, to be sent instead
+    Replayed(StoredResponse),
+}
+
+pub struct HeldIdempotencyKey {
+    input: IdempotencyInput,
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    /// Requests which were not processed, such as the ones rejected due to a concurrent request on
+    /// the same resource, can be retried with the same key
+    should_store_response: bool,
+}
+
+impl IdempotencyContext {
+    /// Holds the key of the request, or finds the response stored for an earlier request with
+    /// the same key
+    pub async fn acquire(
+        &mut self,
+        input: IdempotencyInput,
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+    ) -> RouterResult<()> {
+        *self = match input.acquire(state, merchant_id).await? {
+            Some(response) => Self::Replayed(response),
+            None => Self::Held(Box::new(HeldIdempotencyKey {
+                input,
+                state: state.clone(),
+                merchant_id: merchant_id.to_owned(),
+                should_store_response: true,
+            })),
+        };
+        Ok(())
+    }
+
+    pub fn is_replayed(&self) -> bool {
+        matches!(self, Self::Replayed(_))
+    }
+
+    /// The response of the request is not stored, and the key can be used again
+    pub fn skip_storing_response(&mut self) {
+        if let Self::Held(held_key) = self {
+            held_key.should_store_response = false;
+        }
+    }
+
+    /// Returns the response to be sent for the request. The response of a request holding the
+    /// key is stored before the key is freed, including the responses of requests which failed
+    /// so that they are not processed again.
+    #[instrument(skip_all)]
+    pub async fn release(self, response: HttpResponse) -> HttpResponse {
+        let held_key = match self {
+            Self::NotApplicable => return response,
+            Self::Replayed(stored_response) => return stored_response.into_http_response(),
+            Self::Held(held_key) => held_key,
+        };
+
+        let response = if held_key.should_store_response {
+            let (response, stored_response) = StoredResponse::from_http_response(response).await;
+            if let Some(stored_response) = stored_response {
+                held_key
+                    .input
+                    .store_response(&held_key.state, &held_key.merchant_id, stored_response)
+                    .await
+                    .map_err(|error| logger::error!(?error, "Failed to store idempotent response"))
+                    .ok();
+            }
+            response
+        } else {
+            response
+        };
+
+        held_key
+            .input
+            .free_lock(&held_key.state, &held_key.merchant_id)
+            .await;
+        response
+    }
+}
+
+impl IdempotencyInput {
+    /// Returns `None` if the flow does not honour idempotency keys or if the header is absent
+    pub fn from_request<T: Serialize>(
+        flow: &impl FlowMetric,
+        request_headers: &HeaderMap,
+        endpoint: &str,
+        payload: &T,
+    ) -> RouterResult<Option<Self>> {
+        if !flow.supports_idempotency_key() {
+            return Ok(None);
+        }
+
+        let Some(header_value) = request_headers.get(headers::IDEMPOTENCY_KEY) else {
+            return Ok(None);
+        };
+
+        let idempotency_key = header_value
+            .to_str()
+            .ok()
+            .map(str::trim)
+            .filter(|key| !key.is_empty() && key.len() <= IDEMPOTENCY_KEY_MAX_LENGTH)
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{}` header must be a non-empty string of at most {} characters",
+                    headers::IDEMPOTENCY_KEY,
+                    IDEMPOTENCY_KEY_MAX_LENGTH
+                ),
+            })?;
+
+        let request_body = serde_json::to_vec(payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize request for idempotency check")?;
+        let request_hash = crypto::Sha256
+            .generate_digest(&request_body)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to hash request for idempotency check")?;
+
+        Ok(Some(Self {
+            idempotency_key: idempotency_key.to_owned(),
+            endpoint: endpoint.to_owned(),
+            request_hash: hex::encode(request_hash),
+        }))
+    }
+
+    fn get_redis_key(&self, merchant_id: &id_type::MerchantId) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            IDEMPOTENCY_KEY_PREFIX,
+            merchant_id.get_string_repr(),
+            self.endpoint,
+            self.idempotency_key
+        )
+    }
+
+    fn get_lock_action(&self) -> LockAction {
+        LockAction::Hold {
+            input: LockingInput {
+                unique_locking_key: format!("{}_{}", self.endpoint, self.idempotency_key),
+                api_identifier: lock_utils::ApiIdentifier::IdempotencyKey,
+                // Concurrent requests with the same key are rejected rather than queued
+                override_lock_retries: Some(1),
+            },
+        }
+    }
+
+    /// Returns the response stored for an earlier request with the same key, if any. Otherwise the
+    /// key is held until [`Self::free_lock`] is called once the current request completes.
+    #[instrument(skip_all)]
+    async fn acquire<A>(
+        &self,
+        state: &A,
+        merchant_id: &id_type::MerchantId,
+    ) -> RouterResult<Option<StoredResponse>>
+    where
+        A: SessionStateInfo,
+    {
+        if let Some(record) = self.get_stored_response(state, merchant_id).await? {
+            return self.get_replayable_response(record).map(Some);
+        }
+
+        self.get_lock_action()
+            .perform_locking_action(state, merchant_id.to_owned())
+            .await
+            .map_err(get_lock_error)?;
+
+        // The request holding the key could have completed between the lookup and the lock
+        let stored_response = self.get_stored_response(state, merchant_id).await;
+        if !matches!(stored_response, Ok(None)) {
+            self.free_lock(state, merchant_id).await;
+        }
+
+        stored_response?
+            .map(|record| self.get_replayable_response(record))
+            .transpose()
+    }
+
+    async fn free_lock<A>(&self, state: &A, merchant_id: &id_type::MerchantId)
+    where
+        A: SessionStateInfo,
+    {
+        self.get_lock_action()
+            .free_lock_action(state, merchant_id.to_owned())
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to free idempotency key"))
+            .ok();
+    }
+
+    fn get_replayable_response(
+        &self,
+        record: IdempotentResponseRecord,
+    ) -> RouterResult<StoredResponse> {
+        if record.request_hash != self.request_hash {
+            return Err(report!(errors::ApiErrorResponse::IdempotencyKeyMismatch));
+        }
+
+        logger::info!(
+            idempotency_key = %self.idempotency_key,
+            "Replaying stored response for idempotent request"
+        );
+        Ok(record.response)
+    }
+
+    async fn get_stored_response<A>(
+        &self,
+        state: &A,
+        merchant_id: &id_type::MerchantId,
+    ) -> RouterResult<Option<IdempotentResponseRecord>>
+    where
+        A: SessionStateInfo,
+    {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        match redis_conn
+            .get_and_deserialize_key::<IdempotentResponseRecord>(
+                &self.get_redis_key(merchant_id).as_str().into(),
+                "IdempotentResponseRecord",
+            )
+            .await
+        {
+            Ok(record) => Ok(Some(record)),
+            Err(error) if matches!(error.current_context(), RedisError::NotFound) => Ok(None),
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch stored idempotent response"),
+        }
+    }
+
+    async fn store_response<A>(
+        &self,
+        state: &A,
+        merchant_id: &id_type::MerchantId,
+        response: StoredResponse,
+    ) -> RouterResult<()>
+    where
+        A: SessionStateInfo,
+    {
+        let record = IdempotentResponseRecord {
+            request_hash: self.request_hash.clone(),
+            response,
+        };
+
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                &self.get_redis_key(merchant_id).as_str().into(),
+                record,
+                i64::from(state.conf().idempotency.ttl_in_seconds),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store idempotent response")
+    }
+}
+
+/// A request holding the key has not completed yet
+fn get_lock_error(
+    error: error_stack::Report<errors::ApiErrorResponse>,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    match error.current_context() {
+        errors::ApiErrorResponse::ResourceBusy => {
+            error.change_context(errors::ApiErrorResponse::IdempotencyKeyInUse)
+        }
+        _ => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_input(request_hash: &str) -> IdempotencyInput {
+        IdempotencyInput {
+            idempotency_key: "key_1".to_string(),
+            endpoint: "/payments".to_string(),
+            request_hash: request_hash.to_string(),
+        }
+    }
+
+    fn get_stored_response() -> StoredResponse {
+        StoredResponse {
+            status_code: 400,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("x-hs-latency".to_string(), "10".to_string()),
+            ],
+            body: r#"{"error":{"code":"IR_06"}}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_replay_with_matching_request_hash() {
+        let record = IdempotentResponseRecord {
+            request_hash: "hash_1".to_string(),
+            response: get_stored_response(),
+        };
+
+        assert_eq!(
+            get_input("hash_1").get_replayable_response(record).unwrap(),
+            get_stored_response()
+        );
+    }
+
+    #[test]
+    fn test_replay_with_mismatching_request_hash() {
+        let record = IdempotentResponseRecord {
+            request_hash: "hash_1".to_string(),
+            response: get_stored_response(),
+        };
+
+        let error = get_input("hash_2")
+            .get_replayable_response(record)
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyMismatch
+        ));
+    }
+
+    #[test]
+    fn test_lock_error_of_request_in_flight() {
+        let error = get_lock_error(report!(errors::ApiErrorResponse::ResourceBusy));
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyInUse
+        ));
+
+        let error = get_lock_error(report!(errors::ApiErrorResponse::InternalServerError));
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InternalServerError
+        ));
+    }
+
+    #[test]
+    fn test_idempotent_request_keys() {
+        let input = get_input("hash_1");
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+
+        assert_eq!(
+            input.get_redis_key(&merchant_id),
+            "IDEMPOTENCY_merchant_1_/payments_key_1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stored_response_round_trip() {
+        let response = HttpResponse::build(StatusCode::CREATED)
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .insert_header(("x-request-id", "request_1"))
+            .body(r#"{"payment_id":"pay_1"}"#);
+
+        let (response, stored_response) = StoredResponse::from_http_response(response).await;
+        let stored_response = stored_response.unwrap();
+
+        // The response is sent as it was before it was stored
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            body::to_bytes(response.into_body()).await.unwrap(),
+            r#"{"payment_id":"pay_1"}"#
+        );
+
+        assert_eq!(stored_response.status_code, 201);
+        assert!(stored_response
+            .headers
+            .contains(&("x-request-id".to_string(), "request_1".to_string())));
+        assert!(!stored_response
+            .headers
+            .iter()
+            .any(|(name, _)| name == header::CONTENT_LENGTH.as_str()));
+
+        let replayed_response = stored_response.into_http_response();
+        assert_eq!(replayed_response.status(), StatusCode::CREATED);
+        assert_eq!(
+            replayed_response
+                .headers()
+                .get("x-request-id")
+                .and_then(|value| value.to_str().ok()),
+            Some("request_1")
+        );
+        assert_eq!(
+            body::to_bytes(replayed_response.into_body()).await.unwrap(),
+            r#"{"payment_id":"pay_1"}"#
+        );
+    }
+
+    #[test]
+    fn test_replay_of_failed_response() {
+        let replayed_response = get_stored_response().into_http_response();
+        assert_eq!(replayed_response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            replayed_response
+                .headers()
+                .get(header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok()),
+            Some("application/json")
+        );
+    }
+}
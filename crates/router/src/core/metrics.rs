@@ -30,6 +30,12 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_DEADLINE_REMINDER_SENT_METRIC, GLOBAL_METER); // No. of reminders sent for disputes approaching their evidence deadline
+counter_metric!(DISPUTE_AUTO_ACCEPTED_METRIC, GLOBAL_METER); // No. of disputes accepted as per the profile dispute policy
+counter_metric!(DISPUTE_DEADLINE_MISSED_METRIC, GLOBAL_METER); // No. of disputes marked as expired since the evidence deadline was missed
+
+counter_metric!(ACCOUNT_UPDATER_CARDS_EXPORTED, GLOBAL_METER); // No. of cards exported in account updater batches
+counter_metric!(ACCOUNT_UPDATER_CARDS_UPDATED, GLOBAL_METER); // No. of payment methods updated from account updater response files
 
 counter_metric!(INCOMING_PAYOUT_WEBHOOK_METRIC, GLOBAL_METER); // No. of incoming payout webhooks
 counter_metric!(
@@ -40,6 +46,9 @@ counter_metric!(
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_INCOMING_QUEUED_COUNT, GLOBAL_METER); // No. of incoming webhooks queued as their resource was locked
+counter_metric!(WEBHOOK_QUEUED_PROCESSING_RETRY_COUNT, GLOBAL_METER); // No. of retries of queued incoming webhooks
+counter_metric!(WEBHOOK_QUEUED_DEAD_LETTERED_COUNT, GLOBAL_METER); // No. of queued incoming webhooks moved to the dead letter queue
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
@@ -83,6 +92,8 @@ counter_metric!(
 );
 counter_metric!(DYNAMIC_SUCCESS_BASED_ROUTING, GLOBAL_METER);
 counter_metric!(DYNAMIC_CONTRACT_BASED_ROUTING, GLOBAL_METER);
+counter_metric!(THREE_DS_DECISION_RULE_EXECUTION_COUNT, GLOBAL_METER);
+counter_metric!(THREE_DS_DECISION_RULE_AUTHORIZATION_COUNT, GLOBAL_METER);
 
 #[cfg(feature = "partial-auth")]
 counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
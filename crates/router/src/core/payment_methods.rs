@@ -1,4 +1,7 @@
+#[cfg(feature = "v1")]
+pub mod account_updater;
 pub mod cards;
+pub mod local_vault;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 #[cfg(all(
@@ -0,0 +1,1117 @@
+//! Card account updater for the stored cards of a merchant. Cards which are about to expire, cards
+//! which were deactivated, and cards whose merchant initiated payments or whose update by a
+//! previous batch failed, are periodically exported in batches for the card networks, and the
+//! response files of the batches update the cards in the locker and in the payment methods. The
+//! request files of the batches contain card numbers. They are only kept in the file storage
+//! encrypted with the key of the merchant, and leave it encrypted for the card network updater.
+
+pub mod transformers;
+
+use ::payment_methods::controller::{DataDuplicationCheck, PaymentMethodsController};
+use api_models::{
+    account_updater as account_updater_api,
+    payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData},
+};
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    date_time,
+    ext_traits::ValueExt,
+    generate_id, id_type,
+    request::RequestContent,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use self::transformers::{
+    AccountUpdaterFileHeader, AccountUpdaterRequestRecord, AccountUpdaterResponseCode,
+    AccountUpdaterResponseRecord, CardExpiry,
+};
+use super::{
+    cards::{self, PmCards},
+    network_tokenization,
+};
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        metrics, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    headers,
+    routes::{metrics as router_metrics, SessionState},
+    services::{self, encryption, ApplicationResponse},
+    types::{api, domain, storage},
+};
+
+const ACCOUNT_UPDATER_TAG: &str = "ACCOUNT_UPDATER";
+const ACCOUNT_UPDATER_EXPORT_NAME: &str = "ACCOUNT_UPDATER_EXPORT";
+const ACCOUNT_UPDATER_INGEST_NAME: &str = "ACCOUNT_UPDATER_INGEST";
+const ACCOUNT_UPDATER_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::AccountUpdaterWorkflow;
+
+const REQUEST_FILE_NAME: &str = "request";
+const RESPONSE_FILE_NAME: &str = "response";
+/// Payment methods whose merchant initiated payments failed, or whose update by a batch failed
+const FAILED_CARDS_KEY_PREFIX: &str = "ACCOUNT_UPDATER_FAILED_CARDS";
+
+fn get_export_task_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{ACCOUNT_UPDATER_RUNNER}_{ACCOUNT_UPDATER_EXPORT_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_ingest_task_id(batch_id: &str) -> String {
+    format!("{ACCOUNT_UPDATER_RUNNER}_{ACCOUNT_UPDATER_INGEST_NAME}_{batch_id}")
+}
+
+fn get_failed_cards_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{FAILED_CARDS_KEY_PREFIX}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_batch_file_key(
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    file_name: &str,
+) -> String {
+    format!(
+        "account_updater/{}/{batch_id}/{file_name}",
+        merchant_id.get_string_repr()
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn schedule_account_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<account_updater_api::AccountUpdaterScheduleResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let task_id = get_export_task_id(merchant_id);
+    // The first batch is exported right away, and the following ones once every batch interval
+    let schedule_time = date_time::now();
+
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve account updater export task")?;
+
+    match existing_task {
+        Some(process) => {
+            db.update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(storage::enums::ProcessTrackerStatus::New),
+                    updated_at: Some(schedule_time),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule account updater export task")?;
+        }
+        None => {
+            let tracking_data = storage::AccountUpdaterTrackingData {
+                merchant_id: merchant_id.clone(),
+                task: storage::AccountUpdaterTask::Export,
+            };
+            add_account_updater_task(
+                db,
+                task_id,
+                ACCOUNT_UPDATER_EXPORT_NAME,
+                tracking_data,
+                schedule_time,
+            )
+            .await?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        account_updater_api::AccountUpdaterScheduleResponse {
+            merchant_id: merchant_id.clone(),
+            is_scheduled: true,
+            next_batch_at: Some(schedule_time),
+            batch_interval_in_hours: state.conf.account_updater.batch_interval_in_hours,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn unschedule_account_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<account_updater_api::AccountUpdaterScheduleResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let existing_task = db
+        .find_process_by_id(&get_export_task_id(merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve account updater export task")?;
+
+    if let Some(process) = existing_task {
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke account updater export task")?;
+    }
+
+    Ok(ApplicationResponse::Json(
+        account_updater_api::AccountUpdaterScheduleResponse {
+            merchant_id: merchant_id.clone(),
+            is_scheduled: false,
+            next_batch_at: None,
+            batch_interval_in_hours: state.conf.account_updater.batch_interval_in_hours,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn create_account_updater_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<account_updater_api::AccountUpdaterBatchResponse> {
+    export_account_updater_batch(&state, &merchant_context)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// The request file is returned encrypted for the card network updater, so that the card numbers
+/// in it can only be read by the card networks
+#[instrument(skip_all)]
+pub async fn retrieve_account_updater_request_file(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: account_updater_api::AccountUpdaterBatchId,
+) -> RouterResponse<account_updater_api::AccountUpdaterRequestFileResponse> {
+    let network_updater = state.conf.account_updater.network_updater.as_ref().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No card network updater is configured".to_string(),
+        },
+    )?;
+    let file =
+        retrieve_batch_file(&state, &merchant_context, &req.batch_id, REQUEST_FILE_NAME).await?;
+    let encrypted_file = encrypt_request_file(network_updater, &file).await?;
+
+    Ok(ApplicationResponse::Json(
+        account_updater_api::AccountUpdaterRequestFileResponse {
+            batch_id: req.batch_id,
+            key_id: network_updater.key_id.clone(),
+            encrypted_file,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn upload_account_updater_response_file(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: account_updater_api::AccountUpdaterResponseFileRequest,
+) -> RouterResponse<account_updater_api::AccountUpdaterResponseFileResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    // Only the batches exported for the merchant accept a response file
+    retrieve_batch_file(&state, &merchant_context, &req.batch_id, REQUEST_FILE_NAME).await?;
+
+    let response_file = transformers::parse_response_file(req.file.peek())?;
+    if response_file.header.merchant_id != *merchant_id
+        || response_file.header.batch_id != req.batch_id
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Header of the response file does not belong to the batch".to_string(),
+        }));
+    }
+
+    let task_id = get_ingest_task_id(&req.batch_id);
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve account updater ingest task")?;
+    if existing_task.is_some() {
+        return Err(report!(errors::ApiErrorResponse::DuplicateRequest {
+            message: format!(
+                "Response file of the batch {} was already uploaded",
+                req.batch_id
+            ),
+        }));
+    }
+
+    store_batch_file(
+        &state,
+        &merchant_context,
+        &req.batch_id,
+        RESPONSE_FILE_NAME,
+        req.file.peek(),
+    )
+    .await?;
+
+    let schedule_time = date_time::now();
+    let tracking_data = storage::AccountUpdaterTrackingData {
+        merchant_id: merchant_id.clone(),
+        task: storage::AccountUpdaterTask::Ingest {
+            batch_id: req.batch_id.clone(),
+        },
+    };
+    add_account_updater_task(
+        db,
+        task_id,
+        ACCOUNT_UPDATER_INGEST_NAME,
+        tracking_data,
+        schedule_time,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        account_updater_api::AccountUpdaterResponseFileResponse {
+            batch_id: req.batch_id,
+            record_count: u32::try_from(response_file.records.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Record count of the response file exceeds u32")?,
+            ingest_scheduled_at: schedule_time,
+        },
+    ))
+}
+
+/// Exports the active cards which expire within the configured window, along with the inactive
+/// cards and the cards whose merchant initiated payments or whose update by a previous batch
+/// failed, into the request file of a new batch
+#[instrument(skip_all)]
+pub async fn export_account_updater_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+) -> RouterResult<account_updater_api::AccountUpdaterBatchResponse> {
+    let db = &*state.store;
+    let config = &state.conf.account_updater;
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+    let created_at = date_time::now();
+
+    // Last month, counted from the current one, in which a card expiring soon may expire
+    let window_end =
+        i32::from(u8::from(created_at.month())) - 1 + i32::from(config.expiry_window_in_months);
+    let (window_year, window_month) = (created_at.year() + window_end / 12, window_end % 12 + 1);
+
+    let mut records = Vec::new();
+    for status in [
+        common_enums::PaymentMethodStatus::Active,
+        common_enums::PaymentMethodStatus::Inactive,
+    ] {
+        let mut offset = 0;
+        loop {
+            let payment_methods = db
+                .find_payment_method_list_by_merchant_id_status(
+                    &state.into(),
+                    merchant_context.get_merchant_key_store(),
+                    merchant_id,
+                    status,
+                    Some(i64::from(config.page_size)),
+                    Some(offset),
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list payment methods of the merchant")?;
+            let is_last_page = payment_methods.len()
+                < usize::try_from(config.page_size)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            for payment_method in payment_methods {
+                let is_exported = status == common_enums::PaymentMethodStatus::Inactive
+                    || get_stored_card_expiry(&payment_method).is_some_and(|card_expiry| {
+                        card_expiry.expires_by(window_year, window_month)
+                    });
+                if is_exported {
+                    records.extend(get_request_record(state, payment_method).await);
+                }
+            }
+
+            if is_last_page {
+                break;
+            }
+            offset += i64::from(config.page_size);
+        }
+    }
+
+    // The failed cards are exported once, a card which fails again is recorded again
+    for payment_method_id in take_failed_cards(state, merchant_id).await? {
+        let payment_method = match db
+            .find_payment_method(
+                &state.into(),
+                merchant_context.get_merchant_key_store(),
+                &payment_method_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_method) => payment_method,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    %payment_method_id,
+                    "Failed to find failed card for account updater batch"
+                );
+                continue;
+            }
+        };
+        let is_exported = records
+            .iter()
+            .any(|record| record.payment_method_id == payment_method_id);
+        if payment_method.merchant_id == *merchant_id && !is_exported {
+            records.extend(get_request_record(state, payment_method).await);
+        }
+    }
+
+    let header = AccountUpdaterFileHeader {
+        merchant_id: merchant_id.clone(),
+        batch_id: generate_id(consts::ID_LENGTH, "aub"),
+        created_on: date_time::format_date(created_at, date_time::DateFormat::YYYYMMDD)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to format account updater batch date")?,
+    };
+    let request_file = transformers::build_request_file(&header, &records);
+    store_batch_file(
+        state,
+        merchant_context,
+        &header.batch_id,
+        REQUEST_FILE_NAME,
+        &request_file,
+    )
+    .await?;
+
+    // The request file stays retrievable by the merchant when its delivery fails
+    if let Some(network_updater) = config.network_updater.as_ref() {
+        deliver_request_file(state, network_updater, &header, &request_file)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    batch_id = %header.batch_id,
+                    "Failed to deliver account updater request file"
+                )
+            })
+            .ok();
+    }
+
+    let record_count = u32::try_from(records.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Record count of the request file exceeds u32")?;
+    metrics::ACCOUNT_UPDATER_CARDS_EXPORTED.add(u64::from(record_count), &[]);
+    logger::info!(batch_id = %header.batch_id, record_count, "Exported account updater batch");
+
+    Ok(account_updater_api::AccountUpdaterBatchResponse {
+        batch_id: header.batch_id,
+        record_count,
+        created_at,
+    })
+}
+
+/// Applies the updates in the response file of a batch to the payment methods. A failure to
+/// update one payment method does not stop the updates of the other payment methods, the payment
+/// methods which failed to update are exported again in the next batch.
+#[instrument(skip_all)]
+pub async fn ingest_account_updater_response_file(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+) -> RouterResult<()> {
+    let file = retrieve_batch_file(state, merchant_context, batch_id, RESPONSE_FILE_NAME).await?;
+    let response_file = transformers::parse_response_file(file.peek())?;
+    let mut failed_cards = Vec::new();
+
+    for record in &response_file.records {
+        match Box::pin(apply_account_updater_record(
+            state,
+            merchant_context,
+            record,
+        ))
+        .await
+        {
+            Ok(Some(payment_method)) => {
+                metrics::ACCOUNT_UPDATER_CARDS_UPDATED.add(
+                    1,
+                    router_env::metric_attributes!((
+                        "response_code",
+                        record.response_code.to_string()
+                    )),
+                );
+                trigger_payment_method_updated_webhook(state, merchant_context, &payment_method)
+                    .await
+                    .map_err(|error| logger::warn!(payment_method_outgoing_webhook_error=?error))
+                    .ok();
+            }
+            Ok(None) => (),
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    payment_method_id = %record.payment_method_id,
+                    response_code = %record.response_code,
+                    "Failed to apply account updater record"
+                );
+                failed_cards.push((
+                    record.payment_method_id.clone(),
+                    date_time::now_unix_timestamp().to_string(),
+                ));
+            }
+        }
+    }
+
+    if !failed_cards.is_empty() {
+        record_failed_cards(
+            state,
+            merchant_context.get_merchant_account().get_id(),
+            failed_cards,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Request record of the stored card of a payment method, if the card could be read from the
+/// locker
+async fn get_request_record(
+    state: &SessionState,
+    payment_method: domain::PaymentMethod,
+) -> Option<AccountUpdaterRequestRecord> {
+    let card_expiry = get_stored_card_expiry(&payment_method)?;
+
+    cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to get card from locker for account updater batch"
+        )
+    })
+    .ok()
+    .map(|card| AccountUpdaterRequestRecord {
+        payment_method_id: payment_method.payment_method_id,
+        card_number: card.card_number,
+        card_expiry,
+    })
+}
+
+/// Records the payment method of a failed merchant initiated payment, so that its card is exported
+/// in the next batch of the merchant
+pub async fn record_failed_merchant_initiated_payment(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    record_failed_cards(
+        state,
+        merchant_id,
+        vec![(
+            payment_method_id.to_owned(),
+            date_time::now_unix_timestamp().to_string(),
+        )],
+    )
+    .await
+}
+
+/// Records the failed payment methods, along with the time of the failure
+async fn record_failed_cards(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    failed_cards: Vec<(String, String)>,
+) -> RouterResult<()> {
+    let failed_cards_ttl =
+        i64::from(state.conf.account_updater.batch_interval_in_hours).saturating_mul(2 * 60 * 60);
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .set_hash_fields(
+            &get_failed_cards_key(merchant_id).as_str().into(),
+            failed_cards,
+            Some(failed_cards_ttl),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record failed account updater cards")
+}
+
+/// Returns the failed payment methods, and clears them
+async fn take_failed_cards(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Vec<String>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let failed_cards_key = get_failed_cards_key(merchant_id);
+
+    let failed_cards = redis_conn
+        .get_hash_fields::<std::collections::HashMap<String, String>>(
+            &failed_cards_key.as_str().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve failed account updater cards")?;
+    redis_conn
+        .delete_key(&failed_cards_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to clear failed account updater cards")?;
+
+    Ok(failed_cards.into_keys().collect())
+}
+
+/// Returns the updated payment method, if the record changed the payment method
+async fn apply_account_updater_record(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    record: &AccountUpdaterResponseRecord,
+) -> RouterResult<Option<domain::PaymentMethod>> {
+    let db = &*state.store;
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_method = db
+        .find_payment_method(
+            &state.into(),
+            key_store,
+            &record.payment_method_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    if payment_method.merchant_id != *merchant_context.get_merchant_account().get_id() {
+        return Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound));
+    }
+
+    match record.response_code {
+        AccountUpdaterResponseCode::ContactCardholder | AccountUpdaterResponseCode::NoUpdate => {
+            Ok(None)
+        }
+        AccountUpdaterResponseCode::AccountClosed => {
+            if payment_method.status == common_enums::PaymentMethodStatus::Inactive {
+                return Ok(None);
+            }
+            db.update_payment_method(
+                &state.into(),
+                key_store,
+                payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(common_enums::PaymentMethodStatus::Inactive),
+                },
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment method status")
+            .map(Some)
+        }
+        AccountUpdaterResponseCode::NewAccountNumber | AccountUpdaterResponseCode::NewExpiry => {
+            Box::pin(update_stored_card(
+                state,
+                merchant_context,
+                payment_method,
+                record,
+            ))
+            .await
+            .map(Some)
+        }
+    }
+}
+
+/// Replaces the card in the locker with the reissued card, and updates the card details of the
+/// payment method. Payment methods which were deactivated are activated again. The reissued card
+/// is stored before the previous card is deleted, so that a failure never loses the stored card.
+async fn update_stored_card(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    record: &AccountUpdaterResponseRecord,
+) -> RouterResult<domain::PaymentMethod> {
+    let db = &*state.store;
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let card_reference = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or_else(|| payment_method.payment_method_id.clone());
+
+    let existing_card = get_stored_card_details(&payment_method)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to obtain card details of the payment method")?;
+    let card_from_locker = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        &card_reference,
+    )
+    .await
+    .attach_printable("Error getting card from locker")?;
+
+    let previous_card = api::CardDetail {
+        card_number: card_from_locker.card_number,
+        card_exp_month: card_from_locker.card_exp_month,
+        card_exp_year: card_from_locker.card_exp_year,
+        card_holder_name: card_from_locker.name_on_card,
+        nick_name: card_from_locker.nick_name.map(Secret::new),
+        card_issuing_country: existing_card.issuer_country.clone(),
+        card_network: existing_card.card_network.clone(),
+        card_issuer: existing_card.card_issuer.clone(),
+        card_type: existing_card.card_type.clone(),
+    };
+    let (card_exp_month, card_exp_year) = match record.new_card_expiry {
+        Some(card_expiry) => (card_expiry.get_exp_month(), card_expiry.get_exp_year()),
+        None => (
+            previous_card.card_exp_month.clone(),
+            previous_card.card_exp_year.clone(),
+        ),
+    };
+    let updated_card = api::CardDetail {
+        card_number: record
+            .new_card_number
+            .clone()
+            .unwrap_or_else(|| previous_card.card_number.clone()),
+        card_exp_month,
+        card_exp_year,
+        ..previous_card.clone()
+    };
+
+    let new_pm = api::PaymentMethodCreate {
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(updated_card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+    let pm_cards = PmCards {
+        state,
+        merchant_context,
+    };
+    let (stored_card, duplication_check) = Box::pin(pm_cards.add_card_to_locker(
+        new_pm.clone(),
+        &updated_card,
+        &payment_method.customer_id,
+        None,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add updated card to locker")?;
+
+    let new_card_reference = match duplication_check {
+        // The locker keeps a single card for a card number, a card reissued with the same card
+        // number can only be updated in place
+        Some(DataDuplicationCheck::Duplicated | DataDuplicationCheck::MetaDataChanged) => {
+            Box::pin(replace_card_in_locker(
+                &pm_cards,
+                new_pm,
+                &updated_card,
+                &previous_card,
+                &payment_method.customer_id,
+                &card_reference,
+            ))
+            .await?;
+            None
+        }
+        None => Some(stored_card.payment_method_id),
+    };
+    let updated_pmd = PaymentMethodsData::Card(CardDetailsPaymentMethod {
+        last4_digits: Some(updated_card.card_number.get_last4()),
+        card_isin: Some(updated_card.card_number.get_card_isin()),
+        expiry_month: Some(updated_card.card_exp_month.clone()),
+        expiry_year: Some(updated_card.card_exp_year.clone()),
+        ..existing_card
+    });
+    let pm_data_encrypted = cards::create_encrypted_data(&state.into(), key_store, updated_pmd)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt payment method data")?;
+
+    let status = (payment_method.status == common_enums::PaymentMethodStatus::Inactive)
+        .then_some(common_enums::PaymentMethodStatus::Active);
+    let payment_method = db
+        .update_payment_method(
+            &state.into(),
+            key_store,
+            payment_method,
+            storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data: Some(pm_data_encrypted.into()),
+                status,
+                locker_id: new_card_reference.clone(),
+                payment_method: None,
+                payment_method_type: None,
+                payment_method_issuer: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    // The previous card is deleted only once the payment method refers to the reissued card
+    if new_card_reference.is_some() {
+        pm_cards
+            .delete_card_from_locker(
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                &card_reference,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    payment_method_id = %payment_method.payment_method_id,
+                    "Failed to delete the previous card from locker"
+                )
+            })
+            .ok();
+    }
+
+    Box::pin(network_tokenization::lifecycle::reprovision_network_token(
+        state,
+        merchant_context,
+        payment_method,
+        &updated_card,
+    ))
+    .await
+}
+
+/// Replaces the card stored under `card_reference` with the reissued card. The previous card is
+/// stored again if the reissued card cannot be stored.
+async fn replace_card_in_locker(
+    pm_cards: &PmCards<'_>,
+    new_pm: api::PaymentMethodCreate,
+    updated_card: &api::CardDetail,
+    previous_card: &api::CardDetail,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> RouterResult<()> {
+    pm_cards
+        .delete_card_from_locker(
+            customer_id,
+            pm_cards.merchant_context.get_merchant_account().get_id(),
+            card_reference,
+        )
+        .await?;
+
+    let Err(error) = Box::pin(pm_cards.add_card_to_locker(
+        new_pm.clone(),
+        updated_card,
+        customer_id,
+        Some(card_reference),
+    ))
+    .await
+    else {
+        return Ok(());
+    };
+
+    Box::pin(pm_cards.add_card_to_locker(
+        api::PaymentMethodCreate {
+            card: Some(previous_card.clone()),
+            ..new_pm
+        },
+        previous_card,
+        customer_id,
+        Some(card_reference),
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to restore the previous card in locker")?;
+
+    Err(error)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add updated card to locker")
+}
+
+async fn trigger_payment_method_updated_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<()> {
+    let Some(profile_id) = merchant_context
+        .get_merchant_account()
+        .default_profile
+        .as_ref()
+    else {
+        logger::warn!(
+            payment_method_id = %payment_method.payment_method_id,
+            "Merchant has no default profile, skipping payment method updated webhook"
+        );
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let payment_method_response = match (PmCards {
+        state,
+        merchant_context,
+    })
+    .retrieve_payment_method(api::PaymentMethodId {
+        payment_method_id: payment_method.payment_method_id.clone(),
+    })
+    .await?
+    {
+        ApplicationResponse::Json(payment_method_response)
+        | ApplicationResponse::JsonWithHeaders((payment_method_response, _)) => {
+            Ok(payment_method_response)
+        }
+        ApplicationResponse::StatusOk
+        | ApplicationResponse::TextPlain(_)
+        | ApplicationResponse::JsonForRedirection(_)
+        | ApplicationResponse::Form(_)
+        | ApplicationResponse::GenericLinkForm(_)
+        | ApplicationResponse::PaymentLinkForm(_)
+        | ApplicationResponse::FileData(_) => {
+            Err(report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unexpected response while retrieving payment method"))
+        }
+    }?;
+
+    Box::pin(
+        webhooks_core::create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile,
+            common_enums::EventType::PaymentMethodUpdated,
+            common_enums::EventClass::PaymentMethods,
+            payment_method.payment_method_id.clone(),
+            diesel_models::enums::EventObjectType::PaymentMethodDetails,
+            api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+            Some(payment_method.created_at),
+            // Every update of the card by a batch is a distinct event for the merchant
+            webhooks_core::utils::generate_event_id(),
+        ),
+    )
+    .await
+}
+
+fn get_stored_card_details(
+    payment_method: &domain::PaymentMethod,
+) -> Option<CardDetailsPaymentMethod> {
+    payment_method
+        .payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .and_then(|data| {
+            data.parse_value::<PaymentMethodsData>("PaymentMethodsData")
+                .ok()
+        })
+        .and_then(|data| match data {
+            PaymentMethodsData::Card(card) => Some(card),
+            PaymentMethodsData::BankDetails(_) | PaymentMethodsData::WalletDetails(_) => None,
+        })
+}
+
+/// Expiry of the stored card of a payment method, for the cards saved to the locker
+fn get_stored_card_expiry(payment_method: &domain::PaymentMethod) -> Option<CardExpiry> {
+    if payment_method.get_payment_method_type() != Some(common_enums::PaymentMethod::Card) {
+        return None;
+    }
+    get_stored_card_details(payment_method)
+        .filter(|card| card.saved_to_locker)
+        .and_then(|card| {
+            CardExpiry::from_card_details(card.expiry_month.as_ref()?, card.expiry_year.as_ref()?)
+        })
+}
+
+async fn add_account_updater_task(
+    db: &dyn StorageInterface,
+    task_id: String,
+    task_name: &str,
+    tracking_data: storage::AccountUpdaterTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        task_id,
+        task_name,
+        ACCOUNT_UPDATER_RUNNER,
+        [ACCOUNT_UPDATER_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct account updater process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert account updater process tracker task")?;
+    router_metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "AccountUpdater")),
+    );
+
+    Ok(())
+}
+
+/// Batch files contain card numbers, they are encrypted with the key of the merchant before they
+/// are stored
+async fn encrypt_request_file(
+    network_updater: &settings::NetworkUpdaterConfig,
+    file: &Secret<Vec<u8>>,
+) -> RouterResult<String> {
+    encryption::encrypt_jwe(
+        file.peek(),
+        network_updater.public_key.peek(),
+        services::EncryptionAlgorithm::A256GCM,
+        Some(network_updater.key_id.as_str()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt account updater request file")
+}
+
+async fn deliver_request_file(
+    state: &SessionState,
+    network_updater: &settings::NetworkUpdaterConfig,
+    header: &AccountUpdaterFileHeader,
+    file: &[u8],
+) -> RouterResult<()> {
+    let Some(delivery_url) = network_updater.delivery_url.as_ref() else {
+        return Ok(());
+    };
+    let encrypted_file = encrypt_request_file(network_updater, &Secret::new(file.to_vec())).await?;
+
+    let mut request = services::Request::new(services::Method::Post, delivery_url.as_str());
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        network_updater.api_key.peek().clone().into_masked(),
+    );
+    request.add_default_headers();
+    request.set_body(RequestContent::Json(Box::new(
+        transformers::NetworkUpdaterRequestFile {
+            merchant_id: header.merchant_id.clone(),
+            batch_id: header.batch_id.clone(),
+            key_id: network_updater.key_id.clone(),
+            encrypted_file,
+        },
+    )));
+
+    services::call_connector_api(state, request, "deliver_account_updater_request_file")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the card network updater")?
+        .map_err(|error_response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Card network updater rejected the request file with status {}",
+                error_response.status_code
+            ))
+        })?;
+
+    logger::info!(batch_id = %header.batch_id, "Delivered account updater request file");
+    Ok(())
+}
+
+async fn store_batch_file(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+    file_name: &str,
+    file: &[u8],
+) -> RouterResult<()> {
+    let encrypted_file = GcmAes256
+        .encode_message(
+            merchant_context
+                .get_merchant_key_store()
+                .key
+                .get_inner()
+                .peek(),
+            file,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt account updater batch file")?;
+
+    state
+        .file_storage_client
+        .upload_file(
+            &get_batch_file_key(
+                merchant_context.get_merchant_account().get_id(),
+                batch_id,
+                file_name,
+            ),
+            encrypted_file,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store account updater batch file")
+}
+
+async fn retrieve_batch_file(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    batch_id: &str,
+    file_name: &str,
+) -> RouterResult<Secret<Vec<u8>>> {
+    let encrypted_file = state
+        .file_storage_client
+        .retrieve_file(&get_batch_file_key(
+            merchant_context.get_merchant_account().get_id(),
+            batch_id,
+            file_name,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Account updater batch {batch_id} not found"),
+        })?;
+
+    GcmAes256
+        .decode_message(
+            merchant_context
+                .get_merchant_key_store()
+                .key
+                .get_inner()
+                .peek(),
+            Secret::new(encrypted_file),
+        )
+        .map(Secret::new)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt account updater batch file")
+}
@@ -0,0 +1,352 @@
+//! Network updater file format of the batches of the card account updater. A file is made of a
+//! header record, a detail record for every card and a trailer record with the number of detail
+//! records, one record per line with its fields separated by `|`.
+//!
+//! ```text
+//! H|1.0|<merchant_id>|<batch_id>|<YYYYMMDD>
+//! D|<payment_method_id>|<card_number>|<MMYY>                                     (request file)
+//! D|<payment_method_id>|<response_code>|<new_card_number>|<new_expiry_MMYY>      (response file)
+//! T|<detail_record_count>
+//! ```
+
+use std::str::FromStr;
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::account_updater as account_updater_api;
+use cards::CardNumber;
+use common_utils::id_type;
+use masking::{PeekInterface, Secret};
+
+use crate::core::errors;
+
+const FIELD_SEPARATOR: char = '|';
+const FILE_FORMAT_VERSION: &str = "1.0";
+const HEADER_RECORD_TYPE: &str = "H";
+const DETAIL_RECORD_TYPE: &str = "D";
+const TRAILER_RECORD_TYPE: &str = "T";
+
+#[derive(Debug, MultipartForm)]
+pub struct AccountUpdaterResponseFileForm {
+    #[multipart(limit = "50MB")]
+    pub file: Bytes,
+}
+
+pub fn get_response_file_request(
+    batch_id: String,
+    form: AccountUpdaterResponseFileForm,
+) -> account_updater_api::AccountUpdaterResponseFileRequest {
+    account_updater_api::AccountUpdaterResponseFileRequest {
+        batch_id,
+        file: Secret::new(form.file.data.to_vec()),
+    }
+}
+
+/// Request file of a batch, as delivered to the card network updater
+#[derive(Debug, serde::Serialize)]
+pub struct NetworkUpdaterRequestFile {
+    pub merchant_id: id_type::MerchantId,
+    pub batch_id: String,
+    pub key_id: String,
+    pub encrypted_file: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUpdaterFileHeader {
+    pub merchant_id: id_type::MerchantId,
+    pub batch_id: String,
+    /// Date on which the file was created, in the `YYYYMMDD` format
+    pub created_on: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CardExpiry {
+    year: u16,
+    month: u8,
+}
+
+impl CardExpiry {
+    pub fn new(month: u8, year: u16) -> Option<Self> {
+        (1..=12).contains(&month).then_some(Self { year, month })
+    }
+
+    /// Card expiry from the expiry month and year of a card, the year may have two or four digits
+    pub fn from_card_details(month: &Secret<String>, year: &Secret<String>) -> Option<Self> {
+        let month = month.peek().trim().parse::<u8>().ok()?;
+        let year = year.peek().trim();
+        let year = match year.len() {
+            2 => 2000 + year.parse::<u16>().ok()?,
+            4 => year.parse::<u16>().ok()?,
+            _ => return None,
+        };
+        Self::new(month, year)
+    }
+
+    fn from_mmyy(value: &str) -> Option<Self> {
+        if value.len() != 4 || !value.chars().all(|digit| digit.is_ascii_digit()) {
+            return None;
+        }
+        let (month, year) = value.split_at(2);
+        Self::new(month.parse().ok()?, 2000 + year.parse::<u16>().ok()?)
+    }
+
+    fn to_mmyy(self) -> String {
+        format!("{:02}{:02}", self.month, self.year % 100)
+    }
+
+    /// Whether the card expires in the given month or before it
+    pub fn expires_by(self, year: i32, month: i32) -> bool {
+        (i32::from(self.year), i32::from(self.month)) <= (year, month)
+    }
+
+    pub fn get_exp_month(self) -> Secret<String> {
+        Secret::new(format!("{:02}", self.month))
+    }
+
+    pub fn get_exp_year(self) -> Secret<String> {
+        Secret::new(self.year.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterRequestRecord {
+    pub payment_method_id: String,
+    pub card_number: CardNumber,
+    pub card_expiry: CardExpiry,
+}
+
+/// Response codes of the card networks for the cards of a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+pub enum AccountUpdaterResponseCode {
+    /// The card was reissued with a new card number, and possibly a new expiry
+    #[strum(serialize = "NUM")]
+    NewAccountNumber,
+    /// The card was reissued with a new expiry
+    #[strum(serialize = "EXP")]
+    NewExpiry,
+    /// The account of the card was closed
+    #[strum(serialize = "CLO")]
+    AccountClosed,
+    /// The issuer asks the merchant to contact the cardholder, nothing is updated
+    #[strum(serialize = "CCH")]
+    ContactCardholder,
+    /// No update is available for the card
+    #[strum(serialize = "NON")]
+    NoUpdate,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterResponseRecord {
+    pub payment_method_id: String,
+    pub response_code: AccountUpdaterResponseCode,
+    pub new_card_number: Option<CardNumber>,
+    pub new_card_expiry: Option<CardExpiry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterResponseFile {
+    pub header: AccountUpdaterFileHeader,
+    pub records: Vec<AccountUpdaterResponseRecord>,
+}
+
+pub fn build_request_file(
+    header: &AccountUpdaterFileHeader,
+    records: &[AccountUpdaterRequestRecord],
+) -> Vec<u8> {
+    let header_record = [
+        HEADER_RECORD_TYPE,
+        FILE_FORMAT_VERSION,
+        header.merchant_id.get_string_repr(),
+        &header.batch_id,
+        &header.created_on,
+    ]
+    .join("|");
+    let detail_records = records.iter().map(|record| {
+        [
+            DETAIL_RECORD_TYPE,
+            &record.payment_method_id,
+            record.card_number.peek(),
+            &record.card_expiry.to_mmyy(),
+        ]
+        .join("|")
+    });
+    let trailer_record = [TRAILER_RECORD_TYPE, &records.len().to_string()].join("|");
+
+    std::iter::once(header_record)
+        .chain(detail_records)
+        .chain(std::iter::once(trailer_record))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .into_bytes()
+}
+
+/// Parses the response file of a batch. Errors refer to the records by their line numbers only,
+/// since the records contain card numbers.
+pub fn parse_response_file(
+    data: &[u8],
+) -> Result<AccountUpdaterResponseFile, errors::ApiErrorResponse> {
+    let invalid_file = |message: String| errors::ApiErrorResponse::InvalidRequestData { message };
+
+    let data = std::str::from_utf8(data)
+        .map_err(|_| invalid_file("Response file is not UTF-8 encoded".to_string()))?;
+    let mut lines = data
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let header = match lines.next() {
+        Some((_, line)) => parse_header_record(line).ok_or_else(|| {
+            invalid_file("First record of the response file is not a valid header".to_string())
+        })?,
+        None => return Err(invalid_file("Response file is empty".to_string())),
+    };
+
+    let mut records = Vec::new();
+    let mut trailer_count = None;
+    for (line_number, line) in lines {
+        if trailer_count.is_some() {
+            return Err(invalid_file(format!(
+                "Record on line {line_number} follows the trailer record"
+            )));
+        }
+        let fields = line.split(FIELD_SEPARATOR).collect::<Vec<_>>();
+        match fields.first().copied() {
+            Some(DETAIL_RECORD_TYPE) => {
+                records.push(parse_response_detail_record(&fields).map_err(|reason| {
+                    invalid_file(format!(
+                        "Invalid detail record on line {line_number}: {reason}"
+                    ))
+                })?)
+            }
+            Some(TRAILER_RECORD_TYPE) => {
+                trailer_count = Some(
+                    fields
+                        .get(1)
+                        .and_then(|count| count.parse::<usize>().ok())
+                        .ok_or_else(|| {
+                            invalid_file(format!("Invalid trailer record on line {line_number}"))
+                        })?,
+                );
+            }
+            _ => {
+                return Err(invalid_file(format!(
+                    "Unknown record type on line {line_number}"
+                )))
+            }
+        }
+    }
+
+    match trailer_count {
+        Some(count) if count == records.len() => Ok(AccountUpdaterResponseFile { header, records }),
+        Some(count) => Err(invalid_file(format!(
+            "Trailer record count {count} does not match the {} detail records of the response file",
+            records.len()
+        ))),
+        None => Err(invalid_file(
+            "Response file does not end with a trailer record".to_string(),
+        )),
+    }
+}
+
+fn parse_header_record(line: &str) -> Option<AccountUpdaterFileHeader> {
+    match line.split(FIELD_SEPARATOR).collect::<Vec<_>>().as_slice() {
+        [HEADER_RECORD_TYPE, FILE_FORMAT_VERSION, merchant_id, batch_id, created_on] => {
+            Some(AccountUpdaterFileHeader {
+                merchant_id: id_type::MerchantId::wrap(merchant_id.to_string()).ok()?,
+                batch_id: batch_id.to_string(),
+                created_on: created_on.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn parse_response_detail_record(
+    fields: &[&str],
+) -> Result<AccountUpdaterResponseRecord, &'static str> {
+    let [_, payment_method_id, response_code, new_card_number, new_card_expiry] = fields else {
+        return Err("unexpected number of fields");
+    };
+    if payment_method_id.is_empty() {
+        return Err("missing payment method id");
+    }
+    let response_code =
+        AccountUpdaterResponseCode::from_str(response_code).map_err(|_| "unknown response code")?;
+    let new_card_number = (!new_card_number.is_empty())
+        .then(|| CardNumber::from_str(new_card_number))
+        .transpose()
+        .map_err(|_| "invalid card number")?;
+    let new_card_expiry = (!new_card_expiry.is_empty())
+        .then(|| CardExpiry::from_mmyy(new_card_expiry).ok_or("invalid expiry"))
+        .transpose()?;
+
+    match response_code {
+        AccountUpdaterResponseCode::NewAccountNumber if new_card_number.is_none() => {
+            Err("new card number is required for the response code")
+        }
+        AccountUpdaterResponseCode::NewExpiry if new_card_expiry.is_none() => {
+            Err("new expiry is required for the response code")
+        }
+        _ => Ok(AccountUpdaterResponseRecord {
+            payment_method_id: payment_method_id.to_string(),
+            response_code,
+            new_card_number,
+            new_card_expiry,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_response_file() {
+        let file = "H|1.0|merchant_1|aub_1|20250601\n\
+                    D|pm_1|NUM|4111111111111111|0830\n\
+                    D|pm_2|EXP||0929\n\
+                    D|pm_3|CLO||\n\
+                    T|3\n";
+
+        let response_file = parse_response_file(file.as_bytes()).unwrap();
+
+        assert_eq!(response_file.header.batch_id, "aub_1");
+        assert_eq!(response_file.records.len(), 3);
+        assert_eq!(
+            response_file.records[0].response_code,
+            AccountUpdaterResponseCode::NewAccountNumber
+        );
+        assert_eq!(
+            response_file.records[1].new_card_expiry,
+            CardExpiry::new(9, 2029)
+        );
+        assert!(response_file.records[2].new_card_number.is_none());
+    }
+
+    #[test]
+    fn test_parse_response_file_with_invalid_records() {
+        let missing_card_number = "H|1.0|merchant_1|aub_1|20250601\nD|pm_1|NUM||0830\nT|1";
+        let count_mismatch = "H|1.0|merchant_1|aub_1|20250601\nD|pm_1|NON||\nT|2";
+        let missing_trailer = "H|1.0|merchant_1|aub_1|20250601\nD|pm_1|NON||";
+
+        assert!(parse_response_file(missing_card_number.as_bytes()).is_err());
+        assert!(parse_response_file(count_mismatch.as_bytes()).is_err());
+        assert!(parse_response_file(missing_trailer.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_card_expiry_from_card_details() {
+        let expiry = CardExpiry::from_card_details(
+            &Secret::new("03".to_string()),
+            &Secret::new("30".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(Some(expiry), CardExpiry::new(3, 2030));
+        assert_eq!(expiry.to_mmyy(), "0330");
+        assert!(expiry.expires_by(2030, 3));
+        assert!(!expiry.expires_by(2030, 2));
+    }
+}
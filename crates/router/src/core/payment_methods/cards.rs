@@ -96,7 +96,9 @@ use crate::{
     consts as router_consts,
     core::{
         errors::{self, StorageErrorExt},
-        payment_methods::{network_tokenization, transformers as payment_methods, vault},
+        payment_methods::{
+            local_vault, network_tokenization, transformers as payment_methods, vault,
+        },
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
@@ -209,6 +211,9 @@ impl PaymentMethodsController for PmCards<'_> {
                     payment_method_billing_address,
                     updated_by: None,
                     version: common_types::consts::API_VERSION,
+                    network_token_status: network_token_requestor_reference_id
+                        .as_ref()
+                        .map(|_| enums::NetworkTokenStatus::Active),
                     network_token_requestor_reference_id,
                     network_token_locker_id,
                     network_token_payment_method_data,
@@ -440,6 +445,7 @@ impl PaymentMethodsController for PmCards<'_> {
                     network_token_locker_id: Some(token_pm_resp.payment_method_id),
                     network_token_payment_method_data: pm_network_token_data_encrypted
                         .map(Into::into),
+                    network_token_status: Some(enums::NetworkTokenStatus::Active),
                 };
                 let db = &*self.state.store;
                 let existing_pm = db
@@ -1864,16 +1870,26 @@ pub async fn update_customer_payment_method(
                 .payment_method_id
                 .clone_from(&pm.payment_method_id);
 
-            db.update_payment_method(
-                &((&state).into()),
-                merchant_context.get_merchant_key_store(),
-                pm,
-                pm_update,
-                merchant_context.get_merchant_account().storage_scheme,
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to update payment method in db")?;
+            let updated_pm = db
+                .update_payment_method(
+                    &((&state).into()),
+                    merchant_context.get_merchant_key_store(),
+                    pm,
+                    pm_update,
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update payment method in db")?;
+
+            // The network token of the card is replaced, as it was issued for the old card details
+            Box::pin(network_tokenization::lifecycle::reprovision_network_token(
+                &state,
+                &merchant_context,
+                updated_pm,
+                &updated_card_details,
+            ))
+            .await?;
 
             add_card_resp
         } else {
@@ -2155,9 +2171,13 @@ where
     let jwekey = state.conf.jwekey.get_inner();
     let response_type_name = type_name!(T);
 
-    let response = services::call_connector_api(state, request, flow_name)
-        .await
-        .change_context(errors::VaultError::ApiError)?;
+    let response = if locker.local_vault {
+        local_vault::call_local_vault(state, request).await?
+    } else {
+        services::call_connector_api(state, request, flow_name)
+            .await
+            .change_context(errors::VaultError::ApiError)?
+    };
 
     let is_locker_call_succeeded = response.is_ok();
 
@@ -4123,6 +4143,86 @@ fn filter_recurring_based(
     recurring_enabled.map_or(true, |enabled| payment_method.recurring_enabled == enabled)
 }
 
+/// Explains why the payment method list of a payment leaves out a payment method of a connector,
+/// on top of the constraints of the constraint graph. The reasons are empty if at least one of the
+/// matching payment method types enabled for the connector is listed.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub fn get_payment_method_list_filter_reasons(
+    payment_methods_enabled: &[Secret<serde_json::Value>],
+    payment_method: api_enums::PaymentMethod,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+    amount: Option<MinorUnit>,
+    allowed_payment_method_types: Option<&Vec<api_enums::PaymentMethodType>>,
+) -> Vec<String> {
+    let enabled_payment_method_types = payment_methods_enabled
+        .iter()
+        .filter_map(|payment_methods_enabled| {
+            serde_json::from_value::<PaymentMethodsEnabled>(
+                payment_methods_enabled.clone().expose(),
+            )
+            .ok()
+        })
+        .filter(|payment_methods_enabled| payment_methods_enabled.payment_method == payment_method)
+        .flat_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+        })
+        .filter(|payment_method_type_info| {
+            payment_method_type.map_or(true, |payment_method_type| {
+                payment_method_type_info.payment_method_type == payment_method_type
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if enabled_payment_method_types.is_empty() {
+        let payment_method_type = payment_method_type
+            .map(|payment_method_type| format!(" {payment_method_type}"))
+            .unwrap_or_default();
+        return vec![format!(
+            "payment_method {payment_method}{payment_method_type} is not enabled for the connector (Payment methods enabled)"
+        )];
+    }
+
+    let mut reasons = Vec::new();
+    for payment_method_type_info in &enabled_payment_method_types {
+        let mut type_reasons = Vec::new();
+        if !filter_pm_based_on_allowed_types(
+            allowed_payment_method_types,
+            payment_method_type_info.payment_method_type,
+        ) {
+            type_reasons.push(format!(
+                "payment_method_type {} not in allowed_payment_method_types of the payment (Payment method list)",
+                payment_method_type_info.payment_method_type
+            ));
+        }
+        if !filter_amount_based(payment_method_type_info, amount) {
+            let limit = |limit: Option<MinorUnit>| {
+                limit.map_or("none".to_string(), |limit| {
+                    limit.get_amount_as_i64().to_string()
+                })
+            };
+            type_reasons.push(format!(
+                "payment amount {} not within the minimum {} and maximum {} of payment_method_type {} (Payment method list)",
+                limit(amount),
+                limit(payment_method_type_info.minimum_amount),
+                limit(payment_method_type_info.maximum_amount),
+                payment_method_type_info.payment_method_type
+            ));
+        }
+
+        if type_reasons.is_empty() {
+            return Vec::new();
+        }
+        reasons.extend(type_reasons);
+    }
+
+    reasons
+}
+
 #[cfg(all(
     any(feature = "v2", feature = "v1"),
     not(feature = "payment_methods_v2"),
@@ -5272,3 +5372,89 @@ pub async fn execute_payment_method_tokenization(
 
     Ok(builder.build())
 }
+
+#[cfg(all(
+    test,
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+mod tests {
+    use super::*;
+
+    fn get_payment_methods_enabled(
+        minimum_amount: Option<i64>,
+        maximum_amount: Option<i64>,
+    ) -> Vec<Secret<serde_json::Value>> {
+        vec![Secret::new(serde_json::json!({
+            "payment_method": "card",
+            "payment_method_types": [{
+                "payment_method_type": "credit",
+                "minimum_amount": minimum_amount,
+                "maximum_amount": maximum_amount,
+                "recurring_enabled": true,
+                "installment_payment_enabled": false
+            }]
+        }))]
+    }
+
+    #[test]
+    fn test_filter_reasons_of_listed_payment_method() {
+        let reasons = get_payment_method_list_filter_reasons(
+            &get_payment_methods_enabled(Some(100), Some(10000)),
+            api_enums::PaymentMethod::Card,
+            Some(api_enums::PaymentMethodType::Credit),
+            Some(MinorUnit::new(500)),
+            None,
+        );
+
+        assert!(reasons.is_empty());
+    }
+
+    #[test]
+    fn test_filter_reasons_of_payment_method_not_enabled() {
+        let reasons = get_payment_method_list_filter_reasons(
+            &get_payment_methods_enabled(None, None),
+            api_enums::PaymentMethod::Wallet,
+            Some(api_enums::PaymentMethodType::ApplePay),
+            Some(MinorUnit::new(500)),
+            None,
+        );
+
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons
+            .iter()
+            .all(|reason| reason.contains("is not enabled for the connector")));
+    }
+
+    #[test]
+    fn test_filter_reasons_of_amount_out_of_limits() {
+        let reasons = get_payment_method_list_filter_reasons(
+            &get_payment_methods_enabled(Some(100), Some(1000)),
+            api_enums::PaymentMethod::Card,
+            None,
+            Some(MinorUnit::new(5000)),
+            None,
+        );
+
+        assert_eq!(
+            reasons,
+            vec!["payment amount 5000 not within the minimum 100 and maximum 1000 of payment_method_type credit (Payment method list)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_filter_reasons_of_payment_method_type_not_allowed() {
+        let reasons = get_payment_method_list_filter_reasons(
+            &get_payment_methods_enabled(None, None),
+            api_enums::PaymentMethod::Card,
+            Some(api_enums::PaymentMethodType::Credit),
+            Some(MinorUnit::new(500)),
+            Some(&vec![api_enums::PaymentMethodType::Debit]),
+        );
+
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons
+            .iter()
+            .all(|reason| reason.contains("not in allowed_payment_method_types")));
+    }
+}
@@ -0,0 +1,994 @@
+//! Embedded vault used in place of the external vault when `locker.local_vault` is enabled.
+//!
+//! The local vault answers the requests built for the external vault, the card locker APIs as
+//! well as the vault APIs, with the same JWE/JWS contract. Requests are signed with
+//! `jwekey.vault_private_key` and encrypted with `jwekey.vault_encryption_key`, whose private key
+//! the local vault holds as `jwekey.local_vault_private_key`. Responses are signed with that key
+//! and encrypted for the application, so the callers verify and decrypt the responses of either
+//! vault in the same way, and the identifiers of the entries are always chosen by the vault.
+//!
+//! Every entry is encrypted with a data encryption key of its own. The data encryption key is
+//! encrypted with the key of the merchant from the merchant key store, and then with the
+//! encryption management client. Merchant key stores are encrypted with the master key of the
+//! tenant and entries are stored in the schema of the tenant, which gives every tenant a key
+//! hierarchy of its own.
+
+use api_models::payment_methods::Card;
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, GcmAes256, HmacSha512, SignMessage},
+    ext_traits::{ByteSliceExt, Encode, StringExt},
+    generate_id, id_type,
+};
+use error_stack::{report, ResultExt};
+use josekit::{jwe, jwk::alg::rsa::RsaKeyPair};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+use crate::types::payment_methods as pm_types;
+use crate::{
+    configs::settings,
+    consts,
+    core::{errors, payment_methods::transformers as payment_methods},
+    events::audit_events::{AuditEvent, AuditEventType, LocalVaultOperation},
+    routes::SessionState,
+    services::{self, encryption},
+    types::{self, domain, storage},
+};
+
+const DATA_ENCRYPTION_KEY_LENGTH: usize = 32;
+
+/// Context used to derive the fingerprint key of a merchant from its key, so that the key used
+/// for encryption is never used directly for fingerprinting
+const FINGERPRINT_KEY_CONTEXT: &[u8] = b"local_vault_fingerprint";
+
+const ADD_CARD_REQUEST_URL: &str = "/cards/add";
+const RETRIEVE_CARD_REQUEST_URL: &str = "/cards/retrieve";
+const DELETE_CARD_REQUEST_URL: &str = "/cards/delete";
+
+/// The APIs of the external vault served by the local vault
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LocalVaultEndpoint {
+    AddCard,
+    RetrieveCard,
+    DeleteCard,
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    AddVault,
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    VaultFingerprint,
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    VaultRetrieve,
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    VaultDelete,
+}
+
+impl LocalVaultEndpoint {
+    fn from_request_url(url: &str) -> Option<Self> {
+        [
+            (ADD_CARD_REQUEST_URL, Self::AddCard),
+            (RETRIEVE_CARD_REQUEST_URL, Self::RetrieveCard),
+            (DELETE_CARD_REQUEST_URL, Self::DeleteCard),
+            #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+            (consts::ADD_VAULT_REQUEST_URL, Self::AddVault),
+            #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+            (
+                consts::VAULT_FINGERPRINT_REQUEST_URL,
+                Self::VaultFingerprint,
+            ),
+            #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+            (consts::VAULT_RETRIEVE_REQUEST_URL, Self::VaultRetrieve),
+            #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+            (consts::VAULT_DELETE_REQUEST_URL, Self::VaultDelete),
+        ]
+        .into_iter()
+        .find(|(endpoint_url, _)| url.ends_with(endpoint_url))
+        .map(|(_, endpoint)| endpoint)
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum LocalVaultData {
+    Card(Card),
+    Generic {
+        enc_card_data: Secret<String>,
+    },
+    /// Data stored through the vault APIs, which is returned as it was stored
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    Vault {
+        data: Secret<serde_json::Value>,
+    },
+}
+
+impl LocalVaultData {
+    fn get_fingerprint_data(&self) -> String {
+        match self {
+            Self::Card(card) => card.card_number.peek().to_owned(),
+            Self::Generic { enc_card_data } => enc_card_data.peek().to_owned(),
+            #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+            Self::Vault { data } => data.peek().to_string(),
+        }
+    }
+}
+
+/// Error response of the vault, in the shape of the responses of the card locker APIs
+#[derive(Debug, serde::Serialize)]
+struct LocalVaultErrorResponse {
+    status: &'static str,
+    error_message: String,
+    error_code: String,
+}
+
+/// Keys with which the local vault reads the requests and answers them
+struct LocalVaultKeys<'a> {
+    /// Private key of the vault, to decrypt the requests and sign the responses
+    vault_private_key: &'a [u8],
+    /// Public key of the application, to verify the requests and encrypt the responses
+    application_public_key: Vec<u8>,
+    signing_key_id: &'a str,
+    /// Algorithm with which the application decrypts the responses of the vault
+    response_encryption_algorithm: jwe::alg::rsaes::RsaesJweAlgorithm,
+}
+
+impl<'a> LocalVaultKeys<'a> {
+    fn new(
+        jwekey: &'a settings::Jwekey,
+        locker: &'a settings::Locker,
+    ) -> errors::CustomResult<Self, errors::VaultError> {
+        let application_public_key = RsaKeyPair::from_pem(jwekey.vault_private_key.peek())
+            .change_context(errors::VaultError::ResponseDecryptionFailed)
+            .attach_printable("Failed to read the vault private key of the application")?
+            .to_pem_public_key();
+
+        Ok(Self {
+            vault_private_key: jwekey.local_vault_private_key.peek().as_bytes(),
+            application_public_key,
+            signing_key_id: &locker.locker_signing_key_id,
+            response_encryption_algorithm: match locker.decryption_scheme {
+                settings::DecryptionScheme::RsaOaep => jwe::RSA_OAEP,
+                settings::DecryptionScheme::RsaOaep256 => jwe::RSA_OAEP_256,
+            },
+        })
+    }
+
+    async fn decrypt_request(
+        &self,
+        request: &services::Request,
+    ) -> errors::CustomResult<String, errors::VaultError> {
+        let jwe_body = request
+            .body
+            .as_ref()
+            .ok_or(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Missing body in the local vault request")?
+            .get_inner_value()
+            .peek()
+            .parse_struct::<encryption::JweBody>("JweBody")
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+        let jws = encryption::decrypt_jwe(
+            &payment_methods::get_dotted_jwe(jwe_body),
+            encryption::KeyIdCheck::SkipKeyIdCheck,
+            self.vault_private_key,
+            jwe::RSA_OAEP_256,
+        )
+        .await
+        .change_context(errors::VaultError::ResponseDecryptionFailed)
+        .attach_printable("Failed to decrypt the local vault request")?
+        .parse_struct::<encryption::JwsBody>("JwsBody")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        encryption::verify_sign(
+            payment_methods::get_dotted_jws(jws),
+            &self.application_public_key,
+        )
+        .change_context(errors::VaultError::ResponseDecryptionFailed)
+        .attach_printable("Failed to verify the signature of the local vault request")
+    }
+
+    async fn encrypt_response(
+        &self,
+        payload: &str,
+    ) -> errors::CustomResult<encryption::JweBody, errors::VaultError> {
+        let jws = encryption::jws_sign_payload(
+            payload.as_bytes(),
+            self.signing_key_id,
+            self.vault_private_key,
+        )
+        .await
+        .change_context(errors::VaultError::RequestEncryptionFailed)
+        .attach_printable("Failed to sign the local vault response")?;
+        let jws_body = split_jws(&jws)?
+            .encode_to_vec()
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+        let mut header = jwe::JweHeader::new();
+        header.set_content_encryption(encryption::EncryptionAlgorithm::A256GCM.as_ref());
+        header.set_token_type("JWT");
+        let encrypter = self
+            .response_encryption_algorithm
+            .encrypter_from_pem(&self.application_public_key)
+            .change_context(errors::VaultError::RequestEncryptionFailed)
+            .attach_printable("Failed to read the public key of the application")?;
+        let jwe = jwe::serialize_compact(&jws_body, &header, &encrypter)
+            .change_context(errors::VaultError::RequestEncryptionFailed)
+            .attach_printable("Failed to encrypt the local vault response")?;
+
+        split_jwe(&jwe)
+    }
+}
+
+/// Answers a request built for the external vault, see the module documentation. The response
+/// has the same shape as the one received from the external vault.
+#[instrument(skip_all)]
+pub async fn call_local_vault(
+    state: &SessionState,
+    request: services::Request,
+) -> errors::CustomResult<Result<types::Response, types::Response>, errors::VaultError> {
+    let keys = LocalVaultKeys::new(state.conf.jwekey.get_inner(), &state.conf.locker)?;
+    let endpoint = LocalVaultEndpoint::from_request_url(&request.url)
+        .ok_or(errors::VaultError::VaultAPIError)
+        .attach_printable_lazy(|| format!("The local vault does not serve {}", request.url))?;
+    let payload = keys.decrypt_request(&request).await?;
+
+    let response = match endpoint {
+        LocalVaultEndpoint::AddCard => add_card(state, &payload).await,
+        LocalVaultEndpoint::RetrieveCard => retrieve_card(state, &payload).await,
+        LocalVaultEndpoint::DeleteCard => delete_card(state, &payload).await,
+        #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+        LocalVaultEndpoint::AddVault => add_vault_data(state, &payload).await,
+        #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+        LocalVaultEndpoint::VaultFingerprint => get_vault_fingerprint(&payload),
+        #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+        LocalVaultEndpoint::VaultRetrieve => retrieve_vault_data(state, &payload).await,
+        #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+        LocalVaultEndpoint::VaultDelete => delete_vault_data(state, &payload).await,
+    };
+
+    let (status_code, response_payload) = match response {
+        Ok(response_payload) => (200, response_payload),
+        Err(error) => {
+            logger::error!(?error, ?endpoint, "Local vault request failed");
+            let status_code = match error.current_context() {
+                errors::VaultError::FetchCardFailed | errors::VaultError::DeleteCardFailed => 404,
+                errors::VaultError::RequestEncodingFailed
+                | errors::VaultError::ResponseDeserializationFailed => 400,
+                _ => 500,
+            };
+            let error_response = LocalVaultErrorResponse {
+                status: "Error",
+                error_message: error.current_context().to_string(),
+                error_code: status_code.to_string(),
+            }
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+            (status_code, error_response)
+        }
+    };
+
+    let response = types::Response {
+        headers: None,
+        response: keys
+            .encrypt_response(&response_payload)
+            .await?
+            .encode_to_vec()
+            .change_context(errors::VaultError::RequestEncodingFailed)?
+            .into(),
+        status_code,
+    };
+    Ok(if status_code == 200 {
+        Ok(response)
+    } else {
+        Err(response)
+    })
+}
+
+async fn add_card(
+    state: &SessionState,
+    payload: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let store_locker_req = payload
+        .parse_struct::<payment_methods::StoreLockerReq>("StoreLockerReq")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    let (merchant_id, customer_id, requestor_card_reference, data) = match store_locker_req {
+        payment_methods::StoreLockerReq::LockerCard(store_card_req) => (
+            store_card_req.merchant_id,
+            store_card_req.merchant_customer_id,
+            store_card_req.requestor_card_reference,
+            LocalVaultData::Card(store_card_req.card),
+        ),
+        payment_methods::StoreLockerReq::LockerGeneric(store_generic_req) => (
+            store_generic_req.merchant_id,
+            store_generic_req.merchant_customer_id,
+            None,
+            LocalVaultData::Generic {
+                enc_card_data: Secret::new(store_generic_req.enc_data),
+            },
+        ),
+    };
+
+    let db = &*state.store;
+    let key_store = get_merchant_key_store(state, &merchant_id).await?;
+    let fingerprint_id = generate_fingerprint(&key_store, &data.get_fingerprint_data())?;
+    let encoded_data = data
+        .encode_to_vec()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+    // The reference requested by the caller is only used to replace an entry the customer already
+    // has, new entries are always stored under a reference generated by the vault
+    let requested_entry = match requestor_card_reference {
+        Some(card_reference) => {
+            find_customer_entry(state, &merchant_id, &customer_id, &card_reference)
+                .await?
+                .or_else(|| {
+                    logger::warn!(
+                        "Requested card reference not found in the local vault, ignoring it"
+                    );
+                    None
+                })
+        }
+        None => None,
+    };
+    let existing_entry = db
+        .find_local_vault_entry_by_fingerprint_id(&merchant_id, &customer_id, &fingerprint_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to find local vault entry by fingerprint")?;
+
+    let (vault_id, duplication_check) = match (existing_entry, requested_entry) {
+        (Some(existing_entry), requested_entry)
+            if requested_entry.as_ref().map_or(true, |requested_entry| {
+                requested_entry.vault_id == existing_entry.vault_id
+            }) =>
+        {
+            let existing_data = decrypt_data(state, &key_store, &existing_entry).await?;
+            if existing_data == encoded_data {
+                emit_audit_event(
+                    state,
+                    LocalVaultOperation::Store,
+                    &merchant_id,
+                    &existing_entry.vault_id,
+                );
+                return store_card_response(
+                    existing_entry.vault_id,
+                    Some(payment_methods::DataDuplicationCheck::Duplicated),
+                );
+            }
+
+            // The data has the same fingerprint but different metadata, it is replaced by the
+            // new data under the same reference
+            update_entry(
+                state,
+                &key_store,
+                &merchant_id,
+                &existing_entry.vault_id,
+                fingerprint_id,
+                &encoded_data,
+            )
+            .await?;
+            (
+                existing_entry.vault_id,
+                Some(payment_methods::DataDuplicationCheck::MetaDataChanged),
+            )
+        }
+        (_, Some(requested_entry)) => {
+            // Replace the data stored under the reference of the customer requested by the caller
+            update_entry(
+                state,
+                &key_store,
+                &merchant_id,
+                &requested_entry.vault_id,
+                fingerprint_id,
+                &encoded_data,
+            )
+            .await?;
+            (requested_entry.vault_id, None)
+        }
+        (_, None) => {
+            let vault_id = generate_id(consts::ID_LENGTH, "card");
+            insert_entry(
+                state,
+                &key_store,
+                storage::LocalVaultNew {
+                    vault_id: vault_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    customer_id: Some(customer_id),
+                    fingerprint_id,
+                    data_encryption_key: Vec::new(),
+                    encrypted_data: Vec::new(),
+                    created_at: common_utils::date_time::now(),
+                },
+                &encoded_data,
+            )
+            .await?;
+            (vault_id, None)
+        }
+    };
+
+    emit_audit_event(state, LocalVaultOperation::Store, &merchant_id, &vault_id);
+    store_card_response(vault_id, duplication_check)
+}
+
+async fn retrieve_card(
+    state: &SessionState,
+    payload: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let card_req = payload
+        .parse_struct::<payment_methods::CardReqBody>("CardReqBody")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    let entry = find_customer_entry(
+        state,
+        &card_req.merchant_id,
+        &card_req.merchant_customer_id,
+        &card_req.card_reference,
+    )
+    .await?
+    .ok_or(errors::VaultError::FetchCardFailed)
+    .attach_printable("Local vault entry not found")?;
+
+    let payload = match retrieve_data(state, &card_req.merchant_id, &entry).await? {
+        LocalVaultData::Card(card) => payment_methods::RetrieveCardRespPayload {
+            card: Some(card),
+            enc_card_data: None,
+        },
+        LocalVaultData::Generic { enc_card_data } => payment_methods::RetrieveCardRespPayload {
+            card: None,
+            enc_card_data: Some(enc_card_data),
+        },
+        #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+        LocalVaultData::Vault { .. } => Err(report!(errors::VaultError::FetchCardFailed))
+            .attach_printable("Local vault entry was not stored through the card APIs")?,
+    };
+
+    payment_methods::RetrieveCardResp {
+        status: "Ok".to_string(),
+        error_message: None,
+        error_code: None,
+        payload: Some(payload),
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::VaultError::RequestEncodingFailed)
+}
+
+async fn delete_card(
+    state: &SessionState,
+    payload: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let card_req = payload
+        .parse_struct::<payment_methods::CardReqBody>("CardReqBody")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    let entry = find_customer_entry(
+        state,
+        &card_req.merchant_id,
+        &card_req.merchant_customer_id,
+        &card_req.card_reference,
+    )
+    .await?
+    .ok_or(errors::VaultError::DeleteCardFailed)
+    .attach_printable("Local vault entry not found")?;
+    delete_entry(state, &card_req.merchant_id, &entry.vault_id)
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)
+        .attach_printable("Failed to delete local vault entry")?;
+
+    payment_methods::DeleteCardResp {
+        status: "Ok".to_string(),
+        error_message: None,
+        error_code: None,
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::VaultError::RequestEncodingFailed)
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+async fn add_vault_data(
+    state: &SessionState,
+    payload: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let add_vault_req = payload
+        .parse_struct::<pm_types::AddVaultRequest<Secret<serde_json::Value>>>("AddVaultRequest")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    let key_store = get_merchant_key_store(state, &add_vault_req.entity_id).await?;
+    let data = LocalVaultData::Vault {
+        data: add_vault_req.data,
+    };
+    let fingerprint_id = generate_fingerprint(&key_store, &data.get_fingerprint_data())?;
+    let encoded_data = data
+        .encode_to_vec()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+    insert_entry(
+        state,
+        &key_store,
+        storage::LocalVaultNew {
+            vault_id: add_vault_req.vault_id.get_string_repr().to_owned(),
+            merchant_id: add_vault_req.entity_id.clone(),
+            customer_id: None,
+            fingerprint_id: fingerprint_id.clone(),
+            data_encryption_key: Vec::new(),
+            encrypted_data: Vec::new(),
+            created_at: common_utils::date_time::now(),
+        },
+        &encoded_data,
+    )
+    .await?;
+
+    emit_audit_event(
+        state,
+        LocalVaultOperation::Store,
+        &add_vault_req.entity_id,
+        add_vault_req.vault_id.get_string_repr(),
+    );
+    pm_types::AddVaultResponse {
+        entity_id: Some(add_vault_req.entity_id),
+        vault_id: add_vault_req.vault_id,
+        fingerprint_id: Some(fingerprint_id),
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::VaultError::RequestEncodingFailed)
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+fn get_vault_fingerprint(payload: &str) -> errors::CustomResult<String, errors::VaultError> {
+    let fingerprint_req = payload
+        .parse_struct::<pm_types::VaultFingerprintRequest>("VaultFingerprintRequest")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    let fingerprint = HmacSha512
+        .sign_message(
+            fingerprint_req.key.as_bytes(),
+            fingerprint_req.data.as_bytes(),
+        )
+        .change_context(errors::VaultError::GenerateFingerprintFailed)?;
+
+    pm_types::VaultFingerprintResponse {
+        fingerprint_id: hex::encode(fingerprint),
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::VaultError::RequestEncodingFailed)
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+async fn retrieve_vault_data(
+    state: &SessionState,
+    payload: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let vault_req = payload
+        .parse_struct::<pm_types::VaultRetrieveRequest>("VaultRetrieveRequest")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    let entry = state
+        .store
+        .find_local_vault_entry_by_merchant_id_vault_id(
+            &vault_req.entity_id,
+            vault_req.vault_id.get_string_repr(),
+        )
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to find local vault entry")?;
+
+    match retrieve_data(state, &vault_req.entity_id, &entry).await? {
+        LocalVaultData::Vault { data } => serde_json::json!({ "data": data.peek() })
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::RequestEncodingFailed),
+        LocalVaultData::Card(_) | LocalVaultData::Generic { .. } => {
+            Err(report!(errors::VaultError::FetchCardFailed))
+                .attach_printable("Local vault entry was not stored through the vault APIs")
+        }
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+async fn delete_vault_data(
+    state: &SessionState,
+    payload: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let vault_req = payload
+        .parse_struct::<pm_types::VaultDeleteRequest>("VaultDeleteRequest")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    delete_entry(
+        state,
+        &vault_req.entity_id,
+        vault_req.vault_id.get_string_repr(),
+    )
+    .await
+    .change_context(errors::VaultError::DeleteCardFailed)
+    .attach_printable("Failed to delete local vault entry")?;
+
+    pm_types::VaultDeleteResponse {
+        entity_id: vault_req.entity_id,
+        vault_id: vault_req.vault_id,
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::VaultError::RequestEncodingFailed)
+}
+
+/// Finds the entry stored under the reference for the customer, entries of other customers are
+/// never returned
+async fn find_customer_entry(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> errors::CustomResult<Option<storage::LocalVault>, errors::VaultError> {
+    match state
+        .store
+        .find_local_vault_entry_by_merchant_id_vault_id(merchant_id, card_reference)
+        .await
+    {
+        Ok(entry) => {
+            Ok(Some(entry).filter(|entry| entry.customer_id.as_ref() == Some(customer_id)))
+        }
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::VaultError::FetchCardFailed)
+            .attach_printable("Failed to find local vault entry"),
+    }
+}
+
+async fn retrieve_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    entry: &storage::LocalVault,
+) -> errors::CustomResult<LocalVaultData, errors::VaultError> {
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    let data = decrypt_data(state, &key_store, entry)
+        .await?
+        .parse_struct::<LocalVaultData>("LocalVaultData")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    emit_audit_event(
+        state,
+        LocalVaultOperation::Retrieve,
+        merchant_id,
+        &entry.vault_id,
+    );
+    Ok(data)
+}
+
+/// Encrypts the data and stores it in the entry
+async fn insert_entry(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    entry: storage::LocalVaultNew,
+    data: &[u8],
+) -> errors::CustomResult<(), errors::VaultError> {
+    let (data_encryption_key, encrypted_data) = encrypt_data(state, key_store, data).await?;
+    state
+        .store
+        .insert_local_vault_entry(storage::LocalVaultNew {
+            data_encryption_key,
+            encrypted_data,
+            ..entry
+        })
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to insert local vault entry")?;
+
+    Ok(())
+}
+
+/// The entry is replaced in place once the new data is encrypted, so that a failure leaves the
+/// previously stored data untouched
+async fn update_entry(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &str,
+    fingerprint_id: String,
+    data: &[u8],
+) -> errors::CustomResult<(), errors::VaultError> {
+    let (data_encryption_key, encrypted_data) = encrypt_data(state, key_store, data).await?;
+    state
+        .store
+        .update_local_vault_entry_by_merchant_id_vault_id(
+            merchant_id,
+            vault_id,
+            storage::LocalVaultUpdate {
+                fingerprint_id,
+                data_encryption_key,
+                encrypted_data,
+            },
+        )
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to update local vault entry")?;
+
+    Ok(())
+}
+
+async fn delete_entry(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &str,
+) -> errors::CustomResult<(), errors::StorageError> {
+    state
+        .store
+        .delete_local_vault_entry_by_merchant_id_vault_id(merchant_id, vault_id)
+        .await?;
+    emit_audit_event(state, LocalVaultOperation::Delete, merchant_id, vault_id);
+    Ok(())
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> errors::CustomResult<domain::MerchantKeyStore, errors::VaultError> {
+    state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to fetch merchant key store for local vault")
+}
+
+fn generate_fingerprint(
+    key_store: &domain::MerchantKeyStore,
+    data: &str,
+) -> errors::CustomResult<String, errors::VaultError> {
+    let fingerprint_key = HmacSha512
+        .sign_message(key_store.key.get_inner().peek(), FINGERPRINT_KEY_CONTEXT)
+        .change_context(errors::VaultError::GenerateFingerprintFailed)?;
+    let fingerprint = HmacSha512
+        .sign_message(&fingerprint_key, data.as_bytes())
+        .change_context(errors::VaultError::GenerateFingerprintFailed)?;
+
+    Ok(hex::encode(fingerprint))
+}
+
+/// Returns the wrapped data encryption key and the data encrypted with it
+async fn encrypt_data(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    data: &[u8],
+) -> errors::CustomResult<(Vec<u8>, Vec<u8>), errors::VaultError> {
+    let data_encryption_key =
+        crypto::generate_cryptographically_secure_random_bytes::<DATA_ENCRYPTION_KEY_LENGTH>();
+
+    let encrypted_data = GcmAes256
+        .encode_message(&data_encryption_key, data)
+        .change_context(errors::VaultError::RequestEncryptionFailed)?;
+
+    let data_encryption_key = GcmAes256
+        .encode_message(key_store.key.get_inner().peek(), &data_encryption_key)
+        .change_context(errors::VaultError::RequestEncryptionFailed)?;
+    let data_encryption_key = state
+        .encryption_client
+        .encrypt(&data_encryption_key)
+        .await
+        .change_context(errors::VaultError::RequestEncryptionFailed)
+        .attach_printable("Failed to wrap data encryption key")?;
+
+    Ok((data_encryption_key, encrypted_data))
+}
+
+async fn decrypt_data(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    entry: &storage::LocalVault,
+) -> errors::CustomResult<Vec<u8>, errors::VaultError> {
+    let data_encryption_key = state
+        .encryption_client
+        .decrypt(&entry.data_encryption_key)
+        .await
+        .change_context(errors::VaultError::ResponseDecryptionFailed)
+        .attach_printable("Failed to unwrap data encryption key")?;
+    let data_encryption_key = GcmAes256
+        .decode_message(
+            key_store.key.get_inner().peek(),
+            Secret::new(data_encryption_key),
+        )
+        .change_context(errors::VaultError::ResponseDecryptionFailed)?;
+
+    GcmAes256
+        .decode_message(
+            &data_encryption_key,
+            Secret::new(entry.encrypted_data.clone()),
+        )
+        .change_context(errors::VaultError::ResponseDecryptionFailed)
+}
+
+fn split_jws(jws: &str) -> errors::CustomResult<encryption::JwsBody, errors::VaultError> {
+    let mut parts = jws.split('.');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(header), Some(payload), Some(signature)) => Ok(encryption::JwsBody {
+            header: header.to_string(),
+            payload: payload.to_string(),
+            signature: signature.to_string(),
+        }),
+        _ => Err(report!(errors::VaultError::RequestEncryptionFailed))
+            .attach_printable("Invalid JWS in the local vault response"),
+    }
+}
+
+fn split_jwe(jwe: &str) -> errors::CustomResult<encryption::JweBody, errors::VaultError> {
+    let mut parts = jwe.split('.');
+    match (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) {
+        (Some(header), Some(encrypted_key), Some(iv), Some(encrypted_payload), Some(tag)) => {
+            Ok(encryption::JweBody {
+                header: header.to_string(),
+                iv: iv.to_string(),
+                encrypted_payload: encrypted_payload.to_string(),
+                tag: tag.to_string(),
+                encrypted_key: encrypted_key.to_string(),
+            })
+        }
+        _ => Err(report!(errors::VaultError::RequestEncryptionFailed))
+            .attach_printable("Invalid JWE in the local vault response"),
+    }
+}
+
+fn store_card_response(
+    card_reference: String,
+    duplication_check: Option<payment_methods::DataDuplicationCheck>,
+) -> errors::CustomResult<String, errors::VaultError> {
+    payment_methods::StoreCardResp {
+        status: "Ok".to_string(),
+        error_message: None,
+        error_code: None,
+        payload: Some(payment_methods::StoreCardRespPayload {
+            card_reference,
+            duplication_check,
+        }),
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::VaultError::RequestEncodingFailed)
+}
+
+fn emit_audit_event(
+    state: &SessionState,
+    operation: LocalVaultOperation,
+    merchant_id: &id_type::MerchantId,
+    vault_id: &str,
+) {
+    logger::info!(?operation, vault_id, "Local vault accessed");
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::LocalVaultAccess {
+            operation,
+            merchant_id: merchant_id.to_owned(),
+            vault_id: vault_id.to_owned(),
+        }))
+        .emit();
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_local_vault_endpoint_from_request_url() {
+        assert_eq!(
+            LocalVaultEndpoint::from_request_url("http://localhost:8080/cards/add"),
+            Some(LocalVaultEndpoint::AddCard)
+        );
+        assert_eq!(
+            LocalVaultEndpoint::from_request_url("/cards/retrieve"),
+            Some(LocalVaultEndpoint::RetrieveCard)
+        );
+        assert_eq!(
+            LocalVaultEndpoint::from_request_url("/cards/delete"),
+            Some(LocalVaultEndpoint::DeleteCard)
+        );
+        assert_eq!(
+            LocalVaultEndpoint::from_request_url("/cards/fingerprint"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_split_jws_and_jwe() {
+        let jws_body = split_jws("header.payload.signature").expect("Invalid JWS");
+        assert_eq!(
+            payment_methods::get_dotted_jws(jws_body),
+            "header.payload.signature"
+        );
+        assert!(split_jws("header.payload").is_err());
+
+        let jwe_body = split_jwe("header.key.iv.payload.tag").expect("Invalid JWE");
+        assert_eq!(
+            payment_methods::get_dotted_jwe(jwe_body),
+            "header.key.iv.payload.tag"
+        );
+        assert!(split_jwe("header.key.iv.payload").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_local_vault_request_and_response_encryption() {
+        let application_key_pair = RsaKeyPair::generate(2048).expect("Failed to generate key");
+        let vault_key_pair = RsaKeyPair::generate(2048).expect("Failed to generate key");
+        let application_private_key = application_key_pair.to_pem_private_key();
+        let vault_private_key = vault_key_pair.to_pem_private_key();
+        let keys = LocalVaultKeys {
+            vault_private_key: &vault_private_key,
+            application_public_key: application_key_pair.to_pem_public_key(),
+            signing_key_id: "1",
+            response_encryption_algorithm: jwe::RSA_OAEP,
+        };
+
+        // Request built the way the application builds the requests of the external vault
+        let jws = encryption::jws_sign_payload(b"request", "1", &application_private_key)
+            .await
+            .expect("Failed to sign request");
+        let jwe = encryption::encrypt_jwe(
+            &split_jws(&jws)
+                .expect("Invalid JWS")
+                .encode_to_vec()
+                .expect("Failed to encode JWS"),
+            vault_key_pair.to_pem_public_key(),
+            encryption::EncryptionAlgorithm::A256GCM,
+            None,
+        )
+        .await
+        .expect("Failed to encrypt request");
+        let mut request = services::Request::new(services::Method::Post, "/cards/add");
+        request.set_body(common_utils::request::RequestContent::Json(Box::new(
+            split_jwe(&jwe).expect("Invalid JWE"),
+        )));
+
+        assert_eq!(
+            keys.decrypt_request(&request)
+                .await
+                .expect("Failed to decrypt request"),
+            "request"
+        );
+
+        // Response read the way the application reads the responses of the external vault
+        let response = keys
+            .encrypt_response("response")
+            .await
+            .expect("Failed to encrypt response");
+        let jws = encryption::decrypt_jwe(
+            &payment_methods::get_dotted_jwe(response),
+            encryption::KeyIdCheck::SkipKeyIdCheck,
+            &application_private_key,
+            jwe::RSA_OAEP,
+        )
+        .await
+        .expect("Failed to decrypt response")
+        .parse_struct::<encryption::JwsBody>("JwsBody")
+        .expect("Invalid JWS");
+
+        assert_eq!(
+            encryption::verify_sign(
+                payment_methods::get_dotted_jws(jws),
+                vault_key_pair.to_pem_public_key()
+            )
+            .expect("Failed to verify response"),
+            "response"
+        );
+    }
+
+    #[test]
+    fn test_local_vault_data_round_trip() {
+        let data = LocalVaultData::Generic {
+            enc_card_data: Secret::new("encrypted_card_data".to_string()),
+        };
+        let encoded_data = data.encode_to_vec().expect("Failed to encode");
+        let decoded_data = encoded_data
+            .parse_struct::<LocalVaultData>("LocalVaultData")
+            .expect("Failed to decode");
+
+        assert_eq!(
+            decoded_data.encode_to_vec().expect("Failed to encode"),
+            encoded_data
+        );
+        assert_eq!(decoded_data.get_fingerprint_data(), "encrypted_card_data");
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "v1")]
+pub mod lifecycle;
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use std::fmt::Debug;
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -733,6 +736,33 @@ pub async fn check_token_status_with_tokenization_service(
     tokenization_service: &settings::NetworkTokenizationService,
 ) -> CustomResult<(Option<Secret<String>>, Option<Secret<String>>), errors::NetworkTokenizationError>
 {
+    let token_status = get_token_status_from_tokenization_service(
+        state,
+        customer_id,
+        network_token_requestor_reference_id,
+        tokenization_service,
+    )
+    .await?;
+
+    match token_status.token_status {
+        pm_types::TokenStatus::Active => Ok((
+            Some(token_status.token_expiry_month),
+            Some(token_status.token_expiry_year),
+        )),
+        pm_types::TokenStatus::Inactive => Ok((None, None)),
+    }
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn get_token_status_from_tokenization_service(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    network_token_requestor_reference_id: String,
+    tokenization_service: &settings::NetworkTokenizationService,
+) -> CustomResult<pm_types::CheckTokenStatusResponsePayload, errors::NetworkTokenizationError> {
     let mut request = services::Request::new(
         services::Method::Post,
         tokenization_service.check_token_status_url.as_str(),
@@ -790,13 +820,7 @@ pub async fn check_token_status_with_tokenization_service(
         .parse_struct("Delete Network Tokenization Response")
         .change_context(errors::NetworkTokenizationError::ResponseDeserializationFailed)?;
 
-    match check_token_status_response.payload.token_status {
-        pm_types::TokenStatus::Active => Ok((
-            Some(check_token_status_response.payload.token_expiry_month),
-            Some(check_token_status_response.payload.token_expiry_year),
-        )),
-        pm_types::TokenStatus::Inactive => Ok((None, None)),
-    }
+    Ok(check_token_status_response.payload)
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -0,0 +1,589 @@
+//! Lifecycle management of the network tokens provisioned for stored cards. Token status
+//! changes are received as webhooks from the network tokenization service, tokens are
+//! re-provisioned when the underlying card is updated, and per-token metadata is cached in redis.
+
+use ::payment_methods::controller::PaymentMethodsController;
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::{
+    crypto::{HmacSha256, VerifySignature},
+    ext_traits::{AsyncExt, BytesExt, ValueExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use super::{
+    delete_network_token_from_locker_and_token_service,
+    delete_network_token_from_tokenization_service, get_token_status_from_tokenization_service,
+    make_card_network_tokenization_request,
+};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards::{self, PmCards},
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    settings,
+    types::{
+        api, domain, payment_methods as pm_types,
+        storage::{self, enums},
+    },
+};
+
+fn get_network_token_metadata_key(network_token_requestor_reference_id: &str) -> String {
+    format!("network_token_metadata_{network_token_requestor_reference_id}")
+}
+
+fn verify_lifecycle_webhook_signature(
+    tokenization_service: &settings::NetworkTokenizationService,
+    signature: Option<&str>,
+    body: &[u8],
+) -> RouterResult<()> {
+    let webhook_secret = tokenization_service.webhook_secret.peek();
+    if webhook_secret.is_empty() {
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Webhook secret for network tokenization service is not configured");
+    }
+
+    let signature = signature
+        .map(hex::decode)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Failed to decode network token webhook signature")?
+        .ok_or(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Missing network token webhook signature")?;
+
+    let is_verified = HmacSha256
+        .verify_signature(webhook_secret.as_bytes(), &signature, body)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    if is_verified {
+        Ok(())
+    } else {
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network token webhook signature verification failed")
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn handle_network_token_lifecycle_webhook(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    signature: Option<String>,
+    body: bytes::Bytes,
+) -> RouterResponse<serde_json::Value> {
+    let tokenization_service = state.conf.network_tokenization_service.as_ref().ok_or(
+        errors::ApiErrorResponse::NotSupported {
+            message: "Network tokenization service is not configured".to_string(),
+        },
+    )?;
+    verify_lifecycle_webhook_signature(
+        tokenization_service.get_inner(),
+        signature.as_deref(),
+        &body,
+    )?;
+
+    let webhook: pm_types::NetworkTokenLifecycleWebhook = body
+        .parse_struct("NetworkTokenLifecycleWebhook")
+        .change_context(errors::ApiErrorResponse::WebhookUnprocessableEntity)?;
+
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            &webhook.card_reference,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let payment_method_update = match webhook.event {
+        pm_types::NetworkTokenLifecycleEvent::Suspend => {
+            storage::PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status: Some(enums::NetworkTokenStatus::Suspended),
+            }
+        }
+        pm_types::NetworkTokenLifecycleEvent::Resume => {
+            storage::PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status: Some(enums::NetworkTokenStatus::Active),
+            }
+        }
+        pm_types::NetworkTokenLifecycleEvent::Delete => {
+            // The token is already deleted at the token service, only the locker copy is removed
+            if let Some(network_token_locker_id) = &payment_method.network_token_locker_id {
+                let _ = PmCards {
+                    state: &state,
+                    merchant_context: &merchant_context,
+                }
+                .delete_card_from_locker(
+                    &payment_method.customer_id,
+                    &payment_method.merchant_id,
+                    network_token_locker_id,
+                )
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to delete network token from locker")
+                });
+            }
+            // The payment method no longer refers to the token, so that merchant initiated
+            // payments fall back to the card instead of fetching the deleted token
+            storage::PaymentMethodUpdate::NetworkTokenDataClear {
+                network_token_status: Some(enums::NetworkTokenStatus::Deleted),
+            }
+        }
+    };
+
+    logger::info!(
+        payment_method_id = %payment_method.payment_method_id,
+        event = ?webhook.event,
+        "Updating network token of payment method from lifecycle webhook"
+    );
+    update_network_token(
+        &state,
+        &merchant_context,
+        payment_method,
+        payment_method_update,
+    )
+    .await?;
+    invalidate_network_token_metadata(&state, &webhook.card_reference).await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+async fn update_network_token(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    payment_method_update: storage::PaymentMethodUpdate,
+) -> RouterResult<domain::PaymentMethod> {
+    state
+        .store
+        .update_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            payment_method_update,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token of payment method")
+}
+
+/// Replaces the network token of a payment method after its card was updated. Failures are not
+/// propagated, the network token is removed from the payment method instead.
+#[instrument(skip_all)]
+pub async fn reprovision_network_token(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: domain::PaymentMethod,
+    card: &api::CardDetail,
+) -> RouterResult<domain::PaymentMethod> {
+    let Some(network_token_requestor_reference_id) =
+        payment_method.network_token_requestor_reference_id.clone()
+    else {
+        return Ok(payment_method);
+    };
+
+    delete_existing_network_token(
+        state,
+        merchant_context,
+        &payment_method,
+        network_token_requestor_reference_id.clone(),
+    )
+    .await;
+    invalidate_network_token_metadata(state, &network_token_requestor_reference_id).await;
+
+    let payment_method_update =
+        match provision_network_token(state, merchant_context, &payment_method, card).await {
+            Ok(payment_method_update) => payment_method_update,
+            Err(error) => {
+                logger::error!(?error, "Failed to re-provision network token");
+                storage::PaymentMethodUpdate::NetworkTokenDataClear {
+                    network_token_status: Some(enums::NetworkTokenStatus::Deleted),
+                }
+            }
+        };
+    update_network_token(
+        state,
+        merchant_context,
+        payment_method,
+        payment_method_update,
+    )
+    .await
+}
+
+async fn delete_existing_network_token(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+    network_token_requestor_reference_id: String,
+) {
+    // Without a locker id of its own, the network token can not be told apart from the card
+    let delete_result = match &payment_method.network_token_locker_id {
+        Some(network_token_locker_id) => delete_network_token_from_locker_and_token_service(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            payment_method.payment_method_id.clone(),
+            Some(network_token_locker_id.clone()),
+            network_token_requestor_reference_id,
+            merchant_context,
+        )
+        .await
+        .map(|_| ()),
+        None => match &state.conf.network_tokenization_service {
+            Some(tokenization_service) => delete_network_token_from_tokenization_service(
+                state,
+                network_token_requestor_reference_id,
+                &payment_method.customer_id,
+                tokenization_service.get_inner(),
+            )
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+            None => Ok(()),
+        },
+    };
+
+    if let Err(error) = delete_result {
+        logger::error!(?error, "Failed to delete the existing network token");
+    }
+}
+
+async fn provision_network_token(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+    card: &api::CardDetail,
+) -> RouterResult<storage::PaymentMethodUpdate> {
+    let card_detail = domain::CardDetail {
+        card_number: card.card_number.clone(),
+        card_exp_month: card.card_exp_month.clone(),
+        card_exp_year: card.card_exp_year.clone(),
+        card_issuer: card.card_issuer.clone(),
+        card_network: card.card_network.clone(),
+        card_type: card.card_type.clone(),
+        card_issuing_country: card.card_issuing_country.clone(),
+        bank_code: None,
+        nick_name: card.nick_name.clone(),
+        card_holder_name: card.card_holder_name.clone(),
+        co_badged_card_data: None,
+    };
+    let (token_response, network_token_requestor_reference_id) =
+        make_card_network_tokenization_request(
+            state,
+            &card_detail,
+            None,
+            &payment_method.customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to provision network token for the updated card")?;
+
+    let network_token_details = api::CardDetail {
+        card_number: token_response.token.clone(),
+        card_exp_month: token_response.token_expiry_month.clone(),
+        card_exp_year: token_response.token_expiry_year.clone(),
+        card_holder_name: None,
+        nick_name: None,
+        card_issuing_country: None,
+        card_network: Some(token_response.card_brand.clone()),
+        card_issuer: None,
+        card_type: None,
+    };
+    let payment_method_create_request = api::PaymentMethodCreate {
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(network_token_details.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+    let (token_pm_resp, _) = Box::pin(
+        PmCards {
+            state,
+            merchant_context,
+        }
+        .add_card_to_locker(
+            payment_method_create_request,
+            &network_token_details,
+            &payment_method.customer_id,
+            None,
+        ),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add network token to locker")?;
+
+    let key_manager_state = &state.into();
+    let network_token_pmd_encrypted = token_pm_resp
+        .card
+        .map(|card| PaymentMethodsData::Card(CardDetailsPaymentMethod::from((card, None))))
+        .async_map(|network_token_pmd| {
+            cards::create_encrypted_data(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                network_token_pmd,
+            )
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt network token data")?;
+
+    Ok(storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+        network_token_requestor_reference_id,
+        network_token_locker_id: Some(token_pm_resp.payment_method_id),
+        network_token_payment_method_data: network_token_pmd_encrypted.map(Into::into),
+        network_token_status: Some(enums::NetworkTokenStatus::Active),
+    })
+}
+
+async fn invalidate_network_token_metadata(
+    state: &SessionState,
+    network_token_requestor_reference_id: &str,
+) {
+    let key = get_network_token_metadata_key(network_token_requestor_reference_id);
+    let delete_result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .delete_key(&key.into())
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error).change_context(errors::ApiErrorResponse::InternalServerError),
+    };
+
+    if let Err(error) = delete_result {
+        logger::error!(?error, "Failed to invalidate cached network token metadata");
+    }
+}
+
+/// Fetches the metadata of the network token of a payment method, from the cache if present.
+/// The card art is only available from the network tokenization service for active tokens.
+pub async fn get_network_token_metadata(
+    state: &SessionState,
+    payment_method: &domain::PaymentMethod,
+    network_token_requestor_reference_id: &str,
+) -> RouterResult<pm_types::NetworkTokenMetadata> {
+    let key = get_network_token_metadata_key(network_token_requestor_reference_id);
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    if let Ok(metadata) = redis_conn
+        .get_and_deserialize_key::<pm_types::NetworkTokenMetadata>(
+            &key.as_str().into(),
+            "NetworkTokenMetadata",
+        )
+        .await
+    {
+        return Ok(metadata);
+    }
+
+    let network_token_data = payment_method
+        .network_token_payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .map(|value| value.parse_value::<PaymentMethodsData>("PaymentMethodsData"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize network token data")?
+        .and_then(|pmd| match pmd {
+            PaymentMethodsData::Card(card) => Some(card),
+            _ => None,
+        });
+
+    let mut metadata = pm_types::NetworkTokenMetadata {
+        token_last4: network_token_data
+            .as_ref()
+            .and_then(|card| card.last4_digits.clone()),
+        token_expiry_month: network_token_data
+            .as_ref()
+            .and_then(|card| card.expiry_month.clone()),
+        token_expiry_year: network_token_data
+            .as_ref()
+            .and_then(|card| card.expiry_year.clone()),
+        card_network: network_token_data.and_then(|card| card.card_network),
+        card_art_url: None,
+    };
+
+    if payment_method.is_network_token_active() {
+        if let Some(tokenization_service) = &state.conf.network_tokenization_service {
+            match get_token_status_from_tokenization_service(
+                state,
+                &payment_method.customer_id,
+                network_token_requestor_reference_id.to_string(),
+                tokenization_service.get_inner(),
+            )
+            .await
+            {
+                Ok(token_status) => {
+                    metadata.token_expiry_month = Some(token_status.token_expiry_month);
+                    metadata.token_expiry_year = Some(token_status.token_expiry_year);
+                    metadata.card_art_url = token_status.card_art_url;
+                }
+                Err(error) => {
+                    logger::error!(?error, "Failed to fetch network token status");
+                }
+            }
+        }
+    }
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &key.as_str().into(),
+            &metadata,
+            consts::NETWORK_TOKEN_METADATA_CACHE_TTL,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to cache network token metadata"))
+        .ok();
+
+    Ok(metadata)
+}
+
+#[instrument(skip_all)]
+pub async fn list_customer_network_tokens(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<api::CustomerNetworkTokensListResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &customer_id,
+        merchant_id,
+        merchant_context.get_merchant_key_store(),
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            &customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let mut network_tokens = Vec::new();
+    for payment_method in payment_methods {
+        let Some(network_token_requestor_reference_id) = payment_method
+            .network_token_requestor_reference_id
+            .as_deref()
+        else {
+            continue;
+        };
+        let metadata = get_network_token_metadata(
+            &state,
+            &payment_method,
+            network_token_requestor_reference_id,
+        )
+        .await?;
+
+        network_tokens.push(api::CustomerNetworkToken {
+            payment_method_id: payment_method.payment_method_id.clone(),
+            status: payment_method
+                .network_token_status
+                .unwrap_or(enums::NetworkTokenStatus::Active),
+            card_network: metadata.card_network,
+            token_last4: metadata.token_last4,
+            token_expiry_month: metadata.token_expiry_month,
+            token_expiry_year: metadata.token_expiry_year,
+            card_art_url: metadata.card_art_url,
+        });
+    }
+
+    Ok(ApplicationResponse::Json(
+        api::CustomerNetworkTokensListResponse {
+            customer_id,
+            network_tokens,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::crypto::SignMessage;
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_tokenization_service(webhook_secret: &str) -> settings::NetworkTokenizationService {
+        let url = url::Url::parse("http://localhost:8080").unwrap();
+        settings::NetworkTokenizationService {
+            generate_token_url: url.clone(),
+            fetch_token_url: url.clone(),
+            token_service_api_key: Secret::default(),
+            public_key: Secret::default(),
+            private_key: Secret::default(),
+            key_id: String::new(),
+            delete_token_url: url.clone(),
+            check_token_status_url: url,
+            webhook_secret: Secret::new(webhook_secret.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_verify_lifecycle_webhook_signature() {
+        let tokenization_service = get_tokenization_service("secret");
+        let body = br#"{"cardReference":"ref_123","event":"SUSPEND"}"#;
+        let signature = hex::encode(HmacSha256.sign_message(b"secret", body).unwrap());
+
+        assert!(
+            verify_lifecycle_webhook_signature(&tokenization_service, Some(&signature), body)
+                .is_ok()
+        );
+        assert!(verify_lifecycle_webhook_signature(
+            &tokenization_service,
+            Some(&signature),
+            br#"{"cardReference":"ref_123","event":"RESUME"}"#
+        )
+        .is_err());
+        assert!(verify_lifecycle_webhook_signature(&tokenization_service, None, body).is_err());
+    }
+
+    #[test]
+    fn test_verify_lifecycle_webhook_signature_without_secret() {
+        let tokenization_service = get_tokenization_service("");
+        let body = br#"{"cardReference":"ref_123","event":"DELETE"}"#;
+        let signature = hex::encode(HmacSha256.sign_message(b"", body).unwrap());
+
+        assert!(
+            verify_lifecycle_webhook_signature(&tokenization_service, Some(&signature), body)
+                .is_err()
+        );
+    }
+}
@@ -59,6 +59,14 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     }
 }
 
+impl VirInterpreterBackendCacheWrapper {
+    pub fn get_cached_algorithm(
+        &self,
+    ) -> &backend::VirInterpreterBackend<SurchargeDecisionConfigs> {
+        &self.cached_algorithm
+    }
+}
+
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
     Generate(VirInterpreterBackendCacheWrapper),
@@ -445,29 +453,22 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
 //     Ok(surcharge_metadata)
 // }
 
-#[cfg(feature = "v2")]
-fn get_surcharge_details_from_surcharge_output(
-    _surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
-    _payment_attempt: &storage::PaymentAttempt,
-) -> ConditionalConfigResult<types::SurchargeDetails> {
-    todo!()
-}
-
+/// Returns the surcharge amount and the tax on surcharge amount applicable on `amount`
 #[cfg(feature = "v1")]
-fn get_surcharge_details_from_surcharge_output(
-    surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
-    payment_attempt: &storage::PaymentAttempt,
-) -> ConditionalConfigResult<types::SurchargeDetails> {
-    let surcharge_amount = match surcharge_details.surcharge.clone() {
-        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => amount,
+fn calculate_surcharge_amounts(
+    surcharge_details: &surcharge_decision_configs::SurchargeDetailsOutput,
+    amount: common_utils_types::MinorUnit,
+) -> ConditionalConfigResult<(common_utils_types::MinorUnit, common_utils_types::MinorUnit)> {
+    let surcharge_amount = match &surcharge_details.surcharge {
+        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => *amount,
         surcharge_decision_configs::SurchargeOutput::Rate(percentage) => percentage
-            .apply_and_ceil_result(payment_attempt.net_amount.get_total_amount())
+            .apply_and_ceil_result(amount)
             .change_context(ConfigError::DslExecutionError)
             .attach_printable("Failed to Calculate surcharge amount by applying percentage")?,
     };
     let tax_on_surcharge_amount = surcharge_details
         .tax_on_surcharge
-        .clone()
+        .as_ref()
         .map(|tax_on_surcharge| {
             tax_on_surcharge
                 .apply_and_ceil_result(surcharge_amount)
@@ -476,6 +477,26 @@ fn get_surcharge_details_from_surcharge_output(
         })
         .transpose()?
         .unwrap_or_default();
+    Ok((surcharge_amount, tax_on_surcharge_amount))
+}
+
+#[cfg(feature = "v2")]
+fn get_surcharge_details_from_surcharge_output(
+    _surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
+    _payment_attempt: &storage::PaymentAttempt,
+) -> ConditionalConfigResult<types::SurchargeDetails> {
+    todo!()
+}
+
+#[cfg(feature = "v1")]
+fn get_surcharge_details_from_surcharge_output(
+    surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
+    payment_attempt: &storage::PaymentAttempt,
+) -> ConditionalConfigResult<types::SurchargeDetails> {
+    let (surcharge_amount, tax_on_surcharge_amount) = calculate_surcharge_amounts(
+        &surcharge_details,
+        payment_attempt.net_amount.get_total_amount(),
+    )?;
     Ok(types::SurchargeDetails {
         original_amount: payment_attempt.net_amount.get_order_amount(),
         surcharge: match surcharge_details.surcharge {
@@ -492,6 +513,121 @@ fn get_surcharge_details_from_surcharge_output(
     })
 }
 
+/// Evaluates the surcharge rules against a hypothetical payment, without any side effects
+#[cfg(feature = "v1")]
+pub fn simulate_surcharge_decision(
+    interpreter: &backend::VirInterpreterBackend<SurchargeDecisionConfigs>,
+    payment: surcharge_decision_configs::SurchargeSimulationPayment,
+) -> ConditionalConfigResult<surcharge_decision_configs::SurchargeSimulationResponse> {
+    let backend_input = dsl_inputs::BackendInput {
+        metadata: None,
+        payment: dsl_inputs::PaymentInput {
+            amount: payment.amount,
+            currency: payment.currency,
+            authentication_type: None,
+            card_bin: None,
+            capture_method: None,
+            business_country: None,
+            billing_country: payment.billing_country,
+            business_label: None,
+            setup_future_usage: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: Some(payment.payment_method),
+            payment_method_type: payment.payment_method_type,
+            card_network: payment.card_network.clone(),
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        payout: None,
+    };
+
+    let mut trace = vec![format!(
+        "Evaluating surcharge rules for a {} payment of {} {}, payment method type {}, card network {}, billing country {}",
+        payment.payment_method,
+        payment.amount,
+        payment.currency,
+        payment
+            .payment_method_type
+            .map_or("-".to_string(), |payment_method_type| payment_method_type.to_string()),
+        payment
+            .card_network
+            .as_ref()
+            .map_or("-".to_string(), |card_network| card_network.to_string()),
+        payment
+            .billing_country
+            .map_or("-".to_string(), |billing_country| billing_country.to_string()),
+    )];
+
+    let output = interpreter
+        .execute(backend_input)
+        .change_context(ConfigError::DslExecutionError)?;
+    trace.push(match &output.rule_name {
+        Some(rule_name) => format!("Matched rule `{rule_name}`"),
+        None => "No rule matched the payment, the default selection was applied".to_string(),
+    });
+
+    let surcharge_details = output.connector_selection.surcharge_details;
+    let (surcharge_amount, tax_on_surcharge_amount) = match &surcharge_details {
+        Some(surcharge_details) => {
+            let (surcharge_amount, tax_on_surcharge_amount) =
+                calculate_surcharge_amounts(surcharge_details, payment.amount)?;
+            trace.push(match &surcharge_details.surcharge {
+                surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
+                    format!("Applied a fixed surcharge of {amount}")
+                }
+                surcharge_decision_configs::SurchargeOutput::Rate(percentage) => format!(
+                    "Applied a surcharge rate of {}% on {}, resulting in a surcharge of {surcharge_amount}",
+                    percentage.get_percentage(),
+                    payment.amount
+                ),
+            });
+            trace.push(match &surcharge_details.tax_on_surcharge {
+                Some(tax_on_surcharge) => format!(
+                    "Applied a tax of {}% on the surcharge of {surcharge_amount}, resulting in a tax of {tax_on_surcharge_amount}",
+                    tax_on_surcharge.get_percentage()
+                ),
+                None => "No tax is applicable on the surcharge".to_string(),
+            });
+            (surcharge_amount, tax_on_surcharge_amount)
+        }
+        None => {
+            trace.push("No surcharge is applicable on the payment".to_string());
+            (
+                common_utils_types::MinorUnit::zero(),
+                common_utils_types::MinorUnit::zero(),
+            )
+        }
+    };
+
+    let total_amount = payment.amount + surcharge_amount + tax_on_surcharge_amount;
+    let (surcharge, tax_on_surcharge) = surcharge_details
+        .map(|surcharge_details| {
+            (
+                Some(surcharge_details.surcharge),
+                surcharge_details.tax_on_surcharge,
+            )
+        })
+        .unwrap_or_default();
+
+    Ok(surcharge_decision_configs::SurchargeSimulationResponse {
+        payment,
+        matched_rule: output.rule_name,
+        surcharge,
+        tax_on_surcharge,
+        surcharge_amount,
+        tax_on_surcharge_amount,
+        total_amount,
+        trace,
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn ensure_algorithm_cached(
     store: &dyn StorageInterface,
@@ -533,3 +669,152 @@ pub fn execute_dsl_and_get_conditional_config(
         .change_context(ConfigError::DslExecutionError)?;
     Ok(routing_output)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn get_interpreter() -> backend::VirInterpreterBackend<SurchargeDecisionConfigs> {
+        let program = serde_json::from_value(serde_json::json!({
+            "defaultSelection": {"surcharge_details": null},
+            "rules": [
+                {
+                    "name": "large_card_payments",
+                    "connectorSelection": {
+                        "surcharge_details": {
+                            "surcharge": {"type": "rate", "value": {"percentage": 2.5}},
+                            "tax_on_surcharge": {"percentage": 10}
+                        }
+                    },
+                    "statements": [{
+                        "condition": [
+                            {
+                                "lhs": "payment_method",
+                                "comparison": "equal",
+                                "value": {"type": "enum_variant", "value": "card"},
+                                "metadata": {}
+                            },
+                            {
+                                "lhs": "amount",
+                                "comparison": "greater_than",
+                                "value": {"type": "number", "value": 5000},
+                                "metadata": {}
+                            }
+                        ],
+                        "nested": null
+                    }]
+                },
+                {
+                    "name": "visa_payments",
+                    "connectorSelection": {
+                        "surcharge_details": {
+                            "surcharge": {"type": "fixed", "value": {"amount": 100}},
+                            "tax_on_surcharge": null
+                        }
+                    },
+                    "statements": [{
+                        "condition": [{
+                            "lhs": "card_network",
+                            "comparison": "equal",
+                            "value": {"type": "enum_variant", "value": "Visa"},
+                            "metadata": {}
+                        }],
+                        "nested": null
+                    }]
+                }
+            ],
+            "metadata": {}
+        }))
+        .unwrap();
+        backend::VirInterpreterBackend::with_program(program).unwrap()
+    }
+
+    fn get_payment(
+        amount: i64,
+        payment_method: common_enums::PaymentMethod,
+        card_network: Option<common_enums::CardNetwork>,
+    ) -> surcharge_decision_configs::SurchargeSimulationPayment {
+        surcharge_decision_configs::SurchargeSimulationPayment {
+            amount: MinorUnit::new(amount),
+            currency: common_enums::Currency::USD,
+            payment_method,
+            payment_method_type: None,
+            card_network,
+            billing_country: None,
+        }
+    }
+
+    #[test]
+    fn test_simulate_rate_surcharge_with_tax() {
+        let simulation = simulate_surcharge_decision(
+            &get_interpreter(),
+            get_payment(
+                10000,
+                common_enums::PaymentMethod::Card,
+                Some(common_enums::CardNetwork::Visa),
+            ),
+        )
+        .unwrap();
+
+        // The first rule matching the payment applies
+        assert_eq!(
+            simulation.matched_rule.as_deref(),
+            Some("large_card_payments")
+        );
+        assert_eq!(simulation.surcharge_amount, MinorUnit::new(250));
+        assert_eq!(simulation.tax_on_surcharge_amount, MinorUnit::new(25));
+        assert_eq!(simulation.total_amount, MinorUnit::new(10275));
+        assert!(simulation.tax_on_surcharge.is_some());
+    }
+
+    #[test]
+    fn test_simulate_fixed_surcharge_without_tax() {
+        let simulation = simulate_surcharge_decision(
+            &get_interpreter(),
+            get_payment(
+                3000,
+                common_enums::PaymentMethod::Card,
+                Some(common_enums::CardNetwork::Visa),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(simulation.matched_rule.as_deref(), Some("visa_payments"));
+        assert_eq!(
+            simulation.surcharge,
+            Some(surcharge_decision_configs::SurchargeOutput::Fixed {
+                amount: MinorUnit::new(100)
+            })
+        );
+        assert_eq!(simulation.surcharge_amount, MinorUnit::new(100));
+        assert_eq!(simulation.tax_on_surcharge_amount, MinorUnit::zero());
+        assert_eq!(simulation.total_amount, MinorUnit::new(3100));
+        assert!(simulation
+            .trace
+            .iter()
+            .any(|step| step == "No tax is applicable on the surcharge"));
+    }
+
+    #[test]
+    fn test_simulate_without_matching_rule() {
+        let simulation = simulate_surcharge_decision(
+            &get_interpreter(),
+            get_payment(3000, common_enums::PaymentMethod::Wallet, None),
+        )
+        .unwrap();
+
+        assert_eq!(simulation.matched_rule, None);
+        assert_eq!(simulation.surcharge, None);
+        assert_eq!(simulation.tax_on_surcharge, None);
+        assert_eq!(simulation.surcharge_amount, MinorUnit::zero());
+        assert_eq!(simulation.tax_on_surcharge_amount, MinorUnit::zero());
+        assert_eq!(simulation.total_amount, MinorUnit::new(3000));
+        assert_eq!(
+            simulation.trace.last().map(String::as_str),
+            Some("No surcharge is applicable on the payment")
+        );
+    }
+}
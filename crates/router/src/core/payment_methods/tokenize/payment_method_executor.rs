@@ -395,6 +395,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizePaymentMethodRequest> {
             network_token_requestor_reference_id: network_token_details.1.clone(),
             network_token_locker_id: Some(store_token_response.card_reference.clone()),
             network_token_payment_method_data: Some(enc_token_data.into()),
+            network_token_status: Some(diesel_models::enums::NetworkTokenStatus::Active),
         };
         self.state
             .store
@@ -27,7 +27,7 @@ use crate::{
     utils::OptionExt,
 };
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum StoreLockerReq {
     LockerCard(StoreCardReq),
@@ -36,7 +36,7 @@ use crate::{
     core::{
         errors::ConnectorErrorExt,
         errors::StorageErrorExt,
-        payment_methods::{transformers as pm_transforms, utils},
+        payment_methods::{local_vault, transformers as pm_transforms, utils},
         payments::{self as payments_core, helpers as payment_helpers},
         utils as core_utils,
     },
@@ -1247,9 +1247,13 @@ pub async fn call_to_vault<V: pm_types::VaultingInterface>(
     let request =
         create_vault_request::<V>(jwekey, locker, payload, state.tenant.tenant_id.to_owned())
             .await?;
-    let response = services::call_connector_api(state, request, V::get_vaulting_flow_name())
-        .await
-        .change_context(errors::VaultError::VaultAPIError);
+    let response = if locker.local_vault {
+        local_vault::call_local_vault(state, request).await
+    } else {
+        services::call_connector_api(state, request, V::get_vaulting_flow_name())
+            .await
+            .change_context(errors::VaultError::VaultAPIError)
+    };
 
     let jwe_body: services::JweBody = response
         .get_response_inner("JweBody")
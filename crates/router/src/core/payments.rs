@@ -3,6 +3,7 @@ pub mod conditional_configs;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
+pub mod network_retry_limit;
 pub mod operations;
 
 #[cfg(feature = "retry")]
@@ -10,6 +11,8 @@ pub mod retry;
 pub mod routing;
 #[cfg(feature = "v2")]
 pub mod session_operation;
+#[cfg(feature = "v1")]
+pub mod split_tender;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -453,6 +456,26 @@ where
     )
     .await?;
 
+    if let Some(other_tenders_action) =
+        split_tender::get_other_tenders_action(&operation, &payment_data)?
+    {
+        payment_data = split_tender::call_connector_service_for_other_tenders(
+            state,
+            req_state.clone(),
+            merchant_context,
+            &operation,
+            other_tenders_action,
+            payment_data,
+            &customer,
+            call_connector_action.clone(),
+            &validate_result,
+            header_payload.clone(),
+            &business_profile,
+            &locale,
+        )
+        .await?;
+    }
+
     let mut connector_http_status_code = None;
     let mut external_latency = None;
     if let Some(connector_details) = connector {
@@ -2574,7 +2597,8 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
             // If the status is terminal status, then redirect to merchant return url to provide status
             enums::IntentStatus::Succeeded
             | enums::IntentStatus::Failed
-            | enums::IntentStatus::Cancelled | enums::IntentStatus::RequiresCapture| enums::IntentStatus::Processing=> helpers::get_handle_response_url(
+            | enums::IntentStatus::Cancelled | enums::IntentStatus::RequiresCapture| enums::IntentStatus::Processing
+            | enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => helpers::get_handle_response_url(
                 payment_id,
                 &payment_flow_response.business_profile,
                 payments_response,
@@ -2743,7 +2767,8 @@ impl ValidateStatusForOperation for &PaymentRedirectSync {
             | common_enums::IntentStatus::RequiresCapture
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::RequiresConfirmation
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => {
                 Err(errors::ApiErrorResponse::PaymentUnexpectedState {
                     current_flow: format!("{self:?}"),
                     field_name: "status".to_string(),
@@ -3272,6 +3297,20 @@ where
     // Validating the blocklist guard and generate the fingerprint
     blocklist_guard(state, merchant_context, operation, payment_data).await?;
 
+    // Merchant initiated payments are blocked once the card has been declined as many times as its
+    // network allows. Auto retries are checked by the retry flow before they are made.
+    if is_operation_confirm(operation)
+        && !is_retry_payment
+        && network_retry_limit::is_merchant_initiated_retry(payment_data, &header_payload)
+    {
+        if let Some(ledger) =
+            network_retry_limit::get_ledger_for_payment(state, merchant_context, payment_data)
+                .await?
+        {
+            ledger.check(state).await?;
+        }
+    }
+
     #[cfg(feature = "v1")]
     let merchant_recipient_data = if let Some(true) = payment_data
         .get_payment_intent()
@@ -3405,6 +3444,20 @@ where
             .ok();
     }
 
+    // The fee of the connector is estimated for the payment, to be reported in the analytics
+    let fee_estimation_input = self_routing::cost_based::get_fee_estimation_input(
+        payment_data.get_payment_attempt(),
+        payment_data.get_currency(),
+        payment_data.get_payment_method_data(),
+        payment_data.get_address(),
+    );
+    payment_data.set_estimated_connector_cost_in_attempt(
+        self_routing::cost_based::estimate_connector_cost(
+            merchant_connector_account.get_fee_schedule(),
+            &fee_estimation_input,
+        ),
+    );
+
     // Update the payment trackers just before calling the connector
     // Since the request is already built in the previous step,
     // there should be no error in request construction from hyperswitch end
@@ -3423,7 +3476,10 @@ where
         )
         .await?;
 
-    let router_data = if should_continue_further {
+    // The external latency adds up the calls made to the connector for the payment, only the
+    // latency of this call is counted by the circuit of the connector
+    let external_latency_before_call = router_data.external_latency;
+    let router_data_result = if should_continue_further {
         // The status of payment_attempt and intent will be updated in the previous step
         // update this in router_data.
         // This is added because few connector integrations do not update the status,
@@ -3442,7 +3498,36 @@ where
             .await
     } else {
         Ok(router_data)
-    }?;
+    };
+
+    // Only the calls which reached the connector, or failed to reach it, drive its circuit
+    let connector_call_outcome = match &router_data_result {
+        Ok(router_data) => router_data.connector_http_status_code.map(|status_code| {
+            routing::circuit_breaker::ConnectorCallOutcome::Responded {
+                status_code,
+                latency_in_milliseconds: router_data.external_latency.map(|latency| {
+                    latency.saturating_sub(external_latency_before_call.unwrap_or_default())
+                }),
+            }
+        }),
+        Err(error) => error
+            .downcast_ref::<errors::ApiClientError>()
+            .filter(|error| routing::circuit_breaker::is_connection_error(error))
+            .map(|_| routing::circuit_breaker::ConnectorCallOutcome::ConnectionFailed),
+    };
+    if let Some(connector_call_outcome) = connector_call_outcome {
+        routing::circuit_breaker::record_connector_call(
+            state,
+            connector.merchant_connector_id.as_ref(),
+            connector_call_outcome,
+        )
+        .await;
+    }
+    let router_data = router_data_result?;
+
+    if is_operation_confirm(operation) && network_retry_limit::is_card_decline(&router_data) {
+        network_retry_limit::record_payment_decline(state, merchant_context, payment_data).await;
+    }
 
     let etime_connector = Instant::now();
     let duration_connector = etime_connector.saturating_duration_since(stime_connector);
@@ -5804,11 +5889,19 @@ where
                         | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
                 ) && payment_data.get_force_sync().unwrap_or(false)
         }
-        "PaymentCancel" => matches!(
-            payment_data.get_payment_intent().status,
-            storage_enums::IntentStatus::RequiresCapture
-                | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
-        ),
+        "PaymentCancel" => {
+            matches!(
+                payment_data.get_payment_intent().status,
+                storage_enums::IntentStatus::RequiresCapture
+                    | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+            ) || (matches!(
+                payment_data.get_payment_intent().status,
+                storage_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+            ) && matches!(
+                payment_data.get_payment_attempt().status,
+                storage_enums::AttemptStatus::Authorized
+            ))
+        }
         "PaymentCapture" => {
             matches!(
                 payment_data.get_payment_intent().status,
@@ -6974,6 +7067,10 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
 
+        let mut routing_trace =
+            api::routing::RoutingTrace::for_straight_through_algorithm(&routing_algorithm);
+        routing_trace.algorithm_connectors = connectors.clone();
+
         if check_eligibility {
             let transaction_data = core_routing::PaymentsDslInput::new(
                 payment_data.get_setup_mandate(),
@@ -6996,8 +7093,16 @@ where
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed eligibility analysis and fallback")?;
+            routing_trace.set_eligible_connectors(&connectors);
         }
 
+        routing_trace.final_connectors = connectors.clone();
+        routing::trace::store_routing_trace(
+            &state,
+            routing_trace,
+            payment_data.get_payment_attempt(),
+        );
+
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -7033,6 +7138,10 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
 
+        let mut routing_trace =
+            api::routing::RoutingTrace::for_straight_through_algorithm(routing_algorithm);
+        routing_trace.algorithm_connectors = connectors.clone();
+
         if check_eligibility {
             let transaction_data = core_routing::PaymentsDslInput::new(
                 payment_data.get_setup_mandate(),
@@ -7055,8 +7164,16 @@ where
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("failed eligibility analysis and fallback")?;
+            routing_trace.set_eligible_connectors(&connectors);
         }
 
+        routing_trace.final_connectors = connectors.clone();
+        routing::trace::store_routing_trace(
+            &state,
+            routing_trace,
+            payment_data.get_payment_attempt(),
+        );
+
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -7508,6 +7625,7 @@ pub enum IsNtWithNtiFlow {
     NTWithNTINotSupported,      //Network token with Network transaction id not supported
 }
 
+#[cfg(feature = "v1")]
 pub fn is_network_token_with_network_transaction_id_flow(
     is_connector_agnostic_mit_enabled: Option<bool>,
     is_network_tokenization_enabled: bool,
@@ -7519,9 +7637,7 @@ pub fn is_network_token_with_network_transaction_id_flow(
         payment_method_info.get_payment_method_type(),
         payment_method_info.network_transaction_id.clone(),
         payment_method_info.network_token_locker_id.is_some(),
-        payment_method_info
-            .network_token_requestor_reference_id
-            .is_some(),
+        payment_method_info.is_network_token_active(),
     ) {
         (
             Some(true),
@@ -7656,10 +7772,12 @@ pub async fn route_connector_v2_for_payments(
 
     let connectors = routing::perform_static_routing_v1(
         state,
+        merchant_context.get_merchant_key_store(),
         merchant_context.get_merchant_account().get_id(),
         routing_algorithm_id,
         business_profile,
         &TransactionData::Payment(transaction_data.clone()),
+        None,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -7709,24 +7827,31 @@ where
     F: Send + Clone,
     D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
 {
-    let routing_algorithm_id = {
-        let routing_algorithm = business_profile.routing_algorithm.clone();
+    let algorithm_ref = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|ra| ra.parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant routing algorithm ref")?
+        .unwrap_or_default();
 
-        let algorithm_ref = routing_algorithm
-            .map(|ra| ra.parse_value::<api::routing::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
-            .transpose()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not decode merchant routing algorithm ref")?
-            .unwrap_or_default();
-        algorithm_ref.algorithm_id
+    let mut routing_trace = api::routing::RoutingTrace {
+        algorithm_version: algorithm_ref
+            .algorithm_id
+            .is_some()
+            .then_some(algorithm_ref.timestamp),
+        ..Default::default()
     };
 
     let connectors = routing::perform_static_routing_v1(
         state,
+        merchant_context.get_merchant_key_store(),
         merchant_context.get_merchant_account().get_id(),
-        routing_algorithm_id.as_ref(),
+        algorithm_ref.algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payment(transaction_data.clone()),
+        Some(&mut routing_trace),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -7745,6 +7870,10 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
+    routing_trace.set_eligible_connectors(&connectors);
+
+    let connectors = routing::circuit_breaker::filter_open_circuits(state, connectors).await;
+    routing_trace.set_closed_circuit_connectors(&connectors);
 
     // dynamic success based connector selection
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -7774,15 +7903,35 @@ where
 
         if routing_choice.routing_type.is_dynamic_routing() {
             if state.conf.open_router.enabled {
-                routing::perform_dynamic_routing_with_open_router(
+                match routing::perform_dynamic_routing_with_open_router(
                     state,
                     connectors.clone(),
                     business_profile,
                     payment_attempt,
                 )
                 .await
-                .map_err(|e| logger::error!(open_routing_error=?e))
-                .unwrap_or(connectors)
+                {
+                    Ok(dynamic_connectors) => {
+                        routing_trace.dynamic_routing.push(
+                            api_models::routing::DynamicRoutingTraceStep {
+                                stage: api_models::routing::RoutingTraceStage::OpenRouter,
+                                routing_approach: None,
+                                scores: Vec::new(),
+                                eliminated_connectors: Vec::new(),
+                                connectors: dynamic_connectors.clone(),
+                            },
+                        );
+                        dynamic_connectors
+                    }
+                    Err(e) => {
+                        logger::error!(open_routing_error=?e);
+                        routing_trace.add_fallback(
+                            api_models::routing::RoutingTraceStage::OpenRouter,
+                            e.current_context().to_string(),
+                        );
+                        connectors
+                    }
+                }
             } else {
                 let dynamic_routing_config_params_interpolator =
                     routing_helpers::DynamicRoutingConfigParamsInterpolator::new(
@@ -7822,6 +7971,7 @@ where
                     business_profile,
                     dynamic_routing_config_params_interpolator,
                     payment_data.get_payment_attempt(),
+                    &mut routing_trace,
                 )
                 .await
                 .map_err(|e| logger::error!(dynamic_routing_error=?e))
@@ -7834,6 +7984,11 @@ where
         connectors
     };
 
+    let connectors = routing::circuit_breaker::admit_selected_connector(state, connectors).await;
+
+    routing_trace.final_connectors = connectors.clone();
+    routing::trace::store_routing_trace(state, routing_trace, payment_data.get_payment_attempt());
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
@@ -7900,10 +8055,12 @@ pub async fn route_connector_v1_for_payouts(
 
     let connectors = routing::perform_static_routing_v1(
         state,
+        merchant_context.get_merchant_key_store(),
         merchant_context.get_merchant_account().get_id(),
         routing_algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payout(transaction_data),
+        None,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -8460,6 +8617,215 @@ pub async fn payments_manual_update(
     ))
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn explain_payment_eligibility(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id_from_auth_layer: Option<id_type::ProfileId>,
+    req: payments_api::PaymentsEligibilityExplanationRequest,
+) -> RouterResponse<payments_api::PaymentsEligibilityExplanationResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_account = merchant_context.get_merchant_account();
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &req.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id_from_auth_layer, &payment_intent)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+
+    let billing_address = helpers::get_address_by_id(
+        &state,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_account.get_id(),
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    let mut backend_input = self_routing::make_dsl_input_for_surcharge(
+        &payment_attempt,
+        &payment_intent,
+        billing_address.as_ref().map(Into::into),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the routing input for the payment")?;
+    backend_input.payment_method.payment_method = Some(req.payment_method);
+    backend_input.payment_method.payment_method_type = req.payment_method_type;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_account.get_id(),
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?
+        .filter_based_on_profile_and_connector_type(
+            profile_id,
+            common_enums::ConnectorType::PaymentProcessor,
+        );
+
+    let allowed_payment_method_types = payment_intent
+        .allowed_payment_method_types
+        .clone()
+        .map(|allowed_types| allowed_types.parse_value("Vec<PaymentMethodType>"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to deserialize the allowed payment method types of the payment",
+        )?;
+
+    // Reasons for which the payment method list leaves out each of the connectors, which are not
+    // captured by the constraint graph
+    let mut payment_method_list_filter_reasons = HashMap::new();
+    let chosen = merchant_connector_accounts
+        .into_iter()
+        .filter_map(|mca| {
+            let connector = common_enums::RoutableConnectors::from_str(&mca.connector_name).ok()?;
+            payment_method_list_filter_reasons.insert(
+                mca.get_id(),
+                cards::get_payment_method_list_filter_reasons(
+                    mca.payment_methods_enabled.as_deref().unwrap_or_default(),
+                    req.payment_method,
+                    req.payment_method_type,
+                    Some(payment_intent.amount),
+                    allowed_payment_method_types.as_ref(),
+                ),
+            );
+            Some(api::routing::RoutableConnectorChoice {
+                choice_kind: api::routing::RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id: Some(mca.get_id()),
+            })
+        })
+        .collect();
+
+    let explanations = self_routing::explain_cgraph_filtering(
+        &state,
+        key_store,
+        chosen,
+        backend_input,
+        profile_id,
+        &common_enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to explain the eligibility of the connectors")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payments_api::PaymentsEligibilityExplanationResponse {
+            payment_id: payment_intent.payment_id,
+            payment_method: req.payment_method,
+            payment_method_type: req.payment_method_type,
+            connectors: explanations
+                .into_iter()
+                .map(|(choice, explanation)| {
+                    let filter_reasons = choice
+                        .merchant_connector_id
+                        .as_ref()
+                        .and_then(|mca_id| payment_method_list_filter_reasons.remove(mca_id))
+                        .unwrap_or_default();
+                    let connector_path = format!("Connector: connector {}", choice.connector);
+
+                    payments_api::ConnectorEligibilityExplanation {
+                        connector: choice.connector,
+                        merchant_connector_id: choice.merchant_connector_id,
+                        eligible: explanation.eligible && filter_reasons.is_empty(),
+                        reasons: explanation
+                            .reasons
+                            .into_iter()
+                            .map(|reason| payments_api::IneligibilityReason {
+                                path: reason.path,
+                                message: reason.message,
+                            })
+                            .chain(filter_reasons.into_iter().map(|message| {
+                                payments_api::IneligibilityReason {
+                                    path: vec![connector_path.clone()],
+                                    message,
+                                }
+                            }))
+                            .collect(),
+                    }
+                })
+                .collect(),
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn get_payment_routing_trace(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    req: payments_api::PaymentsRoutingTraceRequest,
+) -> RouterResponse<payments_api::PaymentsRoutingTraceResponse> {
+    let db = &*state.store;
+    let merchant_account = merchant_context.get_merchant_account();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_account.get_id(),
+            merchant_context.get_merchant_key_store(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let traces = db
+        .find_routing_traces_by_merchant_id_payment_id(
+            merchant_account.get_id(),
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing traces of the payment")?
+        .into_iter()
+        .map(routing::trace::get_attempt_routing_trace)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        payments_api::PaymentsRoutingTraceResponse {
+            payment_id: payment_intent.payment_id,
+            traces,
+        },
+    ))
+}
+
 pub trait PaymentMethodChecker<F> {
     fn should_update_in_post_update_tracker(&self) -> bool;
     fn should_update_in_update_tracker(&self) -> bool;
@@ -8575,6 +8941,11 @@ pub trait OperationSessionSetters<F> {
     );
     #[cfg(feature = "v1")]
     fn set_capture_method_in_attempt(&mut self, capture_method: enums::CaptureMethod);
+    #[cfg(feature = "v1")]
+    fn set_estimated_connector_cost_in_attempt(
+        &mut self,
+        estimated_connector_cost: Option<MinorUnit>,
+    );
     fn set_frm_message(&mut self, frm_message: FraudCheck);
     fn set_payment_intent_status(&mut self, status: storage_enums::IntentStatus);
     fn set_authentication_type_in_attempt(
@@ -8855,6 +9226,13 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
         self.payment_attempt.capture_method = Some(capture_method);
     }
 
+    fn set_estimated_connector_cost_in_attempt(
+        &mut self,
+        estimated_connector_cost: Option<MinorUnit>,
+    ) {
+        self.payment_attempt.estimated_connector_cost = estimated_connector_cost;
+    }
+
     fn set_frm_message(&mut self, frm_message: FraudCheck) {
         self.frm_message = Some(frm_message);
     }
@@ -353,17 +353,6 @@ impl<const T: u8>
 {
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutSync for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 #[cfg(feature = "dummy_connector")]
 impl<const T: u8> api::PayoutEligibility for connector::DummyConnector<T> {}
@@ -378,17 +367,6 @@ impl<const T: u8>
 {
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutFulfill for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 #[cfg(feature = "dummy_connector")]
 impl<const T: u8> api::PayoutCancel for connector::DummyConnector<T> {}
@@ -523,18 +501,6 @@ impl<const T: u8>
 {
 }
 
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PaymentIncrementalAuthorization for connector::DummyConnector<T> {}
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<
-        api::IncrementalAuthorization,
-        types::PaymentsIncrementalAuthorizationData,
-        types::PaymentsResponseData,
-    > for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "dummy_connector")]
 impl<const T: u8> api::ConnectorMandateRevoke for connector::DummyConnector<T> {}
 #[cfg(feature = "dummy_connector")]
@@ -2120,7 +2120,8 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
         should_retry_with_pan,
         payment_method_info
             .network_token_requestor_reference_id
-            .clone(),
+            .clone()
+            .filter(|_| payment_method_info.is_network_token_active()),
     );
 
     let co_badged_card_data = payment_method_info
@@ -3704,6 +3705,7 @@ mod tests {
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3780,6 +3782,7 @@ mod tests {
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3854,6 +3857,7 @@ mod tests {
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -3961,6 +3965,14 @@ impl MerchantConnectorAccountType {
         }
     }
 
+    #[cfg(feature = "v1")]
+    pub fn get_fee_schedule(&self) -> Option<&common_types::connector_fees::ConnectorFeeSchedule> {
+        match self {
+            Self::DbVal(db_val) => db_val.fee_schedule.as_ref(),
+            Self::CacheVal(_) => None,
+        }
+    }
+
     #[cfg(feature = "v2")]
     pub fn get_connector_name(&self) -> Option<api_enums::Connector> {
         match self {
@@ -4289,6 +4301,15 @@ pub fn get_attempt_type(
                     ))
             }
         }
+        // A requeued payment is retried with a new attempt once its pending attempt is picked up
+        enums::IntentStatus::Processing
+            if matches!(
+                request.retry_action,
+                Some(api_models::enums::RetryAction::Requeue)
+            ) && payment_attempt.status == enums::AttemptStatus::Pending =>
+        {
+            Ok(AttemptType::New)
+        }
         enums::IntentStatus::Cancelled
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
@@ -4307,6 +4328,9 @@ pub fn get_attempt_type(
         | enums::IntentStatus::RequiresMerchantAction
         | enums::IntentStatus::RequiresPaymentMethod
         | enums::IntentStatus::RequiresConfirmation => Ok(AttemptType::SameOld),
+
+        // The remaining balance of a partially authorized payment is paid with a new attempt
+        enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => Ok(AttemptType::New),
     }
 }
 
@@ -4411,6 +4435,7 @@ impl AttemptType {
             processor_merchant_id: old_payment_attempt.processor_merchant_id,
             created_by: old_payment_attempt.created_by,
             setup_future_usage_applied: None,
+            estimated_connector_cost: None,
         }
     }
 
@@ -4445,7 +4470,7 @@ impl AttemptType {
                 let db = &*state.store;
                 let key_manager_state = &state.into();
                 let new_attempt_count = fetched_payment_intent.attempt_count + 1;
-                let new_payment_attempt_to_insert = Self::make_new_payment_attempt(
+                let mut new_payment_attempt_to_insert = Self::make_new_payment_attempt(
                     request
                         .payment_method_data
                         .as_ref()
@@ -4457,6 +4482,28 @@ impl AttemptType {
                     storage_scheme,
                 );
 
+                // The new attempt of a partially authorized payment only pays its remaining balance
+                if fetched_payment_intent.status
+                    == enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+                {
+                    let remaining_amount = super::split_tender::get_remaining_amount(
+                        state,
+                        &fetched_payment_intent,
+                        storage_scheme,
+                    )
+                    .await?;
+                    new_payment_attempt_to_insert.net_amount =
+                        hyperswitch_domain_models::payments::payment_attempt::NetAmount::new(
+                            remaining_amount,
+                            None,
+                            None,
+                            None,
+                            None,
+                        );
+                    new_payment_attempt_to_insert.amount_capturable = remaining_amount;
+                    new_payment_attempt_to_insert.amount_to_capture = None;
+                }
+
                 #[cfg(feature = "v1")]
                 let new_payment_attempt = db
                     .insert_payment_attempt(new_payment_attempt_to_insert, storage_scheme)
@@ -4561,7 +4608,8 @@ pub fn is_manual_retry_allowed(
         enums::IntentStatus::RequiresCustomerAction
         | enums::IntentStatus::RequiresMerchantAction
         | enums::IntentStatus::RequiresPaymentMethod
-        | enums::IntentStatus::RequiresConfirmation => None,
+        | enums::IntentStatus::RequiresConfirmation
+        | enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
     };
     let is_merchant_id_enabled_for_retries = !connector_request_reference_id_config
         .merchant_ids_send_payment_id_as_connector_request_id
@@ -4678,7 +4726,7 @@ pub async fn get_additional_payment_data(
                 let card_info = card_isin
                     .clone()
                     .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
+                        db.get_card_info_with_bin_range_fallback(&card_isin)
                             .await
                             .map_err(|error| services::logger::warn!(card_info_error=?error))
                             .ok()
@@ -4973,7 +5021,7 @@ pub async fn get_additional_payment_data(
                 let card_info = card_isin
                     .clone()
                     .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
+                        db.get_card_info_with_bin_range_fallback(&card_isin)
                             .await
                             .map_err(|error| services::logger::warn!(card_info_error=?error))
                             .ok()
@@ -6830,6 +6878,10 @@ where
                 connector_response_reference_id,
                 ..
             } => connector_response_reference_id,
+            PaymentsResponseData::IncrementalAuthorizationResponse {
+                connector_authorization_id,
+                ..
+            } => connector_authorization_id,
             _ => &None,
         },
         Err(_) => &None,
@@ -0,0 +1,369 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+#[cfg(feature = "v1")]
+use super::OperationSessionGetters;
+#[cfg(feature = "v1")]
+use crate::core::blocklist::utils as blocklist_utils;
+#[cfg(feature = "v1")]
+use crate::types::domain;
+use crate::{
+    configs::settings::NetworkRetryLimit,
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+    types::{self, api::enums},
+};
+
+pub const NETWORK_RETRY_LEDGER_PREFIX: &str = "NETWORK_RETRY_LEDGER";
+
+/// Declined attempts made by a merchant with a card on a card network, used to enforce the
+/// retry limits set by the network over rolling windows. Cards are identified by a fingerprint
+/// that is the same for every payment made with the card.
+#[derive(Clone, Debug)]
+pub struct NetworkRetryLedger {
+    merchant_id: id_type::MerchantId,
+    card_network: enums::CardNetwork,
+    card_fingerprint: String,
+    /// Limits enforced on the card besides the configured limits of its network
+    additional_limits: Vec<NetworkRetryLimit>,
+}
+
+impl NetworkRetryLedger {
+    pub fn new(
+        merchant_id: id_type::MerchantId,
+        card_network: enums::CardNetwork,
+        card_fingerprint: String,
+    ) -> Self {
+        Self {
+            merchant_id,
+            card_network,
+            card_fingerprint,
+            additional_limits: Vec::new(),
+        }
+    }
+
+    pub fn with_additional_limits(mut self, additional_limits: Vec<NetworkRetryLimit>) -> Self {
+        self.additional_limits = additional_limits;
+        self
+    }
+
+    fn get_redis_key(&self) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            NETWORK_RETRY_LEDGER_PREFIX,
+            self.merchant_id.get_string_repr(),
+            self.card_network,
+            self.card_fingerprint
+        )
+    }
+
+    fn get_limits<'a>(&'a self, state: &'a SessionState) -> Vec<&'a NetworkRetryLimit> {
+        let config = &state.conf.network_retry_limits;
+        let configured_limits = config
+            .limits
+            .iter()
+            .filter(|limit| config.enabled && limit.card_network == self.card_network);
+
+        configured_limits
+            .chain(self.additional_limits.iter())
+            .collect()
+    }
+
+    /// Adds a declined attempt to the ledger. Only as many declines as the largest limit of the
+    /// network are retained, since older declines cannot change the outcome of a check.
+    #[instrument(skip_all)]
+    pub async fn record_decline(&self, state: &SessionState) -> RouterResult<()> {
+        let limits = self.get_limits(state);
+        let (Some(max_retries), Some(window_in_seconds)) = (
+            limits.iter().map(|limit| limit.max_retries).max(),
+            limits.iter().map(|limit| limit.window_in_seconds).max(),
+        ) else {
+            return Ok(());
+        };
+
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let key = self.get_redis_key();
+
+        redis_conn
+            .append_elements_to_list(
+                &key.as_str().into(),
+                common_utils::date_time::now_unix_timestamp().to_string(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record decline in network retry ledger")?;
+
+        redis_conn
+            .set_expiry(&key.as_str().into(), i64::from(window_in_seconds))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry of network retry ledger")?;
+
+        let ledger_length = redis_conn
+            .get_list_length(&key.as_str().into())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch length of network retry ledger")?;
+        let max_retries = usize::try_from(max_retries).unwrap_or(usize::MAX);
+
+        if ledger_length > max_retries {
+            redis_conn
+                .lpop_list_elements(&key.as_str().into(), Some(ledger_length - max_retries))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to trim network retry ledger")?;
+        }
+
+        Ok(())
+    }
+
+    /// Fails with [`errors::ApiErrorResponse::NetworkRetryLimitExceeded`] if the card has been
+    /// declined as many times as any of the limits of its network allow within the window of the
+    /// limit. The card is allowed to be retried if the ledger cannot be read.
+    #[instrument(skip_all)]
+    pub async fn check(&self, state: &SessionState) -> RouterResult<()> {
+        let limits = self.get_limits(state);
+        if limits.is_empty() {
+            return Ok(());
+        }
+
+        let declines = match self.get_declines(state).await {
+            Ok(declines) => declines,
+            Err(error) => {
+                logger::error!(?error, "Failed to fetch network retry ledger");
+                return Ok(());
+            }
+        };
+
+        let now = common_utils::date_time::now_unix_timestamp();
+        match find_exceeded_limit(limits, &declines, now) {
+            Some(limit) => {
+                metrics::NETWORK_RETRY_LIMIT_BLOCKED_COUNT.add(
+                    1,
+                    router_env::metric_attributes!(
+                        ("card_network", self.card_network.to_string()),
+                        ("merchant_id", self.merchant_id.clone()),
+                    ),
+                );
+                logger::info!(
+                    card_network = %self.card_network,
+                    max_retries = limit.max_retries,
+                    window_in_seconds = limit.window_in_seconds,
+                    "Network retry limit reached for card"
+                );
+                Err(report!(
+                    errors::ApiErrorResponse::NetworkRetryLimitExceeded {
+                        card_network: self.card_network.to_string(),
+                    }
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+
+    async fn get_declines(&self, state: &SessionState) -> RouterResult<Vec<i64>> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let declines = redis_conn
+            .get_list_elements(&self.get_redis_key().as_str().into(), 0, -1)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        Ok(declines
+            .iter()
+            .filter_map(|declined_at| declined_at.parse().ok())
+            .collect())
+    }
+}
+
+/// Returns the first limit that the declines made within its window have reached
+fn find_exceeded_limit<'a>(
+    limits: Vec<&'a NetworkRetryLimit>,
+    declines: &[i64],
+    now: i64,
+) -> Option<&'a NetworkRetryLimit> {
+    limits.into_iter().find(|limit| {
+        let declines_in_window = declines
+            .iter()
+            .filter(|declined_at| now - **declined_at < i64::from(limit.window_in_seconds))
+            .count();
+        declines_in_window >= usize::try_from(limit.max_retries).unwrap_or(usize::MAX)
+    })
+}
+
+/// Returns the ledger of the card used for the payment, if the card fingerprint and the network of
+/// the card are known. The fingerprint is generated for the card number when the payment attempt
+/// does not have one yet, and is not known when the card number is not available, as with
+/// connector mandates of payments made without the card number.
+#[cfg(feature = "v1")]
+pub async fn get_ledger_for_payment<F, D>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_data: &D,
+) -> RouterResult<Option<NetworkRetryLedger>>
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    if !state.conf.network_retry_limits.enabled {
+        return Ok(None);
+    }
+
+    let Some(card_network) = get_card_network(payment_data) else {
+        return Ok(None);
+    };
+
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let card_fingerprint = match (
+        payment_data.get_payment_attempt().fingerprint_id.clone(),
+        payment_data.get_payment_method_data(),
+    ) {
+        (Some(fingerprint_id), _) => Some(fingerprint_id),
+        (None, Some(payment_method_data @ domain::PaymentMethodData::Card(_))) => {
+            blocklist_utils::generate_payment_fingerprint(
+                state,
+                merchant_id.clone(),
+                Some(payment_method_data.clone()),
+            )
+            .await?
+        }
+        (None, _) => None,
+    };
+
+    Ok(card_fingerprint.map(|card_fingerprint| {
+        NetworkRetryLedger::new(merchant_id.clone(), card_network, card_fingerprint)
+    }))
+}
+
+#[cfg(feature = "v1")]
+fn get_card_network<F, D>(payment_data: &D) -> Option<enums::CardNetwork>
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    let card_network = match payment_data.get_payment_method_data() {
+        Some(domain::PaymentMethodData::Card(card)) => card.card_network.clone(),
+        _ => None,
+    };
+
+    card_network.or_else(|| {
+        let additional_payment_data = payment_data
+            .get_payment_attempt()
+            .payment_method_data
+            .clone()?
+            .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+            .map_err(|error| logger::warn!(?error, "Failed to parse payment method data"))
+            .ok()?;
+
+        match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        }
+    })
+}
+
+/// Merchant initiated payments and payments confirmed again by the scheduler after a requeue are
+/// retries of a card that is not presented by the customer
+#[cfg(feature = "v1")]
+pub fn is_merchant_initiated_retry<F, D>(
+    payment_data: &D,
+    header_payload: &hyperswitch_domain_models::payments::HeaderPayload,
+) -> bool
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    payment_data.get_mandate_id().is_some()
+        || payment_data.get_payment_intent().off_session == Some(true)
+        || header_payload.payment_confirm_source == Some(enums::PaymentSource::Scheduler)
+}
+
+/// A decline is a failed attempt that was rejected by the connector, as opposed to a technical
+/// failure which leaves the attempt pending
+pub fn is_card_decline<F, Req>(
+    router_data: &types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> bool {
+    match router_data.response.as_ref() {
+        Ok(_) => router_data.status == enums::AttemptStatus::Failure,
+        Err(error_response) => match error_response.attempt_status {
+            Some(attempt_status) => attempt_status == enums::AttemptStatus::Failure,
+            None => !matches!(error_response.status_code, 429 | 500..=511),
+        },
+    }
+}
+
+/// Records the decline of a payment in the ledger of its card. Failures are logged, since the
+/// outcome of the payment does not depend on the ledger.
+#[cfg(feature = "v1")]
+pub async fn record_payment_decline<F, D>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_data: &D,
+) where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    let result = match get_ledger_for_payment(state, merchant_context, payment_data).await {
+        Ok(Some(ledger)) => ledger.record_decline(state).await,
+        Ok(None) => Ok(()),
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to record decline in network retry ledger");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_limit(max_retries: u32, window_in_seconds: u32) -> NetworkRetryLimit {
+        NetworkRetryLimit {
+            card_network: enums::CardNetwork::Visa,
+            max_retries,
+            window_in_seconds,
+        }
+    }
+
+    #[test]
+    fn test_find_exceeded_limit_counts_declines_within_window() {
+        let limit = get_limit(3, 100);
+        let now = 1_000;
+
+        // Declines at the start of the window have left it
+        assert!(find_exceeded_limit(vec![&limit], &[900, 950, 999], now).is_none());
+        assert!(find_exceeded_limit(vec![&limit], &[901, 950, 999], now).is_some());
+        assert!(find_exceeded_limit(vec![&limit], &[950, 999], now).is_none());
+    }
+
+    #[test]
+    fn test_find_exceeded_limit_checks_every_limit() {
+        let daily_limit = get_limit(2, 86_400);
+        let monthly_limit = get_limit(15, 2_592_000);
+        let now = 10_000_000;
+        let declines = [now - 90_000, now - 10, now - 5];
+
+        let exceeded_limit =
+            find_exceeded_limit(vec![&monthly_limit, &daily_limit], &declines, now)
+                .map(|limit| limit.window_in_seconds);
+        assert_eq!(exceeded_limit, Some(86_400));
+
+        let declines = [now - 90_000, now - 10];
+        assert!(find_exceeded_limit(vec![&monthly_limit, &daily_limit], &declines, now).is_none());
+    }
+
+    #[test]
+    fn test_find_exceeded_limit_without_limits() {
+        assert!(find_exceeded_limit(Vec::new(), &[1, 2, 3], 4).is_none());
+    }
+}
@@ -373,6 +373,13 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         Ok(())
     }
 
+    /// Action to be taken on the authorized tenders of a split tender payment other than its
+    /// active attempt, when the operation acts on all of them
+    #[cfg(feature = "v1")]
+    fn get_other_tenders_action(&self) -> Option<super::split_tender::OtherTendersAction> {
+        None
+    }
+
     #[cfg(feature = "v2")]
     async fn create_or_fetch_payment_method<'a>(
         &'a self,
@@ -596,6 +603,10 @@ where
     for<'a> &'a Op: Operation<F, api::PaymentsCaptureRequest, Data = D>,
     D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send,
 {
+    fn get_other_tenders_action(&self) -> Option<super::split_tender::OtherTendersAction> {
+        Some(super::split_tender::OtherTendersAction::Capture)
+    }
+
     #[instrument(skip_all)]
     #[cfg(feature = "v1")]
     async fn get_or_create_customer_details<'a>(
@@ -700,6 +711,10 @@ where
     for<'a> &'a Op: Operation<F, api::PaymentsCancelRequest, Data = D>,
     D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send,
 {
+    fn get_other_tenders_action(&self) -> Option<super::split_tender::OtherTendersAction> {
+        Some(super::split_tender::OtherTendersAction::Void)
+    }
+
     #[instrument(skip_all)]
     #[cfg(feature = "v1")]
     async fn get_or_create_customer_details<'a>(
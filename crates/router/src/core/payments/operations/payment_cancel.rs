@@ -11,7 +11,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, PaymentData},
+        payments::{helpers, operations, split_tender, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
     routes::{app::ReqState, SessionState},
@@ -249,17 +249,24 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelReques
         F: 'b + Send,
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
-        let (intent_status_update, attempt_status_update) =
-            if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
-                let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
-                    status: enums::IntentStatus::Cancelled,
-                    updated_by: storage_scheme.to_string(),
-                    incremental_authorization_allowed: None,
-                };
-                (Some(payment_intent_update), enums::AttemptStatus::Voided)
-            } else {
-                (None, enums::AttemptStatus::VoidInitiated)
+        // Authorized tenders of a split tender payment are voided by the connector even when the
+        // remaining balance of the payment has not been authorized yet
+        let is_authorized_tender =
+            split_tender::is_split_tender_enabled(&payment_data.payment_intent)
+                && payment_data.payment_attempt.status == enums::AttemptStatus::Authorized;
+        let (intent_status_update, attempt_status_update) = if payment_data.payment_intent.status
+            != enums::IntentStatus::RequiresCapture
+            && !is_authorized_tender
+        {
+            let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Cancelled,
+                updated_by: storage_scheme.to_string(),
+                incremental_authorization_allowed: None,
             };
+            (Some(payment_intent_update), enums::AttemptStatus::Voided)
+        } else {
+            (None, enums::AttemptStatus::VoidInitiated)
+        };
 
         if let Some(payment_intent_update) = intent_status_update {
             payment_data.payment_intent = state
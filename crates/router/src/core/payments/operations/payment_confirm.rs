@@ -134,6 +134,25 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 ],
                 "confirm",
             )?;
+        } else if header_payload.payment_confirm_source
+            == Some(common_enums::PaymentSource::Scheduler)
+            && matches!(
+                request.retry_action,
+                Some(api_models::enums::RetryAction::Requeue)
+            )
+        {
+            // Requeued payments are confirmed again by the scheduler while they are still processing
+            helpers::validate_payment_status_against_not_allowed_statuses(
+                payment_intent.status,
+                &[
+                    storage_enums::IntentStatus::Cancelled,
+                    storage_enums::IntentStatus::Succeeded,
+                    storage_enums::IntentStatus::RequiresCapture,
+                    storage_enums::IntentStatus::RequiresMerchantAction,
+                    storage_enums::IntentStatus::RequiresCustomerAction,
+                ],
+                "confirm",
+            )?;
         } else {
             helpers::validate_payment_status_against_not_allowed_statuses(
                 payment_intent.status,
@@ -1796,6 +1815,9 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .payment_attempt
                             .connector_mandate_detail,
                         card_discovery,
+                        estimated_connector_cost: payment_data
+                            .payment_attempt
+                            .estimated_connector_cost,
                     },
                     storage_scheme,
                 )
@@ -1365,6 +1365,7 @@ impl PaymentCreate {
                 processor_merchant_id: merchant_id.to_owned(),
                 created_by: None,
                 setup_future_usage_applied: request.setup_future_usage,
+                estimated_connector_cost: None,
             },
             additional_pm_data,
 
@@ -1598,6 +1599,7 @@ impl PaymentCreate {
             is_iframe_redirection_enabled: request
                 .is_iframe_redirection_enabled
                 .or(business_profile.is_iframe_redirection_enabled),
+            enable_partial_authorization: request.enable_partial_authorization,
         })
     }
 
@@ -40,6 +40,7 @@ impl ValidateStatusForOperation for PaymentGet {
             | common_enums::IntentStatus::Succeeded
             | common_enums::IntentStatus::Failed
             | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::Cancelled => Ok(()),
             // These statuses are not valid for this operation
@@ -57,6 +58,7 @@ impl ValidateStatusForOperation for PaymentGet {
                         common_enums::IntentStatus::Succeeded,
                         common_enums::IntentStatus::Failed,
                         common_enums::IntentStatus::PartiallyCapturedAndCapturable,
+                        common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod,
                         common_enums::IntentStatus::PartiallyCaptured,
                         common_enums::IntentStatus::Cancelled,
                     ]
@@ -5,6 +5,8 @@ use api_models::payments::{ConnectorMandateReferenceId, MandateReferenceId};
 use api_models::routing::RoutableConnectorChoice;
 use async_trait::async_trait;
 use common_enums::AuthorizationStatus;
+#[cfg(feature = "v1")]
+use common_utils::errors::IntegrityCheckError;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use common_utils::ext_traits::ValueExt;
 use common_utils::{
@@ -18,12 +20,18 @@ use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
 use hyperswitch_domain_models::payments::{
     PaymentConfirmData, PaymentIntentData, PaymentStatusData,
 };
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::router_request_types::CaptureIntegrityObject;
+#[cfg(feature = "v1")]
+use hyperswitch_interfaces::integrity::FlowIntegrity;
 use router_derive;
 use router_env::{instrument, logger, tracing};
 use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
+#[cfg(feature = "v1")]
+use crate::core::payments::split_tender;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(feature = "v2")]
@@ -56,6 +64,8 @@ use crate::{
     },
     utils,
 };
+#[cfg(feature = "v1")]
+use crate::{core::utils::integrity, events::audit_events::IntegrityCheckFlow};
 
 #[cfg(feature = "v1")]
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -410,6 +420,22 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAu
                 report!(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("missing incremental_authorization_details in payment_data")
             })?;
+        // A mismatch fails the authorization only if the integrity check policy of the profile
+        // holds the payment for manual review, as it does for the other payment flows
+        let quarantined_integrity_check_error =
+            match (&router_data.response, &router_data.integrity_check) {
+                (Ok(_), Err(err)) => handle_integrity_check_failure(
+                    state,
+                    &mut payment_data,
+                    key_store,
+                    storage_scheme,
+                    IntegrityCheckFlow::IncrementalAuthorization,
+                    err,
+                )
+                .await?
+                .then(|| err.clone()),
+                _ => None,
+            };
         // Update payment_intent and payment_attempt 'amount' if incremental_authorization is successful
         let (option_payment_attempt_update, option_payment_intent_update) = match router_data
             .response
@@ -419,7 +445,9 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAu
             Ok(types::PaymentsResponseData::IncrementalAuthorizationResponse {
                 status, ..
             }) => {
-                if status == AuthorizationStatus::Success {
+                if status == AuthorizationStatus::Success
+                    && quarantined_integrity_check_error.is_none()
+                {
                     (
                         Some(
                             storage::PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -503,12 +531,23 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAu
                 error_code,
                 error_message,
                 connector_authorization_id,
-            }) => Ok(storage::AuthorizationUpdate::StatusUpdate {
-                status: status.clone(),
-                error_code: error_code.clone(),
-                error_message: error_message.clone(),
-                connector_authorization_id: connector_authorization_id.clone(),
-            }),
+            }) => match &quarantined_integrity_check_error {
+                Some(err) => Ok(storage::AuthorizationUpdate::StatusUpdate {
+                    status: AuthorizationStatus::Failure,
+                    error_code: Some("IE".to_string()),
+                    error_message: Some(format!(
+                        "Integrity Check Failed! Value mismatched for fields {}",
+                        err.field_names
+                    )),
+                    connector_authorization_id: connector_authorization_id.clone(),
+                }),
+                None => Ok(storage::AuthorizationUpdate::StatusUpdate {
+                    status: status.clone(),
+                    error_code: error_code.clone(),
+                    error_message: error_message.clone(),
+                    connector_authorization_id: connector_authorization_id.clone(),
+                }),
+            },
             Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("unexpected response in incremental_authorization flow"),
         }?;
@@ -1417,6 +1456,16 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     });
     payment_data.whole_connector_response = router_data.whole_connector_response.clone();
 
+    let capture_sync_integrity_check = check_capture_sync_integrity(
+        payment_data.multiple_capture_data.as_ref(),
+        payment_data.currency,
+        &router_data.response,
+    );
+    let integrity_check = router_data
+        .integrity_check
+        .clone()
+        .and(capture_sync_integrity_check.clone());
+
     // TODO: refactor of gsm_error_category with respective feature flag
     #[allow(unused_variables)]
     let (capture_update, mut payment_attempt_update, gsm_error_category) = match router_data
@@ -1556,7 +1605,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
         Ok(payments_response) => {
             // match on connector integrity check
-            match router_data.integrity_check.clone() {
+            match integrity_check.clone() {
                 Err(err) => {
                     let auth_update = if Some(router_data.auth_type)
                         != payment_data.payment_attempt.authentication_type
@@ -2023,26 +2072,54 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         None => None,
     };
 
-    let amount_captured = get_total_amount_captured(
-        &router_data.request,
-        router_data.amount_captured.map(MinorUnit::new),
-        router_data.status,
-        &payment_data,
+    // Connectors may approve only a part of the amount of a split tender payment
+    let partial_authorization_update = split_tender::get_partial_authorization_update(
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        router_data.connector_response.as_ref(),
+    );
+    if let Some(payment_attempt_update) = partial_authorization_update.clone() {
+        payment_data.payment_attempt = PaymentAttempt::from_storage_model(
+            payment_attempt_update
+                .to_storage_model()
+                .apply_changeset(payment_data.payment_attempt.clone().to_storage_model()),
+        );
+    }
+
+    let other_tenders = split_tender::get_other_tenders(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        storage_scheme,
+    )
+    .await?;
+
+    let amount_captured = split_tender::get_amount_captured(
+        &other_tenders,
+        get_total_amount_captured(
+            &router_data.request,
+            router_data.amount_captured.map(MinorUnit::new),
+            router_data.status,
+            &payment_data,
+        ),
+    );
+
+    let intent_status = split_tender::get_intent_status(
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        &other_tenders,
+        api_models::enums::IntentStatus::foreign_from(payment_data.payment_attempt.status),
     );
 
     let payment_intent_update = match &router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             updated_by: storage_scheme.to_string(),
             // make this false only if initial payment fails, if incremental authorization call fails don't make it false
             incremental_authorization_allowed: Some(false),
         },
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             amount_captured,
             updated_by: storage_scheme.to_string(),
             fingerprint_id: payment_data.payment_attempt.fingerprint_id.clone(),
@@ -2107,6 +2184,19 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         utils::flatten_join_error(payment_attempt_fut)
     )?;
 
+    let payment_attempt = match partial_authorization_update {
+        Some(payment_attempt_update) => state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                payment_attempt_update,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?,
+        None => payment_attempt,
+    };
+
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
     {
         if payment_intent.status.is_in_terminal_state()
@@ -2233,28 +2323,43 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             payment_data.card_testing_guard_data.clone(),
         )
         .await;
+
+        // The stored card of a failed merchant initiated payment may have expired or been reissued
+        if payment_data.mandate_id.is_some()
+            && payment_data.payment_attempt.payment_method == Some(enums::PaymentMethod::Card)
+        {
+            if let Some(payment_method_id) = &payment_data.payment_attempt.payment_method_id {
+                payment_methods::account_updater::record_failed_merchant_initiated_payment(
+                    state,
+                    &payment_data.payment_attempt.merchant_id,
+                    payment_method_id,
+                )
+                .await
+                .map_err(|error| {
+                    logger::warn!(?error, "Failed to record failed merchant initiated payment")
+                })
+                .ok();
+            }
+        }
     }
 
-    match router_data.integrity_check {
+    match integrity_check {
         Ok(()) => Ok(payment_data),
         Err(err) => {
-            metrics::INTEGRITY_CHECK_FAILED.add(
-                1,
-                router_env::metric_attributes!(
-                    (
-                        "connector",
-                        payment_data
-                            .payment_attempt
-                            .connector
-                            .clone()
-                            .unwrap_or_default(),
-                    ),
-                    (
-                        "merchant_id",
-                        payment_data.payment_attempt.merchant_id.clone(),
-                    )
-                ),
-            );
+            let flow = if capture_sync_integrity_check.is_err() {
+                IntegrityCheckFlow::CaptureSync
+            } else {
+                IntegrityCheckFlow::Payment
+            };
+            handle_integrity_check_failure(
+                state,
+                &mut payment_data,
+                key_store,
+                storage_scheme,
+                flow,
+                &err,
+            )
+            .await?;
             Err(error_stack::Report::new(
                 errors::ApiErrorResponse::IntegrityCheckFailed {
                     connector_transaction_id: payment_data
@@ -2272,6 +2377,99 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     }
 }
 
+/// Checks the amount the connector reports for each capture of the payment against the amount
+/// of the capture. Captures reported without an amount, or which cannot be mapped to a capture
+/// by their connector capture id, are not checked.
+#[cfg(feature = "v1")]
+fn check_capture_sync_integrity(
+    multiple_capture_data: Option<&MultipleCaptureData>,
+    currency: enums::Currency,
+    response: &Result<types::PaymentsResponseData, ErrorResponse>,
+) -> Result<(), IntegrityCheckError> {
+    let (
+        Some(multiple_capture_data),
+        Ok(types::PaymentsResponseData::MultipleCaptureResponse {
+            capture_sync_response_list,
+        }),
+    ) = (multiple_capture_data, response)
+    else {
+        return Ok(());
+    };
+
+    capture_sync_response_list.iter().try_for_each(
+        |(connector_capture_id, capture_sync_response)| match multiple_capture_data
+            .get_capture_by_connector_capture_id(connector_capture_id)
+        {
+            Some(capture) => CaptureIntegrityObject::compare(
+                CaptureIntegrityObject {
+                    capture_amount: Some(capture.amount),
+                    currency,
+                },
+                CaptureIntegrityObject {
+                    capture_amount: capture_sync_response.get_amount_captured(),
+                    currency,
+                },
+                Some(connector_capture_id.clone()),
+            ),
+            None => Ok(()),
+        },
+    )
+}
+
+/// Records the integrity check failure of the payment and, if the policy of its profile asks for
+/// it, holds the payment for manual review. Returns whether the payment was held.
+#[cfg(feature = "v1")]
+async fn handle_integrity_check_failure<F: Clone>(
+    state: &SessionState,
+    payment_data: &mut PaymentData<F>,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    flow: IntegrityCheckFlow,
+    error: &IntegrityCheckError,
+) -> RouterResult<bool> {
+    let quarantined = integrity::should_quarantine(
+        state,
+        key_store,
+        payment_data.payment_intent.profile_id.as_ref(),
+    )
+    .await;
+    integrity::record_integrity_check_failure(
+        state,
+        integrity::IntegrityCheckFailure {
+            flow,
+            merchant_id: &payment_data.payment_intent.merchant_id,
+            connector: &payment_data
+                .payment_attempt
+                .connector
+                .clone()
+                .unwrap_or_default(),
+            resource_id: payment_data.payment_intent.get_id().get_string_repr(),
+            error,
+        },
+        quarantined,
+    );
+
+    if quarantined {
+        payment_data.payment_intent = state
+            .store
+            .update_payment_intent(
+                &state.into(),
+                payment_data.payment_intent.clone(),
+                storage::PaymentIntentUpdate::ManualUpdate {
+                    status: Some(enums::IntentStatus::RequiresMerchantAction),
+                    updated_by: storage_scheme.to_string(),
+                },
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            .attach_printable("Failed to hold the payment for manual review")?;
+    }
+
+    Ok(quarantined)
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 async fn update_payment_method_status_and_ntid<F: Clone>(
     state: &SessionState,
@@ -40,6 +40,7 @@ impl ValidateStatusForOperation for PaymentSessionIntent {
             | common_enums::IntentStatus::PartiallyCaptured
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
             | common_enums::IntentStatus::Succeeded
             | common_enums::IntentStatus::Failed => {
                 Err(errors::ApiErrorResponse::PreconditionFailed {
@@ -9,12 +9,13 @@ use router_env::{
 };
 
 use crate::{
+    consts,
     core::{
-        errors::{self, RouterResult, StorageErrorExt},
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payments::{
             self,
             flows::{ConstructFlowSpecificData, Feature},
-            operations,
+            network_retry_limit, operations,
         },
     },
     db::StorageInterface,
@@ -93,7 +94,9 @@ where
         false
     };
 
-    if should_step_up {
+    if should_step_up
+        && !is_network_retry_limit_reached(state, merchant_context, payment_data).await
+    {
         router_data = do_retry(
             &state.clone(),
             req_state.clone(),
@@ -109,6 +112,7 @@ where
             frm_suggestion,
             business_profile,
             false, //should_retry_with_pan is not applicable for step-up
+            &[],
         )
         .await?;
     }
@@ -121,6 +125,9 @@ where
                 None => get_gsm(state, &router_data).await?,
             };
 
+            let requeue_delay_in_seconds =
+                gsm.as_ref().and_then(|gsm| gsm.requeue_delay_in_seconds);
+
             match get_gsm_decision(gsm) {
                 api_models::gsm::GsmDecision::Retry => {
                     retries = get_retries(
@@ -143,6 +150,11 @@ where
                         break;
                     }
 
+                    if is_network_retry_limit_reached(state, merchant_context, payment_data).await {
+                        logger::info!("network retry limit reached for auto_retry payment");
+                        break;
+                    }
+
                     let is_network_token = payment_data
                         .get_payment_method_data()
                         .map(|pmd| pmd.is_network_token_payment_method_data())
@@ -178,17 +190,25 @@ where
                         frm_suggestion,
                         business_profile,
                         should_retry_with_pan,
+                        connector_routing_data.as_slice(),
                     )
                     .await?;
 
                     retries = retries.map(|i| i - 1);
                 }
                 api_models::gsm::GsmDecision::Requeue => {
-                    Err(report!(errors::ApiErrorResponse::NotImplemented {
-                        message: errors::NotImplementedMessage::Reason(
-                            "Requeue not implemented".to_string(),
-                        ),
-                    }))?
+                    router_data = requeue_payment(
+                        state,
+                        payment_data,
+                        router_data,
+                        &mut connector_routing_data,
+                        merchant_context,
+                        operation,
+                        business_profile,
+                        requeue_delay_in_seconds,
+                    )
+                    .await;
+                    break;
                 }
                 api_models::gsm::GsmDecision::DoDefault => break,
             }
@@ -198,6 +218,245 @@ where
     Ok(router_data)
 }
 
+/// Leaves the payment pending and schedules it to be confirmed again through the process tracker,
+/// with the next eligible connector if there is one or else with the same connector. The payment
+/// is left as is if it cannot be requeued.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn requeue_payment<F, ApiRequest, FData, D>(
+    state: &app::SessionState,
+    payment_data: &D,
+    mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
+    connector_routing_data: &mut IntoIter<api::ConnectorRoutingData>,
+    merchant_context: &domain::MerchantContext,
+    operation: &operations::BoxedOperation<'_, F, ApiRequest, D>,
+    business_profile: &domain::Profile,
+    requeue_delay_in_seconds: Option<i32>,
+) -> types::RouterData<F, FData, types::PaymentsResponseData>
+where
+    F: Clone + Send + Sync,
+    D: payments::OperationSessionGetters<F>,
+{
+    if !payments::is_operation_confirm(operation) {
+        logger::info!("requeue is only supported while confirming a payment");
+        return router_data;
+    }
+
+    let payment_intent = payment_data.get_payment_intent();
+    let payment_attempt = payment_data.get_payment_attempt();
+
+    let max_retries = get_retries(
+        state,
+        None,
+        merchant_context.get_merchant_account().get_id(),
+        business_profile,
+    )
+    .await
+    .unwrap_or_default();
+
+    // Every requeue confirms the payment with a new attempt, so the attempts made so far count
+    // against the retries allowed for the merchant
+    if i32::from(payment_intent.attempt_count) > max_retries {
+        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
+        logger::info!("retries exhausted for requeued payment");
+        return router_data;
+    }
+
+    if is_network_retry_limit_reached(state, merchant_context, payment_data).await {
+        logger::info!("network retry limit reached for requeued payment");
+        return router_data;
+    }
+
+    // The payment method data is retained only for the fulfillment time of the payment
+    let requeue_delay = get_requeue_delay(requeue_delay_in_seconds, payment_intent.attempt_count);
+    let fulfillment_time = business_profile
+        .get_order_fulfillment_time()
+        .unwrap_or(consts::DEFAULT_FULFILLMENT_TIME);
+    if requeue_delay.whole_seconds() > fulfillment_time {
+        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
+        logger::info!("requeue delay exceeds the fulfillment time of the payment");
+        return router_data;
+    }
+
+    let tracking_data = match get_requeue_tracking_data(
+        state,
+        payment_data,
+        connector_routing_data,
+        &router_data.connector,
+        merchant_context,
+        business_profile,
+    )
+    .await
+    {
+        Ok(Some(tracking_data)) => tracking_data,
+        Ok(None) => {
+            logger::info!("payment method data is not available to requeue the payment");
+            return router_data;
+        }
+        Err(error) => {
+            logger::error!(?error, "failed to requeue payment");
+            return router_data;
+        }
+    };
+
+    let schedule_time = common_utils::date_time::now().saturating_add(requeue_delay);
+    if let Err(error) =
+        add_payment_requeue_task(&*state.store, payment_attempt, tracking_data, schedule_time).await
+    {
+        logger::error!(
+            ?error,
+            "failed to add payment requeue task to process tracker"
+        );
+        return router_data;
+    }
+
+    if let Err(error_response) = router_data.response.as_mut() {
+        error_response.attempt_status = Some(storage_enums::AttemptStatus::Pending);
+    }
+    router_data.status = storage_enums::AttemptStatus::Pending;
+
+    metrics::AUTO_RETRY_REQUEUED_COUNT.add(1, &[]);
+    logger::info!(?schedule_time, "payment requeued");
+    router_data
+}
+
+/// Retries with the card of the payment are not made once any of the retry limits of its network
+/// has been reached
+#[cfg(feature = "v1")]
+async fn is_network_retry_limit_reached<F, D>(
+    state: &app::SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_data: &D,
+) -> bool
+where
+    F: Clone,
+    D: payments::OperationSessionGetters<F>,
+{
+    match network_retry_limit::get_ledger_for_payment(state, merchant_context, payment_data).await {
+        Ok(Some(ledger)) => ledger.check(state).await.is_err(),
+        Ok(None) => false,
+        Err(error) => {
+            logger::error!(?error, "failed to fetch network retry ledger");
+            false
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn get_requeue_tracking_data<F, D>(
+    state: &app::SessionState,
+    payment_data: &D,
+    connector_routing_data: &mut IntoIter<api::ConnectorRoutingData>,
+    current_connector: &str,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+) -> RouterResult<Option<storage::payment_attempt::PaymentRequeueTrackingData>>
+where
+    F: Clone,
+    D: payments::OperationSessionGetters<F>,
+{
+    let payment_intent = payment_data.get_payment_intent();
+    let payment_attempt = payment_data.get_payment_attempt();
+
+    let Some(payment_method) = payment_attempt.payment_method else {
+        return Ok(None);
+    };
+
+    let payment_token = match (
+        payment_attempt.payment_token.clone(),
+        payment_data.get_payment_method_data(),
+    ) {
+        (Some(payment_token), _) => payment_token,
+        (None, Some(payment_method_data)) => {
+            payments::helpers::store_in_vault_and_generate_ppmt(
+                state,
+                payment_method_data,
+                payment_intent,
+                payment_attempt,
+                payment_method,
+                merchant_context.get_merchant_key_store(),
+                Some(business_profile),
+            )
+            .await?
+        }
+        (None, None) => return Ok(None),
+    };
+
+    let (connector_name, merchant_connector_id) = match connector_routing_data.next() {
+        Some(routing_data) => (
+            routing_data.connector_data.connector_name.to_string(),
+            routing_data.connector_data.merchant_connector_id,
+        ),
+        None => (
+            current_connector.to_owned(),
+            payment_attempt.merchant_connector_id.clone(),
+        ),
+    };
+    let connector = api_models::routing::RoutableConnectorChoice {
+        choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+        connector: api_models::enums::RoutableConnectors::from_str(&connector_name)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("connector {connector_name} is not routable for requeue")
+            })?,
+        merchant_connector_id,
+    };
+
+    Ok(Some(storage::payment_attempt::PaymentRequeueTrackingData {
+        merchant_id: payment_intent.merchant_id.clone(),
+        payment_id: payment_intent.get_id().to_owned(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        payment_token,
+        payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        connector,
+    }))
+}
+
+/// The requeue delay configured in GSM, doubled for every attempt already made on the payment
+#[cfg(feature = "v1")]
+fn get_requeue_delay(requeue_delay_in_seconds: Option<i32>, attempt_count: i16) -> time::Duration {
+    let base_delay = requeue_delay_in_seconds
+        .map(i64::from)
+        .unwrap_or(consts::DEFAULT_REQUEUE_DELAY_IN_SECONDS);
+    let backoff_factor =
+        2_i64.saturating_pow(u32::try_from(attempt_count.saturating_sub(1)).unwrap_or_default());
+    time::Duration::seconds(base_delay.saturating_mul(backoff_factor))
+}
+
+#[cfg(feature = "v1")]
+async fn add_payment_requeue_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    tracking_data: storage::payment_attempt::PaymentRequeueTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PaymentRequeueWorkflow;
+    let task = "PAYMENT_REQUEUE";
+    let tag = ["REQUEUE", "PAYMENT"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn is_step_up_enabled_for_merchant_connector(
     state: &app::SessionState,
@@ -329,6 +588,7 @@ pub async fn do_retry<F, ApiRequest, FData, D>(
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
     business_profile: &domain::Profile,
     should_retry_with_pan: bool,
+    remaining_connectors: &[api::ConnectorRoutingData],
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     F: Clone + Send + Sync,
@@ -345,6 +605,15 @@ where
 {
     metrics::AUTO_RETRY_PAYMENT_COUNT.add(1, &[]);
 
+    let routing_trace = get_retry_routing_trace(
+        payment_data.get_payment_attempt().attempt_id.clone(),
+        std::iter::once(connector).chain(
+            remaining_connectors
+                .iter()
+                .map(|connector_routing_data| &connector_routing_data.connector_data),
+        ),
+    );
+
     modify_trackers(
         state,
         connector.connector_name.to_string(),
@@ -356,6 +625,12 @@ where
     )
     .await?;
 
+    payments::routing::trace::store_routing_trace(
+        state,
+        routing_trace,
+        payment_data.get_payment_attempt(),
+    );
+
     let (router_data, _mca) = payments::call_connector_service(
         state,
         req_state,
@@ -380,6 +655,32 @@ where
     Ok(router_data)
 }
 
+/// Routing trace of the attempt retrying the previous attempt, with the connector the payment is
+/// retried with followed by the connectors left to be tried
+#[cfg(feature = "v1")]
+fn get_retry_routing_trace<'a>(
+    retried_attempt_id: String,
+    connectors: impl Iterator<Item = &'a api::ConnectorData>,
+) -> api::routing::RoutingTrace {
+    api::routing::RoutingTrace {
+        retried_attempt_id: Some(retried_attempt_id),
+        final_connectors: connectors
+            .filter_map(|connector_data| {
+                api_models::enums::RoutableConnectors::from_str(
+                    &connector_data.connector_name.to_string(),
+                )
+                .ok()
+                .map(|connector| api::routing::RoutableConnectorChoice {
+                    choice_kind: api::routing::RoutableChoiceKind::FullStruct,
+                    connector,
+                    merchant_connector_id: connector_data.merchant_connector_id.clone(),
+                })
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all)]
 pub async fn modify_trackers<F, FData, D>(
@@ -689,6 +990,7 @@ pub fn make_new_payment_attempt(
         processor_merchant_id: old_payment_attempt.processor_merchant_id,
         created_by: old_payment_attempt.created_by,
         setup_future_usage_applied: setup_future_usage_intent, // setup future usage is picked from intent for new payment attempt
+        estimated_connector_cost: None,
     }
 }
 
@@ -777,3 +1079,34 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requeue_delay_defaults_when_not_configured() {
+        assert_eq!(
+            get_requeue_delay(None, 1),
+            time::Duration::seconds(consts::DEFAULT_REQUEUE_DELAY_IN_SECONDS)
+        );
+        assert_eq!(get_requeue_delay(Some(30), 1), time::Duration::seconds(30));
+    }
+
+    #[test]
+    fn test_requeue_delay_doubles_for_every_attempt() {
+        assert_eq!(get_requeue_delay(Some(30), 2), time::Duration::seconds(60));
+        assert_eq!(get_requeue_delay(Some(30), 3), time::Duration::seconds(120));
+        assert_eq!(get_requeue_delay(Some(30), 5), time::Duration::seconds(480));
+        // A payment always has an attempt, a count of zero is treated as the first attempt
+        assert_eq!(get_requeue_delay(Some(30), 0), time::Duration::seconds(30));
+    }
+
+    #[test]
+    fn test_requeue_delay_saturates() {
+        assert_eq!(
+            get_requeue_delay(Some(i32::MAX), i16::MAX),
+            time::Duration::seconds(i64::MAX)
+        );
+    }
+}
@@ -1,3 +1,9 @@
+pub mod circuit_breaker;
+pub mod cost_based;
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+pub mod success_rate_estimator;
+#[cfg(feature = "v1")]
+pub mod trace;
 mod transformers;
 pub mod utils;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -16,8 +22,6 @@ use api_models::{
     routing::ConnectorSelection,
 };
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-use common_utils::ext_traits::AsyncExt;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use common_utils::{ext_traits::BytesExt, request};
 use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
@@ -38,7 +42,7 @@ use hyperswitch_domain_models::address::Address;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine, RoutingEvent};
 use kgraph_utils::{
-    mca as mca_graph,
+    explanation as kgraph_explanation, mca as mca_graph,
     transformers::{IntoContext, IntoDirValue},
     types::CountryCurrencyFilter,
 };
@@ -81,6 +85,19 @@ pub enum CachedAlgorithm {
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    CostBased(Vec<routing_types::RoutableConnectorChoice>),
+}
+
+impl CachedAlgorithm {
+    pub fn get_kind(&self) -> routing_types::RoutingAlgorithmKind {
+        match self {
+            Self::Single(_) => routing_types::RoutingAlgorithmKind::Single,
+            Self::Priority(_) => routing_types::RoutingAlgorithmKind::Priority,
+            Self::VolumeSplit(_) => routing_types::RoutingAlgorithmKind::VolumeSplit,
+            Self::Advanced(_) => routing_types::RoutingAlgorithmKind::Advanced,
+            Self::CostBased(_) => routing_types::RoutingAlgorithmKind::CostBased,
+        }
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -191,6 +208,17 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
     };
+    let payout = dsl_inputs::PayoutInput {
+        recipient_country: get_payout_recipient_country(
+            payout_data.payout_method_data.as_ref(),
+            payout_data
+                .billing_address
+                .as_ref()
+                .and_then(|address| address.country),
+        )
+        .map(api_enums::Country::from_alpha2),
+        priority: payout_data.payouts.priority,
+    };
     Ok(dsl_inputs::BackendInput {
         mandate,
         metadata,
@@ -199,9 +227,32 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout: Some(payout),
     })
 }
 
+/// The country of the bank account the payout is sent to, falling back to the country of the
+/// billing address of the recipient
+#[cfg(feature = "payouts")]
+fn get_payout_recipient_country(
+    payout_method_data: Option<&api_models::payouts::PayoutMethodData>,
+    billing_country: Option<api_enums::CountryAlpha2>,
+) -> Option<api_enums::CountryAlpha2> {
+    use api_models::payouts::{Bank, PayoutMethodData};
+
+    let bank_country = match payout_method_data {
+        Some(PayoutMethodData::Bank(Bank::Ach(ach))) => ach.bank_country_code,
+        Some(PayoutMethodData::Bank(Bank::Bacs(bacs))) => bacs.bank_country_code,
+        Some(PayoutMethodData::Bank(Bank::Sepa(sepa))) => sepa.bank_country_code,
+        Some(PayoutMethodData::Bank(Bank::Pix(_)))
+        | Some(PayoutMethodData::Card(_))
+        | Some(PayoutMethodData::Wallet(_))
+        | None => None,
+    };
+
+    bank_country.or(billing_country)
+}
+
 #[cfg(feature = "v2")]
 pub fn make_dsl_input(
     payments_dsl_input: &routing::PaymentsDslInput<'_>,
@@ -314,9 +365,47 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout: None,
     })
 }
 
+/// Card details of the attempt, enriched with the BIN data of the card when it was confirmed
+#[cfg(feature = "v1")]
+fn get_additional_card_info(
+    payment_attempt: &oss_storage::PaymentAttempt,
+) -> Option<api_models::payments::AdditionalCardInfo> {
+    let additional_payment_data = payment_attempt
+        .payment_method_data
+        .clone()?
+        .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        .map_err(|error| logger::warn!(?error, "Failed to parse payment method data"))
+        .ok()?;
+
+    match additional_payment_data {
+        api_models::payments::AdditionalPaymentData::Card(card_info) => Some(*card_info),
+        _ => None,
+    }
+}
+
+/// The issuing country in the BIN data is either an alpha-2 code or the name of the country
+#[cfg(feature = "v1")]
+fn get_issuer_data(
+    card_info: &api_models::payments::AdditionalCardInfo,
+) -> dsl_inputs::IssuerDataInput {
+    dsl_inputs::IssuerDataInput {
+        name: card_info.card_issuer.clone(),
+        country: card_info
+            .card_issuing_country
+            .as_deref()
+            .and_then(|country| {
+                CountryAlpha2::from_str(country)
+                    .map(api_enums::Country::from_alpha2)
+                    .or_else(|_| api_enums::Country::from_str(country))
+                    .ok()
+            }),
+    }
+}
+
 #[cfg(feature = "v1")]
 pub fn make_dsl_input(
     payments_dsl_input: &routing::PaymentsDslInput<'_>,
@@ -370,6 +459,7 @@ pub fn make_dsl_input(
             },
         ),
     };
+    let additional_card_info = get_additional_card_info(payments_dsl_input.payment_attempt);
     let payment_method_input = dsl_inputs::PaymentMethodInput {
         payment_method: payments_dsl_input.payment_attempt.payment_method,
         payment_method_type: payments_dsl_input.payment_attempt.payment_method_type,
@@ -380,6 +470,11 @@ pub fn make_dsl_input(
                 domain::PaymentMethodData::Card(card) => card.card_network.clone(),
 
                 _ => None,
+            })
+            .or_else(|| {
+                additional_card_info
+                    .as_ref()
+                    .and_then(|card_info| card_info.card_network.clone())
             }),
     };
 
@@ -427,16 +522,19 @@ pub fn make_dsl_input(
         mandate: mandate_data,
         acquirer_data: None,
         customer_device_data: None,
-        issuer_data: None,
+        issuer_data: additional_card_info.as_ref().map(get_issuer_data),
+        payout: None,
     })
 }
 
 pub async fn perform_static_routing_v1(
     state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
     merchant_id: &common_utils::id_type::MerchantId,
     algorithm_id: Option<&common_utils::id_type::RoutingId>,
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
+    mut routing_trace: Option<&mut routing_types::RoutingTrace>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let algorithm_id = if let Some(id) = algorithm_id {
         id
@@ -454,6 +552,14 @@ pub async fn perform_static_routing_v1(
             .get_default_fallback_list_of_connector_under_profile()
             .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
+        if let Some(routing_trace) = routing_trace {
+            routing_trace.add_fallback(
+                routing_types::RoutingTraceStage::StaticRouting,
+                "No routing algorithm is active on the profile, the default fallback configuration was used".to_string(),
+            );
+            routing_trace.algorithm_connectors = fallback_config.clone();
+        }
+
         return Ok(fallback_config);
     };
     let cached_algorithm = ensure_algorithm_cached_v1(
@@ -465,7 +571,8 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
-    Ok(match cached_algorithm.as_ref() {
+    let mut matched_rule = None;
+    let connectors = match cached_algorithm.as_ref() {
         CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
@@ -473,6 +580,16 @@ pub async fn perform_static_routing_v1(
         CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
             .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
 
+        CachedAlgorithm::CostBased(plist) => match transaction_data {
+            routing::TransactionData::Payment(payment_data) => {
+                cost_based::rank_connectors_by_cost(state, key_store, payment_data, plist.clone())
+                    .await
+            }
+            // Payouts are routed to the connectors in the order of the algorithm
+            #[cfg(feature = "payouts")]
+            routing::TransactionData::Payout(_) => plist.clone(),
+        },
+
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
                 routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
@@ -492,7 +609,8 @@ pub async fn perform_static_routing_v1(
                 // errors are ignored as this is just for diff checking as of now (optional flow).
                 logger::error!(decision_engine_euclid_evaluate_error=?e, "decision_engine_euclid: error in evaluation of rule")
             ).unwrap_or_default();
-            let routable_connectors = execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
+            let (routable_connectors, rule_name) =
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
             let connectors = routable_connectors
                 .iter()
                 .map(|c| c.connector.to_string())
@@ -502,9 +620,19 @@ pub async fn perform_static_routing_v1(
                 connectors,
                 "evaluate_routing".to_string(),
             );
+            matched_rule = rule_name;
             routable_connectors
         }
-    })
+    };
+
+    if let Some(routing_trace) = routing_trace.as_deref_mut() {
+        routing_trace.algorithm_id = Some(algorithm_id.clone());
+        routing_trace.algorithm_kind = Some(cached_algorithm.get_kind());
+        routing_trace.matched_rule = matched_rule;
+        routing_trace.algorithm_connectors = connectors.clone();
+    }
+
+    Ok(connectors)
 }
 
 async fn ensure_algorithm_cached_v1(
@@ -589,16 +717,19 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
+/// Executes the advanced routing algorithm, returning the chosen connectors along with the name of
+/// the rule that matched, which is `None` when the default selection was used
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::StaticRoutingAlgorithm = interpreter
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let backend_output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
+    let routing_output: routing_types::StaticRoutingAlgorithm =
+        backend_output.connector_selection.foreign_into();
 
-    Ok(match routing_output {
+    let connectors = match routing_output {
         routing_types::StaticRoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits)
@@ -606,7 +737,9 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, backend_output.rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -641,6 +774,9 @@ pub async fn refresh_routing_cache_v1(
 
             CachedAlgorithm::Advanced(interpreter)
         }
+        routing_types::StaticRoutingAlgorithm::CostBased(plist) => {
+            CachedAlgorithm::CostBased(plist)
+        }
         api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
             Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
                 .attach_printable("Unsupported algorithm received")?
@@ -910,6 +1046,48 @@ pub async fn perform_cgraph_filtering(
     Ok(final_selection)
 }
 
+/// Explains, for each of the chosen connectors, why [`perform_cgraph_filtering`] would or would
+/// not retain it
+pub async fn explain_cgraph_filtering(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    backend_input: dsl_inputs::BackendInput,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<
+    Vec<(
+        routing_types::RoutableConnectorChoice,
+        kgraph_explanation::EligibilityExplanation,
+    )>,
+> {
+    let context = euclid_graph::AnalysisContext::from_dir_values(
+        backend_input
+            .into_context()
+            .change_context(errors::RoutingError::KgraphAnalysisError)?,
+    );
+    let cached_cgraph = get_merchant_cgraph(state, key_store, profile_id, transaction_type).await?;
+
+    chosen
+        .into_iter()
+        .map(|choice| {
+            let euclid_choice: ast::ConnectorChoice = choice.clone().foreign_into();
+            let dir_val = euclid_choice
+                .into_dir_value()
+                .change_context(errors::RoutingError::KgraphAnalysisError)?;
+            let explanation = kgraph_explanation::explain_value_eligibility(
+                &cached_cgraph,
+                dir_val,
+                &context,
+                None,
+            )
+            .change_context(errors::RoutingError::KgraphAnalysisError)?;
+
+            Ok((choice, explanation))
+        })
+        .collect()
+}
+
 pub async fn perform_eligibility_analysis(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
@@ -1095,6 +1273,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1242,6 +1421,7 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1336,13 +1516,16 @@ async fn perform_session_routing_for_pm_type(
 
         match cached_algorithm.as_ref() {
             CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
-            CachedAlgorithm::Priority(plist) => plist.clone(),
+            CachedAlgorithm::Priority(plist) | CachedAlgorithm::CostBased(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    interpreter,
+                )?
+                .0
+            }
         }
     } else {
         routing::helpers::get_merchant_default_config(
@@ -1417,13 +1600,16 @@ async fn get_chosen_connectors<'a>(
 
         match cached_algorithm.as_ref() {
             CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
-            CachedAlgorithm::Priority(plist) => plist.clone(),
+            CachedAlgorithm::Priority(plist) | CachedAlgorithm::CostBased(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    interpreter,
+                )?
+                .0
+            }
         }
     } else {
         profile_wrapper
@@ -1547,6 +1733,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        payout: None,
     };
     Ok(backend_input)
 }
@@ -1686,6 +1873,7 @@ pub async fn perform_dynamic_routing_with_intelligent_router(
     profile: &domain::Profile,
     dynamic_routing_config_params_interpolator: routing::helpers::DynamicRoutingConfigParamsInterpolator,
     payment_attempt: &oss_storage::PaymentAttempt,
+    routing_trace: &mut api_routing::RoutingTrace,
 ) -> RoutingResult<Vec<api_routing::RoutableConnectorChoice>> {
     let dynamic_routing_algo_ref: api_routing::DynamicRoutingAlgorithmRef = profile
         .dynamic_routing_algorithm
@@ -1706,72 +1894,85 @@ pub async fn perform_dynamic_routing_with_intelligent_router(
         profile.get_id().get_string_repr()
     );
 
-    let mut connector_list = match dynamic_routing_algo_ref
-        .success_based_algorithm
-        .as_ref()
-        .async_map(|algorithm| {
-            perform_success_based_routing(
-                state,
-                routable_connectors.clone(),
-                profile.get_id(),
-                &payment_attempt.merchant_id,
-                &payment_attempt.payment_id,
-                dynamic_routing_config_params_interpolator.clone(),
-                algorithm.clone(),
-            )
-        })
+    let success_based_connectors = match dynamic_routing_algo_ref.success_based_algorithm.as_ref() {
+        Some(algorithm) => perform_success_based_routing(
+            state,
+            routable_connectors.clone(),
+            profile.get_id(),
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            dynamic_routing_config_params_interpolator.clone(),
+            algorithm.clone(),
+            routing_trace,
+        )
         .await
-        .transpose()
-        .inspect_err(|e| logger::error!(dynamic_routing_error=?e))
-        .ok()
-        .flatten()
-    {
+        .inspect_err(|e| {
+            logger::error!(dynamic_routing_error=?e);
+            routing_trace.add_fallback(
+                api_routing::RoutingTraceStage::SuccessBasedRouting,
+                e.current_context().to_string(),
+            );
+        })
+        .ok(),
+        None => None,
+    };
+
+    let mut connector_list = match success_based_connectors {
         Some(success_based_list) => success_based_list,
         None => {
             // Only run contract based if success based returns None
-            dynamic_routing_algo_ref
-                .contract_based_routing
-                .as_ref()
-                .async_map(|algorithm| {
-                    perform_contract_based_routing(
-                        state,
-                        routable_connectors.clone(),
-                        profile.get_id(),
-                        &payment_attempt.merchant_id,
-                        &payment_attempt.payment_id,
-                        dynamic_routing_config_params_interpolator.clone(),
-                        algorithm.clone(),
-                    )
-                })
+            match dynamic_routing_algo_ref.contract_based_routing.as_ref() {
+                Some(algorithm) => perform_contract_based_routing(
+                    state,
+                    routable_connectors.clone(),
+                    profile.get_id(),
+                    &payment_attempt.merchant_id,
+                    &payment_attempt.payment_id,
+                    dynamic_routing_config_params_interpolator.clone(),
+                    algorithm.clone(),
+                    routing_trace,
+                )
                 .await
-                .transpose()
-                .inspect_err(|e| logger::error!(dynamic_routing_error=?e))
-                .ok()
-                .flatten()
-                .unwrap_or(routable_connectors.clone())
+                .inspect_err(|e| {
+                    logger::error!(dynamic_routing_error=?e);
+                    routing_trace.add_fallback(
+                        api_routing::RoutingTraceStage::ContractBasedRouting,
+                        e.current_context().to_string(),
+                    );
+                })
+                .ok(),
+                None => None,
+            }
+            .unwrap_or(routable_connectors.clone())
         }
     };
 
-    connector_list = dynamic_routing_algo_ref
+    connector_list = match dynamic_routing_algo_ref
         .elimination_routing_algorithm
         .as_ref()
-        .async_map(|algorithm| {
-            perform_elimination_routing(
-                state,
-                connector_list.clone(),
-                profile.get_id(),
-                &payment_attempt.merchant_id,
-                &payment_attempt.payment_id,
-                dynamic_routing_config_params_interpolator.clone(),
-                algorithm.clone(),
-            )
-        })
+    {
+        Some(algorithm) => perform_elimination_routing(
+            state,
+            connector_list.clone(),
+            profile.get_id(),
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            dynamic_routing_config_params_interpolator.clone(),
+            algorithm.clone(),
+            routing_trace,
+        )
         .await
-        .transpose()
-        .inspect_err(|e| logger::error!(dynamic_routing_error=?e))
-        .ok()
-        .flatten()
-        .unwrap_or(connector_list);
+        .inspect_err(|e| {
+            logger::error!(dynamic_routing_error=?e);
+            routing_trace.add_fallback(
+                api_routing::RoutingTraceStage::EliminationRouting,
+                e.current_context().to_string(),
+            );
+        })
+        .ok(),
+        None => None,
+    }
+    .unwrap_or(connector_list);
 
     Ok(connector_list)
 }
@@ -2010,6 +2211,7 @@ pub async fn perform_success_based_routing(
     payment_id: &common_utils::id_type::PaymentId,
     success_based_routing_config_params_interpolator: routing::helpers::DynamicRoutingConfigParamsInterpolator,
     success_based_algo_ref: api_routing::SuccessBasedAlgorithm,
+    routing_trace: &mut api_routing::RoutingTrace,
 ) -> RoutingResult<Vec<api_routing::RoutableConnectorChoice>> {
     if success_based_algo_ref.enabled_feature
         == api_routing::DynamicRoutingFeatures::DynamicConnectorSelection
@@ -2018,14 +2220,6 @@ pub async fn perform_success_based_routing(
             "performing success_based_routing for profile {}",
             profile_id.get_string_repr()
         );
-        let client = state
-            .grpc_client
-            .dynamic_routing
-            .success_rate_client
-            .as_ref()
-            .ok_or(errors::RoutingError::SuccessRateClientInitializationError)
-            .attach_printable("success_rate gRPC client not found")?;
-
         let success_based_routing_configs = routing::helpers::fetch_dynamic_routing_configs::<
             api_routing::SuccessBasedRoutingConfig,
         >(
@@ -2051,6 +2245,38 @@ pub async fn perform_success_based_routing(
                     .ok_or(errors::RoutingError::SuccessBasedRoutingParamsNotFoundError)?,
             );
 
+        let client = match state
+            .grpc_client
+            .dynamic_routing
+            .success_rate_client
+            .as_ref()
+        {
+            Some(client) => client,
+            None if state.conf.success_rate_estimator.enabled => {
+                logger::debug!(
+                    "success_rate gRPC client not found, estimating success rates in-process"
+                );
+                routing_trace.add_fallback(
+                    api_routing::RoutingTraceStage::SuccessBasedRouting,
+                    "success_rate gRPC client not found, estimated success rates in-process"
+                        .to_string(),
+                );
+                return perform_success_based_routing_with_estimator(
+                    state,
+                    routable_connectors,
+                    profile_id,
+                    &success_based_routing_configs,
+                    success_based_routing_config_params,
+                    routing_trace,
+                )
+                .await;
+            }
+            None => {
+                return Err(errors::RoutingError::SuccessRateClientInitializationError)
+                    .attach_printable("success_rate gRPC client not found");
+            }
+        };
+
         let event_request = api_routing::CalSuccessRateEventRequest {
             id: profile_id.get_string_repr().to_string(),
             params: success_based_routing_config_params.clone(),
@@ -2086,24 +2312,51 @@ pub async fn perform_success_based_routing(
             RoutingEngine::IntelligentRouter,
         );
 
-        let success_based_connectors = client
+        let success_based_connectors = match client
             .calculate_success_rate(
                 profile_id.get_string_repr().into(),
-                success_based_routing_configs,
-                success_based_routing_config_params,
-                routable_connectors,
+                success_based_routing_configs.clone(),
+                success_based_routing_config_params.clone(),
+                routable_connectors.clone(),
                 state.get_grpc_headers(),
             )
             .await
-            .inspect_err(|e| {
+        {
+            Ok(success_based_connectors) => success_based_connectors,
+            Err(error) => {
                 routing_event
-                    .set_error(serde_json::json!({"error": e.current_context().to_string()}));
+                    .set_error(serde_json::json!({"error": error.current_context().to_string()}));
                 state.event_handler().log_event(&routing_event);
-            })
-            .change_context(errors::RoutingError::SuccessRateCalculationError)
-            .attach_printable(
-                "unable to calculate/fetch success rate from dynamic routing service",
-            )?;
+
+                if state.conf.success_rate_estimator.enabled {
+                    logger::error!(
+                        ?error,
+                        "unable to calculate/fetch success rate from dynamic routing service, estimating success rates in-process"
+                    );
+                    routing_trace.add_fallback(
+                        api_routing::RoutingTraceStage::SuccessBasedRouting,
+                        format!(
+                            "{}, estimated success rates in-process",
+                            error.current_context()
+                        ),
+                    );
+                    return perform_success_based_routing_with_estimator(
+                        state,
+                        routable_connectors,
+                        profile_id,
+                        &success_based_routing_configs,
+                        success_based_routing_config_params,
+                        routing_trace,
+                    )
+                    .await;
+                }
+                return Err(error)
+                    .change_context(errors::RoutingError::SuccessRateCalculationError)
+                    .attach_printable(
+                        "unable to calculate/fetch success rate from dynamic routing service",
+                    );
+            }
+        };
 
         let event_response = api_routing::CalSuccessRateEventResponse {
             labels_with_score: success_based_connectors
@@ -2167,12 +2420,70 @@ pub async fn perform_success_based_routing(
         routing_event.set_status_code(200);
         routing_event.set_routable_connectors(connectors.clone());
         state.event_handler().log_event(&routing_event);
+
+        routing_trace
+            .dynamic_routing
+            .push(api_routing::DynamicRoutingTraceStep {
+                stage: api_routing::RoutingTraceStage::SuccessBasedRouting,
+                routing_approach: Some(event_response.routing_approach),
+                scores: event_response
+                    .labels_with_score
+                    .into_iter()
+                    .map(|label_with_score| api_routing::RoutingTraceConnectorScore {
+                        label: label_with_score.label,
+                        score: label_with_score.score,
+                    })
+                    .collect(),
+                eliminated_connectors: Vec::new(),
+                connectors: connectors.clone(),
+            });
         Ok(connectors)
     } else {
         Ok(routable_connectors)
     }
 }
 
+/// success based dynamic routing with the in-process success rate estimator, used when the
+/// dynamic routing service is not deployed or cannot be reached
+#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+async fn perform_success_based_routing_with_estimator(
+    state: &SessionState,
+    routable_connectors: Vec<api_routing::RoutableConnectorChoice>,
+    profile_id: &common_utils::id_type::ProfileId,
+    success_based_routing_configs: &api_routing::SuccessBasedRoutingConfig,
+    success_based_routing_config_params: String,
+    routing_trace: &mut api_routing::RoutingTrace,
+) -> RoutingResult<Vec<api_routing::RoutableConnectorChoice>> {
+    let (connectors, routing_approach, scores) = success_rate_estimator::SuccessRateEstimator::new(
+        state,
+        profile_id,
+        success_based_routing_configs,
+        success_based_routing_config_params,
+    )
+    .rank_connectors(state, routable_connectors)
+    .await?;
+
+    logger::debug!(
+        success_based_routing_connectors=?connectors,
+        routing_approach=%routing_approach,
+        "estimated success rates in-process"
+    );
+
+    routing_trace
+        .dynamic_routing
+        .push(api_routing::DynamicRoutingTraceStep {
+            stage: api_routing::RoutingTraceStage::SuccessBasedRouting,
+            routing_approach: Some(routing_approach),
+            scores: scores
+                .into_iter()
+                .map(|(label, score)| api_routing::RoutingTraceConnectorScore { label, score })
+                .collect(),
+            eliminated_connectors: Vec::new(),
+            connectors: connectors.clone(),
+        });
+    Ok(connectors)
+}
+
 /// elimination dynamic routing
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 pub async fn perform_elimination_routing(
@@ -2183,6 +2494,7 @@ pub async fn perform_elimination_routing(
     payment_id: &common_utils::id_type::PaymentId,
     elimination_routing_configs_params_interpolator: routing::helpers::DynamicRoutingConfigParamsInterpolator,
     elimination_algo_ref: api_routing::EliminationRoutingAlgorithm,
+    routing_trace: &mut api_routing::RoutingTrace,
 ) -> RoutingResult<Vec<api_routing::RoutableConnectorChoice>> {
     if elimination_algo_ref.enabled_feature
         == api_routing::DynamicRoutingFeatures::DynamicConnectorSelection
@@ -2366,6 +2678,16 @@ pub async fn perform_elimination_routing(
         routing_event.set_status_code(200);
         routing_event.set_routable_connectors(connectors.clone());
         state.event_handler().log_event(&routing_event);
+
+        routing_trace
+            .dynamic_routing
+            .push(api_routing::DynamicRoutingTraceStep {
+                stage: api_routing::RoutingTraceStage::EliminationRouting,
+                routing_approach: Some(api_routing::RoutingApproach::Elimination),
+                scores: Vec::new(),
+                eliminated_connectors,
+                connectors: connectors.clone(),
+            });
         Ok(connectors)
     } else {
         Ok(routable_connectors)
@@ -2381,6 +2703,7 @@ pub async fn perform_contract_based_routing(
     payment_id: &common_utils::id_type::PaymentId,
     _dynamic_routing_config_params_interpolator: routing::helpers::DynamicRoutingConfigParamsInterpolator,
     contract_based_algo_ref: api_routing::ContractRoutingAlgorithm,
+    routing_trace: &mut api_routing::RoutingTrace,
 ) -> RoutingResult<Vec<api_routing::RoutableConnectorChoice>> {
     if contract_based_algo_ref.enabled_feature
         == api_routing::DynamicRoutingFeatures::DynamicConnectorSelection
@@ -2487,6 +2810,7 @@ pub async fn perform_contract_based_routing(
                 "unable to calculate/fetch contract score from dynamic routing service",
             );
 
+        let mut scores = Vec::new();
         let contract_based_connectors = match contract_based_connectors_result {
             Ok(resp) => {
                 let event_response = api_routing::CalContractScoreEventResponse {
@@ -2504,6 +2828,14 @@ pub async fn perform_contract_based_routing(
                 routing_event.set_response_body(&event_response);
                 routing_event
                     .set_routing_approach(api_routing::RoutingApproach::ContractBased.to_string());
+                scores = event_response
+                    .labels_with_score
+                    .into_iter()
+                    .map(|score_data| api_routing::RoutingTraceConnectorScore {
+                        label: score_data.label,
+                        score: score_data.score,
+                    })
+                    .collect();
                 resp
             }
             Err(err) => match err.current_context() {
@@ -2574,8 +2906,53 @@ pub async fn perform_contract_based_routing(
         routing_event.set_status_code(200);
         routing_event.set_routable_connectors(connectors.clone());
         state.event_handler().log_event(&routing_event);
+
+        routing_trace
+            .dynamic_routing
+            .push(api_routing::DynamicRoutingTraceStep {
+                stage: api_routing::RoutingTraceStage::ContractBasedRouting,
+                routing_approach: Some(api_routing::RoutingApproach::ContractBased),
+                scores,
+                eliminated_connectors: Vec::new(),
+                connectors: connectors.clone(),
+            });
         Ok(connectors)
     } else {
         Ok(routable_connectors)
     }
 }
+
+#[cfg(all(test, feature = "payouts"))]
+mod tests {
+    use api_models::payouts::{Bank, PayoutMethodData, SepaBankTransfer};
+
+    use super::*;
+
+    #[test]
+    fn test_get_payout_recipient_country() {
+        let sepa = |bank_country_code| {
+            PayoutMethodData::Bank(Bank::Sepa(SepaBankTransfer {
+                bank_country_code,
+                ..Default::default()
+            }))
+        };
+
+        // The country of the bank account takes precedence over the billing address
+        assert_eq!(
+            get_payout_recipient_country(
+                Some(&sepa(Some(api_enums::CountryAlpha2::DE))),
+                Some(api_enums::CountryAlpha2::FR),
+            ),
+            Some(api_enums::CountryAlpha2::DE)
+        );
+        assert_eq!(
+            get_payout_recipient_country(Some(&sepa(None)), Some(api_enums::CountryAlpha2::FR)),
+            Some(api_enums::CountryAlpha2::FR)
+        );
+        assert_eq!(
+            get_payout_recipient_country(None, Some(api_enums::CountryAlpha2::FR)),
+            Some(api_enums::CountryAlpha2::FR)
+        );
+        assert_eq!(get_payout_recipient_country(Some(&sepa(None)), None), None);
+    }
+}
@@ -0,0 +1,732 @@
+//! Circuit breakers of the merchant connector accounts, driven by the outcome of the calls made to
+//! the connectors. A circuit opens when too many of the calls made to a connector account within
+//! a window fail or are slow, and the connector account is then skipped during connector
+//! selection. Once the open duration has passed the circuit is half open: a limited number of
+//! payments are routed to the connector account again, the first failure opens the circuit again
+//! and enough successes close it.
+
+use std::collections::HashMap;
+
+use api_models::{admin::ConnectorCircuitState, routing::RoutableConnectorChoice};
+use common_utils::{date_time, ext_traits::ByteSliceExt, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::ConnectorCircuitBreakerConfig,
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+};
+
+pub const CIRCUIT_BREAKER_PREFIX: &str = "CIRCUIT_BREAKER";
+
+const TOTAL_CALLS_FIELD: &str = "total";
+const FAILED_CALLS_FIELD: &str = "failed";
+const SUCCEEDED_TRIALS_FIELD: &str = "succeeded";
+const ADMITTED_TRIALS_FIELD: &str = "admitted";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct OpenCircuit {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    opened_at: PrimitiveDateTime,
+}
+
+/// State of the circuit of a connector account, along with the calls counted in the current window
+#[derive(Debug, Clone)]
+pub struct CircuitStatus {
+    pub state: ConnectorCircuitState,
+    /// Time at which the circuit was opened, if it is open or half open
+    pub opened_at: Option<PrimitiveDateTime>,
+    pub calls_in_window: u64,
+    pub failed_calls_in_window: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+}
+
+impl CircuitBreaker {
+    pub fn new(merchant_connector_id: id_type::MerchantConnectorAccountId) -> Self {
+        Self {
+            merchant_connector_id,
+        }
+    }
+
+    fn get_state_key(&self) -> String {
+        format!(
+            "{}_{}",
+            CIRCUIT_BREAKER_PREFIX,
+            self.merchant_connector_id.get_string_repr()
+        )
+    }
+
+    fn get_window_key(&self, config: &ConnectorCircuitBreakerConfig, now: i64) -> String {
+        let window = now / i64::from(config.window_in_seconds.max(1));
+        format!("{}_WINDOW_{}", self.get_state_key(), window)
+    }
+
+    fn get_trials_key(&self) -> String {
+        format!("{}_TRIALS", self.get_state_key())
+    }
+
+    /// Reads the state of the circuit, along with the calls counted in the current window
+    pub async fn get_status(&self, state: &SessionState) -> RouterResult<CircuitStatus> {
+        Self::get_statuses(state, std::slice::from_ref(self))
+            .await?
+            .pop()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Missing the status of the connector circuit")
+    }
+
+    /// Reads the states of several circuits, along with the calls counted in their current
+    /// windows, in the order of the circuit breakers. The states are read with a single command
+    /// and the windows concurrently.
+    pub async fn get_statuses(
+        state: &SessionState,
+        circuit_breakers: &[Self],
+    ) -> RouterResult<Vec<CircuitStatus>> {
+        let config = &state.conf.connector_circuit_breaker;
+        let now = date_time::now();
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let redis_conn = &redis_conn;
+        let window_keys = circuit_breakers
+            .iter()
+            .map(|circuit_breaker| {
+                circuit_breaker.get_window_key(config, now.assume_utc().unix_timestamp())
+            })
+            .collect::<Vec<_>>();
+
+        let (opened_ats, windows) = futures::try_join!(
+            Self::get_opened_ats(state, circuit_breakers),
+            futures::future::try_join_all(window_keys.iter().map(|window_key| async move {
+                redis_conn
+                    .get_hash_fields::<HashMap<String, u64>>(&window_key.as_str().into())
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the calls counted by the connector circuit")
+            }))
+        )?;
+
+        Ok(opened_ats
+            .into_iter()
+            .zip(windows)
+            .map(|(opened_at, window)| CircuitStatus {
+                state: get_circuit_state(config, opened_at, now),
+                opened_at,
+                calls_in_window: window.get(TOTAL_CALLS_FIELD).copied().unwrap_or_default(),
+                failed_calls_in_window: window.get(FAILED_CALLS_FIELD).copied().unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Reads the time at which the circuit was opened, if it is open or half open
+    async fn get_opened_at(&self, state: &SessionState) -> RouterResult<Option<PrimitiveDateTime>> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        match redis_conn
+            .get_and_deserialize_key::<OpenCircuit>(
+                &self.get_state_key().as_str().into(),
+                "OpenCircuit",
+            )
+            .await
+        {
+            Ok(open_circuit) => Ok(Some(open_circuit.opened_at)),
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    redis_interface::errors::RedisError::NotFound
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the state of the connector circuit"),
+        }
+    }
+
+    /// Reads the times at which several circuits were opened with a single command, in the order
+    /// of the circuit breakers
+    async fn get_opened_ats(
+        state: &SessionState,
+        circuit_breakers: &[Self],
+    ) -> RouterResult<Vec<Option<PrimitiveDateTime>>> {
+        if circuit_breakers.is_empty() {
+            return Ok(vec![]);
+        }
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let open_circuits = redis_conn
+            .get_multiple_keys::<Vec<u8>>(
+                &circuit_breakers
+                    .iter()
+                    .map(|circuit_breaker| circuit_breaker.get_state_key().as_str().into())
+                    .collect::<Vec<_>>(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the states of the connector circuits")?;
+
+        open_circuits
+            .into_iter()
+            .map(|open_circuit| -> RouterResult<Option<PrimitiveDateTime>> {
+                Ok(open_circuit
+                    .filter(|value| !value.is_empty())
+                    .map(|value| value.parse_struct::<OpenCircuit>("OpenCircuit"))
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to deserialize the state of the connector circuit")?
+                    .map(|open_circuit| open_circuit.opened_at))
+            })
+            .collect()
+    }
+
+    /// Reads the states of several circuits with a single command, in the order of the circuit
+    /// breakers
+    async fn get_states(
+        state: &SessionState,
+        circuit_breakers: &[Self],
+    ) -> RouterResult<Vec<ConnectorCircuitState>> {
+        let config = &state.conf.connector_circuit_breaker;
+        let now = date_time::now();
+
+        Ok(Self::get_opened_ats(state, circuit_breakers)
+            .await?
+            .into_iter()
+            .map(|opened_at| get_circuit_state(config, opened_at, now))
+            .collect())
+    }
+
+    /// Admits a payment to the half open circuit, as long as the number of payments admitted
+    /// since it turned half open is below the limit. The admissions expire after the open
+    /// duration, so that payments which were admitted but never reached the connector do not keep
+    /// the circuit half open forever.
+    async fn admit_trial(&self, state: &SessionState) -> RouterResult<bool> {
+        let config = &state.conf.connector_circuit_breaker;
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let admitted_trials = redis_conn
+            .increment_fields_in_hash_with_expiry(
+                &self.get_trials_key().as_str().into(),
+                &[(ADMITTED_TRIALS_FIELD, 1)],
+                i64::from(config.open_duration_in_seconds),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to admit the trial call of the connector circuit")?;
+
+        Ok(is_trial_admitted(
+            config,
+            admitted_trials.first().copied().unwrap_or_default(),
+        ))
+    }
+
+    /// Counts a call made to the connector account and moves the circuit to the state the call
+    /// leads to. Calls made while the circuit is open, such as those of payments which were
+    /// routed before it opened, are not counted.
+    #[instrument(skip_all)]
+    pub async fn record_call(&self, state: &SessionState, failed: bool) -> RouterResult<()> {
+        let config = &state.conf.connector_circuit_breaker;
+        let now = date_time::now();
+        let circuit_state = get_circuit_state(config, self.get_opened_at(state).await?, now);
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        match circuit_state {
+            ConnectorCircuitState::Open => Ok(()),
+            ConnectorCircuitState::HalfOpen if failed => self.open(state).await,
+            ConnectorCircuitState::HalfOpen => {
+                let succeeded_trials = redis_conn
+                    .increment_fields_in_hash_with_expiry(
+                        &self.get_trials_key().as_str().into(),
+                        &[(SUCCEEDED_TRIALS_FIELD, 1)],
+                        i64::from(config.open_duration_in_seconds),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to count the trial call of the connector circuit")?;
+
+                if should_close_circuit(
+                    config,
+                    succeeded_trials.first().copied().unwrap_or_default(),
+                ) {
+                    self.close(state).await?;
+                }
+                Ok(())
+            }
+            ConnectorCircuitState::Closed => {
+                let window_key = self.get_window_key(config, now.assume_utc().unix_timestamp());
+                let counts = redis_conn
+                    .increment_fields_in_hash_with_expiry(
+                        &window_key.as_str().into(),
+                        &[
+                            (TOTAL_CALLS_FIELD, 1),
+                            (FAILED_CALLS_FIELD, i64::from(failed)),
+                        ],
+                        i64::from(config.window_in_seconds),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to count the call in the connector circuit window")?;
+
+                if let (Some(total_calls), Some(failed_calls)) =
+                    (counts.first().copied(), counts.get(1).copied())
+                {
+                    if should_open_circuit(config, total_calls, failed_calls) {
+                        self.open(state).await?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn open(&self, state: &SessionState) -> RouterResult<()> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let state_key = self.get_state_key();
+        let trials_key = self.get_trials_key();
+        futures::try_join!(
+            async {
+                redis_conn
+                    .serialize_and_set_key(
+                        &state_key.as_str().into(),
+                        OpenCircuit {
+                            opened_at: date_time::now(),
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to open the connector circuit")
+            },
+            async {
+                redis_conn
+                    .delete_key(&trials_key.as_str().into())
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to clear the trial calls of the connector circuit")
+            }
+        )?;
+
+        logger::warn!(
+            merchant_connector_id = self.merchant_connector_id.get_string_repr(),
+            "Opened the connector circuit"
+        );
+        metrics::CONNECTOR_CIRCUIT_OPENED.add(
+            1,
+            router_env::metric_attributes!((
+                "merchant_connector_id",
+                self.merchant_connector_id.get_string_repr().to_owned()
+            )),
+        );
+        Ok(())
+    }
+
+    /// Closes the circuit, discarding the calls counted so far
+    pub async fn close(&self, state: &SessionState) -> RouterResult<()> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let window_key = self.get_window_key(
+            &state.conf.connector_circuit_breaker,
+            date_time::now_unix_timestamp(),
+        );
+
+        redis_conn
+            .delete_multiple_keys(&[
+                self.get_state_key().as_str().into(),
+                self.get_trials_key().as_str().into(),
+                window_key.as_str().into(),
+            ])
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to close the connector circuit")?;
+
+        logger::info!(
+            merchant_connector_id = self.merchant_connector_id.get_string_repr(),
+            "Closed the connector circuit"
+        );
+        metrics::CONNECTOR_CIRCUIT_CLOSED.add(
+            1,
+            router_env::metric_attributes!((
+                "merchant_connector_id",
+                self.merchant_connector_id.get_string_repr().to_owned()
+            )),
+        );
+        Ok(())
+    }
+}
+
+/// Outcome of a call made to a connector account, as far as its circuit is concerned
+#[derive(Clone, Copy, Debug)]
+pub enum ConnectorCallOutcome {
+    /// The connector responded, or the call timed out, with this status code
+    Responded {
+        status_code: u16,
+        latency_in_milliseconds: Option<u128>,
+    },
+    /// The request could not be sent to the connector, or the connection was lost before the
+    /// connector responded
+    ConnectionFailed,
+}
+
+/// Whether the error of a connector call means that the connector could not be reached, as
+/// opposed to the request not being built
+pub fn is_connection_error(error: &errors::ApiClientError) -> bool {
+    matches!(
+        error,
+        errors::ApiClientError::RequestNotSent(_)
+            | errors::ApiClientError::ConnectionClosedIncompleteMessage
+            | errors::ApiClientError::RequestTimeoutReceived
+    )
+}
+
+/// A call is counted as failed when the connector could not be reached, responded with a server
+/// error, or took longer than the slow call threshold. Declines are not failures of the
+/// connector.
+pub fn is_failed_call(
+    config: &ConnectorCircuitBreakerConfig,
+    outcome: ConnectorCallOutcome,
+) -> bool {
+    match outcome {
+        ConnectorCallOutcome::Responded {
+            status_code,
+            latency_in_milliseconds,
+        } => {
+            status_code >= 500
+                || latency_in_milliseconds.is_some_and(|latency| {
+                    latency > u128::from(config.slow_call_threshold_in_milliseconds)
+                })
+        }
+        ConnectorCallOutcome::ConnectionFailed => true,
+    }
+}
+
+/// A circuit without an open marker is closed, and an open circuit turns half open once the open
+/// duration has passed
+fn get_circuit_state(
+    config: &ConnectorCircuitBreakerConfig,
+    opened_at: Option<PrimitiveDateTime>,
+    now: PrimitiveDateTime,
+) -> ConnectorCircuitState {
+    match opened_at {
+        None => ConnectorCircuitState::Closed,
+        Some(opened_at)
+            if now - opened_at
+                < time::Duration::seconds(i64::from(config.open_duration_in_seconds)) =>
+        {
+            ConnectorCircuitState::Open
+        }
+        Some(_) => ConnectorCircuitState::HalfOpen,
+    }
+}
+
+/// A closed circuit opens once its window has enough calls and enough of them failed
+fn should_open_circuit(
+    config: &ConnectorCircuitBreakerConfig,
+    total_calls: usize,
+    failed_calls: usize,
+) -> bool {
+    total_calls >= usize::try_from(config.minimum_calls).unwrap_or(usize::MAX)
+        && failed_calls.saturating_mul(100)
+            >= usize::from(config.failure_rate_threshold).saturating_mul(total_calls)
+}
+
+/// A half open circuit closes once enough of its trial calls succeeded
+fn should_close_circuit(config: &ConnectorCircuitBreakerConfig, succeeded_trials: usize) -> bool {
+    succeeded_trials >= usize::try_from(config.half_open_success_threshold).unwrap_or(usize::MAX)
+}
+
+/// A half open circuit admits payments until the limit of trial payments is reached
+fn is_trial_admitted(config: &ConnectorCircuitBreakerConfig, admitted_trials: usize) -> bool {
+    admitted_trials <= usize::try_from(config.half_open_max_probe_calls).unwrap_or(usize::MAX)
+}
+
+/// Counts a call made to the connector account in its circuit. Failing to do so is only logged,
+/// the outcome of the payment does not depend on the circuit breaker.
+pub async fn record_connector_call(
+    state: &SessionState,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+    outcome: ConnectorCallOutcome,
+) {
+    let config = &state.conf.connector_circuit_breaker;
+    let Some(merchant_connector_id) = merchant_connector_id.filter(|_| config.enabled) else {
+        return;
+    };
+
+    let failed = is_failed_call(config, outcome);
+    if let Err(error) = CircuitBreaker::new(merchant_connector_id.clone())
+        .record_call(state, failed)
+        .await
+    {
+        logger::error!(?error, "Failed to record the call in the connector circuit");
+    }
+}
+
+/// Removes the connectors whose circuit is open from the connectors eligible for the payment.
+/// Connectors whose circuit is half open are kept, a trial payment is only admitted to the one
+/// which ends up selected (see [`admit_selected_connector`]). Circuits whose state cannot be read
+/// are treated as closed, and if all the connectors are skipped they are all kept, since skipping
+/// every connector would fail the payment outright.
+#[instrument(skip_all)]
+pub async fn filter_open_circuits(
+    state: &SessionState,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    if !state.conf.connector_circuit_breaker.enabled {
+        return connectors;
+    }
+
+    let circuit_states = match get_connector_circuit_states(state, &connectors).await {
+        Ok(circuit_states) => circuit_states,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the states of the connector circuits"
+            );
+            return connectors;
+        }
+    };
+
+    let (skipped, closed_connectors): (Vec<_>, Vec<_>) = connectors
+        .iter()
+        .cloned()
+        .zip(circuit_states)
+        .partition(|(_, circuit_state)| *circuit_state == ConnectorCircuitState::Open);
+
+    for (connector, _) in skipped.iter() {
+        logger::info!(
+            connector = %connector.connector,
+            "Skipping connector whose circuit is open"
+        );
+    }
+
+    if closed_connectors.is_empty() && !connectors.is_empty() {
+        logger::warn!("Circuits of all the eligible connectors are open, not skipping any");
+        return connectors;
+    }
+
+    closed_connectors
+        .into_iter()
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+/// Admits a trial payment to the circuit of the selected connector, the first of the routed
+/// connectors, if it is half open. When the circuit has already admitted as many trial payments
+/// as allowed the connector is skipped and the next one is tried, unless it is the last one left.
+/// Failing to read or admit is only logged, and the connectors are then kept as they are.
+#[instrument(skip_all)]
+pub async fn admit_selected_connector(
+    state: &SessionState,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    if !state.conf.connector_circuit_breaker.enabled {
+        return connectors;
+    }
+
+    let circuit_states = match get_connector_circuit_states(state, &connectors).await {
+        Ok(circuit_states) => circuit_states,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the states of the connector circuits"
+            );
+            return connectors;
+        }
+    };
+
+    let mut remaining_connectors = connectors.len();
+    let mut connectors = connectors.into_iter().zip(circuit_states).peekable();
+    while let Some((connector, circuit_state)) = connectors.peek() {
+        let is_admitted = match (circuit_state, connector.merchant_connector_id.as_ref()) {
+            (ConnectorCircuitState::HalfOpen, Some(merchant_connector_id)) => {
+                CircuitBreaker::new(merchant_connector_id.clone())
+                    .admit_trial(state)
+                    .await
+                    .unwrap_or_else(|error| {
+                        logger::error!(
+                            ?error,
+                            "Failed to admit the trial call of the connector circuit"
+                        );
+                        true
+                    })
+            }
+            _ => true,
+        };
+        if is_admitted || remaining_connectors <= 1 {
+            break;
+        }
+
+        logger::info!(
+            connector = %connector.connector,
+            "Skipping connector whose half open circuit admitted all its trial payments"
+        );
+        connectors.next();
+        remaining_connectors = remaining_connectors.saturating_sub(1);
+    }
+
+    connectors.map(|(connector, _)| connector).collect()
+}
+
+/// Reads the states of the circuits of the connectors, in the order of the connectors. Connectors
+/// without a connector account are treated as closed.
+async fn get_connector_circuit_states(
+    state: &SessionState,
+    connectors: &[RoutableConnectorChoice],
+) -> RouterResult<Vec<ConnectorCircuitState>> {
+    let circuit_breakers = connectors
+        .iter()
+        .filter_map(|connector| connector.merchant_connector_id.clone())
+        .map(CircuitBreaker::new)
+        .collect::<Vec<_>>();
+    let mut circuit_states = CircuitBreaker::get_states(state, &circuit_breakers)
+        .await?
+        .into_iter();
+
+    Ok(connectors
+        .iter()
+        .map(|connector| {
+            connector
+                .merchant_connector_id
+                .as_ref()
+                .and_then(|_| circuit_states.next())
+                .unwrap_or(ConnectorCircuitState::Closed)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_config() -> ConnectorCircuitBreakerConfig {
+        ConnectorCircuitBreakerConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_failed_call() {
+        let config = get_config();
+        let responded = |status_code, latency_in_milliseconds| ConnectorCallOutcome::Responded {
+            status_code,
+            latency_in_milliseconds,
+        };
+
+        assert!(!is_failed_call(&config, responded(200, Some(100))));
+        // Declines are not failures of the connector
+        assert!(!is_failed_call(&config, responded(402, Some(100))));
+        assert!(!is_failed_call(&config, responded(200, None)));
+        assert!(is_failed_call(&config, responded(500, Some(100))));
+        assert!(is_failed_call(&config, responded(504, None)));
+        assert!(!is_failed_call(&config, responded(200, Some(10000))));
+        assert!(is_failed_call(&config, responded(200, Some(10001))));
+        assert!(is_failed_call(
+            &config,
+            ConnectorCallOutcome::ConnectionFailed
+        ));
+    }
+
+    #[test]
+    fn test_is_connection_error() {
+        assert!(is_connection_error(
+            &errors::ApiClientError::RequestNotSent("connection refused".to_string())
+        ));
+        assert!(is_connection_error(
+            &errors::ApiClientError::ConnectionClosedIncompleteMessage
+        ));
+        assert!(is_connection_error(
+            &errors::ApiClientError::RequestTimeoutReceived
+        ));
+        assert!(!is_connection_error(
+            &errors::ApiClientError::UrlParsingFailed
+        ));
+        assert!(!is_connection_error(
+            &errors::ApiClientError::BodySerializationFailed
+        ));
+    }
+
+    #[test]
+    fn test_circuit_state_over_time() {
+        let config = get_config();
+        let opened_at = date_time::now();
+
+        assert_eq!(
+            get_circuit_state(&config, None, opened_at),
+            ConnectorCircuitState::Closed
+        );
+        assert_eq!(
+            get_circuit_state(&config, Some(opened_at), opened_at),
+            ConnectorCircuitState::Open
+        );
+        assert_eq!(
+            get_circuit_state(
+                &config,
+                Some(opened_at),
+                opened_at + time::Duration::seconds(29)
+            ),
+            ConnectorCircuitState::Open
+        );
+        assert_eq!(
+            get_circuit_state(
+                &config,
+                Some(opened_at),
+                opened_at + time::Duration::seconds(30)
+            ),
+            ConnectorCircuitState::HalfOpen
+        );
+    }
+
+    #[test]
+    fn test_closed_circuit_opens_on_failure_rate() {
+        let config = get_config();
+
+        // Too few calls in the window, however many of them failed
+        assert!(!should_open_circuit(&config, 19, 19));
+        assert!(!should_open_circuit(&config, 20, 9));
+        assert!(should_open_circuit(&config, 20, 10));
+        assert!(should_open_circuit(&config, 40, 40));
+        assert!(!should_open_circuit(&config, 40, 19));
+    }
+
+    #[test]
+    fn test_half_open_circuit_closes_on_successful_trials() {
+        let config = get_config();
+
+        assert!(!should_close_circuit(&config, 0));
+        assert!(!should_close_circuit(&config, 4));
+        assert!(should_close_circuit(&config, 5));
+    }
+
+    #[test]
+    fn test_half_open_circuit_admits_limited_trials() {
+        let config = get_config();
+
+        assert!(is_trial_admitted(&config, 1));
+        assert!(is_trial_admitted(&config, 10));
+        assert!(!is_trial_admitted(&config, 11));
+    }
+}
@@ -0,0 +1,148 @@
+//! Cost based routing, which ranks the connectors by the expected cost of the payment. The fee
+//! charged by a connector is estimated from the fee schedule of its merchant connector account.
+//! Connectors whose cost cannot be estimated follow the others, and the order of the routing
+//! algorithm breaks ties, so that the algorithm falls back to its priority order.
+
+#[cfg(feature = "v1")]
+use std::str::FromStr;
+
+use api_models::routing::RoutableConnectorChoice;
+#[cfg(feature = "v1")]
+use common_types::connector_fees::{ConnectorFeeSchedule, FeeEstimationInput};
+#[cfg(feature = "v1")]
+use common_utils::types::MinorUnit;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payment_address::PaymentAddress;
+#[cfg(feature = "v1")]
+use router_env::{instrument, logger, tracing};
+
+#[cfg(feature = "v1")]
+use crate::types::storage;
+use crate::{core::routing, routes::SessionState, types::domain};
+
+/// The details of the payment the fees of the connectors depend on
+#[cfg(feature = "v1")]
+pub fn get_fee_estimation_input(
+    payment_attempt: &storage::PaymentAttempt,
+    currency: common_enums::Currency,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+    address: &PaymentAddress,
+) -> FeeEstimationInput {
+    let card = match payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => Some(card),
+        _ => None,
+    };
+    let card_type = card
+        .and_then(|card| card.card_type.as_deref())
+        .and_then(|card_type| common_enums::CardType::from_str(&card_type.to_uppercase()).ok())
+        .or(match payment_attempt.payment_method_type {
+            Some(common_enums::PaymentMethodType::Credit) => Some(common_enums::CardType::Credit),
+            Some(common_enums::PaymentMethodType::Debit) => Some(common_enums::CardType::Debit),
+            _ => None,
+        });
+
+    FeeEstimationInput {
+        amount: payment_attempt.get_total_amount(),
+        currency,
+        card_network: card.and_then(|card| card.card_network.clone()),
+        card_type,
+        billing_country: address
+            .get_payment_method_billing()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country),
+    }
+}
+
+/// Estimates the fee charged by the connector for the payment. The cost is unknown when the
+/// connector has no fee schedule, or none of its fees applies to the payment.
+#[cfg(feature = "v1")]
+pub fn estimate_connector_cost(
+    fee_schedule: Option<&ConnectorFeeSchedule>,
+    input: &FeeEstimationInput,
+) -> Option<MinorUnit> {
+    fee_schedule?
+        .estimate_cost(input)
+        .map_err(|error| logger::error!(?error, "Failed to estimate the cost of the connector"))
+        .ok()
+        .flatten()
+}
+
+/// Orders the connectors by the fee they are expected to charge for the payment. The merchant
+/// connector accounts of the merchant are fetched once for all the connectors, failing to do so
+/// is only logged and the costs are then unknown.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn rank_connectors_by_cost(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    let input = get_fee_estimation_input(
+        payments_dsl_input.payment_attempt,
+        payments_dsl_input.currency,
+        payments_dsl_input.payment_method_data,
+        payments_dsl_input.address,
+    );
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            &key_store.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the merchant connector accounts to estimate their cost"
+            )
+        })
+        .ok();
+
+    let mut connectors_with_cost = connectors
+        .into_iter()
+        .map(|connector| {
+            let cost = connector
+                .merchant_connector_id
+                .as_ref()
+                .zip(merchant_connector_accounts.as_ref())
+                .and_then(|(merchant_connector_id, merchant_connector_accounts)| {
+                    merchant_connector_accounts
+                        .iter()
+                        .find(|mca| mca.merchant_connector_id == *merchant_connector_id)
+                })
+                .and_then(|mca| estimate_connector_cost(mca.fee_schedule.as_ref(), &input));
+            (connector, cost)
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, connectors of the same cost keep the order of the routing algorithm
+    connectors_with_cost
+        .sort_by_key(|(_, cost)| cost.map_or((1, 0), |cost| (0, cost.get_amount_as_i64())));
+    logger::debug!(
+        estimated_connector_costs = ?connectors_with_cost
+            .iter()
+            .map(|(connector, cost)| (connector.to_string(), *cost))
+            .collect::<Vec<_>>()
+    );
+
+    connectors_with_cost
+        .into_iter()
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+/// Fee schedules are only supported for the merchant connector accounts of v1, the connectors
+/// keep the order of the routing algorithm
+#[cfg(feature = "v2")]
+pub async fn rank_connectors_by_cost(
+    _state: &SessionState,
+    _key_store: &domain::MerchantKeyStore,
+    _payments_dsl_input: &routing::PaymentsDslInput<'_>,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    connectors
+}
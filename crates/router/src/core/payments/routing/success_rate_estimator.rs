@@ -0,0 +1,333 @@
+//! Success rate estimator used for success based routing when the dynamic routing service is not
+//! deployed or cannot be reached. The outcomes of payments are counted in Redis in blocks of
+//! `current_block_threshold.duration_in_mins`, for the same params and connector labels that the
+//! dynamic routing service is given. The success rate of a connector is computed over the last
+//! `max_aggregates_size` blocks, and is `default_success_rate` until `min_aggregates_size` of
+//! these blocks have outcomes counted in them. Blocks bounded by
+//! `current_block_threshold.max_total_count` are not supported, blocks always span a duration.
+
+use std::collections::HashMap;
+
+use api_models::routing::{
+    RoutableConnectorChoice, RoutingApproach, SuccessBasedRoutingConfig,
+    SuccessBasedRoutingConfigBody, SuccessRateSpecificityLevel,
+};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use rand::{seq::SliceRandom, Rng};
+use router_env::{instrument, logger, tracing};
+
+use super::RoutingResult;
+use crate::{core::errors, routes::SessionState};
+
+pub const SUCCESS_RATE_ESTIMATOR_PREFIX: &str = "SUCCESS_RATE_ESTIMATOR";
+
+const TOTAL_FIELD: &str = "total";
+const SUCCESS_FIELD: &str = "success";
+
+/// Reads a value of the success based routing config, falling back to the default config when it
+/// is not set
+fn get_config_value<T>(
+    config: &SuccessBasedRoutingConfig,
+    get_value: impl Fn(&SuccessBasedRoutingConfigBody) -> Option<T>,
+) -> Option<T> {
+    config.config.as_ref().and_then(&get_value).or_else(|| {
+        SuccessBasedRoutingConfig::default()
+            .config
+            .as_ref()
+            .and_then(&get_value)
+    })
+}
+
+#[derive(Clone, Debug)]
+pub struct SuccessRateEstimator {
+    /// The profile the outcomes are counted for, or `global` when they are shared by all profiles
+    scope: String,
+    params: String,
+    block_duration_in_seconds: i64,
+    max_aggregates_size: u32,
+    min_aggregates_size: u32,
+    default_success_rate: f64,
+    exploration_percent: f64,
+}
+
+impl SuccessRateEstimator {
+    pub fn new(
+        state: &SessionState,
+        profile_id: &id_type::ProfileId,
+        config: &SuccessBasedRoutingConfig,
+        params: String,
+    ) -> Self {
+        let scope = match config
+            .config
+            .as_ref()
+            .map(|config| config.specificity_level)
+            .unwrap_or_default()
+        {
+            SuccessRateSpecificityLevel::Merchant => profile_id.get_string_repr().to_owned(),
+            SuccessRateSpecificityLevel::Global => "global".to_owned(),
+        };
+        let block_duration_in_mins = get_config_value(config, |config| {
+            config
+                .current_block_threshold
+                .as_ref()
+                .and_then(|threshold| threshold.duration_in_mins)
+        })
+        .filter(|duration| *duration > 0)
+        .unwrap_or(
+            state
+                .conf
+                .success_rate_estimator
+                .default_block_duration_in_mins,
+        );
+
+        Self {
+            scope,
+            params,
+            block_duration_in_seconds: i64::try_from(block_duration_in_mins)
+                .unwrap_or(i64::MAX)
+                .saturating_mul(60)
+                .max(1),
+            max_aggregates_size: get_config_value(config, |config| config.max_aggregates_size)
+                .unwrap_or_default()
+                .max(1),
+            min_aggregates_size: get_config_value(config, |config| config.min_aggregates_size)
+                .unwrap_or_default(),
+            default_success_rate: get_config_value(config, |config| config.default_success_rate)
+                .unwrap_or(100.0),
+            exploration_percent: get_config_value(config, |config| config.exploration_percent)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn get_current_block(&self) -> i64 {
+        common_utils::date_time::now_unix_timestamp() / self.block_duration_in_seconds
+    }
+
+    fn get_redis_key(&self, label: &str, block: i64) -> String {
+        format!(
+            "{}_{}_{}_{}_{}",
+            SUCCESS_RATE_ESTIMATOR_PREFIX, self.scope, self.params, label, block
+        )
+    }
+
+    /// Deletes the outcomes counted for the profile, so that the success rates are estimated
+    /// afresh once its success based routing config changes. The outcomes counted globally are
+    /// shared with the other profiles and are left as is.
+    #[instrument(skip_all)]
+    pub async fn clear_outcomes(
+        state: &SessionState,
+        profile_id: &id_type::ProfileId,
+    ) -> RoutingResult<usize> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::RoutingError::SuccessRateEstimationError)?;
+
+        redis_conn
+            .delete_keys_matching_pattern(
+                &format!(
+                    "{}_{}_*",
+                    SUCCESS_RATE_ESTIMATOR_PREFIX,
+                    profile_id.get_string_repr()
+                )
+                .as_str()
+                .into(),
+            )
+            .await
+            .change_context(errors::RoutingError::SuccessRateEstimationError)
+            .attach_printable("Failed to clear the outcomes counted for the success rate")
+    }
+
+    /// Counts the outcome of a payment routed to the connector in the current block
+    #[instrument(skip_all)]
+    pub async fn record_outcome(
+        &self,
+        state: &SessionState,
+        connector: &RoutableConnectorChoice,
+        success: bool,
+    ) -> RoutingResult<()> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::RoutingError::SuccessRateEstimationError)?;
+        let key = self.get_redis_key(&connector.to_string(), self.get_current_block());
+
+        // A block is read for as long as it is part of the window
+        redis_conn
+            .increment_fields_in_hash_with_expiry(
+                &key.as_str().into(),
+                &[(TOTAL_FIELD, 1), (SUCCESS_FIELD, i64::from(success))],
+                self.block_duration_in_seconds
+                    .saturating_mul(i64::from(self.max_aggregates_size)),
+            )
+            .await
+            .change_context(errors::RoutingError::SuccessRateEstimationError)
+            .attach_printable("Failed to count the payment outcome for the success rate")?;
+
+        Ok(())
+    }
+
+    /// Fetches the blocks in the window of each of the labels in a single pipeline, and computes
+    /// the success rates in the order of the labels
+    async fn get_success_rates(
+        &self,
+        state: &SessionState,
+        labels: &[String],
+    ) -> RoutingResult<Vec<f64>> {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::RoutingError::SuccessRateEstimationError)?;
+        let current_block = self.get_current_block();
+        let blocks = (current_block - i64::from(self.max_aggregates_size) + 1)..=current_block;
+
+        let keys = labels
+            .iter()
+            .flat_map(|label| {
+                blocks
+                    .clone()
+                    .map(|block| self.get_redis_key(label, block).as_str().into())
+            })
+            .collect::<Vec<_>>();
+        let counts = redis_conn
+            .get_hash_fields_of_multiple_keys::<HashMap<String, u32>>(&keys)
+            .await
+            .change_context(errors::RoutingError::SuccessRateEstimationError)
+            .attach_printable("Failed to fetch the success rate blocks")?;
+
+        Ok(counts
+            .chunks(usize::try_from(self.max_aggregates_size).unwrap_or(usize::MAX))
+            .map(|blocks| self.compute_success_rate(blocks))
+            .collect())
+    }
+
+    /// Computes the success rate over the counts of the blocks in the window. Blocks without
+    /// outcomes do not count towards `min_aggregates_size`.
+    fn compute_success_rate(&self, blocks: &[HashMap<String, u32>]) -> f64 {
+        let mut aggregates = 0;
+        let mut total = 0u32;
+        let mut successes = 0u32;
+        for counts in blocks {
+            let block_total = counts.get(TOTAL_FIELD).copied().unwrap_or_default();
+
+            if block_total > 0 {
+                aggregates += 1;
+                total = total.saturating_add(block_total);
+                successes = successes
+                    .saturating_add(counts.get(SUCCESS_FIELD).copied().unwrap_or_default());
+            }
+        }
+
+        if aggregates < self.min_aggregates_size || total == 0 {
+            return self.default_success_rate;
+        }
+        f64::from(successes) * 100.0 / f64::from(total)
+    }
+
+    /// Orders the connectors by their success rate. For `exploration_percent` of the payments the
+    /// connectors are shuffled instead, so that the success rates of the connectors which are not
+    /// preferred keep being refreshed. The estimated success rates are returned along with the
+    /// order, and are empty when the connectors were shuffled.
+    #[instrument(skip_all)]
+    pub async fn rank_connectors(
+        &self,
+        state: &SessionState,
+        mut connectors: Vec<RoutableConnectorChoice>,
+    ) -> RoutingResult<(
+        Vec<RoutableConnectorChoice>,
+        RoutingApproach,
+        Vec<(String, f64)>,
+    )> {
+        if rand::thread_rng().gen_range(0.0..100.0) < self.exploration_percent {
+            connectors.shuffle(&mut rand::thread_rng());
+            return Ok((connectors, RoutingApproach::Exploration, Vec::new()));
+        }
+
+        let labels = connectors
+            .iter()
+            .map(|connector| connector.to_string())
+            .collect::<Vec<_>>();
+        let scores = self.get_success_rates(state, &labels).await?;
+        let mut connectors_with_score = connectors.into_iter().zip(scores).collect::<Vec<_>>();
+        connectors_with_score.sort_by(|(_, score), (_, other_score)| other_score.total_cmp(score));
+        let estimated_success_rates = connectors_with_score
+            .iter()
+            .map(|(connector, score)| (connector.to_string(), *score))
+            .collect::<Vec<_>>();
+        logger::debug!(?estimated_success_rates);
+
+        Ok((
+            connectors_with_score
+                .into_iter()
+                .map(|(connector, _)| connector)
+                .collect(),
+            RoutingApproach::Exploitation,
+            estimated_success_rates,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_estimator() -> SuccessRateEstimator {
+        SuccessRateEstimator {
+            scope: "pro_123".to_owned(),
+            params: "card:credit".to_owned(),
+            block_duration_in_seconds: 300,
+            max_aggregates_size: 3,
+            min_aggregates_size: 2,
+            default_success_rate: 100.0,
+            exploration_percent: 0.0,
+        }
+    }
+
+    fn get_block(total: u32, success: u32) -> HashMap<String, u32> {
+        HashMap::from([
+            (TOTAL_FIELD.to_owned(), total),
+            (SUCCESS_FIELD.to_owned(), success),
+        ])
+    }
+
+    #[test]
+    fn test_get_redis_key() {
+        assert_eq!(
+            get_estimator().get_redis_key("stripe:mca_123", 42),
+            "SUCCESS_RATE_ESTIMATOR_pro_123_card:credit_stripe:mca_123_42"
+        );
+    }
+
+    #[test]
+    fn test_success_rate_over_the_window() {
+        let estimator = get_estimator();
+
+        assert_eq!(
+            estimator
+                .compute_success_rate(&[get_block(10, 9), get_block(0, 0), get_block(30, 21),]),
+            75.0
+        );
+        assert_eq!(
+            estimator
+                .compute_success_rate(&[get_block(10, 0), get_block(10, 0), get_block(10, 0),]),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_default_success_rate_below_min_aggregates() {
+        let estimator = get_estimator();
+
+        // Missing and empty blocks do not count towards the minimum number of blocks
+        assert_eq!(
+            estimator.compute_success_rate(&[HashMap::new(), HashMap::new(), HashMap::new()]),
+            100.0
+        );
+        assert_eq!(
+            estimator.compute_success_rate(&[HashMap::new(), get_block(0, 0), get_block(10, 1)]),
+            100.0
+        );
+        assert_eq!(estimator.compute_success_rate(&[]), 100.0);
+    }
+}
@@ -0,0 +1,171 @@
+//! Routing trace of a payment attempt. The trace records each of the stages a payment goes
+//! through while being routed, from the routing algorithm of the profile to the final order of
+//! the connectors, so that the connector a payment was routed to can be explained afterwards.
+
+use api_models::{payments::PaymentAttemptRoutingTrace, routing::RoutingTrace};
+use common_utils::{ext_traits::ValueExt, generate_id};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine, RoutingEvent};
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::{app::SessionStateInfo, SessionState},
+    types::storage,
+};
+
+/// Logs the routing trace of the payment attempt to the routing events and stores it. The trace is
+/// stored in the background and failures are only logged, as the trace must neither delay nor fail
+/// the payment.
+#[instrument(skip_all)]
+pub fn store_routing_trace(
+    state: &SessionState,
+    routing_trace: RoutingTrace,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let routed_connector = routing_trace.final_connectors.first().cloned();
+
+    let mut routing_event = RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        "".to_string(),
+        "Routing trace",
+        serde_json::json!({
+            "attempt_id": payment_attempt.attempt_id,
+            "algorithm_id": routing_trace.algorithm_id,
+            "algorithm_version": routing_trace.algorithm_version,
+        }),
+        "RoutingTrace".to_string(),
+        ApiMethod::Internal,
+        payment_attempt.payment_id.get_string_repr().to_string(),
+        payment_attempt.profile_id.clone(),
+        payment_attempt.merchant_id.clone(),
+        state.request_id,
+        RoutingEngine::Internal,
+    );
+    routing_event.set_response_body(&routing_trace);
+    routing_event.set_routable_connectors(routing_trace.final_connectors.clone());
+    if let Some(connector) = routed_connector.clone() {
+        routing_event.set_payment_connector(connector);
+    }
+    if let Some(step) = routing_trace.dynamic_routing.last() {
+        if let Some(routing_approach) = &step.routing_approach {
+            routing_event.set_routing_approach(routing_approach.to_string());
+        }
+    }
+    routing_event.set_status_code(200);
+    state.event_handler().log_event(&routing_event);
+
+    let trace = match serde_json::to_value(&routing_trace) {
+        Ok(trace) => trace,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize the routing trace");
+            return;
+        }
+    };
+
+    let routing_trace_new = storage::RoutingTraceNew {
+        id: generate_id(consts::ID_LENGTH, "routing_trace"),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        profile_id: payment_attempt.profile_id.clone(),
+        algorithm_id: routing_trace.algorithm_id,
+        routed_connector: routed_connector.map(|connector| connector.connector.to_string()),
+        trace,
+        created_at: common_utils::date_time::now(),
+    };
+
+    let store = state.store.clone();
+    tokio::spawn(
+        async move {
+            if let Err(error) = store.insert_routing_trace(routing_trace_new).await {
+                logger::error!(?error, "Failed to store the routing trace");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Converts a stored routing trace to the routing trace of the payment attempt in the response
+pub fn get_attempt_routing_trace(
+    routing_trace: storage::RoutingTrace,
+) -> RouterResult<PaymentAttemptRoutingTrace> {
+    routing_trace
+        .trace
+        .parse_value("RoutingTrace")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the routing trace")
+        .map(|trace| PaymentAttemptRoutingTrace {
+            attempt_id: routing_trace.attempt_id,
+            routed_connector: routing_trace.routed_connector,
+            created_at: routing_trace.created_at,
+            trace,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use api_models::{enums::RoutableConnectors, routing::RoutableConnectorChoice};
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn get_stored_routing_trace(trace: serde_json::Value) -> storage::RoutingTrace {
+        storage::RoutingTrace {
+            id: "routing_trace_1".to_string(),
+            payment_id: id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_1"))
+                .expect("Invalid payment id"),
+            attempt_id: "pay_1_1".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::Borrowed("pro_1"))
+                .expect("Invalid profile id"),
+            algorithm_id: None,
+            routed_connector: Some("stripe".to_string()),
+            trace,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_get_attempt_routing_trace() {
+        let connector = RoutableConnectorChoice {
+            choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+            connector: RoutableConnectors::Stripe,
+            merchant_connector_id: None,
+        };
+        let routing_trace = RoutingTrace {
+            matched_rule: Some("high_value".to_string()),
+            eligible_connectors: vec![connector.clone()],
+            final_connectors: vec![connector],
+            ..Default::default()
+        };
+        let stored_routing_trace = get_stored_routing_trace(
+            serde_json::to_value(&routing_trace).expect("Failed to serialize the routing trace"),
+        );
+
+        let attempt_routing_trace = get_attempt_routing_trace(stored_routing_trace)
+            .expect("Failed to convert the routing trace");
+
+        assert_eq!(attempt_routing_trace.attempt_id, "pay_1_1");
+        assert_eq!(
+            attempt_routing_trace.routed_connector.as_deref(),
+            Some("stripe")
+        );
+        assert_eq!(
+            attempt_routing_trace.trace.matched_rule.as_deref(),
+            Some("high_value")
+        );
+        assert_eq!(attempt_routing_trace.trace.final_connectors.len(), 1);
+    }
+
+    #[test]
+    fn test_get_attempt_routing_trace_with_invalid_trace() {
+        let stored_routing_trace =
+            get_stored_routing_trace(serde_json::json!({ "final_connectors": "stripe" }));
+
+        assert!(get_attempt_routing_trace(stored_routing_trace).is_err());
+    }
+}
@@ -0,0 +1,499 @@
+use common_utils::types::MinorUnit;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::{
+    payments::{
+        payment_attempt::{NetAmount, PaymentAttempt, PaymentAttemptUpdate},
+        HeaderPayload, PaymentIntent,
+    },
+    router_data::{ConnectorResponseData, RouterData},
+};
+use router_env::{instrument, logger, tracing};
+
+use super::{
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::{self, BoxedOperation, Operation, PaymentResponse},
+    CallConnectorAction, OperationSessionGetters, OperationSessionSetters,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{self as router_types, api, domain, storage::enums},
+    utils::OptionExt,
+};
+
+/// Split tender payments are paid with several payment methods, each of which authorizes a part of
+/// the amount of the payment in an attempt of its own
+pub fn is_split_tender_enabled(payment_intent: &PaymentIntent) -> bool {
+    payment_intent.enable_partial_authorization == Some(true)
+}
+
+fn is_authorized_tender(payment_attempt: &PaymentAttempt) -> bool {
+    matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Authorized | enums::AttemptStatus::Charged
+    )
+}
+
+/// Part of the amount of the payment authorized by the tenders. The surcharge, shipping cost and
+/// tax of the tenders are left out, the same as in the amount of the payment intent.
+fn get_authorized_order_amount<'a>(tenders: impl Iterator<Item = &'a PaymentAttempt>) -> MinorUnit {
+    tenders
+        .filter(|tender| is_authorized_tender(tender))
+        .map(|tender| tender.net_amount.get_order_amount())
+        .sum()
+}
+
+/// Returns the attempts of the payment other than the given attempt that have authorized a part
+/// of the amount of the payment
+#[instrument(skip_all)]
+pub async fn get_other_tenders(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<Vec<PaymentAttempt>> {
+    if !is_split_tender_enabled(payment_intent) {
+        return Ok(Vec::new());
+    }
+
+    let payment_attempts = state
+        .store
+        .find_attempts_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    Ok(payment_attempts
+        .into_iter()
+        .filter(|tender| {
+            tender.attempt_id != payment_attempt.attempt_id && is_authorized_tender(tender)
+        })
+        .collect())
+}
+
+/// Balance of the payment that is yet to be authorized with another payment method
+#[instrument(skip_all)]
+pub async fn get_remaining_amount(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<MinorUnit> {
+    let payment_attempts = state
+        .store
+        .find_attempts_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    let remaining_amount =
+        payment_intent.amount - get_authorized_order_amount(payment_attempts.iter());
+    if remaining_amount <= MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Partially authorized payment has no remaining balance");
+    }
+
+    Ok(remaining_amount)
+}
+
+/// Reduces the amount of the attempt to the amount approved by the connector, when the connector
+/// approves only a part of the amount of a split tender payment. The shortfall is taken off the
+/// order amount of the attempt, its surcharge, shipping cost and tax are retained.
+pub fn get_partial_authorization_update(
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+    connector_response: Option<&ConnectorResponseData>,
+) -> Option<PaymentAttemptUpdate> {
+    if !is_split_tender_enabled(payment_intent) || !is_authorized_tender(payment_attempt) {
+        return None;
+    }
+
+    let authorized_amount =
+        connector_response.and_then(ConnectorResponseData::get_authorized_amount)?;
+    let net_amount =
+        get_partially_authorized_net_amount(&payment_attempt.net_amount, authorized_amount)?;
+
+    logger::info!(
+        attempt_id = %payment_attempt.attempt_id,
+        %authorized_amount,
+        "Payment partially authorized by connector"
+    );
+
+    Some(PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
+        net_amount,
+        amount_capturable: match payment_attempt.status {
+            enums::AttemptStatus::Authorized => authorized_amount,
+            _ => MinorUnit::zero(),
+        },
+    })
+}
+
+fn get_partially_authorized_net_amount(
+    net_amount: &NetAmount,
+    authorized_amount: MinorUnit,
+) -> Option<NetAmount> {
+    let shortfall = net_amount.get_total_amount() - authorized_amount;
+    if shortfall <= MinorUnit::zero() {
+        return None;
+    }
+
+    let order_amount = net_amount.get_order_amount() - shortfall;
+    Some(NetAmount::new(
+        if order_amount < MinorUnit::zero() {
+            MinorUnit::zero()
+        } else {
+            order_amount
+        },
+        net_amount.get_shipping_cost(),
+        net_amount.get_order_tax_amount(),
+        net_amount.get_surcharge_amount(),
+        net_amount.get_tax_on_surcharge(),
+    ))
+}
+
+/// Status of a split tender payment, based on the status of its active attempt and the amount
+/// authorized by all of its tenders
+pub fn get_intent_status(
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+    other_tenders: &[PaymentAttempt],
+    intent_status: enums::IntentStatus,
+) -> enums::IntentStatus {
+    if !is_split_tender_enabled(payment_intent) {
+        return intent_status;
+    }
+
+    let authorized_amount =
+        get_authorized_order_amount(other_tenders.iter().chain(std::iter::once(payment_attempt)));
+    let has_uncaptured_tenders = other_tenders
+        .iter()
+        .any(|tender| tender.status == enums::AttemptStatus::Authorized);
+
+    get_split_tender_intent_status(
+        intent_status,
+        payment_intent.amount,
+        authorized_amount,
+        has_uncaptured_tenders,
+        !other_tenders.is_empty(),
+    )
+}
+
+fn get_split_tender_intent_status(
+    intent_status: enums::IntentStatus,
+    payment_amount: MinorUnit,
+    authorized_amount: MinorUnit,
+    has_uncaptured_tenders: bool,
+    has_other_tenders: bool,
+) -> enums::IntentStatus {
+    match intent_status {
+        enums::IntentStatus::Succeeded | enums::IntentStatus::RequiresCapture
+            if authorized_amount < payment_amount =>
+        {
+            enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+        }
+        enums::IntentStatus::Succeeded if has_uncaptured_tenders => {
+            enums::IntentStatus::RequiresCapture
+        }
+        // A tender that fails does not affect the tenders that have already been authorized
+        enums::IntentStatus::Failed if has_other_tenders => {
+            enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+        }
+        intent_status => intent_status,
+    }
+}
+
+/// Amount captured by all the tenders of a split tender payment
+pub fn get_amount_captured(
+    other_tenders: &[PaymentAttempt],
+    amount_captured: Option<MinorUnit>,
+) -> Option<MinorUnit> {
+    let captured_by_other_tenders = other_tenders
+        .iter()
+        .filter(|tender| tender.status == enums::AttemptStatus::Charged)
+        .map(|tender| tender.net_amount.get_total_amount())
+        .sum::<MinorUnit>();
+
+    add_amount_captured_by_other_tenders(amount_captured, captured_by_other_tenders)
+}
+
+fn add_amount_captured_by_other_tenders(
+    amount_captured: Option<MinorUnit>,
+    captured_by_other_tenders: MinorUnit,
+) -> Option<MinorUnit> {
+    match amount_captured {
+        Some(amount_captured) => Some(amount_captured + captured_by_other_tenders),
+        None if captured_by_other_tenders > MinorUnit::zero() => Some(captured_by_other_tenders),
+        None => None,
+    }
+}
+
+/// Action taken on the authorized tenders of a split tender payment other than its active attempt
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OtherTendersAction {
+    Capture,
+    Void,
+}
+
+/// The connector calls of the cancel and capture flows act on the active attempt of the payment,
+/// the other tenders of a split tender payment are voided or captured separately
+pub fn get_other_tenders_action<F: Clone, Req, D>(
+    operation: &BoxedOperation<'_, F, Req, D>,
+    payment_data: &D,
+) -> RouterResult<Option<OtherTendersAction>>
+where
+    D: OperationSessionGetters<F>,
+{
+    if !is_split_tender_enabled(payment_data.get_payment_intent()) {
+        return Ok(None);
+    }
+    Ok(operation.to_domain()?.get_other_tenders_action())
+}
+
+/// Voids or captures the authorized tenders of the payment other than its active attempt. The
+/// active attempt is restored in the payment data once all the tenders have been processed.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn call_connector_service_for_other_tenders<F, Req, FData, D>(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: &domain::MerchantContext,
+    operation: &BoxedOperation<'_, F, Req, D>,
+    other_tenders_action: OtherTendersAction,
+    mut payment_data: D,
+    customer: &Option<domain::Customer>,
+    call_connector_action: CallConnectorAction,
+    validate_result: &operations::ValidateResult,
+    header_payload: HeaderPayload,
+    business_profile: &domain::Profile,
+    locale: &Option<String>,
+) -> RouterResult<D>
+where
+    F: Send + Clone + Sync,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+    D: ConstructFlowSpecificData<F, FData, router_types::PaymentsResponseData>,
+    RouterData<F, FData, router_types::PaymentsResponseData>: Feature<F, FData> + Send,
+    dyn api::Connector:
+        services::api::ConnectorIntegration<F, FData, router_types::PaymentsResponseData>,
+    PaymentResponse: Operation<F, FData, Data = D>,
+    FData: Send + Sync + Clone,
+{
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let other_tenders = get_other_tenders(
+        state,
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_attempt(),
+        storage_scheme,
+    )
+    .await?;
+
+    if other_tenders_action == OtherTendersAction::Void
+        && other_tenders
+            .iter()
+            .any(|tender| tender.status == enums::AttemptStatus::Charged)
+    {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "You cannot cancel this payment because some of its payment methods have already been charged, refund them instead".to_string(),
+        }));
+    }
+
+    let active_attempt = payment_data.get_payment_attempt().clone();
+    for mut tender in other_tenders
+        .into_iter()
+        .filter(|tender| tender.status == enums::AttemptStatus::Authorized)
+    {
+        let connector_name = tender
+            .connector
+            .clone()
+            .get_required_value("connector")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Connector not found for the tender")?;
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector_name,
+            api::GetToken::Connector,
+            tender.merchant_connector_id.clone(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector name received for the tender")?;
+
+        // Tenders are always captured in full
+        tender.amount_to_capture = None;
+        payment_data.set_payment_attempt(tender);
+
+        let (router_data, _) = Box::pin(super::call_connector_service(
+            state,
+            req_state.clone(),
+            merchant_context,
+            connector_data,
+            operation,
+            &mut payment_data,
+            customer,
+            call_connector_action.clone(),
+            validate_result,
+            None,
+            header_payload.clone(),
+            None,
+            business_profile,
+            false,
+            false,
+            None,
+            None,
+        ))
+        .await?;
+
+        let response_operation = Box::new(PaymentResponse);
+        payment_data = response_operation
+            .to_post_update_tracker()?
+            .update_tracker(
+                state,
+                payment_data,
+                router_data,
+                merchant_context.get_merchant_key_store(),
+                storage_scheme,
+                locale,
+                #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
+                Vec::new(),
+                #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
+                business_profile,
+            )
+            .await?;
+    }
+
+    payment_data.set_payment_attempt(active_attempt);
+    Ok(payment_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partially_authorized_net_amount() {
+        let net_amount = NetAmount::new(
+            MinorUnit::new(1000),
+            Some(MinorUnit::new(100)),
+            None,
+            Some(MinorUnit::new(50)),
+            Some(MinorUnit::new(5)),
+        );
+
+        assert_eq!(
+            get_partially_authorized_net_amount(&net_amount, MinorUnit::new(1155)),
+            None
+        );
+
+        // The shortfall is taken off the order amount, the surcharge and shipping cost are kept
+        let partially_authorized_net_amount =
+            get_partially_authorized_net_amount(&net_amount, MinorUnit::new(755));
+        assert_eq!(
+            partially_authorized_net_amount,
+            Some(NetAmount::new(
+                MinorUnit::new(600),
+                Some(MinorUnit::new(100)),
+                None,
+                Some(MinorUnit::new(50)),
+                Some(MinorUnit::new(5)),
+            ))
+        );
+        assert_eq!(
+            partially_authorized_net_amount.map(|net_amount| net_amount.get_total_amount()),
+            Some(MinorUnit::new(755))
+        );
+
+        assert_eq!(
+            get_partially_authorized_net_amount(&net_amount, MinorUnit::new(100))
+                .map(|net_amount| net_amount.get_order_amount()),
+            Some(MinorUnit::zero())
+        );
+    }
+
+    #[test]
+    fn test_split_tender_intent_status() {
+        let payment_amount = MinorUnit::new(1000);
+
+        assert_eq!(
+            get_split_tender_intent_status(
+                enums::IntentStatus::Succeeded,
+                payment_amount,
+                MinorUnit::new(600),
+                false,
+                false,
+            ),
+            enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+        );
+        assert_eq!(
+            get_split_tender_intent_status(
+                enums::IntentStatus::RequiresCapture,
+                payment_amount,
+                MinorUnit::new(600),
+                false,
+                true,
+            ),
+            enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+        );
+        assert_eq!(
+            get_split_tender_intent_status(
+                enums::IntentStatus::Succeeded,
+                payment_amount,
+                payment_amount,
+                true,
+                true,
+            ),
+            enums::IntentStatus::RequiresCapture
+        );
+        assert_eq!(
+            get_split_tender_intent_status(
+                enums::IntentStatus::Succeeded,
+                payment_amount,
+                payment_amount,
+                false,
+                true,
+            ),
+            enums::IntentStatus::Succeeded
+        );
+        assert_eq!(
+            get_split_tender_intent_status(
+                enums::IntentStatus::Failed,
+                payment_amount,
+                MinorUnit::new(400),
+                true,
+                true,
+            ),
+            enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
+        );
+        assert_eq!(
+            get_split_tender_intent_status(
+                enums::IntentStatus::Failed,
+                payment_amount,
+                MinorUnit::zero(),
+                false,
+                false,
+            ),
+            enums::IntentStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_amount_captured_by_other_tenders() {
+        assert_eq!(
+            add_amount_captured_by_other_tenders(Some(MinorUnit::new(600)), MinorUnit::new(400)),
+            Some(MinorUnit::new(1000))
+        );
+        assert_eq!(
+            add_amount_captured_by_other_tenders(None, MinorUnit::new(400)),
+            Some(MinorUnit::new(400))
+        );
+        assert_eq!(
+            add_amount_captured_by_other_tenders(None, MinorUnit::zero()),
+            None
+        );
+    }
+}
@@ -295,6 +295,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         customer_id: None,
         surcharge_details: None,
         request_extended_authorization: None,
+        enable_partial_authorization: None,
         request_incremental_authorization: matches!(
             payment_data
                 .payment_intent
@@ -2879,6 +2880,7 @@ where
             issuer_error_code: payment_attempt.issuer_error_code,
             issuer_error_message: payment_attempt.issuer_error_message,
             is_iframe_redirection_enabled: payment_intent.is_iframe_redirection_enabled,
+            enable_partial_authorization: payment_intent.enable_partial_authorization,
             whole_connector_response: payment_data.get_whole_connector_response(),
         };
 
@@ -3590,6 +3592,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .transpose()?,
             customer_acceptance: payment_data.customer_acceptance,
             request_extended_authorization: attempt.request_extended_authorization,
+            enable_partial_authorization: payment_data.payment_intent.enable_partial_authorization,
             split_payments,
             merchant_order_reference_id,
             integrity_object: None,
@@ -3692,6 +3695,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
                 .connector
                 .connector_transaction_id(payment_data.payment_attempt.clone())?
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
+            integrity_object: None,
         })
     }
 }
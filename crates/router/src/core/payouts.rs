@@ -30,6 +30,7 @@ use error_stack::{report, ResultExt};
 #[cfg(feature = "olap")]
 use futures::future::join_all;
 use hyperswitch_domain_models::payment_methods::PaymentMethod;
+use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
 use masking::{PeekInterface, Secret};
 #[cfg(feature = "payout_retry")]
 use retry::GsmValidation;
@@ -48,9 +49,10 @@ use crate::{
             self, ConnectorErrorExt, CustomResult, RouterResponse, RouterResult, StorageErrorExt,
         },
         payments::{self, customers, helpers as payment_helpers},
-        utils as core_utils,
+        utils::{self as core_utils, integrity},
     },
     db::StorageInterface,
+    events::audit_events::IntegrityCheckFlow,
     routes::SessionState,
     services,
     types::{
@@ -1607,6 +1609,70 @@ pub async fn complete_create_payout(
     Ok(())
 }
 
+pub fn check_payout_integrity<T, Request>(
+    request: &Request,
+    payout_response_data: &Result<types::PayoutsResponseData, types::ErrorResponse>,
+) -> Result<(), common_utils::errors::IntegrityCheckError>
+where
+    T: FlowIntegrity,
+    Request: GetIntegrityObject<T> + CheckIntegrity<Request, T>,
+{
+    let connector_payout_id = payout_response_data
+        .as_ref()
+        .ok()
+        .and_then(|resp_data| resp_data.connector_payout_id.clone());
+
+    request.check_integrity(request, connector_payout_id)
+}
+
+/// Records the mismatch found by the integrity check of the connector response. If the integrity
+/// check policy of the profile asks for it, the payout is held in the `pending` status with the
+/// mismatch as its error, instead of taking the status returned by the connector.
+fn apply_payout_integrity_check(
+    state: &SessionState,
+    payout_data: &PayoutData,
+    integrity_check: &Result<(), common_utils::errors::IntegrityCheckError>,
+    status: storage_enums::PayoutStatus,
+) -> (storage_enums::PayoutStatus, Option<String>, Option<String>) {
+    let Err(err) = integrity_check else {
+        return (status, None, None);
+    };
+
+    let quarantined = payout_data
+        .business_profile
+        .integrity_check_policy
+        .as_ref()
+        .is_some_and(|policy| policy.quarantine_on_mismatch);
+    integrity::record_integrity_check_failure(
+        state,
+        integrity::IntegrityCheckFailure {
+            flow: IntegrityCheckFlow::Payout,
+            merchant_id: &payout_data.payouts.merchant_id,
+            connector: payout_data
+                .payout_attempt
+                .connector
+                .as_deref()
+                .unwrap_or_default(),
+            resource_id: &payout_data.payouts.payout_id,
+            error: err,
+        },
+        quarantined,
+    );
+
+    if quarantined {
+        (
+            storage_enums::PayoutStatus::Pending,
+            Some("IE".to_string()),
+            Some(format!(
+                "Integrity Check Failed! Value mismatched for fields {}",
+                err.field_names
+            )),
+        )
+    } else {
+        (status, None, None)
+    }
+}
+
 pub async fn create_payout(
     state: &SessionState,
     merchant_context: &domain::MerchantContext,
@@ -1643,7 +1709,7 @@ pub async fn create_payout(
     complete_payout_quote_steps_if_required(state, connector_data, &mut router_data).await?;
 
     // 5. Call connector service
-    let router_data_resp = services::execute_connector_processing_step(
+    let mut router_data_resp = services::execute_connector_processing_step(
         state,
         connector_integration,
         &router_data,
@@ -1654,6 +1720,10 @@ pub async fn create_payout(
     .await
     .to_payout_failed_response()?;
 
+    // Initiating Integrity check
+    router_data_resp.integrity_check =
+        check_payout_integrity(&router_data_resp.request, &router_data_resp.response);
+
     // 6. Process data returned by the connector
     let db = &*state.store;
     match router_data_resp.response {
@@ -1662,11 +1732,17 @@ pub async fn create_payout(
             let status = payout_response_data
                 .status
                 .unwrap_or(payout_attempt.status.to_owned());
+            let (status, error_code, error_message) = apply_payout_integrity_check(
+                state,
+                payout_data,
+                &router_data_resp.integrity_check,
+                status,
+            );
             let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
                 connector_payout_id: payout_response_data.connector_payout_id,
                 status,
-                error_code: None,
-                error_message: None,
+                error_code,
+                error_message,
                 is_eligible: payout_response_data.payout_eligible,
                 unified_code: None,
                 unified_message: None,
@@ -2354,7 +2430,7 @@ pub async fn fulfill_payout(
     > = connector_data.connector.get_connector_integration();
 
     // 4. Call connector service
-    let router_data_resp = services::execute_connector_processing_step(
+    let mut router_data_resp = services::execute_connector_processing_step(
         state,
         connector_integration,
         &router_data,
@@ -2365,6 +2441,10 @@ pub async fn fulfill_payout(
     .await
     .to_payout_failed_response()?;
 
+    // Initiating Integrity check
+    router_data_resp.integrity_check =
+        check_payout_integrity(&router_data_resp.request, &router_data_resp.response);
+
     // 5. Process data returned by the connector
     let db = &*state.store;
     match router_data_resp.response {
@@ -2372,12 +2452,18 @@ pub async fn fulfill_payout(
             let status = payout_response_data
                 .status
                 .unwrap_or(payout_data.payout_attempt.status.to_owned());
+            let (status, error_code, error_message) = apply_payout_integrity_check(
+                state,
+                payout_data,
+                &router_data_resp.integrity_check,
+                status,
+            );
             payout_data.payouts.status = status;
             let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
                 connector_payout_id: payout_response_data.connector_payout_id,
                 status,
-                error_code: None,
-                error_message: None,
+                error_code,
+                error_message,
                 is_eligible: payout_response_data.payout_eligible,
                 unified_code: None,
                 unified_message: None,
@@ -911,7 +911,7 @@ pub async fn decide_payout_connector(
                     &state.conf.connectors,
                     &conn.connector.to_string(),
                     api::GetToken::Connector,
-                    payout_attempt.merchant_connector_id.clone(),
+                    conn.merchant_connector_id,
                 )
                 .map(|connector_data| connector_data.into())
             })
@@ -953,8 +953,6 @@ pub async fn decide_payout_connector(
             .attach_printable("Empty connector list returned")?
             .clone();
 
-        connectors.remove(0);
-
         let connector_data = connectors
             .into_iter()
             .map(|conn| {
@@ -962,7 +960,7 @@ pub async fn decide_payout_connector(
                     &state.conf.connectors,
                     &conn.connector.to_string(),
                     api::GetToken::Connector,
-                    payout_attempt.merchant_connector_id.clone(),
+                    conn.merchant_connector_id,
                 )
                 .map(|connector_data| connector_data.into())
             })
@@ -290,8 +290,8 @@ pub async fn modify_trackers(
         error_code: None,
         created_at: common_utils::date_time::now(),
         last_modified_at: common_utils::date_time::now(),
-        merchant_connector_id: None,
-        routing_info: None,
+        merchant_connector_id: connector.merchant_connector_id.clone(),
+        routing_info: payout_data.payout_attempt.routing_info.to_owned(),
         unified_code: None,
         unified_message: None,
         additional_payout_method_data: payout_data
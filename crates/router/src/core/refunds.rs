@@ -25,11 +25,13 @@ use crate::{
         payments::{self, access_token, helpers},
         refunds::transformers::SplitRefundInput,
         utils::{
-            self as core_utils, refunds_transformers as transformers,
+            self as core_utils, integrity, refunds_transformers as transformers,
             refunds_validator as validator,
         },
     },
-    db, logger,
+    db,
+    events::audit_events::IntegrityCheckFlow,
+    logger,
     routes::{metrics, SessionState},
     services,
     types::{
@@ -287,6 +289,16 @@ pub async fn trigger_refund_to_gateway(
         router_data
     };
 
+    let quarantined_integrity_check_error = handle_refund_integrity_check_failure(
+        state,
+        merchant_context,
+        &connector.connector_name.to_string(),
+        refund,
+        IntegrityCheckFlow::Refund,
+        &router_data_res,
+    )
+    .await;
+
     let refund_update = match router_data_res.response {
         Err(err) => {
             let option_gsm = helpers::get_gsm_record(
@@ -342,24 +354,14 @@ pub async fn trigger_refund_to_gateway(
         }
         Ok(response) => {
             // match on connector integrity checks
-            match router_data_res.integrity_check.clone() {
-                Err(err) => {
+            match quarantined_integrity_check_error {
+                Some(err) => {
                     let (refund_connector_transaction_id, processor_refund_data) =
                         err.connector_transaction_id.map_or((None, None), |txn_id| {
                             let (refund_id, refund_data) =
                                 ConnectorTransactionId::form_id_and_data(txn_id);
                             (Some(refund_id), refund_data)
                         });
-                    metrics::INTEGRITY_CHECK_FAILED.add(
-                        1,
-                        router_env::metric_attributes!(
-                            ("connector", connector.connector_name.to_string()),
-                            (
-                                "merchant_id",
-                                merchant_context.get_merchant_account().get_id().clone()
-                            ),
-                        ),
-                    );
                     storage::RefundUpdate::ErrorUpdate {
                         refund_status: Some(enums::RefundStatus::ManualReview),
                         refund_error_message: Some(format!(
@@ -376,7 +378,7 @@ pub async fn trigger_refund_to_gateway(
                         issuer_error_message: None,
                     }
                 }
-                Ok(()) => {
+                None => {
                     if response.refund_status == diesel_models::enums::RefundStatus::Success {
                         metrics::SUCCESSFUL_REFUND.add(
                             1,
@@ -429,6 +431,42 @@ pub async fn trigger_refund_to_gateway(
     Ok(response)
 }
 
+/// Records the mismatch found by the integrity check of a successful connector response. The
+/// error is returned if the integrity check policy of the profile holds the refund for manual
+/// review, otherwise the refund takes the status returned by the connector.
+async fn handle_refund_integrity_check_failure<F>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    connector_name: &str,
+    refund: &storage::Refund,
+    flow: IntegrityCheckFlow,
+    router_data: &types::RefundsRouterData<F>,
+) -> Option<common_utils::errors::IntegrityCheckError> {
+    let (Ok(_), Err(err)) = (&router_data.response, &router_data.integrity_check) else {
+        return None;
+    };
+
+    let quarantined = integrity::should_quarantine(
+        state,
+        merchant_context.get_merchant_key_store(),
+        refund.profile_id.as_ref(),
+    )
+    .await;
+    integrity::record_integrity_check_failure(
+        state,
+        integrity::IntegrityCheckFailure {
+            flow,
+            merchant_id: merchant_context.get_merchant_account().get_id(),
+            connector: connector_name,
+            resource_id: &refund.refund_id,
+            error: err,
+        },
+        quarantined,
+    );
+
+    quarantined.then(|| err.clone())
+}
+
 pub fn check_refund_integrity<T, Request>(
     request: &Request,
     refund_response_data: &Result<types::RefundsResponseData, ErrorResponse>,
@@ -666,6 +704,16 @@ pub async fn sync_refund_with_gateway(
         router_data
     };
 
+    let quarantined_integrity_check_error = handle_refund_integrity_check_failure(
+        state,
+        merchant_context,
+        &connector.connector_name.to_string(),
+        refund,
+        IntegrityCheckFlow::RefundSync,
+        &router_data_res,
+    )
+    .await;
+
     let refund_update = match router_data_res.response {
         Err(error_message) => {
             let refund_status = match error_message.status_code {
@@ -686,18 +734,8 @@ pub async fn sync_refund_with_gateway(
                 issuer_error_message: error_message.network_error_message,
             }
         }
-        Ok(response) => match router_data_res.integrity_check.clone() {
-            Err(err) => {
-                metrics::INTEGRITY_CHECK_FAILED.add(
-                    1,
-                    router_env::metric_attributes!(
-                        ("connector", connector.connector_name.to_string()),
-                        (
-                            "merchant_id",
-                            merchant_context.get_merchant_account().get_id().clone()
-                        ),
-                    ),
-                );
+        Ok(response) => match quarantined_integrity_check_error {
+            Some(err) => {
                 let (refund_connector_transaction_id, processor_refund_data) = err
                     .connector_transaction_id
                     .map_or((None, None), |refund_id| {
@@ -721,7 +759,7 @@ pub async fn sync_refund_with_gateway(
                     issuer_error_message: None,
                 }
             }
-            Ok(()) => {
+            None => {
                 let (connector_refund_id, processor_refund_data) =
                     ConnectorTransactionId::form_id_and_data(response.connector_refund_id);
                 storage::RefundUpdate::Update {
@@ -42,7 +42,7 @@ use crate::{
     types::{
         self, api as api_types, api::payments as payments_types, storage, transformers::ForeignInto,
     },
-    workflows::{payment_sync, revenue_recovery::get_schedule_time_to_retry_mit_payments},
+    workflows::{payment_sync, revenue_recovery as revenue_recovery_workflow},
 };
 
 type RecoveryResult<T> = error_stack::Result<T, errors::RecoveryError>;
@@ -131,17 +131,26 @@ impl RevenueRecoveryPaymentsAttemptStatus {
                     )
                     .await?;
 
-                // get a reschedule time
-                let schedule_time = get_schedule_time_to_retry_mit_payments(
-                    db,
-                    &revenue_recovery_payment_data
-                        .merchant_account
-                        .get_id()
-                        .clone(),
-                    process_tracker.retry_count + 1,
+                revenue_recovery_workflow::record_network_retry_decline(
+                    state,
+                    revenue_recovery_payment_data.merchant_account.get_id(),
+                    &payment_attempt,
+                    revenue_recovery_metadata,
                 )
                 .await;
 
+                // get a reschedule time
+                let schedule_time = revenue_recovery_payment_data
+                    .get_schedule_time_based_on_retry_type(
+                        state,
+                        revenue_recovery_payment_data.merchant_account.get_id(),
+                        process_tracker.retry_count + 1,
+                        payment_intent,
+                        &payment_attempt,
+                        revenue_recovery_metadata,
+                    )
+                    .await;
+
                 // check if retry is possible
                 if let Some(schedule_time) = schedule_time {
                     // schedule a retry
@@ -169,9 +178,10 @@ impl RevenueRecoveryPaymentsAttemptStatus {
                 let action = Box::pin(Action::payment_sync_call(
                     state,
                     revenue_recovery_payment_data,
-                    payment_intent.get_id(),
+                    payment_intent,
                     &process_tracker,
                     payment_attempt,
+                    revenue_recovery_metadata,
                 ))
                 .await?;
 
@@ -283,7 +293,6 @@ impl Action {
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
         revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> RecoveryResult<Self> {
-        let db = &*state.store;
         let response = revenue_recovery_core::api::call_proxy_api(
             state,
             payment_intent,
@@ -299,11 +308,13 @@ impl Action {
                 )),
                 RevenueRecoveryPaymentsAttemptStatus::Failed => {
                     Self::decide_retry_failure_action(
-                        db,
+                        state,
                         merchant_id,
                         process.clone(),
                         revenue_recovery_payment_data,
+                        payment_intent,
                         &payment_data.payment_attempt,
+                        revenue_recovery_metadata,
                     )
                     .await
                 }
@@ -473,17 +484,17 @@ impl Action {
     pub async fn payment_sync_call(
         state: &SessionState,
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
-        global_payment_id: &id_type::GlobalPaymentId,
+        payment_intent: &PaymentIntent,
         process: &storage::ProcessTracker,
         payment_attempt: payment_attempt::PaymentAttempt,
+        revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> RecoveryResult<Self> {
         let response = revenue_recovery_core::api::call_psync_api(
             state,
-            global_payment_id,
+            payment_intent.get_id(),
             revenue_recovery_payment_data,
         )
         .await;
-        let db = &*state.store;
         match response {
             Ok(_payment_data) => match payment_attempt.status.foreign_into() {
                 RevenueRecoveryPaymentsAttemptStatus::Succeeded => {
@@ -491,11 +502,13 @@ impl Action {
                 }
                 RevenueRecoveryPaymentsAttemptStatus::Failed => {
                     Self::decide_retry_failure_action(
-                        db,
+                        state,
                         revenue_recovery_payment_data.merchant_account.get_id(),
                         process.clone(),
                         revenue_recovery_payment_data,
+                        payment_intent,
                         &payment_attempt,
+                        revenue_recovery_metadata,
                     )
                     .await
                 }
@@ -684,15 +697,32 @@ impl Action {
     }
 
     pub(crate) async fn decide_retry_failure_action(
-        db: &dyn StorageInterface,
+        state: &SessionState,
         merchant_id: &id_type::MerchantId,
         pt: storage::ProcessTracker,
         revenue_recovery_payment_data: &storage::revenue_recovery::RevenueRecoveryPaymentData,
+        payment_intent: &PaymentIntent,
         payment_attempt: &payment_attempt::PaymentAttempt,
+        revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> RecoveryResult<Self> {
+        revenue_recovery_workflow::record_network_retry_decline(
+            state,
+            merchant_id,
+            payment_attempt,
+            revenue_recovery_metadata,
+        )
+        .await;
+
         let next_retry_count = pt.retry_count + 1;
         let schedule_time = revenue_recovery_payment_data
-            .get_schedule_time_based_on_retry_type(db, merchant_id, next_retry_count)
+            .get_schedule_time_based_on_retry_type(
+                state,
+                merchant_id,
+                next_retry_count,
+                payment_intent,
+                payment_attempt,
+                revenue_recovery_metadata,
+            )
             .await;
 
         match schedule_time {
@@ -646,7 +646,8 @@ pub async fn link_routing_config(
         | diesel_models::enums::RoutingAlgorithmKind::Priority
         | diesel_models::enums::RoutingAlgorithmKind::Advanced
         | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
-        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule => {
+        | diesel_models::enums::RoutingAlgorithmKind::ThreeDsDecisionRule
+        | diesel_models::enums::RoutingAlgorithmKind::CostBased => {
             let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
                 .routing_algorithm
                 .clone()
@@ -1678,6 +1679,17 @@ pub async fn success_based_routing_update_configs(
     .await
     .map_err(|e| logger::error!("unable to publish into the redact channel for evicting the success based routing config cache {e:?}"));
 
+    // The outcomes counted by the estimator were counted under the previous config
+    if state.conf.success_rate_estimator.enabled {
+        payments_routing::success_rate_estimator::SuccessRateEstimator::clear_outcomes(
+            &state,
+            &profile_id,
+        )
+        .await
+        .map_err(|error| logger::error!(success_rate_estimator_error=?error))
+        .ok();
+    }
+
     let new_record = record.foreign_into();
 
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(
@@ -422,7 +422,8 @@ impl RoutingAlgorithmHelpers<'_> {
                 self.connector_choice(choice)?;
             }
 
-            routing_types::StaticRoutingAlgorithm::Priority(list) => {
+            routing_types::StaticRoutingAlgorithm::Priority(list)
+            | routing_types::StaticRoutingAlgorithm::CostBased(list) => {
                 for choice in list {
                     self.connector_choice(choice)?;
                 }
@@ -536,7 +537,8 @@ pub async fn validate_connectors_in_routing_config(
             connector_choice(choice)?;
         }
 
-        routing_types::StaticRoutingAlgorithm::Priority(list) => {
+        routing_types::StaticRoutingAlgorithm::Priority(list)
+        | routing_types::StaticRoutingAlgorithm::CostBased(list) => {
             for choice in list {
                 connector_choice(choice)?;
             }
@@ -850,15 +852,6 @@ pub async fn push_metrics_with_update_window_for_success_based_routing(
 ) -> RouterResult<()> {
     if let Some(success_based_algo_ref) = dynamic_routing_algo_ref.success_based_algorithm {
         if success_based_algo_ref.enabled_feature != routing_types::DynamicRoutingFeatures::None {
-            let client = state
-                .grpc_client
-                .dynamic_routing
-                .success_rate_client
-                .as_ref()
-                .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
-                    message: "success_rate gRPC client not found".to_string(),
-                })?;
-
             let payment_connector = &payment_attempt.connector.clone().ok_or(
                 errors::ApiErrorResponse::GenericNotFoundError {
                     message: "unable to derive payment connector from payment attempt".to_string(),
@@ -906,6 +899,41 @@ pub async fn push_metrics_with_update_window_for_success_based_routing(
                         .change_context(errors::ApiErrorResponse::InternalServerError)?,
                 );
 
+            // The estimator keeps counting while the dynamic routing service is reachable, so
+            // that its success rates are current whenever it has to take over
+            if state.conf.success_rate_estimator.enabled {
+                routing::payments_routing::success_rate_estimator::SuccessRateEstimator::new(
+                    state,
+                    profile_id,
+                    &success_based_routing_configs,
+                    success_based_routing_config_params.clone(),
+                )
+                .record_outcome(
+                    state,
+                    &routable_connector,
+                    payment_status_attribute == common_enums::AttemptStatus::Charged,
+                )
+                .await
+                .map_err(|error| logger::error!(success_rate_estimator_error=?error))
+                .ok();
+            }
+
+            let Some(client) = state
+                .grpc_client
+                .dynamic_routing
+                .success_rate_client
+                .as_ref()
+            else {
+                return if state.conf.success_rate_estimator.enabled {
+                    Ok(())
+                } else {
+                    Err(errors::ApiErrorResponse::GenericNotFoundError {
+                        message: "success_rate gRPC client not found".to_string(),
+                    }
+                    .into())
+                };
+            };
+
             let success_based_connectors = client
                 .calculate_entity_and_global_success_rate(
                     profile_id.get_string_repr().into(),
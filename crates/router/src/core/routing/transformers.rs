@@ -92,6 +92,7 @@ impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
             storage_enums::RoutingAlgorithmKind::Advanced => Self::Advanced,
             storage_enums::RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             storage_enums::RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            storage_enums::RoutingAlgorithmKind::CostBased => Self::CostBased,
         }
     }
 }
@@ -105,6 +106,7 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
             RoutingAlgorithmKind::Advanced => Self::Advanced,
             RoutingAlgorithmKind::Dynamic => Self::Dynamic,
             RoutingAlgorithmKind::ThreeDsDecisionRule => Self::ThreeDsDecisionRule,
+            RoutingAlgorithmKind::CostBased => Self::CostBased,
         }
     }
 }
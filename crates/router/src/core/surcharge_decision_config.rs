@@ -1,9 +1,20 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
 use api_models::surcharge_decision_configs::{
     SurchargeDecisionConfigReq, SurchargeDecisionManagerRecord, SurchargeDecisionManagerResponse,
 };
+#[cfg(feature = "v1")]
+use api_models::surcharge_decision_configs::{
+    SurchargeDecisionConfigs, SurchargeSimulationBatchPayment, SurchargeSimulationBatchRequest,
+    SurchargeSimulationBatchResponse, SurchargeSimulationRequest, SurchargeSimulationResponse,
+};
 use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use euclid::{backend::VirInterpreterBackend, frontend::ast::Program};
 
+#[cfg(feature = "v1")]
+use crate::core::{errors::RouterResult, payment_methods::surcharge_decision_configs};
 use crate::{
     core::errors::{self, RouterResponse},
     routes::SessionState,
@@ -238,3 +249,240 @@ pub async fn retrieve_surcharge_decision_config(
         .attach_printable("The Surcharge Decision Config Record was not found")?;
     Ok(service_api::ApplicationResponse::Json(record))
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, MultipartForm)]
+pub struct SurchargeSimulationBatchForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+    /// Surcharge rules to simulate as JSON, the rules configured for the merchant are used when
+    /// not provided
+    pub algorithm: Option<Text<String>>,
+}
+
+#[cfg(feature = "v1")]
+pub fn get_surcharge_simulation_batch_request(
+    form: SurchargeSimulationBatchForm,
+) -> Result<SurchargeSimulationBatchRequest, errors::ApiErrorResponse> {
+    let algorithm = form
+        .algorithm
+        .map(|algorithm| serde_json::from_str(&algorithm.into_inner()))
+        .transpose()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid surcharge rules: {error}"),
+        })?;
+    let payments = parse_surcharge_simulation_payments(form.file.data.as_ref())?;
+
+    Ok(SurchargeSimulationBatchRequest {
+        algorithm,
+        payments,
+    })
+}
+
+/// Parses the payments to simulate from a CSV file with a header row, one payment per row
+#[cfg(feature = "v1")]
+fn parse_surcharge_simulation_payments(
+    file: &[u8],
+) -> Result<Vec<SurchargeSimulationBatchPayment>, errors::ApiErrorResponse> {
+    let mut reader = csv::Reader::from_reader(file);
+    let headers = reader
+        .headers()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid header row: {error}"),
+        })?
+        .clone();
+
+    reader
+        .records()
+        .map(|record| {
+            let record =
+                record.map_err(|error| get_invalid_payment_error(error.position(), &error))?;
+            let position = record.position();
+            let payment = record
+                .deserialize(Some(&headers))
+                .map_err(|error| get_invalid_payment_error(position, &error))?;
+            Ok(SurchargeSimulationBatchPayment {
+                line_number: position.map_or(0, |position| position.line()),
+                payment,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "v1")]
+fn get_invalid_payment_error(
+    position: Option<&csv::Position>,
+    error: &csv::Error,
+) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::InvalidRequestData {
+        message: match position {
+            Some(position) => format!("Invalid payment on line {}: {error}", position.line()),
+            None => format!("Invalid payment: {error}"),
+        },
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn get_surcharge_interpreter_for_simulation(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    algorithm: Option<Program<SurchargeDecisionConfigs>>,
+) -> RouterResult<VirInterpreterBackend<SurchargeDecisionConfigs>> {
+    use common_utils::ext_traits::ValueExt;
+    use euclid::backend::EuclidBackend;
+
+    if let Some(algorithm) = algorithm {
+        return VirInterpreterBackend::with_program(algorithm)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Invalid surcharge rules".to_string(),
+            })
+            .attach_printable("Error initializing DSL interpreter backend");
+    }
+
+    let algorithm_ref: api_models::routing::RoutingAlgorithmRef = merchant_context
+        .get_merchant_account()
+        .routing_algorithm
+        .clone()
+        .map(|value| value.parse_value("routing algorithm"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm")?
+        .unwrap_or_default();
+    let algorithm_id = algorithm_ref.surcharge_config_algo_id.ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No surcharge decision config is configured for the merchant".to_string(),
+        },
+    )?;
+
+    let cached_algorithm = surcharge_decision_configs::ensure_algorithm_cached(
+        &*state.store,
+        merchant_context.get_merchant_account().get_id(),
+        algorithm_id.as_str(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the surcharge decision config")?;
+    Ok(cached_algorithm.get_cached_algorithm().clone())
+}
+
+#[cfg(feature = "v1")]
+pub async fn simulate_surcharge_decision_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SurchargeSimulationRequest,
+) -> RouterResponse<SurchargeSimulationResponse> {
+    let interpreter =
+        get_surcharge_interpreter_for_simulation(&state, &merchant_context, request.algorithm)
+            .await?;
+    let simulation =
+        surcharge_decision_configs::simulate_surcharge_decision(&interpreter, request.payment)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to simulate the surcharge decision config")?;
+    Ok(service_api::ApplicationResponse::Json(simulation))
+}
+
+#[cfg(feature = "v1")]
+pub async fn simulate_surcharge_decision_config_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: SurchargeSimulationBatchRequest,
+) -> RouterResponse<Vec<SurchargeSimulationBatchResponse>> {
+    let interpreter =
+        get_surcharge_interpreter_for_simulation(&state, &merchant_context, request.algorithm)
+            .await?;
+    let simulations = request
+        .payments
+        .into_iter()
+        .map(|batch_payment| {
+            let (simulation, error) = match surcharge_decision_configs::simulate_surcharge_decision(
+                &interpreter,
+                batch_payment.payment,
+            ) {
+                Ok(simulation) => (Some(simulation), None),
+                Err(error) => (None, Some(error.current_context().to_string())),
+            };
+            SurchargeSimulationBatchResponse {
+                line_number: batch_payment.line_number,
+                simulation,
+                error,
+            }
+        })
+        .collect();
+    Ok(service_api::ApplicationResponse::Json(simulations))
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_surcharge_simulation_payments() {
+        let file = "amount,currency,payment_method,payment_method_type,card_network,billing_country
+10000,USD,card,credit,Visa,
+2500,EUR,wallet,apple_pay,,
+";
+
+        let payments = parse_surcharge_simulation_payments(file.as_bytes()).unwrap();
+
+        assert_eq!(payments.len(), 2);
+        // The header row is the first line of the file
+        assert_eq!(payments[0].line_number, 2);
+        assert_eq!(payments[1].line_number, 3);
+        let payments = payments
+            .into_iter()
+            .map(|batch_payment| batch_payment.payment)
+            .collect::<Vec<_>>();
+        assert_eq!(payments[0].amount, MinorUnit::new(10000));
+        assert_eq!(payments[0].currency, common_enums::Currency::USD);
+        assert_eq!(
+            payments[0].payment_method,
+            common_enums::PaymentMethod::Card
+        );
+        assert_eq!(
+            payments[0].card_network,
+            Some(common_enums::CardNetwork::Visa)
+        );
+        assert_eq!(payments[0].billing_country, None);
+        assert_eq!(
+            payments[1].payment_method,
+            common_enums::PaymentMethod::Wallet
+        );
+        assert_eq!(payments[1].card_network, None);
+    }
+
+    #[test]
+    fn test_parse_surcharge_simulation_payments_with_invalid_rows() {
+        let invalid_currency =
+            "amount,currency,payment_method,payment_method_type,card_network,billing_country
+10000,USD,card,credit,Visa,
+10000,DOLLARS,card,credit,Visa,
+";
+        let missing_fields =
+            "amount,currency,payment_method,payment_method_type,card_network,billing_country
+10000,USD
+";
+        let invalid_amount =
+            "amount,currency,payment_method,payment_method_type,card_network,billing_country
+ten,USD,card,credit,Visa,
+";
+
+        for (file, line_number) in [
+            (invalid_currency, 3),
+            (missing_fields, 2),
+            (invalid_amount, 2),
+        ] {
+            let error = parse_surcharge_simulation_payments(file.as_bytes()).unwrap_err();
+            assert!(
+                matches!(
+                    &error,
+                    errors::ApiErrorResponse::InvalidRequestData { message }
+                        if message.starts_with(&format!("Invalid payment on line {line_number}:"))
+                ),
+                "{error:?}"
+            );
+        }
+    }
+}
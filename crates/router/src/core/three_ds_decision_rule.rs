@@ -1,22 +1,27 @@
 pub mod utils;
 
+use api_models::three_ds_decision_rule as api_threedsecure;
 use common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule;
-use common_utils::ext_traits::ValueExt;
+use common_utils::ext_traits::{Encode, StringExt, ValueExt};
+use diesel_models::configs;
 use error_stack::ResultExt;
 use euclid::{
     backend::{self, inputs as dsl_inputs, EuclidBackend},
     frontend::ast,
 };
 use hyperswitch_domain_models::merchant_context::MerchantContext;
-use router_env::{instrument, tracing};
+use router_env::{instrument, metric_attributes, tracing};
 
 use crate::{
     core::{
         errors,
-        errors::{RouterResponse, StorageErrorExt},
+        errors::{RouterResponse, RouterResult, StorageErrorExt},
+        metrics,
     },
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
     services,
-    types::transformers::ForeignFrom,
+    types::{storage::enums as storage_enums, transformers::ForeignFrom},
     SessionState,
 };
 
@@ -24,15 +29,33 @@ use crate::{
 pub async fn execute_three_ds_decision_rule(
     state: SessionState,
     merchant_context: MerchantContext,
-    request: api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
-) -> RouterResponse<api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteResponse> {
+    request: api_threedsecure::ThreeDsDecisionRuleExecuteRequest,
+) -> RouterResponse<api_threedsecure::ThreeDsDecisionRuleExecuteResponse> {
     let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    // Assign the payment to a rule version if a rollout is configured on the requested rule
+    let rollout = find_three_ds_decision_rule_rollout(db, merchant_id, &request.routing_id).await?;
+    let (routing_id, rollout_variant) = match (rollout, request.payment_id.as_ref()) {
+        (Some(rollout), Some(payment_id)) => {
+            let rollout_variant =
+                utils::get_rollout_variant(payment_id, rollout.candidate_percentage)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to assign the payment to a rollout variant")?;
+            let routing_id = match rollout_variant {
+                api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Baseline => {
+                    rollout.baseline_routing_id
+                }
+                api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Candidate => {
+                    rollout.candidate_routing_id
+                }
+            };
+            (routing_id, Some(rollout_variant))
+        }
+        _ => (request.routing_id.clone(), None),
+    };
     // Retrieve the rule from database
     let routing_algorithm = db
-        .find_routing_algorithm_by_algorithm_id_merchant_id(
-            &request.routing_id,
-            merchant_context.get_merchant_account().get_id(),
-        )
+        .find_routing_algorithm_by_algorithm_id_merchant_id(&routing_id, merchant_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
     let algorithm: Algorithm = routing_algorithm
@@ -59,13 +82,236 @@ pub async fn execute_three_ds_decision_rule(
     // Apply PSD2 validations to the decision
     let final_decision =
         utils::apply_psd2_validations_during_execute(result.get_output().get_decision(), &request);
+    let challenge_requested = utils::is_challenge_decision(&final_decision);
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ThreeDsDecisionRuleExecuted {
+                merchant_id: merchant_id.clone(),
+                routing_id: routing_id.clone(),
+                rollout_variant,
+                payment_id: request.payment_id.clone(),
+                challenge_requested,
+            },
+        ))
+        .emit();
+    metrics::THREE_DS_DECISION_RULE_EXECUTION_COUNT.add(
+        1,
+        metric_attributes!(
+            ("routing_id", routing_id.get_string_repr().to_owned()),
+            (
+                "rollout_variant",
+                rollout_variant.map_or("none".to_string(), |variant| variant.to_string())
+            ),
+            (
+                "decision_type",
+                if challenge_requested {
+                    "challenge"
+                } else {
+                    "frictionless"
+                }
+            ),
+        ),
+    );
     // Construct response
-    let response = api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteResponse {
+    let response = api_threedsecure::ThreeDsDecisionRuleExecuteResponse {
         decision: final_decision,
+        routing_id,
+        rollout_variant,
     };
     Ok(services::ApplicationResponse::Json(response))
 }
 
+async fn find_three_ds_decision_rule_rollout(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    baseline_routing_id: &common_utils::id_type::RoutingId,
+) -> RouterResult<Option<api_threedsecure::ThreeDsDecisionRuleRollout>> {
+    let key = merchant_id.get_three_ds_decision_rule_rollout_key(baseline_routing_id);
+    match db.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("ThreeDsDecisionRuleRollout")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse 3DS decision rule rollout")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch 3DS decision rule rollout"),
+    }
+}
+
+async fn validate_three_ds_decision_rule(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    routing_id: &common_utils::id_type::RoutingId,
+) -> RouterResult<()> {
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(routing_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    common_utils::fp_utils::when(
+        routing_algorithm.kind != storage_enums::RoutingAlgorithmKind::ThreeDsDecisionRule,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Routing algorithm {} is not a 3DS decision rule",
+                    routing_id.get_string_repr()
+                ),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_three_ds_decision_rule_rollout(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    request: api_threedsecure::ThreeDsDecisionRuleRolloutRequest,
+) -> RouterResponse<api_threedsecure::ThreeDsDecisionRuleRollout> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    common_utils::fp_utils::when(request.candidate_percentage > 100, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "candidate_percentage must be between 0 and 100".to_string(),
+        })
+    })?;
+    common_utils::fp_utils::when(
+        request.baseline_routing_id == request.candidate_routing_id,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Baseline and candidate rule versions must be different".to_string(),
+            })
+        },
+    )?;
+    validate_three_ds_decision_rule(db, merchant_id, &request.baseline_routing_id).await?;
+    validate_three_ds_decision_rule(db, merchant_id, &request.candidate_routing_id).await?;
+
+    let rollout = api_threedsecure::ThreeDsDecisionRuleRollout {
+        baseline_routing_id: request.baseline_routing_id,
+        candidate_routing_id: request.candidate_routing_id,
+        candidate_percentage: request.candidate_percentage,
+        modified_at: common_utils::date_time::now_unix_timestamp(),
+    };
+    let serialized_rollout = rollout
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize 3DS decision rule rollout")?;
+
+    let key = merchant_id.get_three_ds_decision_rule_rollout_key(&rollout.baseline_routing_id);
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_rollout),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update 3DS decision rule rollout")
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew {
+                key,
+                config: serialized_rollout,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert 3DS decision rule rollout")
+            .map(|_| ()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch 3DS decision rule rollout"),
+    }?;
+
+    Ok(services::ApplicationResponse::Json(rollout))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_three_ds_decision_rule_rollout(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    baseline_routing_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<api_threedsecure::ThreeDsDecisionRuleRollout> {
+    let rollout = find_three_ds_decision_rule_rollout(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+        &baseline_routing_id,
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "3DS decision rule rollout does not exist for the rule".to_string(),
+    })?;
+    Ok(services::ApplicationResponse::Json(rollout))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_three_ds_decision_rule_rollout(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    baseline_routing_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<()> {
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_three_ds_decision_rule_rollout_key(&baseline_routing_id);
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "3DS decision rule rollout does not exist for the rule".to_string(),
+        })?;
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+#[instrument(skip_all)]
+pub async fn record_three_ds_decision_rule_outcome(
+    state: SessionState,
+    merchant_context: MerchantContext,
+    request: api_threedsecure::ThreeDsDecisionRuleOutcomeRequest,
+) -> RouterResponse<()> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    // Outcomes are only accepted for the rules of the merchant, so that the metrics of a rule
+    // cannot be skewed by another merchant
+    validate_three_ds_decision_rule(state.store.as_ref(), merchant_id, &request.routing_id).await?;
+
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ThreeDsDecisionRuleOutcome {
+                merchant_id: merchant_id.clone(),
+                routing_id: request.routing_id.clone(),
+                rollout_variant: request.rollout_variant,
+                authorization_successful: request.authorization_successful,
+            },
+        ))
+        .emit();
+    metrics::THREE_DS_DECISION_RULE_AUTHORIZATION_COUNT.add(
+        1,
+        metric_attributes!(
+            (
+                "routing_id",
+                request.routing_id.get_string_repr().to_owned()
+            ),
+            (
+                "rollout_variant",
+                request
+                    .rollout_variant
+                    .map_or("none".to_string(), |variant| variant.to_string())
+            ),
+            ("authorization_successful", request.authorization_successful),
+        ),
+    );
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Algorithm {
     data: serde_json::Value,
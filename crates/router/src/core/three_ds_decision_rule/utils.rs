@@ -1,5 +1,9 @@
 use api_models::three_ds_decision_rule as api_threedsecure;
 use common_types::three_ds_decision_rule_engine::ThreeDSDecision;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::{CryptoError, CustomResult},
+};
 use euclid::backend::inputs as dsl_inputs;
 
 use crate::{consts::PSD2_COUNTRIES, types::transformers::ForeignFrom};
@@ -38,6 +42,33 @@ pub fn apply_psd2_validations_during_execute(
     }
 }
 
+// function to check whether the decision leads to a 3DS challenge
+pub fn is_challenge_decision(decision: &ThreeDSDecision) -> bool {
+    matches!(
+        decision,
+        ThreeDSDecision::ChallengeRequested | ThreeDSDecision::ChallengePreferred
+    )
+}
+
+// function to deterministically assign a payment to a rollout variant, so that retries of the
+// same payment are always evaluated with the same rule version
+pub fn get_rollout_variant(
+    payment_id: &common_utils::id_type::PaymentId,
+    candidate_percentage: u8,
+) -> CustomResult<api_threedsecure::ThreeDsDecisionRuleRolloutVariant, CryptoError> {
+    let digest = crypto::Sha256.generate_digest(payment_id.get_string_repr().as_bytes())?;
+    let bucket = digest
+        .iter()
+        .take(8)
+        .fold(0u64, |hash, byte| (hash << 8) | u64::from(*byte))
+        % 100;
+    Ok(if bucket < u64::from(candidate_percentage) {
+        api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Candidate
+    } else {
+        api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Baseline
+    })
+}
+
 impl ForeignFrom<api_threedsecure::PaymentData> for dsl_inputs::PaymentInput {
     fn foreign_from(request_payment_data: api_threedsecure::PaymentData) -> Self {
         Self {
@@ -110,6 +141,73 @@ impl ForeignFrom<api_threedsecure::ThreeDsDecisionRuleExecuteRequest> for dsl_in
             acquirer_data: request.acquirer.map(ForeignFrom::foreign_from),
             customer_device_data: request.customer_device.map(ForeignFrom::foreign_from),
             issuer_data: request.issuer.map(ForeignFrom::foreign_from),
+            payout: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_payment_id(index: usize) -> common_utils::id_type::PaymentId {
+        common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Owned(format!(
+            "pay_rollout_{index}"
+        )))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rollout_variant_is_stable_for_a_payment() {
+        for index in 0..50 {
+            let payment_id = get_payment_id(index);
+            let rollout_variant = get_rollout_variant(&payment_id, 30).unwrap();
+            for _ in 0..3 {
+                assert_eq!(
+                    get_rollout_variant(&payment_id, 30).unwrap(),
+                    rollout_variant
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rollout_variant_edges() {
+        for index in 0..200 {
+            let payment_id = get_payment_id(index);
+            assert_eq!(
+                get_rollout_variant(&payment_id, 0).unwrap(),
+                api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Baseline
+            );
+            assert_eq!(
+                get_rollout_variant(&payment_id, 100).unwrap(),
+                api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Candidate
+            );
+        }
+    }
+
+    #[test]
+    fn test_rollout_variant_split() {
+        let candidate_count = (0..1000)
+            .filter(|index| {
+                get_rollout_variant(&get_payment_id(*index), 20).unwrap()
+                    == api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Candidate
+            })
+            .count();
+        assert!((150..=250).contains(&candidate_count));
+
+        // Raising the percentage only moves payments from the baseline to the candidate
+        for index in 0..200 {
+            let payment_id = get_payment_id(index);
+            if get_rollout_variant(&payment_id, 20).unwrap()
+                == api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Candidate
+            {
+                assert_eq!(
+                    get_rollout_variant(&payment_id, 50).unwrap(),
+                    api_threedsecure::ThreeDsDecisionRuleRolloutVariant::Candidate
+                );
+            }
         }
     }
 }
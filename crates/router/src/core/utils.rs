@@ -1,3 +1,4 @@
+pub mod integrity;
 pub mod refunds_transformers;
 pub mod refunds_validator;
 
@@ -209,6 +210,7 @@ pub async fn construct_payout_router_data<'a, F>(
                     phone_country_code: c.phone_country_code,
                 }),
             connector_transfer_method_id,
+            integrity_object: None,
         },
         response: Ok(types::PayoutsResponseData::default()),
         access_token: None,
@@ -0,0 +1,102 @@
+//! Handling of the connector responses whose amount or currency does not match the ones sent to
+//! the connector, as found by the integrity checks of the connector integrations
+
+use common_utils::{errors::IntegrityCheckError, id_type};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    events::audit_events::{AuditEvent, AuditEventType, IntegrityCheckFlow},
+    routes::{metrics, SessionState},
+    types::domain,
+};
+
+/// A connector response which did not match the request sent to the connector
+pub struct IntegrityCheckFailure<'a> {
+    pub flow: IntegrityCheckFlow,
+    pub merchant_id: &'a id_type::MerchantId,
+    pub connector: &'a str,
+    /// Id of the payment, refund, payout or dispute the connector responded about
+    pub resource_id: &'a str,
+    pub error: &'a IntegrityCheckError,
+}
+
+/// Checks whether the integrity check policy of the profile holds the payments and payouts whose
+/// connector response did not match for manual review. Failing to fetch the profile is only
+/// logged, the mismatch is recorded either way.
+#[instrument(skip_all)]
+pub async fn should_quarantine(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<&id_type::ProfileId>,
+) -> bool {
+    let Some(profile_id) = profile_id else {
+        return false;
+    };
+
+    match state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+    {
+        Ok(profile) => profile
+            .integrity_check_policy
+            .is_some_and(|policy| policy.quarantine_on_mismatch),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the profile to apply its integrity check policy"
+            );
+            false
+        }
+    }
+}
+
+/// Records the mismatch as an audit event, so that it can be reconciled, and in the integrity
+/// check metrics
+pub fn record_integrity_check_failure(
+    state: &SessionState,
+    failure: IntegrityCheckFailure<'_>,
+    quarantined: bool,
+) {
+    logger::error!(
+        flow = %failure.flow,
+        connector = failure.connector,
+        resource_id = failure.resource_id,
+        mismatched_fields = failure.error.field_names,
+        quarantined,
+        "Integrity check failed"
+    );
+
+    metrics::INTEGRITY_CHECK_FAILED.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", failure.connector.to_string()),
+            ("merchant_id", failure.merchant_id.clone()),
+            ("flow", failure.flow.to_string()),
+        ),
+    );
+    if quarantined {
+        metrics::INTEGRITY_CHECK_QUARANTINED.add(
+            1,
+            router_env::metric_attributes!(
+                ("connector", failure.connector.to_string()),
+                ("merchant_id", failure.merchant_id.clone()),
+                ("flow", failure.flow.to_string()),
+            ),
+        );
+    }
+
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::IntegrityCheckFailed {
+            flow: failure.flow,
+            merchant_id: failure.merchant_id.clone(),
+            connector: failure.connector.to_string(),
+            resource_id: failure.resource_id.to_string(),
+            connector_transaction_id: failure.error.connector_transaction_id.clone(),
+            mismatched_fields: failure.error.field_names.clone(),
+            quarantined,
+        }))
+        .emit();
+}
@@ -82,6 +82,7 @@ pub async fn check_existence_and_add_domain_to_db(
         status: None,
         connector_wallets_details: Box::new(None),
         additional_merchant_data: Box::new(None),
+        fee_schedule: None,
     };
     #[cfg(feature = "v2")]
     let updated_mca = storage::MerchantConnectorAccountUpdate::Update {
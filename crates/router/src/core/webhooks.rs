@@ -6,6 +6,8 @@ mod incoming_v2;
 mod outgoing;
 #[cfg(feature = "v2")]
 mod outgoing_v2;
+#[cfg(feature = "v1")]
+mod queue;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 pub mod recovery_incoming;
 pub mod types;
@@ -17,8 +19,9 @@ pub mod webhook_events;
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
-        create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        create_event_and_trigger_outgoing_webhook,
+        create_event_with_idempotent_event_id_and_trigger_outgoing_webhook,
+        get_outgoing_webhook_request, trigger_webhook_and_raise_event,
     },
 };
 #[cfg(feature = "v2")]
@@ -15,26 +15,30 @@ use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     mandates::CommonMandateReference,
     payments::{payment_attempt::PaymentAttempt, HeaderPayload},
-    router_request_types::VerifyWebhookSourceRequestData,
+    router_request_types::{DisputeIntegrityObject, VerifyWebhookSourceRequestData},
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
 };
-use hyperswitch_interfaces::webhooks::{IncomingWebhookFlowError, IncomingWebhookRequestDetails};
+use hyperswitch_interfaces::{
+    integrity::FlowIntegrity,
+    webhooks::{IncomingWebhookFlowError, IncomingWebhookRequestDetails},
+};
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId};
 
-use super::{types, utils, MERCHANT_ID};
+use super::{queue, types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics,
         payments::{self, tokenization},
-        refunds, relay, utils as core_utils,
+        refunds, relay,
+        utils::{self as core_utils, integrity},
         webhooks::utils::construct_webhook_router_data,
     },
     db::StorageInterface,
-    events::api_logs::ApiEvent,
+    events::{api_logs::ApiEvent, audit_events::IntegrityCheckFlow},
     logger,
     routes::{
         app::{ReqState, SessionStateInfo},
@@ -70,17 +74,36 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     is_relay_webhook: bool,
 ) -> RouterResponse<serde_json::Value> {
     let start_instant = Instant::now();
-    let (application_response, webhooks_response_tracker, serialized_req) =
-        Box::pin(incoming_webhooks_core::<W>(
+    let request_details = IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
+    let mut webhook_queue = None;
+    let core_result = Box::pin(incoming_webhooks_core::<W>(
+        state.clone(),
+        req_state.clone(),
+        &request_details,
+        merchant_context.clone(),
+        connector_name_or_mca_id,
+        is_relay_webhook,
+        queue::WebhookLocking::Acquire(&mut webhook_queue),
+    ))
+    .await;
+
+    // Webhooks queued while the resource was locked are processed before the lock is freed
+    if let Some((webhook_queue, lock_status)) = webhook_queue {
+        webhook_queue.spawn_processing::<W>(
             state.clone(),
             req_state,
-            req,
             merchant_context.clone(),
-            connector_name_or_mca_id,
-            body.clone(),
-            is_relay_webhook,
-        ))
-        .await?;
+            lock_status,
+        );
+    }
+
+    let (application_response, webhooks_response_tracker, serialized_req) = core_result?;
 
     logger::info!(incoming_webhook_payload = ?serialized_req);
 
@@ -127,14 +150,14 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
+pub(super) async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
     req_state: ReqState,
-    req: &actix_web::HttpRequest,
+    request: &IncomingWebhookRequestDetails<'_>,
     merchant_context: domain::MerchantContext,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
     is_relay_webhook: bool,
+    webhook_locking: queue::WebhookLocking<'_>,
 ) -> errors::RouterResult<(
     services::ApplicationResponse<serde_json::Value>,
     WebhookResponseTracker,
@@ -150,11 +173,11 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         )),
     );
     let mut request_details = IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
+        method: request.method.clone(),
+        uri: request.uri.clone(),
+        headers: request.headers,
+        query_params: request.query_params.clone(),
+        body: request.body,
     };
 
     // Fetch the merchant connector account to get the webhooks source secret
@@ -377,6 +400,43 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 id: profile_id.get_string_repr().to_owned(),
             })?;
 
+        // Webhooks are processed one at a time for a resource, webhooks received while the resource
+        // is locked are acknowledged right away and processed later by the lock holder
+        let held_locking_input = match (is_relay_webhook, webhook_locking) {
+            (false, queue::WebhookLocking::Acquire(locked_webhook_queue)) => {
+                let webhook_queue = queue::IncomingWebhookQueue::new(
+                    &state,
+                    merchant_context.get_merchant_account(),
+                    &object_ref_id,
+                )
+                .await;
+                let lock_status = webhook_queue
+                    .lock_or_enqueue(&state, connector_name_or_mca_id, request)
+                    .await?;
+                let locking_input = webhook_queue.get_locking_input().clone();
+                let is_webhook_queued = lock_status == api_locking::LockStatus::Busy;
+                *locked_webhook_queue = Some((webhook_queue, lock_status));
+
+                if is_webhook_queued {
+                    let response = connector
+                        .get_webhook_api_response(&request_details, None)
+                        .switch()
+                        .attach_printable(
+                            "Could not get incoming webhook api response from connector",
+                        )?;
+
+                    return Ok((
+                        response,
+                        WebhookResponseTracker::NoEffect,
+                        serde_json::Value::Null,
+                    ));
+                }
+                Some(locking_input)
+            }
+            (_, queue::WebhookLocking::Held(locking_input)) => Some(locking_input),
+            (true, queue::WebhookLocking::Acquire(_)) => None,
+        };
+
         // If the incoming webhook is a relay webhook, then we need to trigger the relay webhook flow
         let result_response = if is_relay_webhook {
             let relay_webhook_response = Box::pin(relay_incoming_webhook_flow(
@@ -428,6 +488,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     &connector,
                     &request_details,
                     event_type,
+                    held_locking_input.as_ref(),
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for payments failed"),
@@ -610,6 +671,7 @@ async fn payments_incoming_webhook_flow(
     connector: &ConnectorEnum,
     request_details: &IncomingWebhookRequestDetails<'_>,
     event_type: webhooks::IncomingWebhookEvent,
+    held_locking_input: Option<&api_locking::LockingInput>,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     let consume_or_trigger_flow = if source_verified {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
@@ -626,12 +688,20 @@ async fn payments_incoming_webhook_flow(
             )
             .await?;
 
-            let lock_action = api_locking::LockAction::Hold {
-                input: api_locking::LockingInput {
-                    unique_locking_key: payment_id.get_string_repr().to_owned(),
-                    api_identifier: lock_utils::ApiIdentifier::Payments,
-                    override_lock_retries: None,
-                },
+            let locking_input = api_locking::LockingInput {
+                unique_locking_key: payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: None,
+            };
+            // The payment is not locked again when the webhook already holds its lock
+            let lock_action = if held_locking_input
+                .is_some_and(|held_input| held_input.locks_same_resource(&locking_input))
+            {
+                api_locking::LockAction::NotApplicable
+            } else {
+                api_locking::LockAction::Hold {
+                    input: locking_input,
+                }
             };
 
             lock_action
@@ -1206,11 +1276,13 @@ async fn get_or_update_dispute_object(
                 organization_id: organization_id.clone(),
                 dispute_currency: Some(dispute_details.currency),
             };
-            state
+            let dispute = state
                 .store
                 .insert_dispute(new_dispute.clone())
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            schedule_dispute_deadline_task(db, &dispute, business_profile).await;
+            Ok(dispute)
         }
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
@@ -1235,13 +1307,39 @@ async fn get_or_update_dispute_object(
                 challenge_required_by: dispute_details.challenge_required_by,
                 connector_updated_at: dispute_details.updated_at,
             };
-            db.update_dispute(dispute, update_dispute)
+            let previous_challenge_required_by = dispute.challenge_required_by;
+            let previous_dispute_status = dispute.dispute_status;
+            let updated_dispute = db
+                .update_dispute(dispute, update_dispute)
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            if updated_dispute.challenge_required_by != previous_challenge_required_by
+                || updated_dispute.dispute_status != previous_dispute_status
+            {
+                schedule_dispute_deadline_task(db, &updated_dispute, business_profile).await;
+            }
+            Ok(updated_dispute)
         }
     }
 }
 
+/// Reminders of the evidence deadline are not critical to the processing of the webhook
+async fn schedule_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    dispute: &diesel_models::dispute::Dispute,
+    business_profile: &domain::Profile,
+) {
+    disputes::schedule_dispute_deadline_task(db, dispute, business_profile)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to schedule dispute deadline task in process tracker"
+            )
+        })
+        .ok();
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn external_authentication_incoming_webhook_flow(
@@ -1662,6 +1760,20 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        // The dispute is raised by the connector, a mismatch is only recorded for reconciliation
+        if let Err(err) = check_dispute_integrity(&payment_attempt, &dispute_object) {
+            integrity::record_integrity_check_failure(
+                &state,
+                integrity::IntegrityCheckFailure {
+                    flow: IntegrityCheckFlow::Dispute,
+                    merchant_id: merchant_context.get_merchant_account().get_id(),
+                    connector: &dispute_object.connector,
+                    resource_id: &dispute_object.dispute_id,
+                    error: &err,
+                },
+                false,
+            );
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -1691,6 +1803,29 @@ async fn disputes_incoming_webhook_flow(
     }
 }
 
+/// Checks the amount and currency of the dispute against the disputed payment, a dispute can be
+/// raised for part of the payment but not for more than it
+fn check_dispute_integrity(
+    payment_attempt: &storage::PaymentAttempt,
+    dispute: &storage::Dispute,
+) -> Result<(), common_utils::errors::IntegrityCheckError> {
+    let Some(currency) = payment_attempt.currency else {
+        return Ok(());
+    };
+
+    DisputeIntegrityObject::compare(
+        DisputeIntegrityObject {
+            dispute_amount: payment_attempt.get_total_amount(),
+            currency,
+        },
+        DisputeIntegrityObject {
+            dispute_amount: dispute.dispute_amount,
+            currency: dispute.dispute_currency.unwrap_or(currency),
+        },
+        Some(dispute.connector_dispute_id.clone()),
+    )
+}
+
 #[instrument(skip_all)]
 async fn bank_transfer_webhook_flow(
     state: SessionState,
@@ -1773,7 +1908,7 @@ async fn bank_transfer_webhook_flow(
     }
 }
 
-async fn get_payment_id(
+pub(super) async fn get_payment_id(
     db: &dyn StorageInterface,
     payment_id: &api::PaymentIdType,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -59,10 +59,46 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+    );
+    Box::pin(
+        create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+            state,
+            merchant_context,
+            business_profile,
+            event_type,
+            event_class,
+            primary_object_id,
+            primary_object_type,
+            content,
+            primary_object_created_at,
+            idempotent_event_id,
+        ),
+    )
+    .await
+}
+
+/// Same as [`create_event_and_trigger_outgoing_webhook`], for resources that can raise the same
+/// event type more than once, whose callers decide which events are duplicates of one another
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
     let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
 
     if !state.conf.webhooks.outgoing_enabled
@@ -1026,6 +1062,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1070,5 +1111,11 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
@@ -0,0 +1,764 @@
+use actix_web::http::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, Uri,
+};
+use api_models::{payments as payment_types, webhooks};
+use base64::Engine;
+use common_utils::{ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use tracing_futures::Instrument;
+
+use super::{incoming, types, MERCHANT_ID};
+use crate::{
+    consts,
+    core::{
+        api_locking::{LockAction, LockStatus, LockingInput},
+        errors::{self, RouterResult},
+        metrics,
+    },
+    routes::{
+        app::{ReqState, SessionStateInfo},
+        lock_utils, SessionState,
+    },
+    types::domain,
+};
+
+const INCOMING_WEBHOOK_QUEUE_PREFIX: &str = "INCOMING_WEBHOOK_QUEUE";
+const INCOMING_WEBHOOK_DEAD_LETTER_QUEUE_PREFIX: &str = "INCOMING_WEBHOOK_DLQ";
+const INCOMING_WEBHOOK_QUEUE_WAITER_PREFIX: &str = "INCOMING_WEBHOOK_QUEUE_WAITER";
+
+/// Locking of the resource that an incoming webhook refers to
+pub(super) enum WebhookLocking<'a> {
+    /// The resource is locked while the webhook is processed. If it is already locked, the webhook
+    /// is queued instead. The queue of the resource is handed back to the caller in both the cases,
+    /// along with the status of the lock.
+    Acquire(&'a mut Option<(IncomingWebhookQueue, LockStatus)>),
+    /// The resource is already locked by the caller, which is processing the queued webhooks
+    Held(LockingInput),
+}
+
+/// Incoming webhook stored in the queue of its resource, to be processed once the resource is free
+#[derive(Debug, Serialize, Deserialize)]
+struct QueuedIncomingWebhook {
+    connector_name_or_mca_id: String,
+    method: String,
+    uri: String,
+    headers: Vec<(String, Vec<u8>)>,
+    query_params: String,
+    /// Base64 encoded body, as received from the connector
+    body: String,
+    /// Number of failed attempts to process the webhook
+    retry_count: u8,
+}
+
+impl QueuedIncomingWebhook {
+    fn new(
+        connector_name_or_mca_id: &str,
+        request_details: &IncomingWebhookRequestDetails<'_>,
+    ) -> Self {
+        Self {
+            connector_name_or_mca_id: connector_name_or_mca_id.to_owned(),
+            method: request_details.method.to_string(),
+            uri: request_details.uri.to_string(),
+            headers: request_details
+                .headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+                .collect(),
+            query_params: request_details.query_params.clone(),
+            body: consts::BASE64_ENGINE.encode(request_details.body),
+            retry_count: 0,
+        }
+    }
+
+    async fn process<W: types::OutgoingWebhookType>(
+        &self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_context: &domain::MerchantContext,
+        locking_input: &LockingInput,
+    ) -> RouterResult<()> {
+        let method = Method::from_bytes(self.method.as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid method in queued incoming webhook")?;
+        let uri = self
+            .uri
+            .parse::<Uri>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid uri in queued incoming webhook")?;
+        let headers = self
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let name = HeaderName::from_bytes(name.as_bytes())
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Invalid header name in queued incoming webhook")?;
+                let value = HeaderValue::from_bytes(value)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Invalid header value in queued incoming webhook")?;
+                Ok((name, value))
+            })
+            .collect::<RouterResult<HeaderMap>>()?;
+        let body = consts::BASE64_ENGINE
+            .decode(&self.body)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid body in queued incoming webhook")?;
+
+        let request_details = IncomingWebhookRequestDetails {
+            method,
+            uri,
+            headers: &headers,
+            body: &body,
+            query_params: self.query_params.clone(),
+        };
+
+        Box::pin(incoming::incoming_webhooks_core::<W>(
+            state.clone(),
+            req_state.clone(),
+            &request_details,
+            merchant_context.clone(),
+            &self.connector_name_or_mca_id,
+            false,
+            WebhookLocking::Held(locking_input.clone()),
+        ))
+        .await
+        .map(|(_, webhook_response_tracker, _)| {
+            logger::info!(
+                ?webhook_response_tracker,
+                "Processed queued incoming webhook"
+            );
+        })
+    }
+}
+
+/// Queue of incoming webhooks received for a resource while it was locked. The queue is processed
+/// in order by the holder of the lock of the resource, before the lock is freed.
+#[derive(Debug, Clone)]
+pub(super) struct IncomingWebhookQueue {
+    merchant_id: id_type::MerchantId,
+    locking_input: LockingInput,
+}
+
+impl IncomingWebhookQueue {
+    pub(super) async fn new(
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        object_reference_id: &webhooks::ObjectReferenceId,
+    ) -> Self {
+        // Payment webhooks lock the payment they refer to, however the payment is identified
+        let payment_id = match object_reference_id {
+            webhooks::ObjectReferenceId::PaymentId(payment_id_type) => incoming::get_payment_id(
+                state.store.as_ref(),
+                payment_id_type,
+                merchant_account.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                logger::warn!(?error, "Failed to find the payment of the incoming webhook")
+            })
+            .ok(),
+            _ => None,
+        };
+
+        Self {
+            merchant_id: merchant_account.get_id().clone(),
+            locking_input: get_locking_input(object_reference_id, payment_id.as_ref()),
+        }
+    }
+
+    pub(super) fn get_locking_input(&self) -> &LockingInput {
+        &self.locking_input
+    }
+
+    fn get_queue_key(&self, prefix: &str) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            prefix,
+            self.merchant_id.get_string_repr(),
+            self.locking_input.api_identifier,
+            self.locking_input.unique_locking_key
+        )
+    }
+
+    fn get_lock_action(&self) -> LockAction {
+        LockAction::QueueWithOk {
+            input: self.locking_input.clone(),
+        }
+    }
+
+    /// Locks the resource, or queues the webhook if the resource is already locked
+    #[instrument(skip_all)]
+    pub(super) async fn lock_or_enqueue(
+        &self,
+        state: &SessionState,
+        connector_name_or_mca_id: &str,
+        request_details: &IncomingWebhookRequestDetails<'_>,
+    ) -> RouterResult<LockStatus> {
+        match self
+            .get_lock_action()
+            .perform_locking_action(state, self.merchant_id.clone())
+            .await
+        {
+            Ok(()) => return Ok(LockStatus::Acquired),
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::ResourceBusy
+                ) => {}
+            Err(error) => return Err(error),
+        }
+
+        let webhook = QueuedIncomingWebhook::new(connector_name_or_mca_id, request_details);
+        self.push(state, INCOMING_WEBHOOK_QUEUE_PREFIX, &webhook)
+            .await?;
+
+        logger::info!(locking_input = ?self.locking_input, "Incoming webhook queued");
+        metrics::WEBHOOK_INCOMING_QUEUED_COUNT.add(
+            1,
+            router_env::metric_attributes!((MERCHANT_ID, self.merchant_id.clone())),
+        );
+
+        Ok(LockStatus::Busy)
+    }
+
+    /// Processes the queued webhooks in the background. If the lock is held by someone else, it is
+    /// waited for until it is freed or expires, by at most one waiter for the resource.
+    pub(super) fn spawn_processing<W: types::OutgoingWebhookType>(
+        self,
+        state: SessionState,
+        req_state: ReqState,
+        merchant_context: domain::MerchantContext,
+        lock_status: LockStatus,
+    ) {
+        tokio::spawn(
+            async move {
+                match lock_status {
+                    LockStatus::Acquired => {
+                        self.process_queued_webhooks::<W>(&state, &req_state, &merchant_context)
+                            .await
+                    }
+                    LockStatus::Busy => {
+                        self.wait_and_process_queued_webhooks::<W>(
+                            &state,
+                            &req_state,
+                            &merchant_context,
+                        )
+                        .await
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
+
+    /// Waits for the lock of the resource and processes the queued webhooks once it is acquired.
+    /// Only one waiter is run per resource, the webhooks queued while a waiter is running are
+    /// processed by it.
+    #[instrument(skip_all)]
+    async fn wait_and_process_queued_webhooks<W: types::OutgoingWebhookType>(
+        &self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_context: &domain::MerchantContext,
+    ) {
+        if !self.try_claim_waiter(state).await {
+            return;
+        }
+
+        loop {
+            if let Err(error) = self
+                .get_wait_lock_action(state)
+                .perform_locking_action(state, self.merchant_id.clone())
+                .await
+            {
+                // The webhooks stay queued to be processed by the next lock holder
+                logger::error!(?error, "Failed to lock the queue of incoming webhooks");
+                self.release_waiter(state).await;
+                return;
+            }
+
+            self.process_queued_webhooks::<W>(state, req_state, merchant_context)
+                .await;
+            self.release_waiter(state).await;
+
+            // A webhook queued after the queue was drained, which saw this waiter running, is left
+            // to this waiter
+            let is_queue_empty = self.is_empty(state).await.unwrap_or_else(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the length of incoming webhook queue"
+                );
+                true
+            });
+            if is_queue_empty || !self.try_claim_waiter(state).await {
+                return;
+            }
+        }
+    }
+
+    /// Claims the waiter of the resource, the claim expires along with the lock it waits for
+    async fn try_claim_waiter(&self, state: &SessionState) -> bool {
+        let Ok(redis_conn) = state.store().get_redis_conn() else {
+            return false;
+        };
+        let waiter_expiry_seconds = state
+            .conf()
+            .lock_settings
+            .redis_lock_expiry_seconds
+            .saturating_mul(2);
+
+        redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &self
+                    .get_queue_key(INCOMING_WEBHOOK_QUEUE_WAITER_PREFIX)
+                    .as_str()
+                    .into(),
+                state.get_request_id(),
+                Some(i64::from(waiter_expiry_seconds)),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to claim incoming webhook queue waiter")
+            })
+            .is_ok_and(|reply| reply == redis_interface::SetnxReply::KeySet)
+    }
+
+    async fn release_waiter(&self, state: &SessionState) {
+        let Ok(redis_conn) = state.store().get_redis_conn() else {
+            return;
+        };
+
+        redis_conn
+            .delete_key(
+                &self
+                    .get_queue_key(INCOMING_WEBHOOK_QUEUE_WAITER_PREFIX)
+                    .as_str()
+                    .into(),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to release incoming webhook queue waiter")
+            })
+            .ok();
+    }
+
+    /// Waits for as long as the lock can be held by someone else before it expires
+    fn get_wait_lock_action(&self, state: &SessionState) -> LockAction {
+        let lock_settings = &state.conf().lock_settings;
+        let lock_retries = lock_settings
+            .redis_lock_expiry_seconds
+            .saturating_mul(1000)
+            .checked_div(lock_settings.delay_between_retries_in_milliseconds)
+            .unwrap_or(lock_settings.lock_retries)
+            .saturating_add(1);
+
+        LockAction::Hold {
+            input: LockingInput {
+                override_lock_retries: Some(lock_retries),
+                ..self.locking_input.clone()
+            },
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn process_queued_webhooks<W: types::OutgoingWebhookType>(
+        &self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_context: &domain::MerchantContext,
+    ) {
+        loop {
+            match self.pop(state).await {
+                Ok(Some(webhook)) => {
+                    self.process_with_retries::<W>(state, req_state, merchant_context, webhook)
+                        .await
+                }
+                Ok(None) => break,
+                Err(error) => {
+                    logger::error!(?error, "Failed to fetch queued incoming webhook");
+                    break;
+                }
+            }
+        }
+
+        self.get_lock_action()
+            .free_lock_action(state, self.merchant_id.clone())
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to free the queue of incoming webhooks")
+            })
+            .ok();
+    }
+
+    /// Retries a failing webhook before moving on to the next one, so that the webhooks are
+    /// processed in the order in which they were received
+    async fn process_with_retries<W: types::OutgoingWebhookType>(
+        &self,
+        state: &SessionState,
+        req_state: &ReqState,
+        merchant_context: &domain::MerchantContext,
+        mut webhook: QueuedIncomingWebhook,
+    ) {
+        let queue_settings = &state.conf().webhooks.queue;
+        loop {
+            let Err(error) = webhook
+                .process::<W>(state, req_state, merchant_context, &self.locking_input)
+                .await
+            else {
+                return;
+            };
+
+            webhook.retry_count = webhook.retry_count.saturating_add(1);
+            logger::error!(
+                ?error,
+                retry_count = webhook.retry_count,
+                "Failed to process queued incoming webhook"
+            );
+
+            if webhook.retry_count > queue_settings.max_retries {
+                self.push(state, INCOMING_WEBHOOK_DEAD_LETTER_QUEUE_PREFIX, &webhook)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to dead letter queued incoming webhook")
+                    })
+                    .ok();
+                metrics::WEBHOOK_QUEUED_DEAD_LETTERED_COUNT.add(
+                    1,
+                    router_env::metric_attributes!((MERCHANT_ID, self.merchant_id.clone())),
+                );
+                return;
+            }
+
+            metrics::WEBHOOK_QUEUED_PROCESSING_RETRY_COUNT.add(
+                1,
+                router_env::metric_attributes!((MERCHANT_ID, self.merchant_id.clone())),
+            );
+            actix_web::rt::time::sleep(tokio::time::Duration::from_millis(u64::from(
+                queue_settings.retry_delay_in_milliseconds,
+            )))
+            .await;
+        }
+    }
+
+    async fn push(
+        &self,
+        state: &SessionState,
+        prefix: &str,
+        webhook: &QueuedIncomingWebhook,
+    ) -> RouterResult<()> {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let queue_key = self.get_queue_key(prefix);
+        let serialized_webhook = serde_json::to_string(webhook)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize incoming webhook")?;
+
+        redis_conn
+            .append_elements_to_list(&queue_key.as_str().into(), serialized_webhook)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to queue incoming webhook")?;
+
+        redis_conn
+            .set_expiry(
+                &queue_key.as_str().into(),
+                i64::from(state.conf().webhooks.queue.ttl_in_seconds),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry of incoming webhook queue")
+    }
+
+    async fn is_empty(&self, state: &SessionState) -> RouterResult<bool> {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        redis_conn
+            .get_list_length(
+                &self
+                    .get_queue_key(INCOMING_WEBHOOK_QUEUE_PREFIX)
+                    .as_str()
+                    .into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the length of incoming webhook queue")
+            .map(|length| length == 0)
+    }
+
+    async fn pop(&self, state: &SessionState) -> RouterResult<Option<QueuedIncomingWebhook>> {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        redis_conn
+            .lpop_list_elements(
+                &self
+                    .get_queue_key(INCOMING_WEBHOOK_QUEUE_PREFIX)
+                    .as_str()
+                    .into(),
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to pop queued incoming webhook")?
+            .into_iter()
+            .next()
+            .map(|webhook| {
+                webhook
+                    .parse_struct::<QueuedIncomingWebhook>("QueuedIncomingWebhook")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+            })
+            .transpose()
+    }
+}
+
+/// Webhooks referring to a payment share the lock of the payment APIs, so that they do not race
+/// with API calls for the same payment. `payment_id` is the payment the webhook was resolved to,
+/// if it could be found.
+fn get_locking_input(
+    object_reference_id: &webhooks::ObjectReferenceId,
+    payment_id: Option<&id_type::PaymentId>,
+) -> LockingInput {
+    let (api_identifier, unique_locking_key) = match (object_reference_id, payment_id) {
+        (webhooks::ObjectReferenceId::PaymentId(_), Some(payment_id)) => (
+            lock_utils::ApiIdentifier::Payments,
+            payment_id.get_string_repr().to_owned(),
+        ),
+        (webhooks::ObjectReferenceId::PaymentId(payment_id), None) => match payment_id {
+            payment_types::PaymentIdType::PaymentIntentId(payment_id) => (
+                lock_utils::ApiIdentifier::Payments,
+                payment_id.get_string_repr().to_owned(),
+            ),
+            payment_types::PaymentIdType::ConnectorTransactionId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("connector_transaction_{id}"),
+            ),
+            payment_types::PaymentIdType::PaymentAttemptId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("payment_attempt_{id}"),
+            ),
+            payment_types::PaymentIdType::PreprocessingId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("preprocessing_{id}"),
+            ),
+        },
+        (webhooks::ObjectReferenceId::RefundId(refund_id), _) => match refund_id {
+            webhooks::RefundIdType::RefundId(id) => {
+                (lock_utils::ApiIdentifier::Webhooks, format!("refund_{id}"))
+            }
+            webhooks::RefundIdType::ConnectorRefundId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("connector_refund_{id}"),
+            ),
+        },
+        (webhooks::ObjectReferenceId::MandateId(mandate_id), _) => match mandate_id {
+            webhooks::MandateIdType::MandateId(id) => {
+                (lock_utils::ApiIdentifier::Webhooks, format!("mandate_{id}"))
+            }
+            webhooks::MandateIdType::ConnectorMandateId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("connector_mandate_{id}"),
+            ),
+        },
+        (webhooks::ObjectReferenceId::ExternalAuthenticationID(authentication_id), _) => {
+            match authentication_id {
+                webhooks::AuthenticationIdType::AuthenticationId(id) => (
+                    lock_utils::ApiIdentifier::Webhooks,
+                    format!("authentication_{id}"),
+                ),
+                webhooks::AuthenticationIdType::ConnectorAuthenticationId(id) => (
+                    lock_utils::ApiIdentifier::Webhooks,
+                    format!("connector_authentication_{id}"),
+                ),
+            }
+        }
+        #[cfg(feature = "payouts")]
+        (webhooks::ObjectReferenceId::PayoutId(payout_id), _) => match payout_id {
+            webhooks::PayoutIdType::PayoutAttemptId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("payout_attempt_{id}"),
+            ),
+            webhooks::PayoutIdType::ConnectorPayoutId(id) => (
+                lock_utils::ApiIdentifier::Webhooks,
+                format!("connector_payout_{id}"),
+            ),
+        },
+    };
+
+    LockingInput {
+        unique_locking_key,
+        api_identifier,
+        override_lock_retries: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    fn payment_id(id: &'static str) -> id_type::PaymentId {
+        id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(id)).expect("invalid payment id")
+    }
+
+    #[test]
+    fn test_payment_webhooks_lock_the_resolved_payment() {
+        let resolved_payment_id = payment_id("pay_123");
+        let references = [
+            payment_types::PaymentIdType::PaymentIntentId(resolved_payment_id.clone()),
+            payment_types::PaymentIdType::ConnectorTransactionId("txn_123".to_string()),
+            payment_types::PaymentIdType::PaymentAttemptId("pay_123_1".to_string()),
+            payment_types::PaymentIdType::PreprocessingId("pre_123".to_string()),
+        ];
+
+        for reference in references {
+            let locking_input = get_locking_input(
+                &webhooks::ObjectReferenceId::PaymentId(reference),
+                Some(&resolved_payment_id),
+            );
+            assert_eq!(
+                locking_input.api_identifier,
+                lock_utils::ApiIdentifier::Payments
+            );
+            assert_eq!(locking_input.unique_locking_key, "pay_123");
+        }
+    }
+
+    #[test]
+    fn test_unresolved_payment_webhooks_lock_the_reference() {
+        let locking_input = get_locking_input(
+            &webhooks::ObjectReferenceId::PaymentId(payment_types::PaymentIdType::PaymentIntentId(
+                payment_id("pay_123"),
+            )),
+            None,
+        );
+        assert_eq!(
+            locking_input.api_identifier,
+            lock_utils::ApiIdentifier::Payments
+        );
+        assert_eq!(locking_input.unique_locking_key, "pay_123");
+
+        let locking_input = get_locking_input(
+            &webhooks::ObjectReferenceId::PaymentId(
+                payment_types::PaymentIdType::ConnectorTransactionId("txn_123".to_string()),
+            ),
+            None,
+        );
+        assert_eq!(
+            locking_input.api_identifier,
+            lock_utils::ApiIdentifier::Webhooks
+        );
+        assert_eq!(
+            locking_input.unique_locking_key,
+            "connector_transaction_txn_123"
+        );
+    }
+
+    #[test]
+    fn test_non_payment_webhooks_ignore_the_payment() {
+        let locking_input = get_locking_input(
+            &webhooks::ObjectReferenceId::RefundId(webhooks::RefundIdType::RefundId(
+                "ref_123".to_string(),
+            )),
+            Some(&payment_id("pay_123")),
+        );
+        assert_eq!(
+            locking_input.api_identifier,
+            lock_utils::ApiIdentifier::Webhooks
+        );
+        assert_eq!(locking_input.unique_locking_key, "refund_ref_123");
+    }
+
+    #[test]
+    fn test_held_payment_lock_is_not_taken_again() {
+        let held_locking_input = get_locking_input(
+            &webhooks::ObjectReferenceId::PaymentId(
+                payment_types::PaymentIdType::ConnectorTransactionId("txn_123".to_string()),
+            ),
+            Some(&payment_id("pay_123")),
+        );
+        let payment_locking_input = LockingInput {
+            unique_locking_key: "pay_123".to_string(),
+            api_identifier: lock_utils::ApiIdentifier::Payments,
+            override_lock_retries: None,
+        };
+        assert!(held_locking_input.locks_same_resource(&payment_locking_input));
+
+        let other_payment_locking_input = LockingInput {
+            unique_locking_key: "pay_456".to_string(),
+            ..payment_locking_input
+        };
+        assert!(!held_locking_input.locks_same_resource(&other_payment_locking_input));
+    }
+
+    #[test]
+    fn test_queue_key_is_scoped_to_merchant_and_resource() {
+        let webhook_queue = IncomingWebhookQueue {
+            merchant_id: id_type::MerchantId::default(),
+            locking_input: get_locking_input(
+                &webhooks::ObjectReferenceId::PaymentId(
+                    payment_types::PaymentIdType::PaymentIntentId(payment_id("pay_123")),
+                ),
+                None,
+            ),
+        };
+        let queue_key = webhook_queue.get_queue_key(INCOMING_WEBHOOK_QUEUE_PREFIX);
+        let waiter_key = webhook_queue.get_queue_key(INCOMING_WEBHOOK_QUEUE_WAITER_PREFIX);
+
+        assert!(queue_key.starts_with("INCOMING_WEBHOOK_QUEUE_"));
+        assert!(queue_key.ends_with("_payments_pay_123"));
+        assert_ne!(queue_key, waiter_key);
+    }
+
+    #[test]
+    fn test_queued_webhook_round_trip() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-signature"),
+            HeaderValue::from_static("signature"),
+        );
+        let body = br#"{"id":"evt_123"}"#;
+        let request_details = IncomingWebhookRequestDetails {
+            method: Method::POST,
+            uri: "/webhooks/merchant_123/stripe?a=b"
+                .parse::<Uri>()
+                .expect("invalid uri"),
+            headers: &headers,
+            body,
+            query_params: "a=b".to_string(),
+        };
+
+        let webhook = QueuedIncomingWebhook::new("stripe", &request_details);
+        let serialized_webhook =
+            serde_json::to_string(&webhook).expect("failed to serialize queued webhook");
+        let queued_webhook = serialized_webhook
+            .parse_struct::<QueuedIncomingWebhook>("QueuedIncomingWebhook")
+            .expect("failed to deserialize queued webhook");
+
+        assert_eq!(queued_webhook.connector_name_or_mca_id, "stripe");
+        assert_eq!(queued_webhook.method, "POST");
+        assert_eq!(queued_webhook.uri, "/webhooks/merchant_123/stripe?a=b");
+        assert_eq!(queued_webhook.query_params, "a=b");
+        assert_eq!(queued_webhook.retry_count, 0);
+        assert_eq!(
+            queued_webhook.headers,
+            vec![("x-signature".to_string(), b"signature".to_vec())]
+        );
+        assert_eq!(
+            consts::BASE64_ENGINE
+                .decode(&queued_webhook.body)
+                .expect("invalid body"),
+            body.to_vec()
+        );
+    }
+}
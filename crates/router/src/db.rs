@@ -21,6 +21,7 @@ pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
 pub mod kafka_store;
+pub mod local_vault;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -34,6 +35,7 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_trace;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -106,6 +108,7 @@ pub trait StorageInterface:
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + local_vault::LocalVaultInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -130,6 +133,7 @@ pub trait StorageInterface:
     + RequestIdStore
     + business_profile::ProfileInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + routing_trace::RoutingTraceInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
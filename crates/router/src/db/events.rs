@@ -729,6 +729,14 @@ impl EventInterface for MockDb {
                 event_to_update.is_overall_delivery_successful =
                     Some(is_overall_delivery_successful)
             }
+            domain::EventUpdate::RedactionUpdate { request, response } => {
+                if let Some(request) = request {
+                    event_to_update.request = Some(request.into());
+                }
+                if let Some(response) = response {
+                    event_to_update.response = Some(response.into());
+                }
+            }
         }
 
         event_to_update
@@ -71,6 +71,7 @@ use crate::{
         generic_link::GenericLinkInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        local_vault::LocalVaultInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
@@ -85,7 +86,13 @@ use crate::{
         AccountsStorageInterface, CommonStorageInterface, GlobalStorageInterface,
         MasterKeyInterface, StorageInterface,
     },
-    services::{kafka::KafkaProducer, Store},
+    services::{
+        kafka::{
+            redaction_event::{KafkaRedactionEvent, RedactedObjectType},
+            KafkaProducer,
+        },
+        Store,
+    },
     types::{domain, storage, AccessToken},
 };
 #[derive(Debug, Clone, Serialize)]
@@ -112,6 +119,23 @@ impl KafkaStore {
             tenant_id,
         }
     }
+
+    /// Publishes the redaction of the personal data of a record to the analytics topics. Failures
+    /// are only logged, as for the other analytics events.
+    fn log_redaction(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        object_type: RedactedObjectType,
+        object_id: &str,
+    ) {
+        let redaction = KafkaRedactionEvent::new(merchant_id, object_type, object_id);
+        if let Err(er) = self
+            .kafka_producer
+            .log_redaction(&redaction, self.tenant_id.clone())
+        {
+            logger::error!(message="Failed to add redaction event {redaction:?}", error_message=?er);
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -294,6 +318,15 @@ impl CardsInfoInterface for KafkaStore {
         self.diesel_store.get_card_info(card_iin).await
     }
 
+    async fn get_card_info_with_bin_range_fallback(
+        &self,
+        card_iin: &str,
+    ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
+        self.diesel_store
+            .get_card_info_with_bin_range_fallback(card_iin)
+            .await
+    }
+
     async fn add_card_info(
         &self,
         data: storage::CardInfo,
@@ -308,6 +341,55 @@ impl CardsInfoInterface for KafkaStore {
     ) -> CustomResult<storage::CardInfo, errors::StorageError> {
         self.diesel_store.update_card_info(card_iin, data).await
     }
+
+    async fn insert_card_bin_import(
+        &self,
+        card_bin_import: storage::CardBinImportNew,
+        ranges: Vec<storage::CardBinRange>,
+    ) -> CustomResult<storage::CardBinImport, errors::StorageError> {
+        self.diesel_store
+            .insert_card_bin_import(card_bin_import, ranges)
+            .await
+    }
+
+    async fn find_card_bin_import_by_version(
+        &self,
+        version: i32,
+    ) -> CustomResult<storage::CardBinImport, errors::StorageError> {
+        self.diesel_store
+            .find_card_bin_import_by_version(version)
+            .await
+    }
+
+    async fn find_active_card_bin_import(
+        &self,
+    ) -> CustomResult<Option<storage::CardBinImport>, errors::StorageError> {
+        self.diesel_store.find_active_card_bin_import().await
+    }
+
+    async fn list_card_bin_imports(
+        &self,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::CardBinImport>, errors::StorageError> {
+        self.diesel_store.list_card_bin_imports(limit, offset).await
+    }
+
+    async fn activate_card_bin_import(
+        &self,
+        version: i32,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store.activate_card_bin_import(version).await
+    }
+
+    async fn list_card_bin_ranges_by_import_version(
+        &self,
+        version: i32,
+    ) -> CustomResult<Vec<storage::CardBinRange>, errors::StorageError> {
+        self.diesel_store
+            .list_card_bin_ranges_by_import_version(version)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -862,7 +944,9 @@ impl EventInterface for KafkaStore {
         event: domain::EventUpdate,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::Event, errors::StorageError> {
-        self.diesel_store
+        let is_redaction = matches!(event, domain::EventUpdate::RedactionUpdate { .. });
+        let event = self
+            .diesel_store
             .update_event_by_merchant_id_event_id(
                 state,
                 merchant_id,
@@ -870,7 +954,13 @@ impl EventInterface for KafkaStore {
                 event,
                 merchant_key_store,
             )
-            .await
+            .await?;
+
+        if is_redaction {
+            self.log_redaction(merchant_id, RedactedObjectType::WebhookEvent, event_id);
+        }
+
+        Ok(event)
     }
 
     async fn count_initial_events_by_constraints(
@@ -895,6 +985,62 @@ impl EventInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl LocalVaultInterface for KafkaStore {
+    async fn find_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        self.diesel_store
+            .find_local_vault_entry_by_merchant_id_vault_id(merchant_id, vault_id)
+            .await
+    }
+
+    async fn find_local_vault_entry_by_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Option<storage::LocalVault>, errors::StorageError> {
+        self.diesel_store
+            .find_local_vault_entry_by_fingerprint_id(merchant_id, customer_id, fingerprint_id)
+            .await
+    }
+
+    async fn insert_local_vault_entry(
+        &self,
+        new: storage::LocalVaultNew,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        self.diesel_store.insert_local_vault_entry(new).await
+    }
+
+    async fn update_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+        local_vault_update: storage::LocalVaultUpdate,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        self.diesel_store
+            .update_local_vault_entry_by_merchant_id_vault_id(
+                merchant_id,
+                vault_id,
+                local_vault_update,
+            )
+            .await
+    }
+
+    async fn delete_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        self.diesel_store
+            .delete_local_vault_entry_by_merchant_id_vault_id(merchant_id, vault_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl LockerMockUpInterface for KafkaStore {
     async fn find_locker_by_card_id(
@@ -1523,6 +1669,10 @@ impl PaymentAttemptInterface for KafkaStore {
         payment_attempt: storage::PaymentAttemptUpdate,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentAttempt, errors::StorageError> {
+        let is_redaction = matches!(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::CustomerDetailsRedactionUpdate { .. }
+        );
         let attempt = self
             .diesel_store
             .update_payment_attempt_with_attempt_id(this.clone(), payment_attempt, storage_scheme)
@@ -1536,6 +1686,14 @@ impl PaymentAttemptInterface for KafkaStore {
             logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
         }
 
+        if is_redaction {
+            self.log_redaction(
+                &attempt.merchant_id,
+                RedactedObjectType::PaymentAttempt,
+                &attempt.attempt_id,
+            );
+        }
+
         Ok(attempt)
     }
 
@@ -1853,6 +2011,11 @@ impl PaymentIntentInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentIntent, errors::StorageError> {
+        #[cfg(feature = "v1")]
+        let is_redaction = matches!(
+            payment_intent,
+            storage::PaymentIntentUpdate::CustomerDetailsRedactionUpdate { .. }
+        );
         let intent = self
             .diesel_store
             .update_payment_intent(
@@ -1877,6 +2040,15 @@ impl PaymentIntentInterface for KafkaStore {
             logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
         };
 
+        #[cfg(feature = "v1")]
+        if is_redaction {
+            self.log_redaction(
+                &intent.merchant_id,
+                RedactedObjectType::PaymentIntent,
+                intent.payment_id.get_string_repr(),
+            );
+        }
+
         Ok(intent)
     }
 
@@ -2193,6 +2365,33 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_list_by_merchant_id_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_list_by_merchant_id_status(
+                state,
+                key_store,
+                merchant_id,
+                status,
+                limit,
+                offset,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
         &self,
@@ -2262,6 +2461,29 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+                state,
+                key_store,
+                merchant_id,
+                network_token_requestor_reference_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn insert_payment_method(
         &self,
         state: &KeyManagerState,
@@ -0,0 +1,392 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait LocalVaultInterface {
+    async fn find_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError>;
+
+    async fn find_local_vault_entry_by_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Option<storage::LocalVault>, errors::StorageError>;
+
+    async fn insert_local_vault_entry(
+        &self,
+        new: storage::LocalVaultNew,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError>;
+
+    async fn update_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+        local_vault_update: storage::LocalVaultUpdate,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError>;
+
+    async fn delete_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LocalVaultInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LocalVault::find_by_merchant_id_vault_id(&conn, merchant_id, vault_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_local_vault_entry_by_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Option<storage::LocalVault>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LocalVault::find_optional_by_merchant_id_customer_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            fingerprint_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_local_vault_entry(
+        &self,
+        new: storage::LocalVaultNew,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        new.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+        local_vault_update: storage::LocalVaultUpdate,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LocalVault::update_by_merchant_id_vault_id(
+            &conn,
+            merchant_id,
+            vault_id,
+            local_vault_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LocalVault::delete_by_merchant_id_vault_id(&conn, merchant_id, vault_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LocalVaultInterface for MockDb {
+    async fn find_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        self.local_vault_entries
+            .lock()
+            .await
+            .iter()
+            .find(|entry| entry.merchant_id == *merchant_id && entry.vault_id == vault_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No local vault entry found for vault_id = {vault_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_local_vault_entry_by_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        fingerprint_id: &str,
+    ) -> CustomResult<Option<storage::LocalVault>, errors::StorageError> {
+        Ok(self
+            .local_vault_entries
+            .lock()
+            .await
+            .iter()
+            .find(|entry| {
+                entry.merchant_id == *merchant_id
+                    && entry.customer_id.as_ref() == Some(customer_id)
+                    && entry.fingerprint_id == fingerprint_id
+            })
+            .cloned())
+    }
+
+    async fn insert_local_vault_entry(
+        &self,
+        new: storage::LocalVaultNew,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let mut local_vault_entries = self.local_vault_entries.lock().await;
+
+        if local_vault_entries.iter().any(|entry| {
+            entry.merchant_id == new.merchant_id
+                && (entry.vault_id == new.vault_id
+                    || (entry.customer_id.is_some()
+                        && entry.customer_id == new.customer_id
+                        && entry.fingerprint_id == new.fingerprint_id))
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "local_vault",
+                key: Some(new.vault_id.clone()),
+            })?;
+        }
+
+        let local_vault_entry = storage::LocalVault {
+            vault_id: new.vault_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            fingerprint_id: new.fingerprint_id,
+            data_encryption_key: new.data_encryption_key,
+            encrypted_data: new.encrypted_data,
+            created_at: new.created_at,
+        };
+        local_vault_entries.push(local_vault_entry.clone());
+
+        Ok(local_vault_entry)
+    }
+
+    async fn update_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+        local_vault_update: storage::LocalVaultUpdate,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let mut local_vault_entries = self.local_vault_entries.lock().await;
+
+        let customer_id = local_vault_entries
+            .iter()
+            .find(|entry| entry.merchant_id == *merchant_id && entry.vault_id == vault_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No local vault entry found for vault_id = {vault_id}"
+            )))?
+            .customer_id
+            .clone();
+
+        if customer_id.is_some()
+            && local_vault_entries.iter().any(|entry| {
+                entry.merchant_id == *merchant_id
+                    && entry.vault_id != vault_id
+                    && entry.customer_id == customer_id
+                    && entry.fingerprint_id == local_vault_update.fingerprint_id
+            })
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "local_vault",
+                key: Some(vault_id.to_owned()),
+            })?;
+        }
+
+        let local_vault_entry = local_vault_entries
+            .iter_mut()
+            .find(|entry| entry.merchant_id == *merchant_id && entry.vault_id == vault_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No local vault entry found for vault_id = {vault_id}"
+            )))?;
+        local_vault_entry.fingerprint_id = local_vault_update.fingerprint_id;
+        local_vault_entry.data_encryption_key = local_vault_update.data_encryption_key;
+        local_vault_entry.encrypted_data = local_vault_update.encrypted_data;
+
+        Ok(local_vault_entry.clone())
+    }
+
+    async fn delete_local_vault_entry_by_merchant_id_vault_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::LocalVault, errors::StorageError> {
+        let mut local_vault_entries = self.local_vault_entries.lock().await;
+
+        let position = local_vault_entries
+            .iter()
+            .position(|entry| entry.merchant_id == *merchant_id && entry.vault_id == vault_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No local vault entry found for vault_id = {vault_id}"
+            )))?;
+
+        Ok(local_vault_entries.remove(position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(clippy::unwrap_used)]
+    mod mockdb_local_vault_interface {
+        use common_utils::{generate_customer_id_of_default_length, id_type};
+
+        use crate::{
+            db::{local_vault::LocalVaultInterface, MockDb},
+            types::storage,
+        };
+
+        fn create_local_vault_new(
+            vault_id: &str,
+            customer_id: Option<id_type::CustomerId>,
+        ) -> storage::LocalVaultNew {
+            storage::LocalVaultNew {
+                vault_id: vault_id.into(),
+                merchant_id: id_type::MerchantId::default(),
+                customer_id,
+                fingerprint_id: "fingerprint_id".into(),
+                data_encryption_key: vec![1, 2, 3],
+                encrypted_data: vec![4, 5, 6],
+                created_at: common_utils::date_time::now(),
+            }
+        }
+
+        #[tokio::test]
+        async fn insert_find_and_delete_local_vault_entry() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+                .await
+                .expect("Failed to create Mock store");
+            let merchant_id = id_type::MerchantId::default();
+            let customer_id = generate_customer_id_of_default_length();
+
+            let created_entry = mockdb
+                .insert_local_vault_entry(create_local_vault_new(
+                    "card_1",
+                    Some(customer_id.clone()),
+                ))
+                .await
+                .unwrap();
+
+            // Entries with the same fingerprint are not duplicated for a customer
+            assert!(mockdb
+                .insert_local_vault_entry(create_local_vault_new(
+                    "card_2",
+                    Some(customer_id.clone())
+                ))
+                .await
+                .is_err());
+
+            let found_entry = mockdb
+                .find_local_vault_entry_by_fingerprint_id(
+                    &merchant_id,
+                    &customer_id,
+                    "fingerprint_id",
+                )
+                .await
+                .unwrap();
+            assert_eq!(Some(created_entry.clone()), found_entry);
+
+            // Entries are replaced in place, keeping their reference
+            let updated_entry = mockdb
+                .update_local_vault_entry_by_merchant_id_vault_id(
+                    &merchant_id,
+                    "card_1",
+                    storage::LocalVaultUpdate {
+                        fingerprint_id: "fingerprint_id".into(),
+                        data_encryption_key: vec![7, 8, 9],
+                        encrypted_data: vec![10, 11, 12],
+                    },
+                )
+                .await
+                .unwrap();
+            assert_eq!(updated_entry.vault_id, "card_1");
+            assert_eq!(updated_entry.encrypted_data, vec![10, 11, 12]);
+            let created_entry = updated_entry;
+
+            let deleted_entry = mockdb
+                .delete_local_vault_entry_by_merchant_id_vault_id(&merchant_id, "card_1")
+                .await
+                .unwrap();
+            assert_eq!(created_entry, deleted_entry);
+
+            assert!(mockdb
+                .find_local_vault_entry_by_merchant_id_vault_id(&merchant_id, "card_1")
+                .await
+                .is_err());
+        }
+
+        #[tokio::test]
+        async fn local_vault_entries_are_scoped_to_the_merchant() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+                .await
+                .expect("Failed to create Mock store");
+            let other_merchant_id =
+                id_type::MerchantId::try_from(std::borrow::Cow::from("other_merchant")).unwrap();
+
+            mockdb
+                .insert_local_vault_entry(create_local_vault_new("vault_1", None))
+                .await
+                .unwrap();
+
+            // Entries without a customer are not deduplicated by their fingerprint
+            mockdb
+                .insert_local_vault_entry(create_local_vault_new("vault_2", None))
+                .await
+                .unwrap();
+
+            // The same vault id can be used by another merchant, but not by the same one
+            assert!(mockdb
+                .insert_local_vault_entry(create_local_vault_new("vault_1", None))
+                .await
+                .is_err());
+            mockdb
+                .insert_local_vault_entry(storage::LocalVaultNew {
+                    merchant_id: other_merchant_id.clone(),
+                    ..create_local_vault_new("vault_1", None)
+                })
+                .await
+                .unwrap();
+
+            mockdb
+                .delete_local_vault_entry_by_merchant_id_vault_id(&other_merchant_id, "vault_1")
+                .await
+                .unwrap();
+            assert!(mockdb
+                .find_local_vault_entry_by_merchant_id_vault_id(
+                    &id_type::MerchantId::default(),
+                    "vault_1"
+                )
+                .await
+                .is_ok());
+        }
+    }
+}
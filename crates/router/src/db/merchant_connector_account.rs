@@ -1258,6 +1258,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
             version: t.version,
+            fee_schedule: t.fee_schedule,
         };
         accounts.push(account.clone());
         account
@@ -1679,6 +1680,7 @@ mod merchant_connector_account_cache_tests {
             ),
             additional_merchant_data: None,
             version: common_types::consts::API_VERSION,
+            fee_schedule: None,
         };
 
         db.insert_merchant_connector_account(key_manager_state, mca.clone(), &merchant_key)
@@ -0,0 +1,129 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RoutingTraceInterface {
+    async fn insert_routing_trace(
+        &self,
+        routing_trace: storage::RoutingTraceNew,
+    ) -> CustomResult<storage::RoutingTrace, errors::StorageError>;
+
+    async fn find_routing_traces_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RoutingTrace>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoutingTraceInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_routing_trace(
+        &self,
+        routing_trace: storage::RoutingTraceNew,
+    ) -> CustomResult<storage::RoutingTrace, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_trace
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_routing_traces_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RoutingTrace>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingTrace::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingTraceInterface for MockDb {
+    async fn insert_routing_trace(
+        &self,
+        routing_trace: storage::RoutingTraceNew,
+    ) -> CustomResult<storage::RoutingTrace, errors::StorageError> {
+        let mut routing_traces = self.routing_traces.lock().await;
+
+        if routing_traces
+            .iter()
+            .any(|existing| existing.id == routing_trace.id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "routing_trace",
+                key: Some(routing_trace.id.clone()),
+            })?;
+        }
+
+        let routing_trace = storage::RoutingTrace {
+            id: routing_trace.id,
+            payment_id: routing_trace.payment_id,
+            attempt_id: routing_trace.attempt_id,
+            merchant_id: routing_trace.merchant_id,
+            profile_id: routing_trace.profile_id,
+            algorithm_id: routing_trace.algorithm_id,
+            routed_connector: routing_trace.routed_connector,
+            trace: routing_trace.trace,
+            created_at: routing_trace.created_at,
+        };
+        routing_traces.push(routing_trace.clone());
+
+        Ok(routing_trace)
+    }
+
+    async fn find_routing_traces_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RoutingTrace>, errors::StorageError> {
+        let mut routing_traces = self
+            .routing_traces
+            .lock()
+            .await
+            .iter()
+            .filter(|routing_trace| {
+                routing_trace.merchant_id == *merchant_id && routing_trace.payment_id == *payment_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        routing_traces.sort_by_key(|routing_trace| routing_trace.created_at);
+
+        Ok(routing_traces)
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingTraceInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_routing_trace(
+        &self,
+        routing_trace: storage::RoutingTraceNew,
+    ) -> CustomResult<storage::RoutingTrace, errors::StorageError> {
+        self.diesel_store.insert_routing_trace(routing_trace).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_routing_traces_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RoutingTrace>, errors::StorageError> {
+        self.diesel_store
+            .find_routing_traces_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
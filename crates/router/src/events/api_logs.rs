@@ -8,10 +8,18 @@ use time::OffsetDateTime;
 use super::EventType;
 #[cfg(feature = "dummy_connector")]
 use crate::routes::dummy_connector::types::{
-    DummyConnectorPaymentCompleteRequest, DummyConnectorPaymentConfirmRequest,
-    DummyConnectorPaymentRequest, DummyConnectorPaymentResponse,
-    DummyConnectorPaymentRetrieveRequest, DummyConnectorRefundRequest,
-    DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorCaptureRequest, DummyConnectorDisputeRequest,
+    DummyConnectorDisputeResolveRequest, DummyConnectorDisputeResponse,
+    DummyConnectorDisputeRetrieveRequest, DummyConnectorIncrementalAuthorizationRequest,
+    DummyConnectorMandatePaymentRequest, DummyConnectorPaymentCompleteRequest,
+    DummyConnectorPaymentConfirmRequest, DummyConnectorPaymentRequest,
+    DummyConnectorPaymentResponse, DummyConnectorPaymentRetrieveRequest,
+    DummyConnectorRefundRequest, DummyConnectorRefundResponse, DummyConnectorRefundRetrieveRequest,
+    DummyConnectorScenario, DummyConnectorScenarioRequest, DummyConnectorVoidRequest,
+};
+#[cfg(all(feature = "dummy_connector", feature = "payouts"))]
+use crate::routes::dummy_connector::types::{
+    DummyConnectorPayoutRequest, DummyConnectorPayoutResponse, DummyConnectorPayoutRetrieveRequest,
 };
 use crate::{
     core::payments::PaymentsRedirectResponseData,
@@ -127,7 +135,27 @@ impl_api_event_type!(
         DummyConnectorPaymentConfirmRequest,
         DummyConnectorRefundRetrieveRequest,
         DummyConnectorRefundResponse,
-        DummyConnectorRefundRequest
+        DummyConnectorRefundRequest,
+        DummyConnectorCaptureRequest,
+        DummyConnectorVoidRequest,
+        DummyConnectorIncrementalAuthorizationRequest,
+        DummyConnectorMandatePaymentRequest,
+        DummyConnectorDisputeRequest,
+        DummyConnectorDisputeRetrieveRequest,
+        DummyConnectorDisputeResolveRequest,
+        DummyConnectorDisputeResponse,
+        DummyConnectorScenarioRequest,
+        DummyConnectorScenario
+    )
+);
+
+#[cfg(all(feature = "dummy_connector", feature = "payouts"))]
+impl_api_event_type!(
+    Miscellaneous,
+    (
+        DummyConnectorPayoutRequest,
+        DummyConnectorPayoutResponse,
+        DummyConnectorPayoutRetrieveRequest
     )
 );
 
@@ -1,4 +1,4 @@
-use api_models::payments::Amount;
+use api_models::{payments::Amount, three_ds_decision_rule::ThreeDsDecisionRuleRolloutVariant};
 use common_utils::types::MinorUnit;
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
@@ -39,6 +39,70 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    LocalVaultAccess {
+        operation: LocalVaultOperation,
+        merchant_id: common_utils::id_type::MerchantId,
+        vault_id: String,
+    },
+    DataSubjectRequest {
+        operation: DataSubjectRequestOperation,
+        merchant_id: common_utils::id_type::MerchantId,
+        customer_id: common_utils::id_type::CustomerId,
+        request_id: String,
+    },
+    IntegrityCheckFailed {
+        flow: IntegrityCheckFlow,
+        merchant_id: common_utils::id_type::MerchantId,
+        connector: String,
+        resource_id: String,
+        connector_transaction_id: Option<String>,
+        mismatched_fields: String,
+        quarantined: bool,
+    },
+    ThreeDsDecisionRuleExecuted {
+        merchant_id: common_utils::id_type::MerchantId,
+        routing_id: common_utils::id_type::RoutingId,
+        rollout_variant: Option<ThreeDsDecisionRuleRolloutVariant>,
+        payment_id: Option<common_utils::id_type::PaymentId>,
+        challenge_requested: bool,
+    },
+    ThreeDsDecisionRuleOutcome {
+        merchant_id: common_utils::id_type::MerchantId,
+        routing_id: common_utils::id_type::RoutingId,
+        rollout_variant: Option<ThreeDsDecisionRuleRolloutVariant>,
+        authorization_successful: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalVaultOperation {
+    Store,
+    Retrieve,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataSubjectRequestOperation {
+    Export,
+    ErasureScheduled,
+    ErasureCancelled,
+    Erasure,
+}
+
+/// The connector flow whose response did not match the request sent to the connector
+#[derive(Debug, Clone, Copy, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IntegrityCheckFlow {
+    Payment,
+    CaptureSync,
+    IncrementalAuthorization,
+    Refund,
+    RefundSync,
+    Payout,
+    Dispute,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +147,11 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::LocalVaultAccess { .. } => "local_vault_access",
+            AuditEventType::DataSubjectRequest { .. } => "data_subject_request",
+            AuditEventType::IntegrityCheckFailed { .. } => "integrity_check_failed",
+            AuditEventType::ThreeDsDecisionRuleExecuted { .. } => "three_ds_decision_rule_executed",
+            AuditEventType::ThreeDsDecisionRuleOutcome { .. } => "three_ds_decision_rule_outcome",
         };
         format!(
             "{event_type}-{}",
@@ -798,6 +798,84 @@ pub async fn connector_list_profile(
     .await
 }
 
+/// Merchant Connector - List Circuit Breakers
+///
+/// List the circuit breaker state of each Merchant Connector of the merchant
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCircuitBreakersList))]
+pub async fn connector_circuit_breaker_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorCircuitBreakersList;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.to_owned(),
+        |state, _auth, merchant_id, _| list_connector_circuit_breakers(state, merchant_id),
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Reset Circuit Breaker
+///
+/// Close the circuit of a Merchant Connector, so that it is considered during connector selection again
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCircuitBreakerReset))]
+pub async fn connector_circuit_breaker_reset(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorCircuitBreakerReset;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            reset_connector_circuit_breaker(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Connector - Update
 ///
 /// To update an existing Merchant Connector. Helpful in enabling / disabling different payment methods and other settings for the connector etc.
@@ -79,7 +79,8 @@ use crate::analytics::AnalyticsProvider;
 use crate::errors::RouterResult;
 #[cfg(feature = "v1")]
 use crate::routes::cards_info::{
-    card_iin_info, create_cards_info, migrate_cards_info, update_cards_info,
+    card_bin_import_diff, card_iin_info, create_cards_info, import_card_bins,
+    list_card_bin_imports, migrate_cards_info, rollback_card_bin_import, update_cards_info,
 };
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::feature_matrix;
@@ -121,6 +122,7 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
@@ -513,6 +515,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            encryption_client: self.encryption_client.clone(),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -584,7 +587,47 @@ impl DummyConnector {
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(
+                web::resource("/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture)),
+            )
+            .service(
+                web::resource("/{payment_id}/void").route(web::post().to(dummy_connector_void)),
+            )
+            .service(
+                web::resource("/{payment_id}/incremental_authorization")
+                    .route(web::post().to(dummy_connector_incremental_authorization)),
+            )
+            .service(
+                web::resource("/mandates/{mandate_id}/payment")
+                    .route(web::post().to(dummy_connector_mandate_payment)),
+            )
+            .service(
+                web::resource("/{payment_id}/dispute")
+                    .route(web::post().to(dummy_connector_dispute)),
+            )
+            .service(
+                web::resource("/disputes/{dispute_id}")
+                    .route(web::get().to(dummy_connector_dispute_data)),
+            )
+            .service(
+                web::resource("/disputes/{dispute_id}/resolve")
+                    .route(web::post().to(dummy_connector_resolve_dispute)),
+            )
+            .service(
+                web::resource("/scenarios")
+                    .route(web::post().to(dummy_connector_register_scenario)),
             );
+        #[cfg(feature = "payouts")]
+        {
+            routes_with_restricted_access = routes_with_restricted_access
+                .service(web::resource("/payouts").route(web::post().to(dummy_connector_payout)))
+                .service(
+                    web::resource("/payouts/{payout_id}")
+                        .route(web::get().to(dummy_connector_payout_data)),
+                );
+        }
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
             .service(
@@ -747,6 +790,14 @@ impl Payments {
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments::payments_manual_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/eligibility_explanation")
+                        .route(web::get().to(payments::payments_eligibility_explanation)),
+                )
+                .service(
+                    web::resource("/{payment_id}/routing_trace")
+                        .route(web::get().to(payments::payments_routing_trace)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -928,6 +979,15 @@ impl Routing {
                     .route(web::get().to(routing::retrieve_surcharge_decision_manager_config))
                     .route(web::delete().to(routing::delete_surcharge_decision_manager_config)),
             )
+            .service(
+                web::resource("/decision/surcharge/simulate")
+                    .route(web::post().to(routing::simulate_surcharge_decision_manager_config)),
+            )
+            .service(
+                web::resource("/decision/surcharge/simulate/batch").route(
+                    web::post().to(routing::simulate_surcharge_decision_manager_config_batch),
+                ),
+            )
             .service(
                 web::resource("/default/profile/{profile_id}").route(web::post().to(
                     |state, req, path, payload| {
@@ -1127,6 +1187,24 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/network_tokens")
+                        .route(web::get().to(payment_methods::list_customer_network_tokens_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_export")
+                        .route(web::post().to(customers::customers_data_export)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_export/{export_id}")
+                        .route(web::get().to(customers::customers_data_export_retrieve)),
+                )
+                .service(
+                    web::resource("/{customer_id}/erasure")
+                        .route(web::post().to(customers::customers_erasure_schedule))
+                        .route(web::get().to(customers::customers_erasure_retrieve))
+                        .route(web::delete().to(customers::customers_erasure_cancel)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
         }
 
@@ -1329,6 +1407,34 @@ impl PaymentMethods {
                     ),
                 ));
         }
+        #[cfg(all(feature = "olap", feature = "v1"))]
+        {
+            route = route.service(
+                web::scope("/account_updater")
+                    .service(
+                        web::resource("/schedule")
+                            .route(web::post().to(payment_methods::account_updater_schedule))
+                            .route(web::delete().to(payment_methods::account_updater_unschedule)),
+                    )
+                    .service(
+                        web::resource("/batches")
+                            .route(web::post().to(payment_methods::account_updater_batch_create)),
+                    )
+                    .service(web::resource("/batches/{batch_id}/request_file").route(
+                        web::get().to(payment_methods::account_updater_request_file_retrieve),
+                    ))
+                    .service(web::resource("/batches/{batch_id}/response_file").route(
+                        web::post().to(payment_methods::account_updater_response_file_upload),
+                    )),
+            );
+        }
+        #[cfg(all(feature = "oltp", feature = "v1"))]
+        {
+            route = route.service(
+                web::resource("/network_token/webhooks/{merchant_id}")
+                    .route(web::post().to(payment_methods::network_token_lifecycle_webhook_api)),
+            );
+        }
         #[cfg(feature = "oltp")]
         {
             route = route
@@ -1645,6 +1751,16 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(connector_create))
                         .route(web::get().to(connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/circuit_breakers")
+                        .route(web::get().to(connector_circuit_breaker_list)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/circuit_breaker/reset",
+                    )
+                    .route(web::post().to(connector_circuit_breaker_reset)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(connector_retrieve))
@@ -1906,6 +2022,10 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/{dispute_id}/draft")
+                    .route(web::post().to(disputes::draft_dispute_evidence)),
+            )
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
@@ -1922,6 +2042,14 @@ impl Cards {
             .service(web::resource("/create").route(web::post().to(create_cards_info)))
             .service(web::resource("/update").route(web::post().to(update_cards_info)))
             .service(web::resource("/update-batch").route(web::post().to(migrate_cards_info)))
+            .service(web::resource("/import").route(web::post().to(import_card_bins)))
+            .service(web::resource("/imports").route(web::get().to(list_card_bin_imports)))
+            .service(
+                web::resource("/imports/rollback").route(web::post().to(rollback_card_bin_import)),
+            )
+            .service(
+                web::resource("/imports/{version}/diff").route(web::get().to(card_bin_import_diff)),
+            )
             .service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
     }
 }
@@ -2194,6 +2322,19 @@ impl ThreeDsDecisionRule {
                 web::resource("/execute")
                     .route(web::post().to(three_ds_decision_rule::execute_decision_rule)),
             )
+            .service(
+                web::resource("/rollout")
+                    .route(web::post().to(three_ds_decision_rule::upsert_decision_rule_rollout)),
+            )
+            .service(
+                web::resource("/rollout/{routing_id}")
+                    .route(web::get().to(three_ds_decision_rule::retrieve_decision_rule_rollout))
+                    .route(web::delete().to(three_ds_decision_rule::delete_decision_rule_rollout)),
+            )
+            .service(
+                web::resource("/outcome")
+                    .route(web::post().to(three_ds_decision_rule::record_decision_rule_outcome)),
+            )
     }
 }
 
@@ -131,3 +131,94 @@ pub async fn migrate_cards_info(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinImport))]
+pub async fn import_card_bins(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<cards_info::bin_import::CardBinImportForm>,
+) -> HttpResponse {
+    let flow = Flow::CardBinImport;
+    let payload = match cards_info::bin_import::get_card_bin_import_request(form) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| cards_info::bin_import::import_card_bins(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinImportList))]
+pub async fn list_card_bin_imports(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<cards_info_api_types::CardBinImportListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::CardBinImportList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| {
+            cards_info::bin_import::list_card_bin_imports(state, constraints)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinImportRollback))]
+pub async fn rollback_card_bin_import(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<cards_info_api_types::CardBinImportRollbackRequest>,
+) -> HttpResponse {
+    let flow = Flow::CardBinImportRollback;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| cards_info::bin_import::rollback_card_bin_import(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CardBinImportDiff))]
+pub async fn card_bin_import_diff(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<i32>,
+    query: web::Query<cards_info_api_types::CardBinImportDiffRequest>,
+) -> HttpResponse {
+    let flow = Flow::CardBinImportDiff;
+    let payload = cards_info_api_types::CardBinImportDiffRequest {
+        version: path.into_inner(),
+        ..query.into_inner()
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| cards_info::bin_import::get_card_bin_import_diff(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
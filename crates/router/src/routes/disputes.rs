@@ -480,6 +480,60 @@ pub async fn retrieve_dispute_evidence(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Disputes - Draft Evidence for a Dispute
+///
+/// To pre-fill the evidence of a dispute from the payment data and generate a summary document for review
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/{dispute_id}/draft",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute evidence was drafted successfully", body = DisputeEvidenceDraftResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Draft Evidence for a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceDraft))]
+pub async fn draft_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputesEvidenceDraft;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::draft_evidence(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Disputes - Delete Evidence attached to a Dispute
 ///
 /// To delete an evidence file attached to a dispute
@@ -12,6 +12,7 @@ mod core;
 mod errors;
 pub mod types;
 mod utils;
+mod webhooks;
 
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCreate))]
@@ -68,7 +69,8 @@ pub async fn dummy_connector_payment(
     req: actix_web::HttpRequest,
     json_payload: web::Json<types::DummyConnectorPaymentRequest>,
 ) -> impl actix_web::Responder {
-    let payload = json_payload.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.scenario = utils::get_scenario_header(req.headers());
     let flow = types::Flow::DummyPaymentCreate;
     Box::pin(api::server_wrap(
         flow,
@@ -115,6 +117,7 @@ pub async fn dummy_connector_refund(
     let flow = types::Flow::DummyRefundCreate;
     let mut payload = json_payload.into_inner();
     payload.payment_id = Some(path.into_inner());
+    payload.scenario = utils::get_scenario_header(req.headers());
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -148,3 +151,232 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCapture))]
+pub async fn dummy_connector_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorCaptureRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentCapture;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    payload.scenario = utils::get_scenario_header(req.headers());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::capture_payment(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentVoid))]
+pub async fn dummy_connector_void(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentVoid;
+    let payload = types::DummyConnectorVoidRequest {
+        payment_id: path.into_inner(),
+        scenario: utils::get_scenario_header(req.headers()),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::void_payment(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentIncrementalAuthorization))]
+pub async fn dummy_connector_incremental_authorization(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorIncrementalAuthorizationRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentIncrementalAuthorization;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    payload.scenario = utils::get_scenario_header(req.headers());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::incremental_authorization(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyMandatePayment))]
+pub async fn dummy_connector_mandate_payment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorMandatePaymentRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyMandatePayment;
+    let mut payload = json_payload.into_inner();
+    payload.mandate_id = Some(path.into_inner());
+    payload.scenario = utils::get_scenario_header(req.headers());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::mandate_payment(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeCreate))]
+pub async fn dummy_connector_dispute(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorDisputeRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeCreate;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::dispute_payment(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeRetrieve))]
+pub async fn dummy_connector_dispute_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeRetrieve;
+    let dispute_id = path.into_inner();
+    let payload = types::DummyConnectorDisputeRetrieveRequest { dispute_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::dispute_data(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeResolve))]
+pub async fn dummy_connector_resolve_dispute(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorDisputeResolveRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeResolve;
+    let mut payload = json_payload.into_inner();
+    payload.dispute_id = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::resolve_dispute(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutCreate))]
+pub async fn dummy_connector_payout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorPayoutRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutCreate;
+    let mut payload = json_payload.into_inner();
+    payload.scenario = utils::get_scenario_header(req.headers());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payout(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutRetrieve))]
+pub async fn dummy_connector_payout_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutRetrieve;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutRetrieveRequest { payout_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payout_data(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioCreate))]
+pub async fn dummy_connector_register_scenario(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorScenarioRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioCreate;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::register_scenario(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -3,3 +3,15 @@ pub const REFUND_ID_PREFIX: &str = "dummy_ref";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
 pub const DUMMY_CONNECTOR_UPI_FAILURE_VPA_ID: &str = "failure@upi";
 pub const DUMMY_CONNECTOR_UPI_SUCCESS_VPA_ID: &str = "success@upi";
+pub const CAPTURE_ID_PREFIX: &str = "dummy_cap";
+pub const MANDATE_ID_PREFIX: &str = "dummy_mandate";
+pub const DISPUTE_ID_PREFIX: &str = "dummy_dispute";
+pub const PAYOUT_ID_PREFIX: &str = "dummy_payout";
+pub const WEBHOOK_ID_PREFIX: &str = "dummy_evt";
+pub const SCENARIO_KEY_PREFIX: &str = "dummy_scenario";
+/// Header through which a call is answered with the failures it lists, as comma separated
+/// `decline_code=<code>`, `latency=<milliseconds>`, `server_error` or `timeout` entries
+pub const DUMMY_CONNECTOR_SCENARIO_HEADER: &str = "x-dummy-connector-scenario";
+/// Header holding the hex encoded HMAC-SHA256 of the webhook body
+pub const DUMMY_CONNECTOR_WEBHOOK_SIGNATURE_HEADER: &str = "x-dummy-connector-signature";
+pub const DUMMY_CONNECTOR_WEBHOOK_TIMEOUT_SECS: u64 = 5;
@@ -1,8 +1,8 @@
 use app::SessionState;
 use common_utils::generate_id_with_default_len;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 
-use super::{errors, types, utils};
+use super::{errors, types, utils, webhooks};
 use crate::{
     routes::{app, dummy_connector::consts},
     services::api,
@@ -20,24 +20,128 @@ pub async fn payment(
     )
     .await;
 
+    let scenario = utils::get_scenario(
+        &state,
+        req.scenario.as_deref(),
+        Some(&req.payment_method_data),
+    )
+    .await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
-    let payment_data =
-        types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
+    let payment_data = process_payment_attempt(&state, payment_attempt, None).await?;
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+async fn process_payment_attempt(
+    state: &SessionState,
+    payment_attempt: types::DummyConnectorPaymentAttempt,
+    mandate_id: Option<String>,
+) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
+    let setup_mandate = payment_attempt.payment_request.setup_mandate;
+    let payment_method_data = payment_attempt.payment_request.payment_method_data.clone();
+    let mut payment_data =
+        types::DummyConnectorPaymentData::process_payment_attempt(state, payment_attempt)?;
+    payment_data.mandate_id = mandate_id;
+
+    if setup_mandate {
+        let mandate = types::DummyConnectorMandate {
+            id: generate_id_with_default_len(consts::MANDATE_ID_PREFIX),
+            payment_id: payment_data.payment_id.clone(),
+            connector: payment_data.connector.clone(),
+            payment_method_data,
+            created: common_utils::date_time::now(),
+        };
+        payment_data.mandate_id = Some(mandate.id.clone());
+        utils::store_data_in_redis(
+            state,
+            mandate.id.clone(),
+            mandate,
+            state.conf.dummy_connector.payment_ttl,
+        )
+        .await?;
+    }
 
     utils::store_data_in_redis(
-        &state,
+        state,
         payment_data.attempt_id.clone(),
         payment_data.payment_id.clone(),
         state.conf.dummy_connector.authorize_ttl,
     )
     .await?;
     utils::store_data_in_redis(
-        &state,
+        state,
         payment_data.payment_id.get_string_repr().to_owned(),
         payment_data.clone(),
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+
+    webhooks::send_payment_webhook(state, &payment_data);
+    Ok(payment_data)
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn mandate_payment(
+    state: SessionState,
+    req: types::DummyConnectorMandatePaymentRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let mandate_id = req
+        .mandate_id
+        .get_required_value("mandate_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "mandate_id",
+        })?;
+    let mandate: types::DummyConnectorMandate = utils::get_data_from_redis(
+        &state,
+        &mandate_id,
+        "DummyConnectorMandate",
+        errors::DummyConnectorErrors::MandateNotFound,
+    )
+    .await?;
+
+    utils::get_payment_data_from_payment_id(
+        &state,
+        mandate.payment_id.get_string_repr().to_owned(),
+    )
+    .await
+    .change_context(errors::DummyConnectorErrors::MandateNotActive)?
+    .is_mandate_active()?;
+
+    let scenario = utils::get_scenario(
+        &state,
+        req.scenario.as_deref(),
+        Some(&mandate.payment_method_data),
+    )
+    .await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
+    let mut payment_attempt: types::DummyConnectorPaymentAttempt =
+        types::DummyConnectorPaymentRequest {
+            amount: req.amount,
+            currency: req.currency,
+            payment_method_data: mandate.payment_method_data,
+            return_url: None,
+            connector: mandate.connector,
+            capture_method: req.capture_method,
+            setup_mandate: false,
+            webhook_url: req.webhook_url,
+            scenario: None,
+        }
+        .into();
+    payment_attempt.off_session = true;
+    let payment_data = process_payment_attempt(&state, payment_attempt, Some(mandate.id)).await?;
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
@@ -97,12 +201,6 @@ pub async fn payment_complete(
 
     let payment_data = utils::get_payment_data_by_attempt_id(&state, req.attempt_id.clone()).await;
 
-    let payment_status = if req.confirm {
-        types::DummyConnectorStatus::Succeeded
-    } else {
-        types::DummyConnectorStatus::Failed
-    };
-
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -112,11 +210,15 @@ pub async fn payment_complete(
     let _ = redis_conn.delete_key(&req.attempt_id.as_str().into()).await;
 
     if let Ok(payment_data) = payment_data {
-        let updated_payment_data = types::DummyConnectorPaymentData {
-            status: payment_status,
+        let mut updated_payment_data = types::DummyConnectorPaymentData {
             next_action: None,
             ..payment_data
         };
+        if req.confirm {
+            updated_payment_data.mark_authorized();
+        } else {
+            updated_payment_data.status = types::DummyConnectorStatus::Failed;
+        }
         utils::store_data_in_redis(
             &state,
             updated_payment_data.payment_id.get_string_repr().to_owned(),
@@ -124,6 +226,7 @@ pub async fn payment_complete(
             state.conf.dummy_connector.payment_ttl,
         )
         .await?;
+        webhooks::send_payment_webhook(&state, &updated_payment_data);
         return Ok(api::ApplicationResponse::JsonForRedirection(
             api_models::payments::RedirectionResponse {
                 return_url: String::new(),
@@ -158,6 +261,9 @@ pub async fn refund_payment(
     )
     .await;
 
+    let scenario = utils::get_scenario(&state, req.scenario.as_deref(), None).await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
     let payment_id = req
         .payment_id
         .get_required_value("payment_id")
@@ -198,6 +304,14 @@ pub async fn refund_payment(
         state.conf.dummy_connector.refund_ttl,
     )
     .await?;
+    webhooks::send_webhook(
+        &state,
+        req.webhook_url.or(payment_data.webhook_url),
+        types::DummyConnectorWebhookEventType::RefundSucceeded,
+        types::DummyConnectorWebhookData::Refund(refund_data.clone()),
+    );
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
@@ -227,3 +341,326 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn capture_payment(
+    state: SessionState,
+    req: types::DummyConnectorCaptureRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let scenario = utils::get_scenario(&state, req.scenario.as_deref(), None).await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+    let mut payment_data =
+        utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
+            .await?;
+
+    let capture_amount = payment_data.is_eligible_for_capture(req.amount)?;
+    payment_data.capture(capture_amount);
+
+    utils::store_data_in_redis(
+        &state,
+        payment_id.get_string_repr().to_owned(),
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    webhooks::send_payment_webhook(&state, &payment_data);
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn void_payment(
+    state: SessionState,
+    req: types::DummyConnectorVoidRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let scenario = utils::get_scenario(&state, req.scenario.as_deref(), None).await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
+    let mut payment_data =
+        utils::get_payment_data_from_payment_id(&state, req.payment_id.clone()).await?;
+    payment_data.void()?;
+
+    utils::store_data_in_redis(
+        &state,
+        req.payment_id,
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    webhooks::send_payment_webhook(&state, &payment_data);
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn incremental_authorization(
+    state: SessionState,
+    req: types::DummyConnectorIncrementalAuthorizationRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let scenario = utils::get_scenario(&state, req.scenario.as_deref(), None).await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+    let mut payment_data =
+        utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
+            .await?;
+
+    payment_data.authorize_increment(req.additional_amount)?;
+
+    utils::store_data_in_redis(
+        &state,
+        payment_id.get_string_repr().to_owned(),
+        payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
+    Ok(api::ApplicationResponse::Json(payment_data.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn dispute_payment(
+    state: SessionState,
+    req: types::DummyConnectorDisputeRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+    let payment_data =
+        utils::get_payment_data_from_payment_id(&state, payment_id.get_string_repr().to_owned())
+            .await?;
+    if !payment_data.is_captured() {
+        return Err(report!(errors::DummyConnectorErrors::PaymentNotSuccessful)
+            .attach_printable("Payment is not successful to be disputed"));
+    }
+
+    let now = common_utils::date_time::now();
+    let dispute_data = types::DummyConnectorDisputeResponse {
+        id: generate_id_with_default_len(consts::DISPUTE_ID_PREFIX),
+        payment_id,
+        status: types::DummyConnectorDisputeStatus::Opened,
+        amount: payment_data.amount_captured,
+        currency: payment_data.currency,
+        reason: req.reason,
+        created: now,
+        updated: now,
+    };
+
+    utils::store_data_in_redis(
+        &state,
+        dispute_data.id.clone(),
+        dispute_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    webhooks::send_webhook(
+        &state,
+        payment_data.webhook_url,
+        types::DummyConnectorWebhookEventType::DisputeOpened,
+        types::DummyConnectorWebhookData::Dispute(dispute_data.clone()),
+    );
+    Ok(api::ApplicationResponse::Json(dispute_data))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn dispute_data(
+    state: SessionState,
+    req: types::DummyConnectorDisputeRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    let dispute_data = utils::get_data_from_redis(
+        &state,
+        &req.dispute_id,
+        "DummyConnectorDisputeResponse",
+        errors::DummyConnectorErrors::DisputeNotFound,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(dispute_data))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn resolve_dispute(
+    state: SessionState,
+    req: types::DummyConnectorDisputeResolveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    let dispute_id = req
+        .dispute_id
+        .get_required_value("dispute_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "dispute_id",
+        })?;
+    let dispute_data: types::DummyConnectorDisputeResponse = utils::get_data_from_redis(
+        &state,
+        &dispute_id,
+        "DummyConnectorDisputeResponse",
+        errors::DummyConnectorErrors::DisputeNotFound,
+    )
+    .await?;
+    if dispute_data.status != types::DummyConnectorDisputeStatus::Opened {
+        return Err(report!(
+            errors::DummyConnectorErrors::DisputeAlreadyResolved
+        ));
+    }
+
+    let dispute_data = types::DummyConnectorDisputeResponse {
+        status: req.outcome.into(),
+        updated: common_utils::date_time::now(),
+        ..dispute_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        dispute_id,
+        dispute_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+
+    // The payment may have expired in the meantime, in which case there is nobody to notify
+    let webhook_url = utils::get_payment_data_from_payment_id(
+        &state,
+        dispute_data.payment_id.get_string_repr().to_owned(),
+    )
+    .await
+    .ok()
+    .and_then(|payment_data| payment_data.webhook_url);
+    let event_type = match req.outcome {
+        types::DummyConnectorDisputeOutcome::Won => {
+            types::DummyConnectorWebhookEventType::DisputeWon
+        }
+        types::DummyConnectorDisputeOutcome::Lost => {
+            types::DummyConnectorWebhookEventType::DisputeLost
+        }
+    };
+    webhooks::send_webhook(
+        &state,
+        webhook_url,
+        event_type,
+        types::DummyConnectorWebhookData::Dispute(dispute_data.clone()),
+    );
+    Ok(api::ApplicationResponse::Json(dispute_data))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1", feature = "payouts"))]
+pub async fn payout(
+    state: SessionState,
+    req: types::DummyConnectorPayoutRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+
+    let card = match &req.payout_method_data {
+        types::DummyConnectorPayoutMethodData::Card(card) => Some(card.clone()),
+        types::DummyConnectorPayoutMethodData::Bank(_) => None,
+    };
+    let scenario = utils::get_scenario(
+        &state,
+        req.scenario.as_deref(),
+        card.clone()
+            .map(types::DummyConnectorPaymentMethodData::Card)
+            .as_ref(),
+    )
+    .await?;
+    utils::simulate_scenario(&state, scenario.as_ref()).await?;
+
+    // Test cards which decline payments decline payouts as well
+    if let Some(card) = card {
+        if let types::DummyConnectorCardFlow::NoThreeDS(_, Some(error)) =
+            card.get_flow_from_card_number()?
+        {
+            Err(error)?;
+        }
+    }
+
+    let payout_data = types::DummyConnectorPayoutResponse {
+        id: generate_id_with_default_len(consts::PAYOUT_ID_PREFIX),
+        status: types::DummyConnectorStatus::Succeeded,
+        amount: req.amount,
+        currency: req.currency,
+        payout_method_type: (&req.payout_method_data).into(),
+        created: common_utils::date_time::now(),
+    };
+    utils::store_data_in_redis(
+        &state,
+        payout_data.id.clone(),
+        payout_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    webhooks::send_webhook(
+        &state,
+        req.webhook_url,
+        types::DummyConnectorWebhookEventType::PayoutSucceeded,
+        types::DummyConnectorWebhookData::Payout(payout_data.clone()),
+    );
+
+    utils::simulate_scenario_timeout(&state, scenario.as_ref()).await?;
+    Ok(api::ApplicationResponse::Json(payout_data))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1", feature = "payouts"))]
+pub async fn payout_data(
+    state: SessionState,
+    req: types::DummyConnectorPayoutRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_retrieve_duration,
+        state.conf.dummy_connector.payment_retrieve_tolerance,
+    )
+    .await;
+
+    let payout_data = utils::get_data_from_redis(
+        &state,
+        &req.payout_id,
+        "DummyConnectorPayoutResponse",
+        errors::DummyConnectorErrors::PayoutNotFound,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(payout_data))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn register_scenario(
+    state: SessionState,
+    req: types::DummyConnectorScenarioRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenario> {
+    utils::store_card_scenario(&state, &req).await?;
+    Ok(api::ApplicationResponse::Json(req.scenario))
+}
@@ -37,6 +37,36 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Invalid scenario: {reason}")]
+    InvalidScenario { reason: &'static str },
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_10", message = "Simulated server error")]
+    SimulatedServerError,
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_11", message = "Simulated timeout")]
+    SimulatedTimeout,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_12", message = "The capture amount exceeds the amount left to capture")]
+    CaptureAmountExceedsAuthorizedAmount,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_13", message = "Payment is not authorized")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_14", message = "Mandate does not exist in our records")]
+    MandateNotFound,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_15", message = "Mandate is not active")]
+    MandateNotActive,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_16", message = "Dispute does not exist in our records")]
+    DisputeNotFound,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_17", message = "Dispute is already resolved")]
+    DisputeAlreadyResolved,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_18", message = "Payout does not exist in our records")]
+    PayoutNotFound,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +113,37 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::InvalidScenario { reason: _ } => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::SimulatedServerError => {
+                AER::InternalServerError(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::SimulatedTimeout => AER::ConnectorError(
+                ApiError::new("DC", 11, self.error_message(), None),
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ),
+            Self::CaptureAmountExceedsAuthorizedAmount => {
+                AER::BadRequest(ApiError::new("DC", 12, self.error_message(), None))
+            }
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 13, self.error_message(), None))
+            }
+            Self::MandateNotFound => {
+                AER::NotFound(ApiError::new("DC", 14, self.error_message(), None))
+            }
+            Self::MandateNotActive => {
+                AER::BadRequest(ApiError::new("DC", 15, self.error_message(), None))
+            }
+            Self::DisputeNotFound => {
+                AER::NotFound(ApiError::new("DC", 16, self.error_message(), None))
+            }
+            Self::DisputeAlreadyResolved => {
+                AER::BadRequest(ApiError::new("DC", 17, self.error_message(), None))
+            }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("DC", 18, self.error_message(), None))
+            }
         }
     }
 }
@@ -16,8 +16,18 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
+    DummyPaymentVoid,
+    DummyPaymentIncrementalAuthorization,
+    DummyMandatePayment,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyDisputeCreate,
+    DummyDisputeRetrieve,
+    DummyDisputeResolve,
+    DummyPayoutCreate,
+    DummyPayoutRetrieve,
+    DummyScenarioCreate,
 }
 
 impl FlowMetric for Flow {}
@@ -64,6 +74,23 @@ pub enum DummyConnectorStatus {
     #[default]
     Processing,
     Failed,
+    Authorized,
+    #[serde(rename = "partially_captured")]
+    PartiallyCaptured,
+    Cancelled,
+}
+
+#[derive(
+    Default, serde::Serialize, serde::Deserialize, strum::Display, Clone, Copy, PartialEq, Debug, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorCaptureMethod {
+    #[default]
+    Automatic,
+    /// The payment can be captured once, for up to the authorized amount
+    Manual,
+    /// The payment can be captured several times, until the authorized amount is exhausted
+    ManualMultiple,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -72,6 +99,8 @@ pub struct DummyConnectorPaymentAttempt {
     pub attempt_id: String,
     pub payment_id: common_utils::id_type::PaymentId,
     pub payment_request: DummyConnectorPaymentRequest,
+    /// Merchant initiated payments made with a mandate skip the customer authentication
+    pub off_session: bool,
 }
 
 impl From<DummyConnectorPaymentRequest> for DummyConnectorPaymentAttempt {
@@ -84,6 +113,7 @@ impl From<DummyConnectorPaymentRequest> for DummyConnectorPaymentAttempt {
             attempt_id,
             payment_id,
             payment_request,
+            off_session: false,
         }
     }
 }
@@ -95,19 +125,28 @@ impl DummyConnectorPaymentAttempt {
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
     ) -> DummyConnectorPaymentData {
-        DummyConnectorPaymentData {
+        let mut payment_data = DummyConnectorPaymentData {
             attempt_id: self.attempt_id,
             payment_id: self.payment_id,
             status,
             amount: self.payment_request.amount,
-            eligible_amount: self.payment_request.amount,
+            eligible_amount: 0,
+            amount_captured: 0,
+            captures: Vec::new(),
+            capture_method: self.payment_request.capture_method,
             connector: self.payment_request.connector,
             created: self.timestamp,
             currency: self.payment_request.currency,
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            mandate_id: None,
+            webhook_url: self.payment_request.webhook_url,
+        };
+        if payment_data.status == DummyConnectorStatus::Succeeded {
+            payment_data.mark_authorized();
         }
+        payment_data
     }
 }
 
@@ -118,6 +157,16 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    #[serde(default)]
+    pub capture_method: DummyConnectorCaptureMethod,
+    /// Whether a mandate should be set up with the payment method, for subsequent merchant
+    /// initiated payments
+    #[serde(default)]
+    pub setup_mandate: bool,
+    /// Url to which the webhooks of the payment are sent
+    pub webhook_url: Option<String>,
+    #[serde(skip)]
+    pub scenario: Option<String>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -296,9 +345,34 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    #[serde(default)]
+    pub capture_method: DummyConnectorCaptureMethod,
+    #[serde(default)]
+    pub amount_captured: i64,
+    #[serde(default)]
+    pub captures: Vec<DummyConnectorCapture>,
+    pub mandate_id: Option<String>,
+    pub webhook_url: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
+    /// Moves a payment whose authorization went through to the state its capture method leads
+    /// to, either captured in full or waiting to be captured
+    pub fn mark_authorized(&mut self) {
+        match self.capture_method {
+            DummyConnectorCaptureMethod::Automatic => {
+                self.status = DummyConnectorStatus::Succeeded;
+                self.amount_captured = self.amount;
+                self.eligible_amount = self.amount;
+            }
+            DummyConnectorCaptureMethod::Manual | DummyConnectorCaptureMethod::ManualMultiple => {
+                self.status = DummyConnectorStatus::Authorized;
+                self.amount_captured = 0;
+                self.eligible_amount = 0;
+            }
+        }
+    }
+
     pub fn is_eligible_for_refund(&self, refund_amount: i64) -> DummyConnectorResult<()> {
         if self.eligible_amount < refund_amount {
             return Err(
@@ -306,12 +380,104 @@ impl DummyConnectorPaymentData {
                     .attach_printable("Eligible amount is lesser than refund amount"),
             );
         }
-        if self.status != DummyConnectorStatus::Succeeded {
+        if !self.is_captured() {
             return Err(report!(DummyConnectorErrors::PaymentNotSuccessful)
                 .attach_printable("Payment is not successful to process the refund"));
         }
         Ok(())
     }
+
+    pub fn is_captured(&self) -> bool {
+        matches!(
+            self.status,
+            DummyConnectorStatus::Succeeded | DummyConnectorStatus::PartiallyCaptured
+        )
+    }
+
+    /// Returns the amount to capture, which defaults to the amount left to capture
+    pub fn is_eligible_for_capture(
+        &self,
+        capture_amount: Option<i64>,
+    ) -> DummyConnectorResult<i64> {
+        let is_capturable = match self.capture_method {
+            DummyConnectorCaptureMethod::Automatic => false,
+            DummyConnectorCaptureMethod::Manual => self.status == DummyConnectorStatus::Authorized,
+            DummyConnectorCaptureMethod::ManualMultiple => matches!(
+                self.status,
+                DummyConnectorStatus::Authorized | DummyConnectorStatus::PartiallyCaptured
+            ),
+        };
+        if !is_capturable {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not in a capturable state"));
+        }
+
+        let amount_capturable = self.amount - self.amount_captured;
+        let capture_amount = capture_amount.unwrap_or(amount_capturable);
+        if capture_amount <= 0 || capture_amount > amount_capturable {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsAuthorizedAmount)
+                    .attach_printable("Capture amount is not within the amount left to capture"),
+            );
+        }
+        Ok(capture_amount)
+    }
+
+    /// Captures are recorded individually so that multiple partial captures can be told apart
+    pub fn capture(&mut self, capture_amount: i64) {
+        self.captures.push(DummyConnectorCapture {
+            id: generate_id_with_default_len(consts::CAPTURE_ID_PREFIX),
+            amount: capture_amount,
+            created: common_utils::date_time::now(),
+        });
+        self.amount_captured += capture_amount;
+        self.eligible_amount += capture_amount;
+        self.status = if self.amount_captured == self.amount {
+            DummyConnectorStatus::Succeeded
+        } else {
+            DummyConnectorStatus::PartiallyCaptured
+        };
+    }
+
+    pub fn is_authorized(&self) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized"));
+        }
+        Ok(())
+    }
+
+    /// Releases the authorization of a payment which has not been captured
+    pub fn void(&mut self) -> DummyConnectorResult<()> {
+        self.is_authorized()?;
+        self.status = DummyConnectorStatus::Cancelled;
+        Ok(())
+    }
+
+    /// Increments the authorized amount of a payment which has not been captured
+    pub fn authorize_increment(&mut self, additional_amount: i64) -> DummyConnectorResult<()> {
+        self.is_authorized()?;
+        if additional_amount <= 0 {
+            return Err(report!(DummyConnectorErrors::PaymentDeclined {
+                message: "Invalid additional amount",
+            })
+            .attach_printable("The additional amount to authorize must be positive"));
+        }
+        self.amount += additional_amount;
+        Ok(())
+    }
+
+    /// A mandate is only usable once the payment which set it up went through
+    pub fn is_mandate_active(&self) -> DummyConnectorResult<()> {
+        if matches!(
+            self.status,
+            DummyConnectorStatus::Processing | DummyConnectorStatus::Failed
+        ) {
+            return Err(report!(DummyConnectorErrors::MandateNotActive)
+                .attach_printable("The payment setting up the mandate did not go through"));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -330,6 +496,10 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub capture_method: DummyConnectorCaptureMethod,
+    pub amount_captured: i64,
+    pub captures: Vec<DummyConnectorCapture>,
+    pub mandate_id: Option<String>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -342,10 +512,22 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            capture_method: value.capture_method,
+            amount_captured: value.amount_captured,
+            captures: value.captures,
+            mandate_id: value.mandate_id,
         }
     }
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct DummyConnectorCapture {
+    pub id: String,
+    pub amount: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
 #[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DummyConnectorPaymentRetrieveRequest {
     pub payment_id: String,
@@ -371,6 +553,9 @@ pub struct DummyConnectorPaymentCompleteBody {
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
     pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub webhook_url: Option<String>,
+    #[serde(skip)]
+    pub scenario: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -409,6 +594,288 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    /// Defaults to the amount left to capture
+    pub amount: Option<i64>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    #[serde(skip)]
+    pub scenario: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorVoidRequest {
+    pub payment_id: String,
+    #[serde(skip)]
+    pub scenario: Option<String>,
+}
+
+#[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorIncrementalAuthorizationRequest {
+    pub additional_amount: i64,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    #[serde(skip)]
+    pub scenario: Option<String>,
+}
+
+/// A payment method stored by a payment made with `setup_mandate`
+#[derive(Clone, Debug, serde::Serialize, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorMandate {
+    pub id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub connector: DummyConnectors,
+    pub payment_method_data: DummyConnectorPaymentMethodData,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorMandatePaymentRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(default)]
+    pub capture_method: DummyConnectorCaptureMethod,
+    pub webhook_url: Option<String>,
+    pub mandate_id: Option<String>,
+    #[serde(skip)]
+    pub scenario: Option<String>,
+}
+
+#[derive(
+    Clone, Copy, Debug, serde::Serialize, serde::Deserialize, strum::EnumString, Eq, PartialEq,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorDeclineCode {
+    CardDeclined,
+    InsufficientFunds,
+    LostCard,
+    StolenCard,
+    ExpiredCard,
+    IncorrectCvc,
+    DoNotHonor,
+}
+
+impl DummyConnectorDeclineCode {
+    pub fn get_message(&self) -> &'static str {
+        match self {
+            Self::CardDeclined => "Card declined",
+            Self::InsufficientFunds => "Insufficient funds",
+            Self::LostCard => "Lost card",
+            Self::StolenCard => "Stolen card",
+            Self::ExpiredCard => "Expired card",
+            Self::IncorrectCvc => "Incorrect CVC",
+            Self::DoNotHonor => "Do not honor",
+        }
+    }
+}
+
+/// Failures injected into a dummy connector call, either through the scenario header or by
+/// registering them against a test card
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorScenario {
+    /// The call is declined with this code
+    pub decline_code: Option<DummyConnectorDeclineCode>,
+    /// The call fails with a 5xx response without being processed
+    #[serde(default)]
+    pub server_error: bool,
+    /// The call is processed, but only answered with a 504 response after
+    /// `dummy_connector.timeout_duration`
+    #[serde(default)]
+    pub timeout: bool,
+    /// Additional delay before the call is processed, in milliseconds
+    pub latency: Option<u64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorScenarioRequest {
+    pub card_number: cards::CardNumber,
+    pub scenario: DummyConnectorScenario,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeRequest {
+    pub reason: Option<String>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeRetrieveRequest {
+    pub dispute_id: String,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorDisputeOutcome {
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeResolveRequest {
+    pub outcome: DummyConnectorDisputeOutcome,
+    pub dispute_id: Option<String>,
+}
+
+#[derive(
+    Clone, Copy, Debug, serde::Serialize, serde::Deserialize, strum::Display, Eq, PartialEq,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorDisputeStatus {
+    Opened,
+    Won,
+    Lost,
+}
+
+impl From<DummyConnectorDisputeOutcome> for DummyConnectorDisputeStatus {
+    fn from(value: DummyConnectorDisputeOutcome) -> Self {
+        match value {
+            DummyConnectorDisputeOutcome::Won => Self::Won,
+            DummyConnectorDisputeOutcome::Lost => Self::Lost,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorDisputeResponse {
+    pub id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub status: DummyConnectorDisputeStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorBankTransfer {
+    pub iban: Secret<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutMethodData {
+    Card(DummyConnectorCard),
+    Bank(DummyConnectorBankTransfer),
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutMethodType {
+    Card,
+    Bank,
+}
+
+#[cfg(feature = "payouts")]
+impl From<&DummyConnectorPayoutMethodData> for DummyConnectorPayoutMethodType {
+    fn from(value: &DummyConnectorPayoutMethodData) -> Self {
+        match value {
+            DummyConnectorPayoutMethodData::Card(_) => Self::Card,
+            DummyConnectorPayoutMethodData::Bank(_) => Self::Bank,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    pub payout_method_data: DummyConnectorPayoutMethodData,
+    pub connector: DummyConnectors,
+    pub webhook_url: Option<String>,
+    #[serde(skip)]
+    pub scenario: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct DummyConnectorPayoutResponse {
+    pub id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub payout_method_type: DummyConnectorPayoutMethodType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRetrieveRequest {
+    pub payout_id: String,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentAuthorized,
+    PaymentCaptured,
+    PaymentCancelled,
+    RefundSucceeded,
+    DisputeOpened,
+    DisputeWon,
+    DisputeLost,
+    PayoutSucceeded,
+}
+
+impl DummyConnectorWebhookEventType {
+    pub fn from_payment_status(status: &DummyConnectorStatus) -> Option<Self> {
+        match status {
+            DummyConnectorStatus::Succeeded => Some(Self::PaymentSucceeded),
+            DummyConnectorStatus::Failed => Some(Self::PaymentFailed),
+            DummyConnectorStatus::Authorized => Some(Self::PaymentAuthorized),
+            DummyConnectorStatus::PartiallyCaptured => Some(Self::PaymentCaptured),
+            DummyConnectorStatus::Cancelled => Some(Self::PaymentCancelled),
+            DummyConnectorStatus::Processing => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum DummyConnectorWebhookData {
+    Payment(DummyConnectorPaymentResponse),
+    Refund(DummyConnectorRefundResponse),
+    Dispute(DummyConnectorDisputeResponse),
+    #[cfg(feature = "payouts")]
+    Payout(DummyConnectorPayoutResponse),
+}
+
+/// Body of the webhooks sent by the dummy connector, signed with
+/// `dummy_connector.webhook_secret`
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub id: String,
+    pub event_type: DummyConnectorWebhookEventType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub data: DummyConnectorWebhookData,
+}
+
+impl DummyConnectorWebhook {
+    pub fn new(
+        event_type: DummyConnectorWebhookEventType,
+        data: DummyConnectorWebhookData,
+    ) -> Self {
+        Self {
+            id: generate_id_with_default_len(consts::WEBHOOK_ID_PREFIX),
+            event_type,
+            created: common_utils::date_time::now(),
+            data,
+        }
+    }
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
@@ -419,3 +886,174 @@ pub struct DummyConnectorUpiFlow {
     pub error: Option<DummyConnectorErrors>,
     pub is_next_action_required: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_payment_data(
+        capture_method: DummyConnectorCaptureMethod,
+        status: DummyConnectorStatus,
+    ) -> DummyConnectorPaymentData {
+        DummyConnectorPaymentData {
+            attempt_id: "attempt_1".to_string(),
+            payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(
+                "dummy_pay_1",
+            ))
+            .unwrap(),
+            status,
+            amount: 1000,
+            eligible_amount: 0,
+            currency: Currency::USD,
+            created: common_utils::date_time::now(),
+            payment_method_type: DummyConnectorPaymentMethodType::Card,
+            connector: DummyConnectors::PhonyPay,
+            next_action: None,
+            return_url: None,
+            capture_method,
+            amount_captured: 0,
+            captures: Vec::new(),
+            mandate_id: None,
+            webhook_url: None,
+        }
+    }
+
+    #[test]
+    fn test_authorization_by_capture_method() {
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Automatic,
+            DummyConnectorStatus::Processing,
+        );
+        payment_data.mark_authorized();
+        assert_eq!(payment_data.status, DummyConnectorStatus::Succeeded);
+        assert_eq!(payment_data.amount_captured, 1000);
+        assert_eq!(payment_data.eligible_amount, 1000);
+
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Manual,
+            DummyConnectorStatus::Processing,
+        );
+        payment_data.mark_authorized();
+        assert_eq!(payment_data.status, DummyConnectorStatus::Authorized);
+        assert_eq!(payment_data.amount_captured, 0);
+    }
+
+    #[test]
+    fn test_capture() {
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Manual,
+            DummyConnectorStatus::Authorized,
+        );
+        assert!(payment_data.is_eligible_for_capture(Some(1001)).is_err());
+        assert!(payment_data.is_eligible_for_capture(Some(0)).is_err());
+
+        let capture_amount = payment_data.is_eligible_for_capture(None).unwrap();
+        assert_eq!(capture_amount, 1000);
+        payment_data.capture(capture_amount);
+        assert_eq!(payment_data.status, DummyConnectorStatus::Succeeded);
+        assert_eq!(payment_data.amount_captured, 1000);
+        assert_eq!(payment_data.eligible_amount, 1000);
+        assert_eq!(payment_data.captures.len(), 1);
+
+        // A captured payment cannot be captured again
+        assert!(payment_data.is_eligible_for_capture(None).is_err());
+
+        let payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Automatic,
+            DummyConnectorStatus::Succeeded,
+        );
+        assert!(payment_data.is_eligible_for_capture(None).is_err());
+    }
+
+    #[test]
+    fn test_multiple_partial_captures() {
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::ManualMultiple,
+            DummyConnectorStatus::Authorized,
+        );
+
+        let capture_amount = payment_data.is_eligible_for_capture(Some(400)).unwrap();
+        payment_data.capture(capture_amount);
+        assert_eq!(payment_data.status, DummyConnectorStatus::PartiallyCaptured);
+        assert!(payment_data.is_eligible_for_refund(400).is_ok());
+        assert!(payment_data.is_eligible_for_refund(401).is_err());
+
+        assert!(payment_data.is_eligible_for_capture(Some(601)).is_err());
+        let capture_amount = payment_data.is_eligible_for_capture(None).unwrap();
+        assert_eq!(capture_amount, 600);
+        payment_data.capture(capture_amount);
+        assert_eq!(payment_data.status, DummyConnectorStatus::Succeeded);
+        assert_eq!(payment_data.captures.len(), 2);
+
+        // A payment which can be captured once is not captured partially again
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Manual,
+            DummyConnectorStatus::Authorized,
+        );
+        payment_data.capture(400);
+        assert!(payment_data.is_eligible_for_capture(None).is_err());
+    }
+
+    #[test]
+    fn test_void() {
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Manual,
+            DummyConnectorStatus::Authorized,
+        );
+        assert!(payment_data.void().is_ok());
+        assert_eq!(payment_data.status, DummyConnectorStatus::Cancelled);
+        assert!(payment_data.void().is_err());
+
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Automatic,
+            DummyConnectorStatus::Succeeded,
+        );
+        assert!(payment_data.void().is_err());
+        assert_eq!(payment_data.status, DummyConnectorStatus::Succeeded);
+    }
+
+    #[test]
+    fn test_incremental_authorization() {
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Manual,
+            DummyConnectorStatus::Authorized,
+        );
+        assert!(payment_data.authorize_increment(0).is_err());
+        assert!(payment_data.authorize_increment(-100).is_err());
+        assert!(payment_data.authorize_increment(500).is_ok());
+        assert_eq!(payment_data.amount, 1500);
+        assert_eq!(payment_data.is_eligible_for_capture(None).unwrap(), 1500);
+
+        let mut payment_data = get_payment_data(
+            DummyConnectorCaptureMethod::Manual,
+            DummyConnectorStatus::Cancelled,
+        );
+        assert!(payment_data.authorize_increment(500).is_err());
+        assert_eq!(payment_data.amount, 1000);
+    }
+
+    #[test]
+    fn test_mandate_activation() {
+        for status in [
+            DummyConnectorStatus::Processing,
+            DummyConnectorStatus::Failed,
+        ] {
+            assert!(
+                get_payment_data(DummyConnectorCaptureMethod::Automatic, status)
+                    .is_mandate_active()
+                    .is_err()
+            );
+        }
+        for status in [
+            DummyConnectorStatus::Succeeded,
+            DummyConnectorStatus::Authorized,
+        ] {
+            assert!(
+                get_payment_data(DummyConnectorCaptureMethod::Automatic, status)
+                    .is_mandate_active()
+                    .is_ok()
+            );
+        }
+    }
+}
@@ -1,6 +1,9 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::AsyncExt,
+};
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
@@ -89,6 +92,193 @@ pub async fn get_payment_data_by_attempt_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+pub async fn get_data_from_redis<T: serde::de::DeserializeOwned>(
+    state: &SessionState,
+    key: &str,
+    type_name: &'static str,
+    not_found_error: errors::DummyConnectorErrors,
+) -> types::DummyConnectorResult<T> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<T>(&key.into(), type_name)
+        .await
+        .change_context(not_found_error)
+}
+
+pub fn get_scenario_header(headers: &actix_web::http::header::HeaderMap) -> Option<String> {
+    headers
+        .get(consts::DUMMY_CONNECTOR_SCENARIO_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+impl FromStr for types::DummyConnectorScenario {
+    type Err = error_stack::Report<errors::DummyConnectorErrors>;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .try_fold(Self::default(), |mut scenario, entry| {
+                match entry.split_once('=') {
+                    Some(("decline_code", decline_code)) => {
+                        scenario.decline_code = Some(
+                            decline_code
+                                .parse::<types::DummyConnectorDeclineCode>()
+                                .change_context(errors::DummyConnectorErrors::InvalidScenario {
+                                    reason: "unknown decline_code",
+                                })?,
+                        );
+                    }
+                    Some(("latency", latency)) => {
+                        scenario.latency = Some(latency.parse::<u64>().change_context(
+                            errors::DummyConnectorErrors::InvalidScenario {
+                                reason: "latency must be a number of milliseconds",
+                            },
+                        )?);
+                    }
+                    None if entry == "server_error" => scenario.server_error = true,
+                    None if entry == "timeout" => scenario.timeout = true,
+                    _ => {
+                        return Err(report!(errors::DummyConnectorErrors::InvalidScenario {
+                            reason: "unknown entry",
+                        })
+                        .attach_printable(format!("Unknown scenario entry {entry}")))
+                    }
+                }
+                Ok(scenario)
+            })
+    }
+}
+
+fn get_card_scenario_key(card_number: &cards::CardNumber) -> types::DummyConnectorResult<String> {
+    // Test card numbers are not kept in redis in the clear
+    let digest = Sha256
+        .generate_digest(card_number.peek().as_bytes())
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to hash the card number")?;
+    Ok(format!(
+        "{}_{}",
+        consts::SCENARIO_KEY_PREFIX,
+        hex::encode(digest)
+    ))
+}
+
+pub async fn store_card_scenario(
+    state: &SessionState,
+    req: &types::DummyConnectorScenarioRequest,
+) -> types::DummyConnectorResult<()> {
+    store_data_in_redis(
+        state,
+        get_card_scenario_key(&req.card_number)?,
+        req.scenario.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await
+}
+
+/// The scenario passed in the header takes precedence over the one registered against the test
+/// card, which itself takes precedence over the failures built into the test card
+pub async fn get_scenario(
+    state: &SessionState,
+    scenario_header: Option<&str>,
+    payment_method_data: Option<&types::DummyConnectorPaymentMethodData>,
+) -> types::DummyConnectorResult<Option<types::DummyConnectorScenario>> {
+    if let Some(scenario_header) = scenario_header {
+        return types::DummyConnectorScenario::from_str(scenario_header).map(Some);
+    }
+    let Some(types::DummyConnectorPaymentMethodData::Card(card)) = payment_method_data else {
+        return Ok(None);
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let registered_scenario = match redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorScenario>(
+            &get_card_scenario_key(&card.number)?.into(),
+            "DummyConnectorScenario",
+        )
+        .await
+    {
+        Ok(scenario) => Some(scenario),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) =>
+        {
+            None
+        }
+        Err(error) => Err(error
+            .change_context(errors::DummyConnectorErrors::InternalServerError)
+            .attach_printable("Failed to fetch the scenario registered against the card"))?,
+    };
+
+    Ok(registered_scenario.or_else(|| card.get_scenario_from_card_number()))
+}
+
+/// Simulates the failures of the scenario which take place before the call is processed. The
+/// latency is capped at `dummy_connector.timeout_duration`, since longer calls are simulated
+/// with the timeout scenario.
+pub async fn simulate_scenario(
+    state: &SessionState,
+    scenario: Option<&types::DummyConnectorScenario>,
+) -> types::DummyConnectorResult<()> {
+    let Some(scenario) = scenario else {
+        return Ok(());
+    };
+    if let Some(latency) = scenario.latency {
+        tokio::sleep(tokio::Duration::from_millis(get_scenario_latency(
+            latency,
+            state.conf.dummy_connector.timeout_duration,
+        )))
+        .await;
+    }
+    if scenario.server_error {
+        return Err(report!(errors::DummyConnectorErrors::SimulatedServerError)
+            .attach_printable("Server error requested by the scenario"));
+    }
+    if let Some(decline_code) = scenario.decline_code {
+        return Err(report!(errors::DummyConnectorErrors::PaymentDeclined {
+            message: decline_code.get_message(),
+        })
+        .attach_printable("Decline requested by the scenario"));
+    }
+    Ok(())
+}
+
+/// The simulated latency is capped at the timeout duration, so that a scenario cannot hold a
+/// worker for longer than a simulated timeout would
+fn get_scenario_latency(latency: u64, timeout_duration: u64) -> u64 {
+    latency.min(timeout_duration)
+}
+
+/// Simulates a timeout once the call has been processed, so that its outcome can only be
+/// learnt by syncing it or through its webhook
+pub async fn simulate_scenario_timeout(
+    state: &SessionState,
+    scenario: Option<&types::DummyConnectorScenario>,
+) -> types::DummyConnectorResult<()> {
+    if scenario.is_some_and(|scenario| scenario.timeout) {
+        tokio::sleep(tokio::Duration::from_millis(
+            state.conf.dummy_connector.timeout_duration,
+        ))
+        .await;
+        return Err(report!(errors::DummyConnectorErrors::SimulatedTimeout)
+            .attach_printable("Timeout requested by the scenario"));
+    }
+    Ok(())
+}
+
 pub fn get_authorize_page(
     payment_data: types::DummyConnectorPaymentData,
     return_url: String,
@@ -226,6 +416,10 @@ impl ProcessPaymentAttempt for types::DummyConnectorCard {
                 }
                 Ok(payment_attempt.build_payment_data(status, None, None))
             }
+            // Merchant initiated payments are not authenticated by the customer
+            types::DummyConnectorCardFlow::ThreeDS(status, _) if payment_attempt.off_session => {
+                Ok(payment_attempt.build_payment_data(status, None, None))
+            }
             types::DummyConnectorCardFlow::ThreeDS(_, _) => {
                 Ok(payment_attempt.clone().build_payment_data(
                     types::DummyConnectorStatus::Processing,
@@ -295,7 +489,7 @@ impl types::DummyConnectorCard {
         let card_number = self.number.peek();
         match card_number.as_str() {
             "4111111111111111" | "4242424242424242" | "5555555555554444" | "38000000000006"
-            | "378282246310005" | "6011111111111117" => {
+            | "378282246310005" | "6011111111111117" | "4000000000000119" | "4000000000006975" => {
                 Ok(types::DummyConnectorCardFlow::NoThreeDS(
                     types::DummyConnectorStatus::Succeeded,
                     None,
@@ -327,6 +521,18 @@ impl types::DummyConnectorCard {
                     message: "Stolen card",
                 }),
             )),
+            "4000000000000069" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
+                types::DummyConnectorStatus::Failed,
+                Some(errors::DummyConnectorErrors::PaymentDeclined {
+                    message: types::DummyConnectorDeclineCode::ExpiredCard.get_message(),
+                }),
+            )),
+            "4000000000000127" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
+                types::DummyConnectorStatus::Failed,
+                Some(errors::DummyConnectorErrors::PaymentDeclined {
+                    message: types::DummyConnectorDeclineCode::IncorrectCvc.get_message(),
+                }),
+            )),
             "4000003800000446" => Ok(types::DummyConnectorCardFlow::ThreeDS(
                 types::DummyConnectorStatus::Succeeded,
                 None,
@@ -335,6 +541,21 @@ impl types::DummyConnectorCard {
                 .attach_printable("The card is not supported")),
         }
     }
+
+    /// Failures the test card always runs into, on top of its payment outcome
+    pub fn get_scenario_from_card_number(&self) -> Option<types::DummyConnectorScenario> {
+        match self.number.peek().as_str() {
+            "4000000000000119" => Some(types::DummyConnectorScenario {
+                server_error: true,
+                ..Default::default()
+            }),
+            "4000000000006975" => Some(types::DummyConnectorScenario {
+                timeout: true,
+                ..Default::default()
+            }),
+            _ => None,
+        }
+    }
 }
 
 impl ProcessPaymentAttempt for types::DummyConnectorWallet {
@@ -405,3 +626,37 @@ impl types::DummyConnectorPaymentData {
             .build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_scenario_from_header_value() {
+        let scenario = types::DummyConnectorScenario::from_str(
+            "decline_code=insufficient_funds, latency=250,timeout",
+        )
+        .unwrap();
+        assert_eq!(
+            scenario,
+            types::DummyConnectorScenario {
+                decline_code: Some(types::DummyConnectorDeclineCode::InsufficientFunds),
+                server_error: false,
+                timeout: true,
+                latency: Some(250),
+            }
+        );
+
+        assert!(types::DummyConnectorScenario::from_str("decline_code=unknown").is_err());
+        assert!(types::DummyConnectorScenario::from_str("latency=soon").is_err());
+        assert!(types::DummyConnectorScenario::from_str("server_error,crash").is_err());
+    }
+
+    #[test]
+    fn test_scenario_latency_is_capped_at_timeout_duration() {
+        assert_eq!(get_scenario_latency(250, 1000), 250);
+        assert_eq!(get_scenario_latency(1000, 1000), 1000);
+        assert_eq!(get_scenario_latency(60_000, 1000), 1000);
+    }
+}
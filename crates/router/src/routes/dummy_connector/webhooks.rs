@@ -0,0 +1,142 @@
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    request::RequestContent,
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{logger, tracing::Instrument};
+
+use super::{consts, errors, types};
+use crate::{headers, routes::SessionState, services};
+
+/// Hex encoded HMAC-SHA256 of the webhook body, which the dummy connector integration verifies
+/// against the webhook secret of the merchant connector account
+fn get_webhook_signature(
+    webhook_secret: &Secret<String>,
+    body: &[u8],
+) -> types::DummyConnectorResult<String> {
+    HmacSha256
+        .sign_message(webhook_secret.peek().as_bytes(), body)
+        .map(hex::encode)
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to sign the webhook")
+}
+
+fn build_webhook_request(
+    state: &SessionState,
+    webhook_url: &str,
+    webhook: &types::DummyConnectorWebhook,
+) -> types::DummyConnectorResult<services::Request> {
+    let body = serde_json::to_vec(webhook)
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to serialize the webhook")?;
+    let signature = get_webhook_signature(&state.conf.dummy_connector.webhook_secret, &body)?;
+
+    Ok(services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url)
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                mime::APPLICATION_JSON.essence_str().to_string().into(),
+            ),
+            (
+                consts::DUMMY_CONNECTOR_WEBHOOK_SIGNATURE_HEADER.to_string(),
+                signature.into(),
+            ),
+        ])
+        .set_body(RequestContent::RawBytes(body))
+        .build())
+}
+
+/// Sends the webhook in the background, after `dummy_connector.webhook_delay` so that it does
+/// not overtake the response of the call which triggered it. Delivery failures are only logged.
+pub fn send_webhook(
+    state: &SessionState,
+    webhook_url: Option<String>,
+    event_type: types::DummyConnectorWebhookEventType,
+    data: types::DummyConnectorWebhookData,
+) {
+    let Some(webhook_url) = webhook_url else {
+        return;
+    };
+    let webhook = types::DummyConnectorWebhook::new(event_type, data);
+    let request = match build_webhook_request(state, &webhook_url, &webhook) {
+        Ok(request) => request,
+        Err(error) => {
+            logger::error!(?error, "Failed to build the dummy connector webhook");
+            return;
+        }
+    };
+
+    let state = state.clone();
+    let webhook_delay = state.conf.dummy_connector.webhook_delay;
+    tokio::spawn(
+        async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(webhook_delay)).await;
+            match state
+                .api_client
+                .send_request(
+                    &state,
+                    request,
+                    Some(consts::DUMMY_CONNECTOR_WEBHOOK_TIMEOUT_SECS),
+                    false,
+                )
+                .await
+            {
+                Ok(response) => logger::info!(
+                    webhook_id = webhook.id,
+                    status_code = response.status().as_u16(),
+                    "Sent the dummy connector webhook"
+                ),
+                Err(error) => logger::error!(
+                    webhook_id = webhook.id,
+                    ?error,
+                    "Failed to send the dummy connector webhook"
+                ),
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Notifies the payment's webhook url of the state the payment has reached, if any
+pub fn send_payment_webhook(state: &SessionState, payment_data: &types::DummyConnectorPaymentData) {
+    if let Some(event_type) =
+        types::DummyConnectorWebhookEventType::from_payment_status(&payment_data.status)
+    {
+        send_webhook(
+            state,
+            payment_data.webhook_url.clone(),
+            event_type,
+            types::DummyConnectorWebhookData::Payment(payment_data.clone().into()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::crypto::VerifySignature;
+
+    use super::*;
+
+    #[test]
+    fn test_webhook_signature_is_verified_with_the_same_secret() {
+        let body = br#"{"id":"whe_1","event_type":"payment_succeeded"}"#;
+        let signature =
+            get_webhook_signature(&Secret::new("webhook_secret".to_string()), body).unwrap();
+        let signature = hex::decode(signature).unwrap();
+
+        assert!(HmacSha256
+            .verify_signature(b"webhook_secret", &signature, body)
+            .unwrap());
+        assert!(!HmacSha256
+            .verify_signature(b"another_secret", &signature, body)
+            .unwrap());
+        assert!(!HmacSha256
+            .verify_signature(b"webhook_secret", &signature, b"{}")
+            .unwrap());
+    }
+}
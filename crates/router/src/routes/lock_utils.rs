@@ -1,6 +1,6 @@
 use router_env::Flow;
 
-#[derive(Clone, Debug, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum ApiIdentifier {
     Payments,
@@ -46,6 +46,7 @@ pub enum ApiIdentifier {
     Proxy,
     ThreeDsDecisionRule,
     GenericTokenization,
+    IdempotencyKey,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -75,6 +76,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingDeleteConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
+            | Flow::DecisionManagerSimulateSurcharge
             | Flow::ToggleDynamicRouting
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
@@ -91,7 +93,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::ConnectorCircuitBreakersList
+            | Flow::ConnectorCircuitBreakerReset => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -104,6 +108,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersDataExport
+            | Flow::CustomersDataExportRetrieve
+            | Flow::CustomersErasureSchedule
+            | Flow::CustomersErasureRetrieve
+            | Flow::CustomersErasureCancel
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
@@ -124,7 +133,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
             | Flow::PaymentMethodSave
-            | Flow::TotalPaymentMethodCount => Self::PaymentMethods,
+            | Flow::TotalPaymentMethodCount
+            | Flow::AccountUpdaterSchedule
+            | Flow::AccountUpdaterUnschedule
+            | Flow::AccountUpdaterBatchCreate
+            | Flow::AccountUpdaterRequestFileRetrieve
+            | Flow::AccountUpdaterResponseFileUpload
+            | Flow::CustomerNetworkTokensList
+            | Flow::NetworkTokenLifecycleWebhookReceive => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
 
@@ -149,6 +165,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetExtendedCardInfo
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
+            | Flow::PaymentsEligibilityExplanation
+            | Flow::PaymentsRoutingTrace
             | Flow::SessionUpdateTaxCalculation
             | Flow::PaymentsConfirmIntent
             | Flow::PaymentsCreateIntent
@@ -200,6 +218,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesList
             | Flow::DisputesFilters
             | Flow::DisputesEvidenceSubmit
+            | Flow::DisputesEvidenceDraft
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
@@ -208,7 +227,11 @@ impl From<Flow> for ApiIdentifier {
             Flow::CardsInfo
             | Flow::CardsInfoCreate
             | Flow::CardsInfoUpdate
-            | Flow::CardsInfoMigrate => Self::CardsInfo,
+            | Flow::CardsInfoMigrate
+            | Flow::CardBinImport
+            | Flow::CardBinImportList
+            | Flow::CardBinImportRollback
+            | Flow::CardBinImportDiff => Self::CardsInfo,
 
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
 
@@ -345,7 +368,11 @@ impl From<Flow> for ApiIdentifier {
             Flow::RevenueRecoveryRetrieve => Self::ProcessTracker,
             Flow::Proxy => Self::Proxy,
 
-            Flow::ThreeDsDecisionRuleExecute => Self::ThreeDsDecisionRule,
+            Flow::ThreeDsDecisionRuleExecute
+            | Flow::ThreeDsDecisionRuleRolloutUpsert
+            | Flow::ThreeDsDecisionRuleRolloutRetrieve
+            | Flow::ThreeDsDecisionRuleRolloutDelete
+            | Flow::ThreeDsDecisionRuleOutcome => Self::ThreeDsDecisionRule,
             Flow::TokenizationCreate | Flow::TokenizationRetrieve => Self::GenericTokenization,
         }
     }
@@ -104,6 +104,8 @@ counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_REQUEUED_COUNT, GLOBAL_METER);
+counter_metric!(NETWORK_RETRY_LIMIT_BLOCKED_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
@@ -132,6 +134,13 @@ counter_metric!(ACCESS_TOKEN_CACHE_MISS, GLOBAL_METER);
 // A counter to indicate the integrity check failures
 counter_metric!(INTEGRITY_CHECK_FAILED, GLOBAL_METER);
 
+// A counter to indicate the payments and payouts held for manual review after an integrity check failure
+counter_metric!(INTEGRITY_CHECK_QUARANTINED, GLOBAL_METER);
+
+// Counters of the circuits of merchant connector accounts that were opened and closed
+counter_metric!(CONNECTOR_CIRCUIT_OPENED, GLOBAL_METER);
+counter_metric!(CONNECTOR_CIRCUIT_CLOSED, GLOBAL_METER);
+
 // Network Tokenization metrics
 histogram_metric_f64!(GENERATE_NETWORK_TOKEN_TIME, GLOBAL_METER);
 histogram_metric_f64!(FETCH_NETWORK_TOKEN_TIME, GLOBAL_METER);
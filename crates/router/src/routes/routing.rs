@@ -3,6 +3,10 @@
 //! Functions that are used to perform the api level configuration, retrieval, updation
 //! of Routing configs.
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+use actix_multipart::form::MultipartForm;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use actix_web::HttpResponse;
 use actix_web::{web, HttpRequest, Responder};
 use api_models::{enums, routing as routing_types, routing::RoutingRetrieveQuery};
 use router_env::{
@@ -753,6 +757,97 @@ pub async fn retrieve_surcharge_decision_manager_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn simulate_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::surcharge_decision_configs::SurchargeSimulationRequest>,
+) -> impl Responder {
+    let flow = Flow::DecisionManagerSimulateSurcharge;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, simulation_request, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            surcharge_decision_config::simulate_surcharge_decision_config(
+                state,
+                merchant_context,
+                simulation_request,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantSurchargeDecisionManagerRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantSurchargeDecisionManagerRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn simulate_surcharge_decision_manager_config_batch(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<surcharge_decision_config::SurchargeSimulationBatchForm>,
+) -> HttpResponse {
+    let flow = Flow::DecisionManagerSimulateSurcharge;
+    let simulation_request =
+        match surcharge_decision_config::get_surcharge_simulation_batch_request(form) {
+            Ok(simulation_request) => simulation_request,
+            Err(e) => return oss_api::log_and_return_error_response(e.into()),
+        };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        simulation_request,
+        |state, auth: auth::AuthenticationData, simulation_request, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            surcharge_decision_config::simulate_surcharge_decision_config_batch(
+                state,
+                merchant_context,
+                simulation_request,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantSurchargeDecisionManagerRead,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::MerchantSurchargeDecisionManagerRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
 pub async fn upsert_decision_manager_config(
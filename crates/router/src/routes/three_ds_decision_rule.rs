@@ -41,3 +41,139 @@ pub async fn execute_decision_rule(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::ThreeDsDecisionRuleRolloutUpsert))]
+#[cfg(feature = "oltp")]
+pub async fn upsert_decision_rule_rollout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::three_ds_decision_rule::ThreeDsDecisionRuleRolloutRequest>,
+) -> impl Responder {
+    let flow = Flow::ThreeDsDecisionRuleRolloutUpsert;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = MerchantContext::NormalMerchant(Box::new(Context(
+                auth.merchant_account,
+                auth.key_store,
+            )));
+            three_ds_decision_rule_core::upsert_three_ds_decision_rule_rollout(
+                state,
+                merchant_context,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ThreeDsDecisionRuleRolloutRetrieve))]
+#[cfg(feature = "oltp")]
+pub async fn retrieve_decision_rule_rollout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let flow = Flow::ThreeDsDecisionRuleRolloutRetrieve;
+    let baseline_routing_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        baseline_routing_id,
+        |state, auth: auth::AuthenticationData, baseline_routing_id, _| {
+            let merchant_context = MerchantContext::NormalMerchant(Box::new(Context(
+                auth.merchant_account,
+                auth.key_store,
+            )));
+            three_ds_decision_rule_core::retrieve_three_ds_decision_rule_rollout(
+                state,
+                merchant_context,
+                baseline_routing_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ThreeDsDecisionRuleRolloutDelete))]
+#[cfg(feature = "oltp")]
+pub async fn delete_decision_rule_rollout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let flow = Flow::ThreeDsDecisionRuleRolloutDelete;
+    let baseline_routing_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        baseline_routing_id,
+        |state, auth: auth::AuthenticationData, baseline_routing_id, _| {
+            let merchant_context = MerchantContext::NormalMerchant(Box::new(Context(
+                auth.merchant_account,
+                auth.key_store,
+            )));
+            three_ds_decision_rule_core::delete_three_ds_decision_rule_rollout(
+                state,
+                merchant_context,
+                baseline_routing_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ThreeDsDecisionRuleOutcome))]
+#[cfg(feature = "oltp")]
+pub async fn record_decision_rule_outcome(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::three_ds_decision_rule::ThreeDsDecisionRuleOutcomeRequest>,
+) -> impl Responder {
+    let flow = Flow::ThreeDsDecisionRuleOutcome;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = MerchantContext::NormalMerchant(Box::new(Context(
+                auth.merchant_account,
+                auth.key_store,
+            )));
+            three_ds_decision_rule_core::record_three_ds_decision_rule_outcome(
+                state,
+                merchant_context,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
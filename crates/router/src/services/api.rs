@@ -61,7 +61,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        idempotency, payments,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -546,6 +546,7 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     func: F,
     api_auth: &dyn AuthenticateAndFetch<U, SessionState>,
     lock_action: api_locking::LockAction,
+    idempotency_context: &mut idempotency::IdempotencyContext,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
     F: Fn(SessionState, U, T, ReqState) -> Fut,
@@ -640,20 +641,45 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
-    let output = {
-        lock_action
-            .clone()
-            .perform_locking_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
-        let res = func(session_state.clone(), auth_out, payload, request_state)
-            .await
-            .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
+    if let Some(idempotency_input) = idempotency::IdempotencyInput::from_request(
+        flow,
+        incoming_request_header,
+        request.path(),
+        &payload,
+    )
+    .switch()?
+    {
+        idempotency_context
+            .acquire(idempotency_input, &session_state, &merchant_id)
             .await
             .switch()?;
-        res
+    }
+
+    let output = if idempotency_context.is_replayed() {
+        // The stored response is sent by the caller in place of this one
+        Ok(ApplicationResponse::StatusOk)
+    } else {
+        async {
+            lock_action
+                .clone()
+                .perform_locking_action(&session_state, merchant_id.to_owned())
+                .await
+                .map_err(|error| {
+                    // The request was not processed, so it can be retried with the same key
+                    idempotency_context.skip_storing_response();
+                    error
+                })
+                .switch()?;
+            let res = func(session_state.clone(), auth_out, payload, request_state)
+                .await
+                .switch();
+            lock_action
+                .free_lock_action(&session_state, merchant_id.to_owned())
+                .await
+                .switch()?;
+            res
+        }
+        .await
     };
     let request_duration = Instant::now()
         .saturating_duration_since(start_instant)
@@ -775,6 +801,7 @@ where
         tag = ?Tag::BeginRequest, payload = ?payload,
     headers = ?incoming_header_to_log);
 
+    let mut idempotency_context = idempotency::IdempotencyContext::default();
     let server_wrap_util_res = server_wrap_util(
         &flow,
         state.clone(),
@@ -784,6 +811,7 @@ where
         func,
         api_auth,
         lock_action,
+        &mut idempotency_context,
     )
     .await
     .map(|response| {
@@ -908,6 +936,7 @@ where
         }
         Err(error) => log_and_return_error_response(error),
     };
+    let res = idempotency_context.release(res).await;
 
     let response_code = res.status().as_u16();
     tracing::Span::current().record("status_code", response_code);
@@ -80,6 +80,15 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        connector: String,
+        amount: String,
+        currency: String,
+        challenge_required_by: String,
+        hours_remaining: i64,
+    },
     WelcomeToCommunity,
 }
 
@@ -239,6 +248,24 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                connector,
+                amount,
+                currency,
+                challenge_required_by,
+                hours_remaining,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                connector = connector,
+                amount = amount,
+                currency = currency,
+                challenge_required_by = challenge_required_by,
+                hours_remaining = hours_remaining,
+            ),
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
@@ -662,6 +689,41 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub dispute_id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub connector: String,
+    pub amount: String,
+    pub currency: String,
+    pub challenge_required_by: time::PrimitiveDateTime,
+    pub hours_remaining: i64,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute_id.clone(),
+            payment_id: self.payment_id.get_string_repr().to_owned(),
+            connector: self.connector.clone(),
+            amount: self.amount.clone(),
+            currency: self.currency.clone(),
+            challenge_required_by: self.challenge_required_by.to_string(),
+            hours_remaining: self.hours_remaining,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
 }
@@ -26,6 +26,7 @@ mod payment_attempt;
 mod payment_attempt_event;
 mod payment_intent;
 mod payment_intent_event;
+pub mod redaction_event;
 mod refund;
 mod refund_event;
 use diesel_models::{authentication::Authentication, refund::Refund};
@@ -39,8 +40,8 @@ use self::{
     authentication::KafkaAuthentication, authentication_event::KafkaAuthenticationEvent,
     dispute::KafkaDispute, dispute_event::KafkaDisputeEvent, payment_attempt::KafkaPaymentAttempt,
     payment_attempt_event::KafkaPaymentAttemptEvent, payment_intent::KafkaPaymentIntent,
-    payment_intent_event::KafkaPaymentIntentEvent, refund::KafkaRefund,
-    refund_event::KafkaRefundEvent,
+    payment_intent_event::KafkaPaymentIntentEvent, redaction_event::KafkaRedactionEvent,
+    refund::KafkaRefund, refund_event::KafkaRefundEvent,
 };
 use crate::{services::kafka::fraud_check::KafkaFraudCheck, types::storage::Dispute};
 
@@ -516,6 +517,19 @@ impl KafkaProducer {
         })
     }
 
+    pub fn log_redaction(
+        &self,
+        redaction: &KafkaRedactionEvent<'_>,
+        tenant_id: TenantID,
+    ) -> MQResult<()> {
+        self.log_event(&KafkaEvent::new(
+            redaction,
+            tenant_id,
+            self.ckh_database_name.clone(),
+        ))
+        .attach_printable_lazy(|| format!("Failed to add redaction event {redaction:?}"))
+    }
+
     pub async fn log_refund(
         &self,
         refund: &Refund,
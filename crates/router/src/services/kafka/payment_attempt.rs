@@ -58,6 +58,7 @@ pub struct KafkaPaymentAttempt<'a> {
     pub organization_id: &'a id_type::OrganizationId,
     pub card_network: Option<String>,
     pub card_discovery: Option<String>,
+    pub estimated_connector_cost: Option<MinorUnit>,
 }
 
 #[cfg(feature = "v1")]
@@ -119,6 +120,7 @@ impl<'a> KafkaPaymentAttempt<'a> {
             card_discovery: attempt
                 .card_discovery
                 .map(|discovery| discovery.to_string()),
+            estimated_connector_cost: attempt.estimated_connector_cost,
         }
     }
 }
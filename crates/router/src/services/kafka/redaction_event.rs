@@ -0,0 +1,49 @@
+use common_utils::id_type;
+use time::OffsetDateTime;
+
+use crate::events;
+
+/// Type of the record whose personal data was redacted
+#[derive(serde::Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactedObjectType {
+    PaymentIntent,
+    PaymentAttempt,
+    WebhookEvent,
+}
+
+/// Published once the personal data of a record is redacted, so that the consumers of the
+/// analytics topics remove the personal data carried by the earlier events of the record.
+#[derive(serde::Serialize, Debug)]
+pub struct KafkaRedactionEvent<'a> {
+    pub merchant_id: &'a id_type::MerchantId,
+    pub object_type: RedactedObjectType,
+    pub object_id: &'a str,
+    #[serde(with = "time::serde::timestamp::nanoseconds")]
+    pub redacted_at: OffsetDateTime,
+}
+
+impl<'a> KafkaRedactionEvent<'a> {
+    pub fn new(
+        merchant_id: &'a id_type::MerchantId,
+        object_type: RedactedObjectType,
+        object_id: &'a str,
+    ) -> Self {
+        Self {
+            merchant_id,
+            object_type,
+            object_id,
+            redacted_at: OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+impl super::KafkaMessage for KafkaRedactionEvent<'_> {
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id.get_string_repr(), self.object_id)
+    }
+
+    fn event_type(&self) -> events::EventType {
+        events::EventType::AuditEvent
+    }
+}
@@ -305,7 +305,8 @@ impl Capturable for PaymentsAuthorizeData {
                     | common_enums::IntentStatus::RequiresPaymentMethod
                     | common_enums::IntentStatus::RequiresConfirmation
                     | common_enums::IntentStatus::RequiresCapture
-                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
                 }
             },
             common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount().get_amount_as_i64()),
@@ -345,7 +346,8 @@ impl Capturable for PaymentsCaptureData {
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::RequiresCapture
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 }
@@ -388,7 +390,8 @@ impl Capturable for CompleteAuthorizeData {
                     | common_enums::IntentStatus::RequiresPaymentMethod
                     | common_enums::IntentStatus::RequiresConfirmation
                     | common_enums::IntentStatus::RequiresCapture
-                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+                    | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
                 }
             },
             common_enums::CaptureMethod::Manual => Some(payment_data.payment_attempt.get_total_amount().get_amount_as_i64()),
@@ -436,7 +439,8 @@ impl Capturable for PaymentsCancelData {
             | common_enums::IntentStatus::RequiresPaymentMethod
             | common_enums::IntentStatus::RequiresConfirmation
             | common_enums::IntentStatus::RequiresCapture
-            | common_enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+            | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | common_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => None,
         }
     }
 }
@@ -905,6 +909,7 @@ impl ForeignFrom<&SetupMandateRouterData> for PaymentsAuthorizeData {
             request_incremental_authorization: data.request.request_incremental_authorization,
             metadata: None,
             request_extended_authorization: None,
+            enable_partial_authorization: None,
             authentication_data: None,
             customer_acceptance: data.request.customer_acceptance.clone(),
             split_payments: None, // TODO: allow charges on mandates?
@@ -197,6 +197,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             is_debit_routing_enabled: Some(item.is_debit_routing_enabled),
             merchant_business_country: item.merchant_business_country,
             is_pre_network_tokenization_enabled: item.is_pre_network_tokenization_enabled,
+            dispute_policy: item.dispute_policy,
+            integrity_check_policy: item.integrity_check_policy,
         })
     }
 }
@@ -448,5 +450,7 @@ pub async fn create_profile_from_merchant_account(
         is_pre_network_tokenization_enabled: request
             .is_pre_network_tokenization_enabled
             .unwrap_or_default(),
+        dispute_policy: request.dispute_policy,
+        integrity_check_policy: request.integrity_check_policy,
     }))
 }
@@ -1,4 +1,9 @@
 use api_models::customers;
+#[cfg(feature = "v1")]
+pub use api_models::customers::{
+    CustomerDataExportResponse, CustomerDataExportRetrieveRequest, CustomerErasureRequest,
+    CustomerErasureRequestInternal, CustomerErasureResponse,
+};
 pub use api_models::customers::{
     CustomerDeleteResponse, CustomerListRequest, CustomerRequest, CustomerUpdateRequest,
     CustomerUpdateRequestInternal,
@@ -27,6 +27,23 @@ pub struct DisputeEvidence {
     pub invoice_showing_distinct_transactions: Option<String>,
     pub recurring_transaction_agreement: Option<String>,
     pub uncategorized_file: Option<String>,
+    // Text evidence last submitted by the merchant, retained so that drafts do not overwrite it
+    pub access_activity_log: Option<String>,
+    pub billing_address: Option<String>,
+    pub cancellation_policy_disclosure: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_purchase_ip: Option<String>,
+    pub product_description: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub service_date: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_date: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub uncategorized_text: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
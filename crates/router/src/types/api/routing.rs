@@ -3,7 +3,7 @@ pub use api_models::{
     routing::{
         ConnectorVolumeSplit, RoutableChoiceKind, RoutableConnectorChoice, RoutingAlgorithmKind,
         RoutingAlgorithmRef, RoutingConfigRequest, RoutingDictionary, RoutingDictionaryRecord,
-        StaticRoutingAlgorithm, StraightThroughAlgorithm,
+        RoutingTrace, RoutingTraceStage, StaticRoutingAlgorithm, StraightThroughAlgorithm,
     },
 };
 
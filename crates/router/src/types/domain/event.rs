@@ -82,6 +82,11 @@ pub enum EventUpdate {
     OverallDeliveryStatusUpdate {
         is_overall_delivery_successful: bool,
     },
+    /// Replaces the stored request and response of the event by their redacted versions
+    RedactionUpdate {
+        request: OptionalEncryptableSecretString,
+        response: OptionalEncryptableSecretString,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -92,6 +97,7 @@ impl From<EventUpdate> for EventUpdateInternal {
                 response,
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
+                request: None,
                 response: response.map(Into::into),
                 is_overall_delivery_successful: None,
             },
@@ -99,9 +105,16 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_overall_delivery_successful,
             } => Self {
                 is_webhook_notified: None,
+                request: None,
                 response: None,
                 is_overall_delivery_successful: Some(is_overall_delivery_successful),
             },
+            EventUpdate::RedactionUpdate { request, response } => Self {
+                is_webhook_notified: None,
+                request: request.map(Into::into),
+                response: response.map(Into::into),
+                is_overall_delivery_successful: None,
+            },
         }
     }
 }
@@ -376,9 +376,35 @@ pub struct CheckTokenStatusResponsePayload {
     pub token_expiry_month: Secret<String>,
     pub token_expiry_year: Secret<String>,
     pub token_status: TokenStatus,
+    #[serde(default)]
+    pub card_art_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CheckTokenStatusResponse {
     pub payload: CheckTokenStatusResponsePayload,
 }
+
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum NetworkTokenLifecycleEvent {
+    Suspend,
+    Resume,
+    Delete,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenLifecycleWebhook {
+    pub card_reference: String, //network token requestor ref id
+    pub event: NetworkTokenLifecycleEvent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkTokenMetadata {
+    pub token_last4: Option<String>,
+    pub token_expiry_month: Option<Secret<String>>,
+    pub token_expiry_year: Option<Secret<String>>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub card_art_url: Option<String>,
+}
@@ -23,6 +23,7 @@ pub mod generic_link;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod local_vault;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -39,6 +40,7 @@ pub mod revenue_recovery;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_trace;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -72,8 +74,9 @@ pub use self::{
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
     capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
     dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    generic_link::*, gsm::*, local_vault::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
     process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    routing_trace::*, unified_translations::*, user::*, user_authentication_method::*,
+    user_role::*,
 };
@@ -1 +1,4 @@
-pub use diesel_models::cards_info::{CardInfo, UpdateCardInfo};
+pub use diesel_models::{
+    card_bin::{CardBinImport, CardBinImportNew, CardBinRange},
+    cards_info::{CardInfo, UpdateCardInfo},
+};
@@ -3,3 +3,9 @@ pub use diesel_models::customers::{Customer, CustomerNew, CustomerUpdateInternal
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 pub use crate::types::domain::CustomerGeneralUpdate;
 pub use crate::types::domain::CustomerUpdate;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CustomerErasureTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+}
@@ -0,0 +1 @@
+pub use diesel_models::local_vault::{LocalVault, LocalVaultNew, LocalVaultUpdate};
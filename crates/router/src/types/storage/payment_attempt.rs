@@ -118,6 +118,20 @@ impl AttemptStatusExt for enums::AttemptStatus {
     }
 }
 
+// Tracking data by process_tracker
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentRequeueTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// Attempt which was left pending when the payment was requeued
+    pub attempt_id: String,
+    pub payment_token: String,
+    pub payment_method: enums::PaymentMethod,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// Connector through which the payment is retried
+    pub connector: api_models::routing::RoutableConnectorChoice,
+}
+
 #[cfg(test)]
 #[cfg(all(
     feature = "v1", // Ignoring tests for v2 since they aren't actively running
@@ -225,6 +239,7 @@ mod tests {
             processor_merchant_id: Default::default(),
             created_by: None,
             setup_future_usage_applied: Default::default(),
+            estimated_connector_cost: Default::default(),
         };
 
         let store = state
@@ -315,6 +330,7 @@ mod tests {
             processor_merchant_id: Default::default(),
             created_by: None,
             setup_future_usage_applied: Default::default(),
+            estimated_connector_cost: Default::default(),
         };
         let store = state
             .stores
@@ -418,6 +434,7 @@ mod tests {
             processor_merchant_id: Default::default(),
             created_by: None,
             setup_future_usage_applied: Default::default(),
+            estimated_connector_cost: Default::default(),
         };
         let store = state
             .stores
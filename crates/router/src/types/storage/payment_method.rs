@@ -178,3 +178,18 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AccountUpdaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub task: AccountUpdaterTask,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountUpdaterTask {
+    /// Periodically exports a batch of the cards of the merchant which need updates
+    Export,
+    /// Applies the updates in the response file of a batch
+    Ingest { batch_id: String },
+}
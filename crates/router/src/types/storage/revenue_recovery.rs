@@ -1,13 +1,15 @@
 use std::fmt::Debug;
 
+use api_models::payments::PaymentRevenueRecoveryMetadata;
 use common_enums::enums;
 use common_utils::id_type;
 use hyperswitch_domain_models::{
     business_profile, merchant_account, merchant_connector_account, merchant_key_store,
+    payments::{payment_attempt::PaymentAttempt, PaymentIntent},
 };
 use router_env::logger;
 
-use crate::{db::StorageInterface, workflows::revenue_recovery};
+use crate::{routes::SessionState, workflows::revenue_recovery};
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct RevenueRecoveryWorkflowTrackingData {
     pub merchant_id: id_type::MerchantId,
@@ -29,10 +31,25 @@ pub struct RevenueRecoveryPaymentData {
 impl RevenueRecoveryPaymentData {
     pub async fn get_schedule_time_based_on_retry_type(
         &self,
-        db: &dyn StorageInterface,
+        state: &SessionState,
         merchant_id: &id_type::MerchantId,
         retry_count: i32,
+        payment_intent: &PaymentIntent,
+        payment_attempt: &PaymentAttempt,
+        revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
     ) -> Option<time::PrimitiveDateTime> {
+        // The retry limits of the card network apply to every retry algorithm
+        if let Some(ledger) = revenue_recovery::get_network_retry_ledger(
+            merchant_id,
+            payment_attempt,
+            revenue_recovery_metadata,
+        ) {
+            if ledger.check(state).await.is_err() {
+                logger::info!("Network retry limit reached for Revenue Recovery payment");
+                return None;
+            }
+        }
+
         match self.retry_algorithm {
             enums::RevenueRecoveryAlgorithmType::Monitoring => {
                 logger::error!("Monitoring type found for Revenue Recovery retry payment");
@@ -40,15 +57,22 @@ impl RevenueRecoveryPaymentData {
             }
             enums::RevenueRecoveryAlgorithmType::Cascading => {
                 revenue_recovery::get_schedule_time_to_retry_mit_payments(
-                    db,
+                    &*state.store,
                     merchant_id,
                     retry_count,
                 )
                 .await
             }
             enums::RevenueRecoveryAlgorithmType::Smart => {
-                // TODO: Integrate the smart retry call to return back a schedule time
-                None
+                revenue_recovery::get_schedule_time_for_smart_retry(
+                    state,
+                    self,
+                    retry_count,
+                    payment_intent,
+                    payment_attempt,
+                    revenue_recovery_metadata,
+                )
+                .await
             }
         }
     }
@@ -480,7 +480,8 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
             | api_enums::IntentStatus::PartiallyCapturedAndCapturable => {
                 Some(storage_enums::EventType::PaymentCaptured)
             }
-            api_enums::IntentStatus::RequiresCapture => {
+            api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod => {
                 Some(storage_enums::EventType::PaymentAuthorized)
             }
             api_enums::IntentStatus::RequiresPaymentMethod
@@ -1065,6 +1066,54 @@ impl ForeignFrom<diesel_models::cards_info::CardInfo> for api_models::cards_info
     }
 }
 
+impl ForeignTryFrom<diesel_models::card_bin::CardBinImport>
+    for api_models::cards_info::CardBinImportResponse
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn foreign_try_from(item: diesel_models::card_bin::CardBinImport) -> Result<Self, Self::Error> {
+        let file_format = item
+            .file_format
+            .parse()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Invalid file format {} stored for card BIN import",
+                    item.file_format
+                )
+            })?;
+        Ok(Self {
+            version: item.version,
+            source: item.source,
+            file_format,
+            total_ranges: item.total_ranges,
+            ranges_added: item.ranges_added,
+            ranges_removed: item.ranges_removed,
+            ranges_modified: item.ranges_modified,
+            is_active: item.is_active,
+            created_at: item.created_at,
+        })
+    }
+}
+
+impl ForeignFrom<diesel_models::card_bin::CardBinRange>
+    for api_models::cards_info::CardBinRangeRecord
+{
+    fn foreign_from(item: diesel_models::card_bin::CardBinRange) -> Self {
+        Self {
+            range_start: item.range_start,
+            range_end: Some(item.range_end),
+            card_issuer: item.card_issuer,
+            card_network: item.card_network,
+            card_type: item.card_type,
+            card_subtype: item.card_subtype,
+            card_issuing_country: item.card_issuing_country,
+            bank_code_id: item.bank_code_id,
+            bank_code: item.bank_code,
+            country_code: item.country_code,
+        }
+    }
+}
+
 impl ForeignTryFrom<domain::MerchantConnectorAccount>
     for api_models::admin::MerchantConnectorListResponse
 {
@@ -1297,6 +1346,7 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
+            fee_schedule: item.fee_schedule,
         };
         Ok(response)
     }
@@ -1882,6 +1932,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             unified_message: value.unified_message,
             error_category: value.error_category,
             clear_pan_possible: value.clear_pan_possible,
+            requeue_delay_in_seconds: value.requeue_delay_in_seconds,
         }
     }
 }
@@ -1902,6 +1953,7 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             unified_message: value.unified_message,
             error_category: value.error_category,
             clear_pan_possible: value.clear_pan_possible,
+            requeue_delay_in_seconds: value.requeue_delay_in_seconds,
         }
     }
 }
@@ -1174,6 +1174,7 @@ where
             | enums::IntentStatus::Failed
             | enums::IntentStatus::PartiallyCaptured
             | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::PartiallyAuthorizedAndRequiresPaymentMethod
     ) {
         let payments_response = crate::core::payments::transformers::payments_to_payments_response(
             payment_data,
@@ -289,6 +289,7 @@ pub async fn generate_sample_data(
             force_3ds_challenge: None,
             force_3ds_challenge_trigger: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
         };
         let (connector_transaction_id, processor_transaction_data) =
             ConnectorTransactionId::form_id_and_data(attempt_id.clone());
@@ -381,6 +382,7 @@ pub async fn generate_sample_data(
             processor_merchant_id: Some(merchant_id.clone()),
             created_by: None,
             setup_future_usage_applied: None,
+            estimated_connector_cost: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
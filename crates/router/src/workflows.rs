@@ -1,9 +1,13 @@
+pub mod account_updater;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod customer_erasure;
+pub mod dispute_deadline;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
+pub mod payment_requeue;
 pub mod payment_sync;
 
 pub mod refund_router;
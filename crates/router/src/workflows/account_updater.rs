@@ -0,0 +1,106 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::core::payment_methods::account_updater;
+use crate::{
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, AccountUpdaterTask, AccountUpdaterTrackingData},
+    },
+};
+
+pub struct AccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: AccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        match tracking_data.task {
+            AccountUpdaterTask::Export => {
+                Box::pin(account_updater::export_account_updater_batch(
+                    state,
+                    &merchant_context,
+                ))
+                .await?;
+
+                // The export task is rescheduled for the next batch until the merchant unschedules it
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::hours(
+                        i64::from(state.conf.account_updater.batch_interval_in_hours),
+                    ));
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            AccountUpdaterTask::Ingest { batch_id } => {
+                Box::pin(account_updater::ingest_account_updater_response_file(
+                    state,
+                    &merchant_context,
+                    &batch_id,
+                ))
+                .await?;
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+use common_utils::ext_traits::ValueExt;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use diesel_models::process_tracker::business_status;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{
+    core::customers::data_subject_requests,
+    types::{domain, storage::CustomerErasureTrackingData},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct CustomerErasureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CustomerErasureWorkflow {
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: CustomerErasureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerErasureTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        Box::pin(data_subject_requests::erase_customer_data(
+            state,
+            &merchant_context,
+            &tracking_data.customer_id,
+        ))
+        .await?;
+
+        data_subject_requests::record_customer_erasure(
+            state,
+            &merchant_context,
+            &tracking_data.customer_id,
+            &process.id,
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "v1", feature = "olap")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        // Failed erasures are not retried, they are reported as failed until they are requested
+        // again
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,359 @@
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "email")]
+use common_utils::{
+    errors::ValidationError,
+    types::{AmountConvertor, StringMajorUnitForConnector},
+};
+use diesel_models::{process_tracker::business_status, DisputeDeadlineTrackingData};
+use router_env::logger;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "email")]
+use crate::{
+    consts, services::email::types::DisputeDeadlineReminder, types::domain::UserEmail,
+    utils::user as user_utils,
+};
+use crate::{
+    core::{disputes, metrics, webhooks as webhooks_core},
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::{api, domain, storage, transformers::ForeignInto},
+};
+
+pub struct DisputeDeadlineWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeDeadlineWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DisputeDeadlineTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // Nothing is left to be tracked once the dispute has moved out of the opened state
+        let challenge_required_by = match dispute.challenge_required_by {
+            Some(challenge_required_by)
+                if dispute.dispute_status == storage::enums::DisputeStatus::DisputeOpened =>
+            {
+                challenge_required_by
+            }
+            _ => {
+                return Ok(db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?);
+            }
+        };
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account.clone(),
+            key_store,
+        )));
+        let dispute_policy = business_profile.dispute_policy.clone().unwrap_or_default();
+        let current_time = common_utils::date_time::now();
+
+        if current_time >= challenge_required_by {
+            let updated_dispute = db
+                .update_dispute(
+                    dispute,
+                    storage::DisputeUpdate::StatusUpdate {
+                        dispute_status: storage::enums::DisputeStatus::DisputeExpired,
+                        connector_status: None,
+                    },
+                )
+                .await?;
+            metrics::DISPUTE_DEADLINE_MISSED_METRIC.add(1, &[]);
+
+            trigger_dispute_webhook(
+                state,
+                merchant_context,
+                business_profile,
+                &updated_dispute,
+                storage::enums::EventType::DisputeExpired,
+            )
+            .await;
+
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        if dispute_policy.should_auto_accept(dispute.dispute_amount) {
+            match Box::pin(disputes::accept_dispute(
+                state.clone(),
+                merchant_context.clone(),
+                None,
+                api::disputes::DisputeId {
+                    dispute_id: dispute.dispute_id.clone(),
+                },
+            ))
+            .await
+            {
+                Ok(_) => {
+                    metrics::DISPUTE_AUTO_ACCEPTED_METRIC.add(1, &[]);
+                    return Ok(db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::COMPLETED_BY_PT,
+                        )
+                        .await?);
+                }
+                // The merchant is still reminded about the dispute if the connector fails to accept it
+                Err(error) => {
+                    logger::error!(?error, dispute_id = %dispute.dispute_id, "Failed to auto accept dispute");
+                }
+            }
+        }
+
+        let due_reminder = disputes::get_due_dispute_deadline_reminder(
+            challenge_required_by,
+            &dispute_policy.reminder_hours_before_deadline,
+            current_time,
+        );
+
+        if let Some(reminder_hours_before_deadline) = due_reminder {
+            let hours_remaining = (challenge_required_by - current_time).whole_hours();
+
+            trigger_dispute_deadline_reminder_webhook(
+                state,
+                merchant_context,
+                business_profile,
+                &dispute,
+                reminder_hours_before_deadline,
+            )
+            .await;
+
+            #[cfg(feature = "email")]
+            send_dispute_deadline_reminder_email(
+                state,
+                &merchant_account,
+                &dispute,
+                challenge_required_by,
+                hours_remaining,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to send dispute deadline reminder email")
+            })
+            .ok();
+
+            logger::info!(
+                dispute_id = %dispute.dispute_id,
+                hours_remaining,
+                "Dispute deadline reminder sent"
+            );
+            metrics::DISPUTE_DEADLINE_REMINDER_SENT_METRIC.add(1, &[]);
+        }
+
+        let schedule_time = disputes::get_dispute_deadline_schedule_time(
+            challenge_required_by,
+            &dispute_policy.reminder_hours_before_deadline,
+            current_time,
+        );
+        db.as_scheduler()
+            .retry_process(process, schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "v1")]
+async fn trigger_dispute_webhook(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    dispute: &storage::Dispute,
+    event_type: storage::enums::EventType,
+) {
+    let disputes_response = Box::new(dispute.clone().foreign_into());
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context,
+        business_profile,
+        event_type,
+        storage::enums::EventClass::Disputes,
+        dispute.dispute_id.clone(),
+        storage::enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+        Some(dispute.created_at),
+    ))
+    .await
+    .map_err(|error| logger::warn!(dispute_outgoing_webhook_error=?error))
+    .ok();
+}
+
+/// Every reminder is a distinct event for the merchant, while retries of the task for the same
+/// reminder are deduplicated
+#[cfg(feature = "v1")]
+async fn trigger_dispute_deadline_reminder_webhook(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    dispute: &storage::Dispute,
+    reminder_hours_before_deadline: u16,
+) {
+    let event_type = storage::enums::EventType::DisputeDeadlineApproaching;
+    let disputes_response = Box::new(dispute.clone().foreign_into());
+    let idempotent_event_id =
+        get_dispute_deadline_reminder_event_id(dispute, reminder_hours_before_deadline);
+
+    Box::pin(
+        webhooks_core::create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_context,
+            business_profile,
+            event_type,
+            storage::enums::EventClass::Disputes,
+            dispute.dispute_id.clone(),
+            storage::enums::EventObjectType::DisputeDetails,
+            api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+            Some(dispute.created_at),
+            idempotent_event_id,
+        ),
+    )
+    .await
+    .map_err(|error| logger::warn!(dispute_outgoing_webhook_error=?error))
+    .ok();
+}
+
+/// The evidence deadline is a part of the event id, so that the reminders are sent again once the
+/// deadline of the dispute is extended. The event type is left out of the id as it is limited to
+/// 64 characters.
+#[cfg(feature = "v1")]
+fn get_dispute_deadline_reminder_event_id(
+    dispute: &storage::Dispute,
+    reminder_hours_before_deadline: u16,
+) -> String {
+    let challenge_required_by = dispute
+        .challenge_required_by
+        .map(|challenge_required_by| challenge_required_by.assume_utc().unix_timestamp())
+        .unwrap_or_default();
+
+    format!(
+        "{}_reminder_{}_{}h",
+        dispute.dispute_id, challenge_required_by, reminder_hours_before_deadline
+    )
+}
+
+#[cfg(all(feature = "v1", feature = "email"))]
+async fn send_dispute_deadline_reminder_email(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    challenge_required_by: time::PrimitiveDateTime,
+    hours_remaining: i64,
+) -> Result<(), errors::ProcessTrackerError> {
+    let email_id = merchant_account
+        .merchant_details
+        .clone()
+        .parse_value::<api::MerchantDetails>("MerchantDetails")?
+        .primary_email
+        .ok_or(errors::ProcessTrackerError::EValidationError(
+            ValidationError::MissingRequiredField {
+                field_name: "email".to_string(),
+            }
+            .into(),
+        ))?;
+
+    let amount = dispute
+        .dispute_currency
+        .and_then(|currency| {
+            StringMajorUnitForConnector
+                .convert(dispute.dispute_amount, currency)
+                .ok()
+        })
+        .map(|amount| amount.get_amount_as_string())
+        .unwrap_or_else(|| dispute.dispute_amount.to_string());
+
+    let email_contents = DisputeDeadlineReminder {
+        recipient_email: UserEmail::from_pii_email(email_id).map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to convert recipient's email to UserEmail from pii::Email"
+            );
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?,
+        subject: consts::EMAIL_SUBJECT_DISPUTE_DEADLINE_REMINDER,
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.clone(),
+        connector: dispute.connector.clone(),
+        amount,
+        currency: dispute.currency.clone(),
+        challenge_required_by,
+        hours_remaining,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .map_err(errors::ProcessTrackerError::EEmailError)?;
+
+    Ok(())
+}
@@ -358,8 +358,10 @@ async fn get_outgoing_webhook_content_and_event_type(
     key_store: domain::MerchantKeyStore,
     tracking_data: &OutgoingWebhookTrackingData,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
+    use ::payment_methods::controller::PaymentMethodsController;
     use api_models::{
         mandates::MandateId,
+        payment_methods::PaymentMethodId,
         payments::{PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
         refunds::{RefundResponse, RefundsRetrieveRequest},
     };
@@ -368,6 +370,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::cards::PmCards,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -535,6 +538,44 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let request = PaymentMethodId {
+                payment_method_id: tracking_data.primary_object_id.clone(),
+            };
+
+            let payment_method_response = match (PmCards {
+                state: &state,
+                merchant_context: &merchant_context,
+            })
+            .retrieve_payment_method(request)
+            .await?
+            {
+                ApplicationResponse::Json(payment_method_response)
+                | ApplicationResponse::JsonWithHeaders((payment_method_response, _)) => {
+                    Ok(payment_method_response)
+                }
+                ApplicationResponse::StatusOk
+                | ApplicationResponse::TextPlain(_)
+                | ApplicationResponse::JsonForRedirection(_)
+                | ApplicationResponse::Form(_)
+                | ApplicationResponse::GenericLinkForm(_)
+                | ApplicationResponse::PaymentLinkForm(_)
+                | ApplicationResponse::FileData(_) => {
+                    Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })
+                }
+            }
+            .map(Box::new)?;
+
+            // Payment method events are not tied to a status of the payment method, the current
+            // details of the payment method are always delivered
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+                Some(EventType::PaymentMethodUpdated),
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
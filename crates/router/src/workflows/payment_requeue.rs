@@ -0,0 +1,304 @@
+use common_utils::ext_traits::{Encode, OptionExt, ValueExt};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        errors::StorageErrorExt,
+        payments::{self as payment_flows, operations},
+    },
+    errors,
+    routes::{lock_utils, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+pub struct PaymentRequeueWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentRequeueWorkflow {
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::payment_attempt::PaymentRequeueTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentRequeueTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        // The payment is locked as it is by the payments API, so that the retry does not race
+        // with a confirm, sync or webhook updating the payment at the same time
+        let lock_action = api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: tracking_data.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: None,
+            },
+        };
+        lock_action
+            .clone()
+            .perform_locking_action(state, tracking_data.merchant_id.clone())
+            .await?;
+
+        let retry_result = retry_requeued_payment(state, merchant_context, &tracking_data).await;
+
+        lock_action
+            .free_lock_action(state, tracking_data.merchant_id.clone())
+            .await?;
+        retry_result?;
+
+        Ok(db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Confirms the requeued payment again with the connector it was requeued for, as long as it is
+/// still processing the attempt which was requeued. Must be called while holding the lock of the
+/// payment.
+#[cfg(feature = "v1")]
+async fn retry_requeued_payment(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    tracking_data: &storage::payment_attempt::PaymentRequeueTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await?;
+
+    // The payment could have been updated through a webhook or a sync since it was requeued
+    if payment_intent.status != enums::IntentStatus::Processing
+        || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+    {
+        return Ok(());
+    }
+
+    let routing = api_models::routing::StraightThroughAlgorithm::Single(Box::new(
+        tracking_data.connector.clone(),
+    ))
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode routing for requeued payment")?;
+
+    let payments_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(
+            tracking_data.payment_id.clone(),
+        )),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        confirm: Some(true),
+        payment_token: Some(tracking_data.payment_token.clone()),
+        payment_method: Some(tracking_data.payment_method),
+        payment_method_type: tracking_data.payment_method_type,
+        routing: Some(routing),
+        retry_action: Some(api_models::enums::RetryAction::Requeue),
+        ..Default::default()
+    };
+
+    // The outgoing webhook for the outcome of the retry is triggered while confirming the payment
+    let confirm_result = Box::pin(payment_flows::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_context.clone(),
+        None,
+        operations::PaymentConfirm,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payment_flows::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::with_source(
+            enums::PaymentSource::Scheduler,
+        ),
+    ))
+    .await;
+
+    if let Err(error) = confirm_result {
+        logger::error!(?error, "Failed to retry requeued payment");
+        fail_requeued_payment(state, merchant_context, tracking_data).await?;
+    }
+
+    Ok(())
+}
+
+/// Moves a requeued payment that could not be retried to failed, so that it is not left processing
+#[cfg(feature = "v1")]
+async fn fail_requeued_payment(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    tracking_data: &storage::payment_attempt::PaymentRequeueTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let (mut payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::PSync>,
+    >(
+        state,
+        state.get_req_state(),
+        &merchant_context,
+        None,
+        operations::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            ..Default::default()
+        },
+        payment_flows::CallConnectorAction::Avoid,
+        services::AuthFlow::Merchant,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await?;
+
+    if payment_data.payment_intent.status != enums::IntentStatus::Processing {
+        return Ok(());
+    }
+
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let payment_attempt_update =
+        hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptUpdate::ErrorUpdate {
+            connector: None,
+            status: enums::AttemptStatus::Failure,
+            error_code: None,
+            error_message: None,
+            error_reason: Some(Some(consts::REQUEUE_FAILED_ERROR_MESSAGE.to_string())),
+            amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
+            updated_by: storage_scheme.to_string(),
+            unified_code: None,
+            unified_message: None,
+            connector_transaction_id: None,
+            payment_method_data: None,
+            authentication_type: None,
+            issuer_error_code: None,
+            issuer_error_message: None,
+        };
+    let payment_intent_update =
+        hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Failed,
+            updated_by: storage_scheme.to_string(),
+            incremental_authorization_allowed: Some(false),
+        };
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt,
+            payment_attempt_update,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            &state.into(),
+            payment_data.payment_intent,
+            payment_intent_update,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let profile_id = payment_data
+        .payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find profile_id in payment intent")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Trigger the outgoing webhook to notify the merchant about the failed payment
+    Box::pin(utils::trigger_payments_webhook(
+        merchant_context,
+        business_profile,
+        payment_data,
+        customer,
+        state,
+        operations::PaymentStatus,
+    ))
+    .await
+    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "v2")]
-use api_models::payments::PaymentsGetIntentRequest;
+use api_models::payments::{PaymentRevenueRecoveryMetadata, PaymentsGetIntentRequest};
 #[cfg(feature = "v2")]
 use common_utils::{
     ext_traits::{StringExt, ValueExt},
@@ -8,7 +8,11 @@ use common_utils::{
 #[cfg(feature = "v2")]
 use error_stack::ResultExt;
 #[cfg(feature = "v2")]
-use hyperswitch_domain_models::payments::PaymentIntentData;
+use hyperswitch_domain_models::payments::{
+    payment_attempt::PaymentAttempt, PaymentIntent, PaymentIntentData,
+};
+#[cfg(feature = "v2")]
+use masking::PeekInterface;
 #[cfg(feature = "v2")]
 use router_env::logger;
 use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors};
@@ -19,6 +23,8 @@ use storage_impl::errors as storage_errors;
 
 #[cfg(feature = "v2")]
 use crate::{
+    configs::settings::NetworkRetryLimit,
+    consts,
     core::{
         payments,
         revenue_recovery::{self as pcr},
@@ -200,3 +206,611 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
 
     scheduler_utils::get_time_from_delta(time_delta)
 }
+
+/// Declines are grouped by how likely they are to be approved on a later retry
+#[cfg(feature = "v2")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SmartRetryDeclineType {
+    /// Declines which will not be approved on a retry, such as fraud or incorrect card data
+    Hard,
+    /// Processor side failures, which usually recover within a few hours
+    Transient,
+    /// Issuer declines such as insufficient funds, which are more likely to be approved after a payday
+    Funds,
+    /// Issuer declines without a more specific category
+    Soft,
+}
+
+#[cfg(feature = "v2")]
+impl SmartRetryDeclineType {
+    fn from_gsm_record(gsm_record: &storage::gsm::GatewayStatusMap) -> Option<Self> {
+        match gsm_record.error_category {
+            Some(
+                common_enums::ErrorCategory::FrmDecline
+                | common_enums::ErrorCategory::ProcessorDeclineIncorrectData,
+            ) => Some(Self::Hard),
+            Some(common_enums::ErrorCategory::ProcessorDowntime) => Some(Self::Transient),
+            Some(common_enums::ErrorCategory::IssueWithPaymentMethod) => Some(Self::Funds),
+            Some(common_enums::ErrorCategory::ProcessorDeclineUnauthorized) => Some(Self::Soft),
+            // A unified code other than the default one means the decline was reviewed and mapped
+            None => gsm_record
+                .unified_code
+                .as_deref()
+                .filter(|unified_code| *unified_code != consts::DEFAULT_UNIFIED_ERROR_CODE)
+                .map(|_| Self::Soft),
+        }
+    }
+
+    /// Minimum delay before the next retry, which grows with the number of retries already made
+    fn get_retry_delay(self, retry_count: i32) -> Option<time::Duration> {
+        let base_delay = match self {
+            Self::Hard => return None,
+            Self::Transient => time::Duration::hours(SMART_RETRY_TRANSIENT_DECLINE_DELAY_IN_HOURS),
+            Self::Funds | Self::Soft => {
+                time::Duration::hours(SMART_RETRY_ISSUER_DECLINE_DELAY_IN_HOURS)
+            }
+        };
+        Some(base_delay.saturating_mul(retry_count.clamp(1, SMART_RETRY_MAX_BACKOFF_MULTIPLIER)))
+    }
+}
+
+#[cfg(feature = "v2")]
+const SMART_RETRY_TRANSIENT_DECLINE_DELAY_IN_HOURS: i64 = 1;
+#[cfg(feature = "v2")]
+const SMART_RETRY_ISSUER_DECLINE_DELAY_IN_HOURS: i64 = 24;
+#[cfg(feature = "v2")]
+const SMART_RETRY_MAX_BACKOFF_MULTIPLIER: i32 = 4;
+#[cfg(feature = "v2")]
+const SMART_RETRY_MID_MONTH_PAYDAY: u8 = 15;
+#[cfg(feature = "v2")]
+const SMART_RETRY_NEXT_BILLING_TIME_BUFFER_IN_HOURS: i64 = 1;
+#[cfg(all(feature = "v2", feature = "olap"))]
+const SMART_RETRY_CHARGE_HISTORY_LIMIT: u32 = 50;
+
+#[cfg(feature = "v2")]
+const NETWORK_RETRY_WINDOW_IN_SECONDS: u32 = 30 * 24 * 60 * 60;
+
+/// Retries allowed by a card network for a declined card within a rolling window of 30 days.
+/// Visa allows 15 retries and Mastercard allows 35 retries. These limits are enforced on Revenue
+/// Recovery payments even if network retry limits are not configured.
+#[cfg(feature = "v2")]
+fn get_network_retry_limits(card_network: &common_enums::CardNetwork) -> Vec<NetworkRetryLimit> {
+    let max_retries = match card_network {
+        common_enums::CardNetwork::Visa => 15,
+        common_enums::CardNetwork::Mastercard => 35,
+        _ => return Vec::new(),
+    };
+
+    vec![NetworkRetryLimit {
+        card_network: card_network.clone(),
+        max_retries,
+        window_in_seconds: NETWORK_RETRY_WINDOW_IN_SECONDS,
+    }]
+}
+
+/// Picks the next retry time for a failed revenue recovery payment based on the decline reason,
+/// paydays and the hours at which the customer was successfully charged before. The retry limits
+/// of the card network are checked before the retry time is picked. Returns `None` if the payment
+/// should not be retried anymore.
+#[cfg(feature = "v2")]
+pub(crate) async fn get_schedule_time_for_smart_retry(
+    state: &SessionState,
+    revenue_recovery_payment_data: &pcr_storage_types::RevenueRecoveryPaymentData,
+    retry_count: i32,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+    revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
+) -> Option<time::PrimitiveDateTime> {
+    let connector = payment_attempt
+        .connector
+        .clone()
+        .unwrap_or_else(|| revenue_recovery_metadata.connector.to_string());
+    let gsm_record = match payment_attempt.error.as_ref() {
+        Some(error) => {
+            payments::helpers::get_gsm_record(
+                state,
+                Some(error.code.clone()),
+                Some(error.message.clone()),
+                connector,
+                consts::AUTHORIZE_FLOW_STR.to_string(),
+            )
+            .await
+        }
+        None => None,
+    };
+
+    let Some(decline_type) = gsm_record
+        .as_ref()
+        .and_then(SmartRetryDeclineType::from_gsm_record)
+    else {
+        logger::debug!("Decline not categorised in GSM, using the cascading retry schedule");
+        return get_schedule_time_to_retry_mit_payments(
+            &*state.store,
+            revenue_recovery_payment_data.merchant_account.get_id(),
+            retry_count,
+        )
+        .await;
+    };
+
+    let Some(retry_delay) = decline_type.get_retry_delay(retry_count) else {
+        logger::info!(
+            ?decline_type,
+            "Hard decline found for Revenue Recovery payment"
+        );
+        return None;
+    };
+
+    // Processor downtimes are retried as soon as possible instead of waiting for a preferred hour
+    let preferred_hour = match decline_type {
+        SmartRetryDeclineType::Transient => None,
+        _ => get_preferred_retry_hour(state, revenue_recovery_payment_data, payment_intent).await,
+    };
+
+    let schedule_time = get_smart_retry_time(
+        common_utils::date_time::now().saturating_add(retry_delay),
+        decline_type,
+        preferred_hour,
+        revenue_recovery_metadata.invoice_next_billing_time,
+    );
+
+    logger::debug!(?decline_type, ?schedule_time, "Smart retry time computed");
+    schedule_time
+}
+
+/// Moves the earliest retry time to the next payday for declines due to insufficient funds and to
+/// the preferred hour of the customer. A retry after the next invoice is created would overlap
+/// with the recovery of that invoice, so the latest allowed time before the next billing time is
+/// picked instead when the moved time falls after it.
+#[cfg(feature = "v2")]
+fn get_smart_retry_time(
+    earliest_time: time::PrimitiveDateTime,
+    decline_type: SmartRetryDeclineType,
+    preferred_hour: Option<u8>,
+    next_billing_time: Option<time::PrimitiveDateTime>,
+) -> Option<time::PrimitiveDateTime> {
+    let mut schedule_time = earliest_time;
+
+    if decline_type == SmartRetryDeclineType::Funds {
+        let payday = get_next_payday(schedule_time.date());
+        if payday > schedule_time.date() {
+            schedule_time = payday.midnight();
+        }
+    }
+
+    if let Some(hour) = preferred_hour {
+        schedule_time = align_to_hour(schedule_time, hour);
+    }
+
+    match next_billing_time {
+        Some(next_billing_time) if schedule_time >= next_billing_time => {
+            let latest_time = next_billing_time.saturating_sub(time::Duration::hours(
+                SMART_RETRY_NEXT_BILLING_TIME_BUFFER_IN_HOURS,
+            ));
+            let latest_preferred_time = preferred_hour
+                .map(|hour| align_to_hour_before(latest_time, hour))
+                .filter(|latest_preferred_time| *latest_preferred_time >= earliest_time);
+
+            let schedule_time =
+                latest_preferred_time.or((latest_time >= earliest_time).then_some(latest_time));
+            if schedule_time.is_none() {
+                logger::info!("No smart retry time is left before the next billing time");
+            }
+            schedule_time
+        }
+        _ => Some(schedule_time),
+    }
+}
+
+#[cfg(feature = "v2")]
+fn get_card_network(payment_attempt: &PaymentAttempt) -> Option<common_enums::CardNetwork> {
+    let payment_method_data = payment_attempt
+        .payment_method_data
+        .as_ref()?
+        .peek()
+        .clone()
+        .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        .map_err(|error| logger::warn!(?error, "Failed to parse payment method data"))
+        .ok()?;
+
+    match payment_method_data {
+        api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.card_network,
+        _ => None,
+    }
+}
+
+/// Returns the ledger of the card used for the Revenue Recovery payment, if the network of the
+/// card is known. Revenue Recovery payments are made with the payment processor token of the card
+/// instead of the card number, so the token is the fingerprint of the card for every attempt.
+#[cfg(feature = "v2")]
+pub(crate) fn get_network_retry_ledger(
+    merchant_id: &id_type::MerchantId,
+    payment_attempt: &PaymentAttempt,
+    revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
+) -> Option<payments::network_retry_limit::NetworkRetryLedger> {
+    get_card_network(payment_attempt).map(|card_network| {
+        let network_retry_limits = get_network_retry_limits(&card_network);
+        payments::network_retry_limit::NetworkRetryLedger::new(
+            merchant_id.clone(),
+            card_network,
+            revenue_recovery_metadata
+                .billing_connector_payment_details
+                .payment_processor_token
+                .clone(),
+        )
+        .with_additional_limits(network_retry_limits)
+    })
+}
+
+/// Records the failed attempt of a Revenue Recovery payment in the ledger of its card, since it
+/// counts towards the retry limits of the card network for every retry algorithm
+#[cfg(feature = "v2")]
+pub(crate) async fn record_network_retry_decline(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_attempt: &PaymentAttempt,
+    revenue_recovery_metadata: &PaymentRevenueRecoveryMetadata,
+) {
+    if let Some(ledger) =
+        get_network_retry_ledger(merchant_id, payment_attempt, revenue_recovery_metadata)
+    {
+        ledger
+            .record_decline(state)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to record decline in network retry ledger")
+            })
+            .ok();
+    }
+}
+
+/// Paydays are assumed to be the first, the fifteenth and the last day of a month
+#[cfg(feature = "v2")]
+fn get_next_payday(date: time::Date) -> time::Date {
+    let last_day_of_month = time::util::days_in_year_month(date.year(), date.month());
+    [1, SMART_RETRY_MID_MONTH_PAYDAY, last_day_of_month]
+        .into_iter()
+        .find(|payday| *payday >= date.day())
+        .and_then(|payday| date.replace_day(payday).ok())
+        .unwrap_or(date)
+}
+
+/// Moves the schedule time to the given hour, without moving it later
+#[cfg(feature = "v2")]
+fn align_to_hour_before(
+    schedule_time: time::PrimitiveDateTime,
+    hour: u8,
+) -> time::PrimitiveDateTime {
+    let Ok(preferred_time) = time::Time::from_hms(hour, 0, 0) else {
+        return schedule_time;
+    };
+    let aligned_time = schedule_time.replace_time(preferred_time);
+    if aligned_time > schedule_time {
+        aligned_time.saturating_sub(time::Duration::days(1))
+    } else {
+        aligned_time
+    }
+}
+
+/// Moves the schedule time to the given hour, without moving it earlier
+#[cfg(feature = "v2")]
+fn align_to_hour(schedule_time: time::PrimitiveDateTime, hour: u8) -> time::PrimitiveDateTime {
+    let Ok(preferred_time) = time::Time::from_hms(hour, 0, 0) else {
+        return schedule_time;
+    };
+    let aligned_time = schedule_time.replace_time(preferred_time);
+    if aligned_time < schedule_time {
+        aligned_time.saturating_add(time::Duration::days(1))
+    } else {
+        aligned_time
+    }
+}
+
+/// Hour of the day at which the customer was most often charged successfully
+#[cfg(all(feature = "v2", feature = "olap"))]
+async fn get_preferred_retry_hour(
+    state: &SessionState,
+    revenue_recovery_payment_data: &pcr_storage_types::RevenueRecoveryPaymentData,
+    payment_intent: &PaymentIntent,
+) -> Option<u8> {
+    use hyperswitch_domain_models::payments::payment_intent::{
+        PaymentIntentFetchConstraints, PaymentIntentListParams,
+    };
+
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: Some(common_enums::IntentStatus::Succeeded),
+        payment_method_type: None,
+        payment_method_subtype: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: Some(payment_intent.profile_id.clone()),
+        customer_id: Some(payment_intent.customer_id.clone()?),
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(SMART_RETRY_CHARGE_HISTORY_LIMIT),
+        order: Default::default(),
+        card_network: None,
+        merchant_order_reference_id: None,
+    }));
+
+    let successful_payments = state
+        .store
+        .get_filtered_payment_intents_attempt(
+            &state.into(),
+            revenue_recovery_payment_data.merchant_account.get_id(),
+            &constraints,
+            &revenue_recovery_payment_data.key_store,
+            revenue_recovery_payment_data
+                .merchant_account
+                .storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch successful payments of the customer"
+            )
+        })
+        .ok()?;
+
+    let mut charges_per_hour = std::collections::BTreeMap::<u8, usize>::new();
+    for (intent, attempt) in successful_payments {
+        let charged_at = attempt.map_or(intent.modified_at, |attempt| attempt.modified_at);
+        *charges_per_hour.entry(charged_at.hour()).or_default() += 1;
+    }
+
+    charges_per_hour
+        .into_iter()
+        .max_by_key(|(_, charges)| *charges)
+        .map(|(hour, _)| hour)
+}
+
+#[cfg(all(feature = "v2", not(feature = "olap")))]
+async fn get_preferred_retry_hour(
+    _state: &SessionState,
+    _revenue_recovery_payment_data: &pcr_storage_types::RevenueRecoveryPaymentData,
+    _payment_intent: &PaymentIntent,
+) -> Option<u8> {
+    None
+}
+
+#[cfg(all(test, feature = "v2"))]
+mod tests {
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    fn get_gsm_record(
+        error_category: Option<common_enums::ErrorCategory>,
+        unified_code: Option<&str>,
+    ) -> storage::gsm::GatewayStatusMap {
+        storage::gsm::GatewayStatusMap {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: "sub_flow".to_string(),
+            code: "card_declined".to_string(),
+            message: "Your card was declined".to_string(),
+            status: "Failure".to_string(),
+            router_error: None,
+            decision: "do_default".to_string(),
+            created_at: datetime!(2025-01-01 0:00),
+            last_modified: datetime!(2025-01-01 0:00),
+            step_up_possible: false,
+            unified_code: unified_code.map(str::to_string),
+            unified_message: None,
+            error_category,
+            clear_pan_possible: false,
+            requeue_delay_in_seconds: None,
+        }
+    }
+
+    #[test]
+    fn test_get_next_payday() {
+        assert_eq!(
+            get_next_payday(date!(2025 - 03 - 01)),
+            date!(2025 - 03 - 01)
+        );
+        assert_eq!(
+            get_next_payday(date!(2025 - 03 - 02)),
+            date!(2025 - 03 - 15)
+        );
+        assert_eq!(
+            get_next_payday(date!(2025 - 03 - 15)),
+            date!(2025 - 03 - 15)
+        );
+        assert_eq!(
+            get_next_payday(date!(2025 - 03 - 16)),
+            date!(2025 - 03 - 31)
+        );
+        assert_eq!(
+            get_next_payday(date!(2025 - 02 - 20)),
+            date!(2025 - 02 - 28)
+        );
+        assert_eq!(
+            get_next_payday(date!(2024 - 02 - 29)),
+            date!(2024 - 02 - 29)
+        );
+    }
+
+    #[test]
+    fn test_align_to_hour() {
+        assert_eq!(
+            align_to_hour(datetime!(2025-03-10 08:30), 14),
+            datetime!(2025-03-10 14:00)
+        );
+        assert_eq!(
+            align_to_hour(datetime!(2025-03-10 16:30), 14),
+            datetime!(2025-03-11 14:00)
+        );
+        assert_eq!(
+            align_to_hour(datetime!(2025-03-10 14:00), 14),
+            datetime!(2025-03-10 14:00)
+        );
+        assert_eq!(
+            align_to_hour(datetime!(2025-03-10 16:30), 24),
+            datetime!(2025-03-10 16:30)
+        );
+
+        assert_eq!(
+            align_to_hour_before(datetime!(2025-03-10 16:30), 14),
+            datetime!(2025-03-10 14:00)
+        );
+        assert_eq!(
+            align_to_hour_before(datetime!(2025-03-10 08:30), 14),
+            datetime!(2025-03-09 14:00)
+        );
+    }
+
+    #[test]
+    fn test_smart_retry_decline_type_from_gsm_record() {
+        let get_decline_type = |error_category, unified_code| {
+            SmartRetryDeclineType::from_gsm_record(&get_gsm_record(error_category, unified_code))
+        };
+
+        assert_eq!(
+            get_decline_type(Some(common_enums::ErrorCategory::FrmDecline), None),
+            Some(SmartRetryDeclineType::Hard)
+        );
+        assert_eq!(
+            get_decline_type(
+                Some(common_enums::ErrorCategory::ProcessorDeclineIncorrectData),
+                None
+            ),
+            Some(SmartRetryDeclineType::Hard)
+        );
+        assert_eq!(
+            get_decline_type(Some(common_enums::ErrorCategory::ProcessorDowntime), None),
+            Some(SmartRetryDeclineType::Transient)
+        );
+        assert_eq!(
+            get_decline_type(
+                Some(common_enums::ErrorCategory::IssueWithPaymentMethod),
+                None
+            ),
+            Some(SmartRetryDeclineType::Funds)
+        );
+        assert_eq!(
+            get_decline_type(
+                Some(common_enums::ErrorCategory::ProcessorDeclineUnauthorized),
+                None
+            ),
+            Some(SmartRetryDeclineType::Soft)
+        );
+        assert_eq!(
+            get_decline_type(None, Some("UE_1000")),
+            Some(SmartRetryDeclineType::Soft)
+        );
+        assert_eq!(
+            get_decline_type(None, Some(consts::DEFAULT_UNIFIED_ERROR_CODE)),
+            None
+        );
+        assert_eq!(get_decline_type(None, None), None);
+    }
+
+    #[test]
+    fn test_smart_retry_decline_type_get_retry_delay() {
+        assert_eq!(SmartRetryDeclineType::Hard.get_retry_delay(1), None);
+        assert_eq!(
+            SmartRetryDeclineType::Transient.get_retry_delay(1),
+            Some(time::Duration::hours(1))
+        );
+        assert_eq!(
+            SmartRetryDeclineType::Soft.get_retry_delay(2),
+            Some(time::Duration::hours(48))
+        );
+        // The backoff stops growing after the maximum multiplier
+        assert_eq!(
+            SmartRetryDeclineType::Funds.get_retry_delay(10),
+            Some(time::Duration::hours(96))
+        );
+        assert_eq!(
+            SmartRetryDeclineType::Funds.get_retry_delay(0),
+            Some(time::Duration::hours(24))
+        );
+    }
+
+    #[test]
+    fn test_get_smart_retry_time_moves_funds_declines_to_payday() {
+        assert_eq!(
+            get_smart_retry_time(
+                datetime!(2025-03-05 10:00),
+                SmartRetryDeclineType::Funds,
+                Some(9),
+                None
+            ),
+            Some(datetime!(2025-03-15 09:00))
+        );
+        assert_eq!(
+            get_smart_retry_time(
+                datetime!(2025-03-05 10:00),
+                SmartRetryDeclineType::Soft,
+                Some(9),
+                None
+            ),
+            Some(datetime!(2025-03-06 09:00))
+        );
+    }
+
+    #[test]
+    fn test_get_smart_retry_time_before_next_billing_time() {
+        // The payday falls after the next billing time, so the latest preferred hour before it is
+        // picked
+        assert_eq!(
+            get_smart_retry_time(
+                datetime!(2025-03-05 10:00),
+                SmartRetryDeclineType::Funds,
+                Some(9),
+                Some(datetime!(2025-03-10 00:00))
+            ),
+            Some(datetime!(2025-03-09 09:00))
+        );
+        // Without a preferred hour, the retry is made just before the next billing time
+        assert_eq!(
+            get_smart_retry_time(
+                datetime!(2025-03-05 10:00),
+                SmartRetryDeclineType::Funds,
+                None,
+                Some(datetime!(2025-03-10 00:00))
+            ),
+            Some(datetime!(2025-03-09 23:00))
+        );
+        // The preferred hour before the next billing time is earlier than the earliest retry time
+        assert_eq!(
+            get_smart_retry_time(
+                datetime!(2025-03-09 12:00),
+                SmartRetryDeclineType::Funds,
+                Some(9),
+                Some(datetime!(2025-03-10 00:00))
+            ),
+            Some(datetime!(2025-03-09 23:00))
+        );
+        // No time is left before the next billing time
+        assert_eq!(
+            get_smart_retry_time(
+                datetime!(2025-03-10 00:30),
+                SmartRetryDeclineType::Soft,
+                None,
+                Some(datetime!(2025-03-10 00:00))
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_network_retry_limits() {
+        let limits = get_network_retry_limits(&common_enums::CardNetwork::Visa);
+        assert_eq!(
+            limits
+                .iter()
+                .map(|limit| (limit.max_retries, limit.window_in_seconds))
+                .collect::<Vec<_>>(),
+            vec![(15, NETWORK_RETRY_WINDOW_IN_SECONDS)]
+        );
+        assert!(get_network_retry_limits(&common_enums::CardNetwork::Discover).is_empty());
+    }
+}
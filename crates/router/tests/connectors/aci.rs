@@ -29,8 +29,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         customer_id: Some(id_type::CustomerId::try_from(Cow::from("aci")).unwrap()),
         tenant_id: id_type::TenantId::try_from_string("public".to_string()).unwrap(),
         connector: "aci".to_string(),
-        payment_id: uuid::Uuid::new_v4().to_string(),
-        attempt_id: uuid::Uuid::new_v4().to_string(),
+        payment_id: utils::generate_id(),
+        attempt_id: utils::generate_id(),
         status: enums::AttemptStatus::default(),
         auth_type: enums::AuthenticationType::NoThreeDs,
         payment_method: enums::PaymentMethod::Card,
@@ -113,7 +113,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         recurring_mandate_payment_data: None,
         connector_response: None,
         preprocessing_id: None,
-        connector_request_reference_id: uuid::Uuid::new_v4().to_string(),
+        connector_request_reference_id: utils::generate_id(),
         #[cfg(feature = "payouts")]
         payout_method_data: None,
         #[cfg(feature = "payouts")]
@@ -150,8 +150,8 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         customer_id: Some(id_type::CustomerId::try_from(Cow::from("aci")).unwrap()),
         tenant_id: id_type::TenantId::try_from_string("public".to_string()).unwrap(),
         connector: "aci".to_string(),
-        payment_id: uuid::Uuid::new_v4().to_string(),
-        attempt_id: uuid::Uuid::new_v4().to_string(),
+        payment_id: utils::generate_id(),
+        attempt_id: utils::generate_id(),
         payment_method_status: None,
         status: enums::AttemptStatus::default(),
         payment_method: enums::PaymentMethod::Card,
@@ -162,7 +162,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             payment_amount: 1000,
             currency: enums::Currency::USD,
 
-            refund_id: uuid::Uuid::new_v4().to_string(),
+            refund_id: utils::generate_id(),
             connector_transaction_id: String::new(),
             refund_amount: 100,
             webhook_url: None,
@@ -186,7 +186,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         recurring_mandate_payment_data: None,
         connector_response: None,
         preprocessing_id: None,
-        connector_request_reference_id: uuid::Uuid::new_v4().to_string(),
+        connector_request_reference_id: utils::generate_id(),
         #[cfg(feature = "payouts")]
         payout_method_data: None,
         #[cfg(feature = "payouts")]
@@ -219,7 +219,7 @@ async fn payments_create_success() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -271,7 +271,7 @@ async fn payments_create_failure() {
             conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            utils::get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -340,7 +340,7 @@ async fn refund_for_successful_payments() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -416,7 +416,7 @@ async fn refunds_create_failure() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -23,6 +23,7 @@ mod bitpay;
 mod bluesnap;
 mod boku;
 mod cashtocode;
+mod cassette;
 mod chargebee;
 mod checkout;
 mod coinbase;
@@ -1,4 +1,12 @@
-use std::{fmt::Debug, marker::PhantomData, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    marker::PhantomData,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use common_utils::pii::Email;
@@ -21,6 +29,88 @@ use test_utils::connector_auth::ConnectorAuthType;
 use tokio::sync::oneshot;
 use wiremock::{Mock, MockServer};
 
+use crate::cassette::{Cassette, CassetteApiClient, CassetteMode};
+
+/// Environment variable which selects the cassette mode of the connector tests, either `record` or
+/// `replay`
+const CASSETTE_MODE_ENV: &str = "CONNECTOR_CASSETTE_MODE";
+
+thread_local! {
+    // Every test runs on a thread of its own, and may go through several api clients
+    static CASSETTE: RefCell<Option<Arc<Mutex<Cassette>>>> = const { RefCell::new(None) };
+    // Number of ids generated by the current test, see `generate_id`
+    static GENERATED_IDS: Cell<u128> = const { Cell::new(0) };
+}
+
+fn get_cassette_mode() -> Option<CassetteMode> {
+    std::env::var(CASSETTE_MODE_ENV)
+        .ok()
+        .map(|mode| CassetteMode::from_str(&mode).expect("Invalid cassette mode"))
+}
+
+/// Generates the id of a payment, attempt or refund of the current test. With a cassette, the ids
+/// are derived from the number of ids generated by the test so far, so that the requests sent on
+/// replay carry the same ids as the recorded ones
+pub fn generate_id() -> String {
+    if get_cassette_mode().is_none() {
+        return uuid::Uuid::new_v4().to_string();
+    }
+
+    GENERATED_IDS.with(|generated_ids| {
+        let id = generated_ids.get() + 1;
+        generated_ids.set(id);
+        uuid::Uuid::from_u128(id).to_string()
+    })
+}
+
+fn generate_customer_id() -> common_utils::id_type::CustomerId {
+    if get_cassette_mode().is_none() {
+        return common_utils::generate_customer_id_of_default_length();
+    }
+
+    common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(format!(
+        "cus_{}",
+        generate_id().replace('-', "")
+    )))
+    .expect("Failed to construct customer id")
+}
+
+/// Cassettes are stored at `tests/connectors/cassettes/<connector>/<test>.json`, derived from
+/// the name of the test thread
+fn get_cassette_path() -> PathBuf {
+    let test_name = std::thread::current()
+        .name()
+        .unwrap_or("unnamed")
+        .replace("::", "/");
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/connectors/cassettes")
+        .join(format!("{test_name}.json"))
+}
+
+/// Api client to send the connector requests of the current test through. Unless a cassette mode
+/// is set, this is the mock client
+pub fn get_api_client() -> Box<dyn services::ApiClient> {
+    let Some(mode) = get_cassette_mode() else {
+        return Box::new(services::MockApiClient);
+    };
+
+    let cassette = CASSETTE.with(|cassette| {
+        Arc::clone(cassette.borrow_mut().get_or_insert_with(|| {
+            let path = get_cassette_path();
+            let cassette = match mode {
+                CassetteMode::Record => Cassette::new(path),
+                CassetteMode::Replay => Cassette::load(path).expect("Failed to load cassette"),
+            };
+            Arc::new(Mutex::new(cassette))
+        }))
+    });
+
+    Box::new(
+        CassetteApiClient::new(mode, cassette, &Default::default())
+            .expect("Failed to construct cassette api client"),
+    )
+}
+
 pub trait Connector {
     fn get_data(&self) -> types::api::ConnectorData;
 
@@ -394,7 +484,7 @@ pub trait ConnectorActions: Connector {
                 payment_amount: 1000,
                 minor_payment_amount: MinorUnit::new(1000),
                 currency: enums::Currency::USD,
-                refund_id: uuid::Uuid::new_v4().to_string(),
+                refund_id: generate_id(),
                 connector_transaction_id: "".to_string(),
                 webhook_url: None,
                 refund_amount: 100,
@@ -468,7 +558,7 @@ pub trait ConnectorActions: Connector {
                 entity_type: enums::PayoutEntityType::Individual,
                 payout_type: Some(payout_type),
                 customer_details: Some(payments::CustomerDetails {
-                    customer_id: Some(common_utils::generate_customer_id_of_default_length()),
+                    customer_id: Some(generate_customer_id()),
                     name: Some(Secret::new("John Doe".to_string())),
                     email: Email::from_str("john.doe@example").ok(),
                     phone: Some(Secret::new("620874518".to_string())),
@@ -477,6 +567,7 @@ pub trait ConnectorActions: Connector {
                 vendor_details: None,
                 priority: None,
                 connector_transfer_method_id: None,
+                integrity_object: None,
             },
             payment_info,
         )
@@ -494,12 +585,12 @@ pub trait ConnectorActions: Connector {
         RouterData {
             flow: PhantomData,
             merchant_id,
-            customer_id: Some(common_utils::generate_customer_id_of_default_length()),
+            customer_id: Some(generate_customer_id()),
             connector: self.get_name(),
             tenant_id: common_utils::id_type::TenantId::try_from_string("public".to_string())
                 .unwrap(),
-            payment_id: uuid::Uuid::new_v4().to_string(),
-            attempt_id: uuid::Uuid::new_v4().to_string(),
+            payment_id: generate_id(),
+            attempt_id: generate_id(),
             status: enums::AttemptStatus::default(),
             auth_type: info
                 .clone()
@@ -535,7 +626,7 @@ pub trait ConnectorActions: Connector {
             recurring_mandate_payment_data: None,
 
             preprocessing_id: None,
-            connector_request_reference_id: uuid::Uuid::new_v4().to_string(),
+            connector_request_reference_id: generate_id(),
             #[cfg(feature = "payouts")]
             payout_method_data: info.and_then(|p| p.payout_method_data),
             #[cfg(feature = "payouts")]
@@ -605,7 +696,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -650,7 +741,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -696,7 +787,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -741,7 +832,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -837,7 +928,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         ))
         .await;
         let state = Arc::new(app_state)
@@ -879,7 +970,7 @@ async fn call_connector<
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        get_api_client(),
     ))
     .await;
     let state = Arc::new(app_state)
@@ -986,6 +1077,7 @@ impl Default for PaymentAuthorizeType {
             surcharge_details: None,
             request_incremental_authorization: false,
             request_extended_authorization: None,
+            enable_partial_authorization: None,
             metadata: None,
             authentication_data: None,
             customer_acceptance: None,
@@ -1074,7 +1166,7 @@ impl Default for PaymentRefundType {
             payment_amount: 100,
             minor_payment_amount: MinorUnit::new(100),
             currency: enums::Currency::USD,
-            refund_id: uuid::Uuid::new_v4().to_string(),
+            refund_id: generate_id(),
             connector_transaction_id: String::new(),
             refund_amount: 100,
             minor_refund_amount: MinorUnit::new(100),
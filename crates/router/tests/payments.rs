@@ -464,6 +464,7 @@ async fn payments_create_core() {
         issuer_error_code: None,
         issuer_error_message: None,
         is_iframe_redirection_enabled: None,
+        enable_partial_authorization: None,
         whole_connector_response: None,
     };
     let expected_response =
@@ -742,6 +743,7 @@ async fn payments_create_core_adyen_no_redirect() {
             issuer_error_code: None,
             issuer_error_message: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
             whole_connector_response: None,
         },
         vec![],
@@ -226,6 +226,7 @@ async fn payments_create_core() {
         issuer_error_code: None,
         issuer_error_message: None,
         is_iframe_redirection_enabled: None,
+        enable_partial_authorization: None,
         whole_connector_response: None,
     };
 
@@ -512,6 +513,7 @@ async fn payments_create_core_adyen_no_redirect() {
             issuer_error_code: None,
             issuer_error_message: None,
             is_iframe_redirection_enabled: None,
+            enable_partial_authorization: None,
             whole_connector_response: None,
         },
         vec![],
@@ -82,6 +82,10 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Connector circuit breakers list flow.
+    ConnectorCircuitBreakersList,
+    /// Connector circuit breaker reset flow.
+    ConnectorCircuitBreakerReset,
     /// Merchant Transfer Keys
     MerchantTransferKey,
     /// ConfigKey create flow.
@@ -104,6 +108,16 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers data export flow.
+    CustomersDataExport,
+    /// Customers data export retrieve flow.
+    CustomersDataExportRetrieve,
+    /// Customers erasure schedule flow.
+    CustomersErasureSchedule,
+    /// Customers erasure retrieve flow.
+    CustomersErasureRetrieve,
+    /// Customers erasure cancel flow.
+    CustomersErasureCancel,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -307,6 +321,8 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Disputes aggregate flow
     DisputesAggregate,
+    /// Dispute Evidence draft assembly flow
+    DisputesEvidenceDraft,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
@@ -361,6 +377,8 @@ pub enum Flow {
     DecisionManagerDeleteConfig,
     /// Retrieve Decision Manager Config
     DecisionManagerRetrieveConfig,
+    /// Simulate Surcharge Decision Manager Config
+    DecisionManagerSimulateSurcharge,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
     /// Get connectors feature matrix
@@ -535,6 +553,10 @@ pub enum Flow {
     RefundsManualUpdate,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// Explain why the merchant's connectors are or aren't eligible for a payment
+    PaymentsEligibilityExplanation,
+    /// Retrieve the routing traces of a payment
+    PaymentsRoutingTrace,
     /// Dynamic Tax Calcultion
     SessionUpdateTaxCalculation,
     ProxyConfirmIntent,
@@ -556,6 +578,20 @@ pub enum Flow {
     TokenizeCardUsingPaymentMethodId,
     /// Cards batch tokenization flow
     TokenizeCardBatch,
+    /// Schedule the periodic card account updater batches flow
+    AccountUpdaterSchedule,
+    /// Unschedule the periodic card account updater batches flow
+    AccountUpdaterUnschedule,
+    /// Card account updater batch create flow
+    AccountUpdaterBatchCreate,
+    /// Card account updater request file retrieve flow
+    AccountUpdaterRequestFileRetrieve,
+    /// Card account updater response file upload flow
+    AccountUpdaterResponseFileUpload,
+    /// Customer network tokens list flow
+    CustomerNetworkTokensList,
+    /// Network token lifecycle webhook receive flow
+    NetworkTokenLifecycleWebhookReceive,
     /// Incoming Relay Webhook Receive
     IncomingRelayWebhookReceive,
     /// Generate Hypersense Token
@@ -582,6 +618,14 @@ pub enum Flow {
     CardsInfoUpdate,
     /// Cards Info migrate flow
     CardsInfoMigrate,
+    /// Card BIN import flow
+    CardBinImport,
+    /// Card BIN import list flow
+    CardBinImportList,
+    /// Card BIN import rollback flow
+    CardBinImportRollback,
+    /// Card BIN import diff flow
+    CardBinImportDiff,
     ///Total payment method count for merchant
     TotalPaymentMethodCount,
     /// Process Tracker Revenue Recovery Workflow Retrieve
@@ -596,11 +640,41 @@ pub enum Flow {
     Proxy,
     /// ThreeDs Decision Rule Execute flow
     ThreeDsDecisionRuleExecute,
+    /// ThreeDs Decision Rule Rollout upsert flow
+    ThreeDsDecisionRuleRolloutUpsert,
+    /// ThreeDs Decision Rule Rollout retrieve flow
+    ThreeDsDecisionRuleRolloutRetrieve,
+    /// ThreeDs Decision Rule Rollout delete flow
+    ThreeDsDecisionRuleRolloutDelete,
+    /// ThreeDs Decision Rule Outcome flow
+    ThreeDsDecisionRuleOutcome,
 }
 
 /// Trait for providing generic behaviour to flow metric
-pub trait FlowMetric: ToString + std::fmt::Debug + Clone {}
-impl FlowMetric for Flow {}
+pub trait FlowMetric: ToString + std::fmt::Debug + Clone {
+    /// Whether requests of the flow honour the `Idempotency-Key` header
+    fn supports_idempotency_key(&self) -> bool {
+        false
+    }
+}
+impl FlowMetric for Flow {
+    fn supports_idempotency_key(&self) -> bool {
+        matches!(
+            self,
+            Self::PaymentsCreate
+                | Self::PaymentsUpdate
+                | Self::PaymentsConfirm
+                | Self::PaymentsCapture
+                | Self::PaymentsCancel
+                | Self::RefundsCreate
+                | Self::PayoutsCreate
+                | Self::PayoutsConfirm
+                | Self::PayoutsFulfill
+                | Self::CustomersCreate
+                | Self::CustomersUpdate
+        )
+    }
+}
 
 /// Category of log event.
 #[derive(Debug)]
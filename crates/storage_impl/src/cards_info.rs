@@ -1,4 +1,6 @@
-pub use diesel_models::{CardInfo, UpdateCardInfo};
+pub use diesel_models::{
+    CardBinImport, CardBinImportNew, CardBinRange, CardInfo, PgPooledConn, UpdateCardInfo,
+};
 use error_stack::report;
 use hyperswitch_domain_models::cards_info::CardsInfoInterface;
 use router_env::{instrument, tracing};
@@ -13,6 +15,57 @@ use crate::{
 
 impl KvStorePartition for CardInfo {}
 
+/// Number of BIN ranges inserted per query, to stay within the bind parameter limit of postgres
+const CARD_BIN_RANGE_INSERT_BATCH_SIZE: usize = 1000;
+
+/// Exact matches on the IIN take precedence over the ranges of the active BIN import
+async fn find_card_info_with_bin_range_fallback(
+    conn: &PgPooledConn,
+    card_iin: &str,
+) -> CustomResult<Option<CardInfo>, StorageError> {
+    if let Some(card_info) = CardInfo::find_by_iin(conn, card_iin)
+        .await
+        .map_err(|error| report!(StorageError::from(error)))?
+    {
+        return Ok(Some(card_info));
+    }
+
+    CardBinRange::find_active_by_card_number_prefix(conn, card_iin)
+        .await
+        .map(|card_bin_range| {
+            card_bin_range.map(|card_bin_range| {
+                card_bin_range.into_card_info(card_iin, common_utils::date_time::now())
+            })
+        })
+        .map_err(|error| report!(StorageError::from(error)))
+}
+
+async fn insert_card_bin_import_with_ranges(
+    conn: &PgPooledConn,
+    card_bin_import: CardBinImportNew,
+    ranges: Vec<CardBinRange>,
+) -> CustomResult<CardBinImport, StorageError> {
+    // The import is inserted as inactive before its ranges, so that a failure midway leaves
+    // behind an unused version instead of ranges which conflict with the next import.
+    let card_bin_import = card_bin_import
+        .insert(conn)
+        .await
+        .map_err(|error| report!(StorageError::from(error)))?;
+
+    let mut ranges = ranges.into_iter().peekable();
+    while ranges.peek().is_some() {
+        let batch = ranges
+            .by_ref()
+            .take(CARD_BIN_RANGE_INSERT_BATCH_SIZE)
+            .collect();
+        CardBinRange::insert_batch(conn, batch)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))?;
+    }
+
+    Ok(card_bin_import)
+}
+
 #[async_trait::async_trait]
 impl<T: DatabaseStore> CardsInfoInterface for RouterStore<T> {
     type Error = StorageError;
@@ -24,6 +77,14 @@ impl<T: DatabaseStore> CardsInfoInterface for RouterStore<T> {
             .map_err(|error| report!(StorageError::from(error)))
     }
     #[instrument(skip_all)]
+    async fn get_card_info_with_bin_range_fallback(
+        &self,
+        card_iin: &str,
+    ) -> CustomResult<Option<CardInfo>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        find_card_info_with_bin_range_fallback(&conn, card_iin).await
+    }
+    #[instrument(skip_all)]
     async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, StorageError> {
         let conn = pg_connection_write(self).await?;
         data.insert(&conn)
@@ -41,6 +102,63 @@ impl<T: DatabaseStore> CardsInfoInterface for RouterStore<T> {
             .await
             .map_err(|error| report!(StorageError::from(error)))
     }
+    #[instrument(skip_all)]
+    async fn insert_card_bin_import(
+        &self,
+        card_bin_import: CardBinImportNew,
+        ranges: Vec<CardBinRange>,
+    ) -> CustomResult<CardBinImport, StorageError> {
+        let conn = pg_connection_write(self).await?;
+        insert_card_bin_import_with_ranges(&conn, card_bin_import, ranges).await
+    }
+    #[instrument(skip_all)]
+    async fn find_card_bin_import_by_version(
+        &self,
+        version: i32,
+    ) -> CustomResult<CardBinImport, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinImport::find_by_version(&conn, version)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn find_active_card_bin_import(
+        &self,
+    ) -> CustomResult<Option<CardBinImport>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinImport::find_active(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn list_card_bin_imports(
+        &self,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<CardBinImport>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinImport::list(&conn, limit, offset)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn activate_card_bin_import(&self, version: i32) -> CustomResult<(), StorageError> {
+        let conn = pg_connection_write(self).await?;
+        CardBinImport::activate(&conn, version)
+            .await
+            .map(|_| ())
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn list_card_bin_ranges_by_import_version(
+        &self,
+        version: i32,
+    ) -> CustomResult<Vec<CardBinRange>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinRange::find_by_import_version(&conn, version)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -54,6 +172,14 @@ impl<T: DatabaseStore> CardsInfoInterface for KVRouterStore<T> {
             .map_err(|error| report!(StorageError::from(error)))
     }
     #[instrument(skip_all)]
+    async fn get_card_info_with_bin_range_fallback(
+        &self,
+        card_iin: &str,
+    ) -> CustomResult<Option<CardInfo>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        find_card_info_with_bin_range_fallback(&conn, card_iin).await
+    }
+    #[instrument(skip_all)]
     async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, StorageError> {
         let conn = pg_connection_write(self).await?;
         data.insert(&conn)
@@ -71,6 +197,63 @@ impl<T: DatabaseStore> CardsInfoInterface for KVRouterStore<T> {
             .await
             .map_err(|error| report!(StorageError::from(error)))
     }
+    #[instrument(skip_all)]
+    async fn insert_card_bin_import(
+        &self,
+        card_bin_import: CardBinImportNew,
+        ranges: Vec<CardBinRange>,
+    ) -> CustomResult<CardBinImport, StorageError> {
+        let conn = pg_connection_write(self).await?;
+        insert_card_bin_import_with_ranges(&conn, card_bin_import, ranges).await
+    }
+    #[instrument(skip_all)]
+    async fn find_card_bin_import_by_version(
+        &self,
+        version: i32,
+    ) -> CustomResult<CardBinImport, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinImport::find_by_version(&conn, version)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn find_active_card_bin_import(
+        &self,
+    ) -> CustomResult<Option<CardBinImport>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinImport::find_active(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn list_card_bin_imports(
+        &self,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<CardBinImport>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinImport::list(&conn, limit, offset)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn activate_card_bin_import(&self, version: i32) -> CustomResult<(), StorageError> {
+        let conn = pg_connection_write(self).await?;
+        CardBinImport::activate(&conn, version)
+            .await
+            .map(|_| ())
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+    #[instrument(skip_all)]
+    async fn list_card_bin_ranges_by_import_version(
+        &self,
+        version: i32,
+    ) -> CustomResult<Vec<CardBinRange>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        CardBinRange::find_by_import_version(&conn, version)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -87,6 +270,13 @@ impl CardsInfoInterface for MockDb {
             .cloned())
     }
 
+    async fn get_card_info_with_bin_range_fallback(
+        &self,
+        card_iin: &str,
+    ) -> CustomResult<Option<CardInfo>, StorageError> {
+        self.get_card_info(card_iin).await
+    }
+
     async fn add_card_info(&self, _data: CardInfo) -> CustomResult<CardInfo, StorageError> {
         Err(StorageError::MockDbError)?
     }
@@ -98,4 +288,44 @@ impl CardsInfoInterface for MockDb {
     ) -> CustomResult<CardInfo, StorageError> {
         Err(StorageError::MockDbError)?
     }
+
+    async fn insert_card_bin_import(
+        &self,
+        _card_bin_import: CardBinImportNew,
+        _ranges: Vec<CardBinRange>,
+    ) -> CustomResult<CardBinImport, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn find_card_bin_import_by_version(
+        &self,
+        _version: i32,
+    ) -> CustomResult<CardBinImport, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn find_active_card_bin_import(
+        &self,
+    ) -> CustomResult<Option<CardBinImport>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn list_card_bin_imports(
+        &self,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<CardBinImport>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn activate_card_bin_import(&self, _version: i32) -> CustomResult<(), StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn list_card_bin_ranges_by_import_version(
+        &self,
+        _version: i32,
+    ) -> CustomResult<Vec<CardBinRange>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
 }
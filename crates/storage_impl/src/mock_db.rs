@@ -42,6 +42,8 @@ pub struct MockDb {
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
+    pub local_vault_entries: Arc<Mutex<Vec<store::LocalVault>>>,
+    pub routing_traces: Arc<Mutex<Vec<store::routing_trace::RoutingTrace>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<store::capture::Capture>>>,
     pub merchant_key_store: Arc<Mutex<Vec<store::merchant_key_store::MerchantKeyStore>>>,
@@ -91,6 +93,8 @@ impl MockDb {
             events: Default::default(),
             disputes: Default::default(),
             lockers: Default::default(),
+            local_vault_entries: Default::default(),
+            routing_traces: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
             merchant_key_store: Default::default(),
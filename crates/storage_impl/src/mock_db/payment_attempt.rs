@@ -235,6 +235,7 @@ impl PaymentAttemptInterface for MockDb {
             processor_merchant_id: payment_attempt.processor_merchant_id,
             created_by: payment_attempt.created_by,
             setup_future_usage_applied: payment_attempt.setup_future_usage_applied,
+            estimated_connector_cost: payment_attempt.estimated_connector_cost,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -102,6 +102,31 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
         .await
     }
 
+    // not supported in kv
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        self.router_store
+            .find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+                state,
+                key_store,
+                merchant_id,
+                network_token_requestor_reference_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     // not supported in kv
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
@@ -351,6 +376,34 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_list_by_merchant_id_status(
+                state,
+                key_store,
+                merchant_id,
+                status,
+                limit,
+                offset,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     #[instrument(skip_all)]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
@@ -487,6 +540,32 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.call_database(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_network_token_requestor_reference_id(
+                &conn,
+                merchant_id,
+                network_token_requestor_reference_id,
+            ),
+        )
+        .await
+    }
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -664,6 +743,30 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    #[instrument(skip_all)]
+    async fn find_payment_method_list_by_merchant_id_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_status(&conn, merchant_id, status, limit, offset),
+        )
+        .await
+    }
+
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     #[instrument(skip_all)]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
@@ -820,6 +923,33 @@ impl PaymentMethodInterface for MockDb {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        self.get_resource::<PaymentMethod, _>(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.network_token_requestor_reference_id.as_deref()
+                        == Some(network_token_requestor_reference_id)
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+    }
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -936,6 +1066,31 @@ impl PaymentMethodInterface for MockDb {
         .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_list_by_merchant_id_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        status: common_enums::PaymentMethodStatus,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        self.get_resources(
+            state,
+            key_store,
+            payment_methods,
+            |pm| pm.merchant_id == *merchant_id && pm.status == status,
+            "cannot find payment method".to_string(),
+        )
+        .await
+    }
+
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
         &self,
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS card_bin_range_import_version_range_end_index;
+
+DROP TABLE IF EXISTS card_bin_range;
+
+DROP TABLE IF EXISTS card_bin_import;
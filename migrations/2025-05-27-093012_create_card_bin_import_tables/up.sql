@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS card_bin_import (
+    version INTEGER PRIMARY KEY,
+    source VARCHAR(255) NOT NULL,
+    file_format VARCHAR(16) NOT NULL,
+    total_ranges INTEGER NOT NULL,
+    ranges_added INTEGER NOT NULL,
+    ranges_removed INTEGER NOT NULL,
+    ranges_modified INTEGER NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS card_bin_range (
+    import_version INTEGER NOT NULL,
+    range_start VARCHAR(19) NOT NULL,
+    range_end VARCHAR(19) NOT NULL,
+    card_issuer TEXT,
+    card_network TEXT,
+    card_type TEXT,
+    card_subtype TEXT,
+    card_issuing_country TEXT,
+    bank_code_id VARCHAR(32),
+    bank_code VARCHAR(32),
+    country_code VARCHAR(32),
+    PRIMARY KEY (import_version, range_start, range_end)
+);
+
+CREATE INDEX IF NOT EXISTS card_bin_range_import_version_range_end_index ON card_bin_range (import_version, range_end);
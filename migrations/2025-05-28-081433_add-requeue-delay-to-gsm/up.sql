@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map ADD COLUMN IF NOT EXISTS requeue_delay_in_seconds INTEGER;
\ No newline at end of file
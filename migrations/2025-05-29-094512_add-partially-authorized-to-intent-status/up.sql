@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE IF NOT EXISTS 'partially_authorized_and_requires_payment_method';
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS enable_partial_authorization BOOLEAN;
\ No newline at end of file
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS local_vault_merchant_id_customer_id_fingerprint_id_index;
+
+DROP TABLE IF EXISTS local_vault;
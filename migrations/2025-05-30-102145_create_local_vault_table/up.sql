@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS local_vault (
+    vault_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    fingerprint_id VARCHAR(128) NOT NULL,
+    data_encryption_key BYTEA NOT NULL,
+    encrypted_data BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, vault_id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS local_vault_merchant_id_customer_id_fingerprint_id_index ON local_vault (merchant_id, customer_id, fingerprint_id);
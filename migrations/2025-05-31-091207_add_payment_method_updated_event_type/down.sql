@@ -0,0 +1,18 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_method_updated'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_method_details'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_methods'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS network_token_status VARCHAR(32);
+
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_network_token_requestor_reference_id_index ON payment_methods (merchant_id, network_token_requestor_reference_id);
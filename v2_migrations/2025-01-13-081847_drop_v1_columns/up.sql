@@ -65,7 +65,8 @@ ALTER TABLE payment_intent DROP COLUMN payment_id,
     DROP COLUMN merchant_order_reference_id,
     DROP COLUMN is_payment_processor_token_flow,
     DROP COLUMN charges,
-    DROP COLUMN extended_return_url;
+    DROP COLUMN extended_return_url,
+    DROP COLUMN enable_partial_authorization;
 
 -- Run below queries only when V1 is deprecated
 ALTER TABLE payment_attempt DROP COLUMN attempt_id,
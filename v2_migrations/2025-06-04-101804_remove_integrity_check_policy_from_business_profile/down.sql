@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS integrity_check_policy JSONB;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE business_profile DROP COLUMN IF EXISTS integrity_check_policy;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS fee_schedule JSONB;
\ No newline at end of file
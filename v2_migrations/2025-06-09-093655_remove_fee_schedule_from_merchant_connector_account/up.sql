@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account DROP COLUMN IF EXISTS fee_schedule;
\ No newline at end of file
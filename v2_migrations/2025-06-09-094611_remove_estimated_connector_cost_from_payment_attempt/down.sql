@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS estimated_connector_cost BIGINT;
\ No newline at end of file
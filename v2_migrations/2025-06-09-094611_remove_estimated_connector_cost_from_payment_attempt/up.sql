@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS estimated_connector_cost;
\ No newline at end of file